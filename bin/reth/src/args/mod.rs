@@ -43,6 +43,12 @@ pub use dev_args::DevArgs;
 mod pruning_args;
 pub use pruning_args::PruningArgs;
 
+/// RollupArgs for configuring the OP Stack rollup node
+#[cfg(feature = "optimism")]
+mod rollup_args;
+#[cfg(feature = "optimism")]
+pub use rollup_args::RollupArgs;
+
 pub mod utils;
 
 pub mod types;
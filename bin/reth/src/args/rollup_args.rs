@@ -0,0 +1,113 @@
+//! clap [Args](clap::Args) for OP Stack rollup configuration
+
+use crate::args::utils::parse_duration_from_secs;
+use clap::Args;
+use reth_rpc::eth::SequencerConfig;
+use std::time::Duration;
+
+/// Parameters for configuring an OP Stack rollup node
+#[derive(Debug, Clone, Args, PartialEq, Eq, Default)]
+#[command(next_help_heading = "Rollup")]
+pub struct RollupArgs {
+    /// HTTP endpoint of the sequencer that `eth_sendRawTransaction` forwards transactions to
+    #[arg(long = "rollup.sequencer-http", value_name = "HTTP_URL")]
+    pub sequencer_http: Option<String>,
+
+    /// Don't insert transactions forwarded to the sequencer into the local pool
+    #[arg(long = "rollup.disable-local-pool-copy", requires = "sequencer_http")]
+    pub disable_local_pool_copy: bool,
+
+    /// Timeout of the requests to the sequencer, in seconds [default: 10]
+    #[arg(
+        long = "rollup.sequencer-timeout",
+        value_parser = parse_duration_from_secs,
+        value_name = "SECONDS",
+        requires = "sequencer_http"
+    )]
+    pub sequencer_timeout: Option<Duration>,
+}
+
+impl RollupArgs {
+    /// Returns the sequencer forwarding config, if a sequencer endpoint is configured.
+    pub fn sequencer_config(&self) -> Option<SequencerConfig> {
+        self.sequencer_http.as_ref().map(|endpoint| {
+            let config = SequencerConfig::new(endpoint.clone())
+                .local_pool_copy(!self.disable_local_pool_copy);
+            match self.sequencer_timeout {
+                Some(timeout) => config.timeout(timeout),
+                None => config,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[clap(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_rollup_args() {
+        let args = CommandParser::<RollupArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, RollupArgs::default());
+        assert_eq!(args.sequencer_config(), None);
+
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.sequencer-http",
+            "https://mainnet-sequencer.optimism.io",
+        ])
+        .args;
+        assert_eq!(
+            args.sequencer_config(),
+            Some(SequencerConfig::new("https://mainnet-sequencer.optimism.io"))
+        );
+
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.sequencer-http",
+            "https://mainnet-sequencer.optimism.io",
+            "--rollup.disable-local-pool-copy",
+        ])
+        .args;
+        assert_eq!(
+            args.sequencer_config(),
+            Some(
+                SequencerConfig::new("https://mainnet-sequencer.optimism.io")
+                    .local_pool_copy(false)
+            )
+        );
+
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.sequencer-http",
+            "https://mainnet-sequencer.optimism.io",
+            "--rollup.sequencer-timeout",
+            "3",
+        ])
+        .args;
+        assert_eq!(
+            args.sequencer_config(),
+            Some(
+                SequencerConfig::new("https://mainnet-sequencer.optimism.io")
+                    .timeout(Duration::from_secs(3))
+            )
+        );
+    }
+
+    #[test]
+    fn test_local_pool_copy_requires_sequencer() {
+        let res = CommandParser::<RollupArgs>::try_parse_from([
+            "reth",
+            "--rollup.disable-local-pool-copy",
+        ]);
+        assert!(res.is_err());
+    }
+}
@@ -13,10 +13,12 @@ use clap::{
     Arg, Args, Command,
 };
 use futures::TryFutureExt;
+use reth_beacon_consensus::BeaconConsensusEngineHandle;
 use reth_network_api::{NetworkInfo, Peers};
 use reth_provider::{
-    AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    EvmEnvProvider, HeaderProvider, StateProviderFactory,
+    AccountReader, AddressAppearancesReader, BadBlocksReader, BlockReaderIdExt,
+    CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider,
+    StateProviderFactory, TransactionTracesReader,
 };
 use reth_rpc::{
     eth::{
@@ -24,7 +26,7 @@ use reth_rpc::{
             DEFAULT_BLOCK_CACHE_MAX_LEN, DEFAULT_ENV_CACHE_MAX_LEN, DEFAULT_RECEIPT_CACHE_MAX_LEN,
         },
         gas_oracle::GasPriceOracleConfig,
-        RPC_DEFAULT_GAS_CAP,
+        KeystoreSigner, DEFAULT_ETH_PROOF_WINDOW, MAX_ETH_PROOF_WINDOW, RPC_DEFAULT_GAS_CAP,
     },
     JwtError, JwtSecret,
 };
@@ -45,6 +47,9 @@ use std::{
 };
 use tracing::{debug, info};
 
+#[cfg(feature = "optimism")]
+use crate::args::RollupArgs;
+
 /// Default max number of subscriptions per connection.
 pub(crate) const RPC_DEFAULT_MAX_SUBS_PER_CONN: u32 = 1024;
 /// Default max request size in MB.
@@ -161,6 +166,15 @@ pub struct RpcServerArgs {
     #[arg(long, value_name = "COUNT", default_value_t = ZeroAsNone::new(constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64))]
     pub rpc_max_logs_per_response: ZeroAsNone,
 
+    /// Maximum number of blocks whose changesets can be read per `debug_getModifiedAccountsBy*`
+    /// or `reth_getStateDiff` request. (0 = no limit)
+    #[arg(long, value_name = "COUNT", default_value_t = ZeroAsNone::new(constants::DEFAULT_MAX_CHANGESET_BLOCKS))]
+    pub rpc_max_changeset_blocks: ZeroAsNone,
+
+    /// Maximum number of blocks that can be traced per `trace_filter` request. (0 = no limit)
+    #[arg(long, value_name = "COUNT", default_value_t = ZeroAsNone::new(constants::DEFAULT_MAX_TRACE_FILTER_BLOCKS))]
+    pub rpc_max_trace_filter_blocks: ZeroAsNone,
+
     /// Maximum gas limit for `eth_call` and call tracing RPC methods.
     #[arg(
         long,
@@ -171,6 +185,32 @@ pub struct RpcServerArgs {
     )]
     pub rpc_gas_cap: u64,
 
+    /// Maximum number of blocks behind the tip for which `eth_getProof` generates historical
+    /// proofs. (0 = latest block only)
+    #[arg(
+        long = "rpc.eth-proof-window",
+        value_name = "COUNT",
+        value_parser = RangedU64ValueParser::<u64>::new().range(..=MAX_ETH_PROOF_WINDOW),
+        default_value_t = DEFAULT_ETH_PROOF_WINDOW
+    )]
+    pub rpc_eth_proof_window: u64,
+
+    /// Directory for the trace files written by `debug_standardTraceBlockToFile`.
+    ///
+    /// Defaults to `<DIR>/<CHAIN_ID>/traces` for the node.
+    #[arg(long = "rpc.trace-dir", value_name = "PATH")]
+    pub rpc_trace_dir: Option<PathBuf>,
+
+    /// Path to an encrypted keystore file, or a directory of keystore files, whose accounts are
+    /// unlocked for `eth_sign`, `eth_signTransaction`, `eth_signTypedData` and
+    /// `eth_sendTransaction`.
+    #[arg(long = "rpc.keystore", value_name = "PATH", requires = "rpc_keystore_password_file")]
+    pub rpc_keystore: Option<PathBuf>,
+
+    /// Path to the file containing the password of the keystore files.
+    #[arg(long = "rpc.keystore-password-file", value_name = "PATH", requires = "rpc_keystore")]
+    pub rpc_keystore_password_file: Option<PathBuf>,
+
     /// Gas price oracle configuration.
     #[clap(flatten)]
     pub gas_price_oracle: GasPriceOracleArgs,
@@ -186,6 +226,11 @@ pub struct RpcServerArgs {
     /// Maximum number of env cache entries.
     #[arg(long, default_value_t = DEFAULT_ENV_CACHE_MAX_LEN)]
     pub env_cache_len: u32,
+
+    /// OP Stack rollup configuration.
+    #[cfg(feature = "optimism")]
+    #[clap(flatten)]
+    pub rollup: RollupArgs,
 }
 
 impl RpcServerArgs {
@@ -199,6 +244,7 @@ impl RpcServerArgs {
         &self,
         components: &Reth,
         engine_api: Engine,
+        engine_handle: BeaconConsensusEngineHandle,
         jwt_secret: JwtSecret,
         conf: &mut Conf,
     ) -> eyre::Result<RethRpcServerHandles>
@@ -212,13 +258,20 @@ impl RpcServerArgs {
         let module_config = self.transport_rpc_module_config();
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
-        let (mut modules, auth_module, mut registry) = RpcModuleBuilder::default()
+        let mut builder = RpcModuleBuilder::default()
             .with_provider(components.provider())
             .with_pool(components.pool())
             .with_network(components.network())
             .with_events(components.events())
             .with_executor(components.task_executor())
-            .build_with_auth_server(module_config, engine_api);
+            .with_engine_handle(engine_handle)
+            .with_bundle_pool(components.bundle_pool());
+        if let Some(keystore_signer) = self.keystore_signer()? {
+            info!(target: "reth::cli", accounts=?keystore_signer.addresses(), "Unlocked keystore accounts");
+            builder = builder.with_keystore_signer(keystore_signer);
+        }
+        let (mut modules, auth_module, mut registry) =
+            builder.build_with_auth_server(module_config, engine_api);
 
         let rpc_components = RethRpcComponents { registry: &mut registry, modules: &mut modules };
         // apply configured customization
@@ -255,6 +308,19 @@ impl RpcServerArgs {
         Ok(handles)
     }
 
+    /// Unlocks the configured keystore, if any.
+    fn keystore_signer(&self) -> eyre::Result<Option<KeystoreSigner>> {
+        let (Some(keystore), Some(password_file)) =
+            (&self.rpc_keystore, &self.rpc_keystore_password_file)
+        else {
+            return Ok(None)
+        };
+        let password = reth_primitives::fs::read_to_string(password_file)?;
+        let password = password.trim_end_matches(['\r', '\n']);
+        let signer = KeystoreSigner::unlock(keystore, password)?;
+        Ok(Some(signer))
+    }
+
     /// Convenience function for starting a rpc server with configs which extracted from cli args.
     pub async fn start_rpc_server<Provider, Pool, Network, Tasks, Events>(
         &self,
@@ -272,6 +338,9 @@ impl RpcServerArgs {
             + EvmEnvProvider
             + ChainSpecProvider
             + ChangeSetReader
+            + BadBlocksReader
+            + AddressAppearancesReader
+            + TransactionTracesReader
             + Clone
             + Unpin
             + 'static,
@@ -341,12 +410,23 @@ impl RethRpcConfig for RpcServerArgs {
     }
 
     fn eth_config(&self) -> EthConfig {
-        EthConfig::default()
+        let config = EthConfig::default()
             .max_tracing_requests(self.rpc_max_tracing_requests)
             .max_blocks_per_filter(self.rpc_max_blocks_per_filter.unwrap_or_max())
             .max_logs_per_response(self.rpc_max_logs_per_response.unwrap_or_max() as usize)
             .rpc_gas_cap(self.rpc_gas_cap)
-            .gpo_config(self.gas_price_oracle_config())
+            .eth_proof_window(self.rpc_eth_proof_window)
+            .gpo_config(self.gas_price_oracle_config());
+
+        // forward raw transactions to the sequencer, if configured
+        #[cfg(feature = "optimism")]
+        {
+            config.sequencer(self.rollup.sequencer_config())
+        }
+        #[cfg(not(feature = "optimism"))]
+        {
+            config
+        }
     }
 
     fn rpc_max_request_size_bytes(&self) -> u32 {
@@ -367,8 +447,14 @@ impl RethRpcConfig for RpcServerArgs {
     }
 
     fn transport_rpc_module_config(&self) -> TransportRpcModuleConfig {
-        let mut config = TransportRpcModuleConfig::default()
-            .with_config(RpcModuleConfig::new(self.eth_config()));
+        let mut module_config = RpcModuleConfig::builder()
+            .eth(self.eth_config())
+            .max_changeset_blocks(self.rpc_max_changeset_blocks.unwrap_or_max())
+            .max_trace_filter_blocks(self.rpc_max_trace_filter_blocks.unwrap_or_max());
+        if let Some(trace_dir) = &self.rpc_trace_dir {
+            module_config = module_config.trace_dir(trace_dir.clone());
+        }
+        let mut config = TransportRpcModuleConfig::default().with_config(module_config.build());
 
         if self.http {
             config = config.with_http(
@@ -523,6 +609,43 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_rpc_eth_proof_window() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.eth_config().eth_proof_window, DEFAULT_ETH_PROOF_WINDOW);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.eth-proof-window", "500"])
+                .args;
+        assert_eq!(args.eth_config().eth_proof_window, 500);
+
+        let too_large = (MAX_ETH_PROOF_WINDOW + 1).to_string();
+        let args = CommandParser::<RpcServerArgs>::try_parse_from([
+            "reth",
+            "--rpc.eth-proof-window",
+            &too_large,
+        ]);
+        assert!(args.is_err());
+    }
+
+    #[test]
+    fn test_rpc_keystore_requires_password_file() {
+        let args =
+            CommandParser::<RpcServerArgs>::try_parse_from(["reth", "--rpc.keystore", "keystore"]);
+        assert!(args.is_err());
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.keystore",
+            "keystore",
+            "--rpc.keystore-password-file",
+            "password.txt",
+        ])
+        .args;
+        assert_eq!(args.rpc_keystore, Some(PathBuf::from("keystore")));
+        assert_eq!(args.rpc_keystore_password_file, Some(PathBuf::from("password.txt")));
+    }
+
     #[test]
     fn test_rpc_server_args_parser() {
         let args =
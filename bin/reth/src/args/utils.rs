@@ -4,6 +4,8 @@ use reth_primitives::{
     fs, AllGenesisFormats, BlockHashOrNumber, ChainSpec, B256, DEV, GOERLI, HOLESKY, MAINNET,
     SEPOLIA,
 };
+#[cfg(feature = "optimism")]
+use reth_primitives::{BASE_GOERLI, OP_GOERLI, OP_MAINNET};
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs},
     path::PathBuf,
@@ -27,6 +29,12 @@ pub fn chain_spec_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Er
         "sepolia" => SEPOLIA.clone(),
         "holesky" => HOLESKY.clone(),
         "dev" => DEV.clone(),
+        #[cfg(feature = "optimism")]
+        "optimism" => OP_MAINNET.clone(),
+        #[cfg(feature = "optimism")]
+        "optimism_goerli" => OP_GOERLI.clone(),
+        #[cfg(feature = "optimism")]
+        "base_goerli" => BASE_GOERLI.clone(),
         _ => {
             let raw = fs::read_to_string(PathBuf::from(shellexpand::full(s)?.into_owned()))?;
             serde_json::from_str(&raw)?
@@ -46,6 +54,12 @@ pub fn genesis_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error
         "sepolia" => SEPOLIA.clone(),
         "holesky" => HOLESKY.clone(),
         "dev" => DEV.clone(),
+        #[cfg(feature = "optimism")]
+        "optimism" => OP_MAINNET.clone(),
+        #[cfg(feature = "optimism")]
+        "optimism_goerli" => OP_GOERLI.clone(),
+        #[cfg(feature = "optimism")]
+        "base_goerli" => BASE_GOERLI.clone(),
         _ => {
             // try to read json from path first
             let mut raw =
@@ -164,6 +178,16 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn parse_known_optimism_chain_spec() {
+        for chain in ["optimism", "optimism_goerli", "base_goerli"] {
+            let spec = chain_spec_value_parser(chain).unwrap();
+            assert!(spec.is_optimism());
+            assert!(genesis_value_parser(chain).unwrap().is_optimism());
+        }
+    }
+
     #[test]
     fn parse_chain_spec_from_memory() {
         let custom_genesis_from_json = r#"
@@ -7,7 +7,7 @@ use crate::{
 use clap::Parser;
 use eyre::Context;
 use futures::{Stream, StreamExt};
-use reth_beacon_consensus::BeaconConsensus;
+use reth_beacon_consensus::consensus_for_chain;
 use reth_provider::{ProviderFactory, StageCheckpointReader};
 
 use crate::args::{utils::genesis_value_parser, DatabaseArgs};
@@ -56,6 +56,9 @@ pub struct ImportCommand {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
@@ -98,7 +101,7 @@ impl ImportCommand {
 
         init_genesis(db.clone(), self.chain.clone())?;
 
-        let consensus = Arc::new(BeaconConsensus::new(self.chain.clone()));
+        let consensus = consensus_for_chain(self.chain.clone());
         info!(target: "reth::cli", "Consensus engine initialized");
 
         // create a new FileClient
@@ -134,16 +137,15 @@ impl ImportCommand {
         Ok(())
     }
 
-    async fn build_import_pipeline<DB, C>(
+    async fn build_import_pipeline<DB>(
         &self,
         config: Config,
         db: DB,
-        consensus: &Arc<C>,
+        consensus: &Arc<dyn Consensus>,
         file_client: Arc<FileClient>,
     ) -> eyre::Result<(Pipeline<DB>, impl Stream<Item = NodeEvent>)>
     where
         DB: Database + Clone + Unpin + 'static,
-        C: Consensus + 'static,
     {
         if !file_client.has_canonical_blocks() {
             eyre::bail!("unable to import non canonical blocks");
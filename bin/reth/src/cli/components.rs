@@ -1,10 +1,12 @@
 //! Components that are used by the node command.
 
 use reth_network_api::{NetworkInfo, Peers};
+use reth_payload_builder::bundle::BundlePool;
 use reth_primitives::ChainSpec;
 use reth_provider::{
-    AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    EvmEnvProvider, StateProviderFactory,
+    AccountReader, AddressAppearancesReader, BadBlocksReader, BlockReaderIdExt,
+    CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    StateProviderFactory, TransactionTracesReader,
 };
 use reth_rpc_builder::{
     auth::AuthServerHandle, RethModuleRegistry, RpcServerHandle, TransportRpcModules,
@@ -21,6 +23,9 @@ pub trait FullProvider:
     + EvmEnvProvider
     + ChainSpecProvider
     + ChangeSetReader
+    + BadBlocksReader
+    + AddressAppearancesReader
+    + TransactionTracesReader
     + Clone
     + Unpin
     + 'static
@@ -34,6 +39,9 @@ impl<T> FullProvider for T where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + BadBlocksReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
         + Clone
         + Unpin
         + 'static
@@ -68,6 +76,10 @@ pub trait RethNodeComponents: Clone + Send + Sync + 'static {
     /// Returns the instance of the events subscription handler.
     fn events(&self) -> Self::Events;
 
+    /// Returns the pool of bundles that's shared by the bundle RPC namespaces and the payload
+    /// builder.
+    fn bundle_pool(&self) -> BundlePool;
+
     /// Helper function to return the chain spec.
     fn chain_spec(&self) -> Arc<ChainSpec> {
         self.provider().chain_spec()
@@ -108,6 +120,7 @@ pub struct RethNodeComponentsImpl<Provider, Pool, Network, Events, Tasks> {
     pub network: Network,
     pub task_executor: Tasks,
     pub events: Events,
+    pub bundle_pool: BundlePool,
 }
 
 impl<Provider, Pool, Network, Events, Tasks> RethNodeComponents
@@ -144,6 +157,10 @@ where
     fn events(&self) -> Self::Events {
         self.events.clone()
     }
+
+    fn bundle_pool(&self) -> BundlePool {
+        self.bundle_pool.clone()
+    }
 }
 
 /// Contains the handles to the spawned RPC servers.
@@ -112,7 +112,8 @@ pub trait RethNodeCommandConfig: fmt::Debug {
                 .extradata(conf.extradata_rlp_bytes())
                 .max_gas_limit(conf.max_gas_limit()),
             components.chain_spec(),
-        );
+        )
+        .with_bundle_pool(components.bundle_pool());
         let (payload_service, payload_builder) = PayloadBuilderService::new(payload_generator);
 
         components
@@ -42,6 +42,9 @@ pub struct Cli<Ext: RethCliExt = ()> {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
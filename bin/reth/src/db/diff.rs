@@ -16,11 +16,12 @@ use clap::Parser;
 
 use reth_db::{
     cursor::DbCursorRO, database::Database, open_db_read_only, table::Table, transaction::DbTx,
-    AccountChangeSet, AccountHistory, AccountsTrie, BlockBodyIndices, BlockOmmers,
-    BlockWithdrawals, Bytecodes, CanonicalHeaders, DatabaseEnvRO, HashedAccount, HashedStorage,
-    HeaderNumbers, HeaderTD, Headers, PlainAccountState, PlainStorageState, PruneCheckpoints,
-    Receipts, StorageChangeSet, StorageHistory, StoragesTrie, SyncStage, SyncStageProgress, Tables,
-    TransactionBlock, Transactions, TxHashNumber, TxSenders,
+    AccountChangeSet, AccountHistory, AccountsTrie, AddressAppearances, BadBlocks,
+    BlockBodyIndices, BlockOmmers, BlockWithdrawals, Bytecodes, CanonicalHeaders, DatabaseEnvRO,
+    HashedAccount, HashedStorage, HeaderNumbers, HeaderTD, Headers, PlainAccountState,
+    PlainStorageState, PruneCheckpoints, Receipts, StorageChangeSet, StorageHistory, StoragesTrie,
+    SyncStage, SyncStageProgress, Tables, TransactionBlock, TransactionTraces, Transactions,
+    TxHashNumber, TxSenders,
 };
 use tracing::info;
 
@@ -140,6 +141,13 @@ impl Command {
                 Tables::PruneCheckpoints => {
                     find_diffs::<PruneCheckpoints>(primary_tx, secondary_tx, output_dir)?
                 }
+                Tables::AddressAppearances => {
+                    find_diffs::<AddressAppearances>(primary_tx, secondary_tx, output_dir)?
+                }
+                Tables::BadBlocks => find_diffs::<BadBlocks>(primary_tx, secondary_tx, output_dir)?,
+                Tables::TransactionTraces => {
+                    find_diffs::<TransactionTraces>(primary_tx, secondary_tx, output_dir)?
+                }
             };
         }
 
@@ -0,0 +1,145 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use reth_db::database::Database;
+use reth_interfaces::{RethError, RethResult};
+use reth_primitives::{Account, BlockNumber, StorageEntry, B256};
+use reth_provider::{BlockNumReader, HeaderProvider, ProviderFactory, StateProvider, StateVisitor};
+use reth_rpc_types::DumpAccount;
+use reth_rpc_types_compat::state::from_primitive_dump_account;
+use serde::Serialize;
+use std::io::{self, BufWriter, Write};
+
+/// The arguments for the `reth db dump-state` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The block to dump the state at, defaults to the latest block
+    #[arg(long)]
+    block: Option<BlockNumber>,
+
+    /// Skips the code of the accounts
+    #[arg(long)]
+    nocode: bool,
+
+    /// Skips the storage of the accounts
+    #[arg(long)]
+    nostorage: bool,
+}
+
+/// The first line of the dump.
+#[derive(Serialize)]
+struct DumpRoot {
+    root: B256,
+}
+
+impl Command {
+    /// Execute `db dump-state` command
+    ///
+    /// Writes the state root of the block as the first line, followed by one line per account,
+    /// ordered by the hashes of their addresses, as in the state trie.
+    pub fn execute<DB: Database>(self, tool: &DbTool<'_, DB>) -> eyre::Result<()> {
+        let factory = ProviderFactory::new(tool.db, tool.chain.clone());
+        let block = match self.block {
+            Some(block) => block,
+            None => factory.best_block_number()?,
+        };
+        let root = factory
+            .header_by_number(block)?
+            .ok_or_else(|| eyre::eyre!("block {block} not found"))?
+            .state_root;
+        let state = factory.history_by_block_number(block)?;
+
+        let mut out = BufWriter::new(io::stdout().lock());
+        serde_json::to_writer(&mut out, &DumpRoot { root })?;
+        writeln!(out)?;
+
+        // the accounts and their storage are written as they're read from the state
+        let mut writer = DumpWriter {
+            out: &mut out,
+            state: &state,
+            nocode: self.nocode,
+            nostorage: self.nostorage,
+            has_storage: false,
+        };
+        state.visit_accounts(&mut writer, !self.nostorage)?;
+        out.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Writes each visited account as a line of the dump.
+///
+/// The storage of an account is written slot by slot as it's visited, so the line of the account
+/// is only closed once its storage is complete.
+struct DumpWriter<'a, W> {
+    out: W,
+    state: &'a dyn StateProvider,
+    nocode: bool,
+    nostorage: bool,
+    /// Whether a storage slot of the current account was written already.
+    has_storage: bool,
+}
+
+impl<W: Write> DumpWriter<'_, W> {
+    fn write_account(&mut self, account: &DumpAccount) -> io::Result<()> {
+        // leave the object open, the storage is appended to it
+        let mut json = serde_json::to_vec(account)?;
+        json.pop();
+        self.out.write_all(&json)?;
+        if !self.nostorage {
+            self.out.write_all(br#","storage":{"#)?;
+        }
+        self.has_storage = false;
+        Ok(())
+    }
+
+    fn write_storage(&mut self, entry: StorageEntry) -> io::Result<()> {
+        if self.has_storage {
+            self.out.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.out, &entry.key)?;
+        self.out.write_all(b":")?;
+        serde_json::to_writer(&mut self.out, &B256::from(entry.value))?;
+        self.has_storage = true;
+        Ok(())
+    }
+
+    fn write_account_end(&mut self) -> io::Result<()> {
+        if !self.nostorage {
+            self.out.write_all(b"}")?;
+        }
+        self.out.write_all(b"}\n")
+    }
+}
+
+impl<W: Write> StateVisitor for DumpWriter<'_, W> {
+    fn visit_account(
+        &mut self,
+        hashed_address: B256,
+        account: Account,
+        storage_root: B256,
+    ) -> RethResult<()> {
+        let code = match account.bytecode_hash {
+            Some(code_hash) if !self.nocode => {
+                self.state.bytecode_by_hash(code_hash)?.map(|code| code.original_bytes())
+            }
+            _ => None,
+        };
+        let account =
+            from_primitive_dump_account(hashed_address, account, storage_root, code, None);
+        self.write_account(&account).map_err(dump_error)
+    }
+
+    fn visit_storage(&mut self, entry: StorageEntry) -> RethResult<()> {
+        self.write_storage(entry).map_err(dump_error)
+    }
+
+    fn visit_account_end(&mut self) -> RethResult<()> {
+        self.write_account_end().map_err(dump_error)
+    }
+}
+
+/// Converts an error writing the dump into an error of the state visit.
+fn dump_error(err: io::Error) -> RethError {
+    RethError::Custom(format!("failed to write the state dump: {err}"))
+}
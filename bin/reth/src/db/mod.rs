@@ -22,6 +22,7 @@ use std::{
 
 mod clear;
 mod diff;
+mod dump_state;
 mod get;
 mod list;
 mod snapshots;
@@ -50,6 +51,9 @@ pub struct Command {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
@@ -78,6 +82,8 @@ pub enum Subcommands {
     Diff(diff::Command),
     /// Gets the content of a table for the given key
     Get(get::Command),
+    /// Dumps the state at a block as JSON lines
+    DumpState(dump_state::Command),
     /// Deletes all database entries
     Drop {
         /// Bypasses the interactive confirmation and drops the database directly
@@ -189,6 +195,11 @@ impl Command {
                 let tool = DbTool::new(&db, self.chain.clone())?;
                 command.execute(&tool)?;
             }
+            Subcommands::DumpState(command) => {
+                let db = open_db_read_only(&db_path, self.db.log_level)?;
+                let tool = DbTool::new(&db, self.chain.clone())?;
+                command.execute(&tool)?;
+            }
             Subcommands::Drop { force } => {
                 if !force {
                     // Ask for confirmation
@@ -10,7 +10,7 @@ use eyre::Context;
 use reth_basic_payload_builder::{
     default_payload_builder, BuildArguments, BuildOutcome, Cancelled, PayloadConfig,
 };
-use reth_beacon_consensus::BeaconConsensus;
+use reth_beacon_consensus::consensus_for_chain;
 use reth_blockchain_tree::{
     BlockchainTree, BlockchainTreeConfig, ShareableBlockchainTree, TreeExternals,
 };
@@ -63,6 +63,9 @@ pub struct Command {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
@@ -147,7 +150,7 @@ impl Command {
         // initialize the database
         let db = Arc::new(init_db(db_path, self.db.log_level)?);
 
-        let consensus: Arc<dyn Consensus> = Arc::new(BeaconConsensus::new(Arc::clone(&self.chain)));
+        let consensus: Arc<dyn Consensus> = consensus_for_chain(Arc::clone(&self.chain));
 
         // configure blockchain tree
         let tree_externals = TreeExternals::new(
@@ -245,11 +248,13 @@ impl Command {
             suggested_fee_recipient: self.suggested_fee_recipient,
             // TODO: add support for withdrawals
             withdrawals: None,
+            #[cfg(feature = "optimism")]
+            optimism_payload_attributes: Default::default(),
         };
         let payload_config = PayloadConfig::new(
             Arc::clone(&best_block),
             Bytes::default(),
-            PayloadBuilderAttributes::new(best_block.hash, payload_attrs),
+            PayloadBuilderAttributes::try_new(best_block.hash, payload_attrs)?,
             self.chain.clone(),
         );
         let args = BuildArguments::new(
@@ -9,7 +9,7 @@ use crate::{
 };
 use clap::Parser;
 use futures::{stream::select as stream_select, StreamExt};
-use reth_beacon_consensus::BeaconConsensus;
+use reth_beacon_consensus::consensus_for_chain;
 use reth_config::Config;
 use reth_db::{database::Database, init_db, DatabaseEnv};
 use reth_downloaders::{
@@ -63,6 +63,9 @@ pub struct Command {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
@@ -210,7 +213,7 @@ impl Command {
         debug!(target: "reth::cli", chain=%self.chain.chain, genesis=?self.chain.genesis_hash(), "Initializing genesis");
         init_genesis(db.clone(), self.chain.clone())?;
 
-        let consensus: Arc<dyn Consensus> = Arc::new(BeaconConsensus::new(Arc::clone(&self.chain)));
+        let consensus: Arc<dyn Consensus> = consensus_for_chain(Arc::clone(&self.chain));
 
         // Configure and build network
         let network_secret_path =
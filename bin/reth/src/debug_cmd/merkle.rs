@@ -7,7 +7,7 @@ use crate::{
 };
 use backon::{ConstantBuilder, Retryable};
 use clap::Parser;
-use reth_beacon_consensus::BeaconConsensus;
+use reth_beacon_consensus::consensus_for_chain;
 use reth_config::Config;
 use reth_db::{cursor::DbCursorRO, init_db, tables, transaction::DbTx, DatabaseEnv};
 use reth_interfaces::{consensus::Consensus, p2p::full_block::FullBlockClient};
@@ -56,6 +56,9 @@ pub struct Command {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
         long,
         value_name = "CHAIN_OR_PATH",
@@ -155,7 +158,7 @@ impl Command {
         info!(target: "reth::cli", target_block_number=self.to, "Finished downloading tip of block range");
 
         // build the full block client
-        let consensus: Arc<dyn Consensus> = Arc::new(BeaconConsensus::new(Arc::clone(&self.chain)));
+        let consensus: Arc<dyn Consensus> = consensus_for_chain(Arc::clone(&self.chain));
         let block_range_client = FullBlockClient::new(fetch_client, consensus);
 
         // get the execution checkpoint
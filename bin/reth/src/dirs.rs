@@ -293,6 +293,13 @@ impl<D> ChainPath<D> {
     pub fn jwt_path(&self) -> PathBuf {
         self.0.join("jwt.hex").into()
     }
+
+    /// Returns the path to the directory for trace files of this chain.
+    ///
+    /// `<DIR>/<CHAIN_ID>/traces`
+    pub fn traces_path(&self) -> PathBuf {
+        self.0.join("traces").into()
+    }
 }
 
 impl<D> AsRef<Path> for ChainPath<D> {
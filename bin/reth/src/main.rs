@@ -5,7 +5,10 @@ static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
 fn main() {
     // ensure we have the correct features enabled
+    #[cfg(not(feature = "optimism"))]
     reth::primitives::ensure_ethereum!();
+    #[cfg(feature = "optimism")]
+    reth::primitives::ensure_optimism!();
 
     if let Err(err) = reth::cli::run() {
         eprintln!("Error: {err:?}");
@@ -28,8 +28,9 @@ use futures::{future::Either, pin_mut, stream, stream_select, StreamExt};
 use metrics_exporter_prometheus::PrometheusHandle;
 use reth_auto_seal_consensus::{AutoSealBuilder, AutoSealConsensus, MiningMode};
 use reth_beacon_consensus::{
+    consensus_for_chain,
     hooks::{EngineHooks, PruneHook},
-    BeaconConsensus, BeaconConsensusEngine, MIN_BLOCKS_FOR_PIPELINE_RUN,
+    BeaconConsensusEngine, MIN_BLOCKS_FOR_PIPELINE_RUN,
 };
 use reth_blockchain_tree::{
     config::BlockchainTreeConfig, externals::TreeExternals, BlockchainTree, ShareableBlockchainTree,
@@ -51,6 +52,7 @@ use reth_interfaces::{
 };
 use reth_network::{error::NetworkError, NetworkConfig, NetworkHandle, NetworkManager};
 use reth_network_api::{NetworkInfo, PeersInfo};
+use reth_payload_builder::bundle::BundlePool;
 use reth_primitives::{
     constants::eip4844::{LoadKzgSettingsError, MAINNET_KZG_TRUSTED_SETUP},
     kzg::KzgSettings,
@@ -58,8 +60,8 @@ use reth_primitives::{
     BlockHashOrNumber, BlockNumber, ChainSpec, DisplayHardforks, Head, SealedHeader, B256,
 };
 use reth_provider::{
-    providers::BlockchainProvider, BlockHashReader, BlockReader, CanonStateSubscriptions,
-    HeaderProvider, ProviderFactory, StageCheckpointReader,
+    bad_blocks_channel, providers::BlockchainProvider, BadBlocksRecorder, BlockHashReader,
+    BlockReader, CanonStateSubscriptions, HeaderProvider, ProviderFactory, StageCheckpointReader,
 };
 use reth_prune::{segments::SegmentSet, Pruner};
 use reth_revm::Factory;
@@ -70,13 +72,15 @@ use reth_stages::{
     prelude::*,
     stages::{
         AccountHashingStage, ExecutionStage, ExecutionStageThresholds, HeaderSyncMode,
-        IndexAccountHistoryStage, IndexStorageHistoryStage, MerkleStage, SenderRecoveryStage,
-        StorageHashingStage, TotalDifficultyStage, TransactionLookupStage,
+        IndexAccountHistoryStage, IndexAddressAppearancesStage, IndexStorageHistoryStage,
+        MerkleStage, SenderRecoveryStage, StorageHashingStage, TotalDifficultyStage,
+        TransactionLookupStage, TransactionTracesStage,
     },
 };
 use reth_tasks::TaskExecutor;
 use reth_transaction_pool::{
-    blobstore::InMemoryBlobStore, TransactionPool, TransactionValidationTaskExecutor,
+    blobstore::InMemoryBlobStore, CoinbaseTipOrdering, TransactionPool,
+    TransactionValidationTaskExecutor,
 };
 use secp256k1::SecretKey;
 use std::{
@@ -87,6 +91,9 @@ use std::{
 use tokio::sync::{mpsc::unbounded_channel, oneshot, watch};
 use tracing::*;
 
+#[cfg(feature = "optimism")]
+use reth_transaction_pool::OpTransactionValidator;
+
 pub mod cl_events;
 pub mod events;
 
@@ -116,6 +123,9 @@ pub struct NodeCommand<Ext: RethCliExt = ()> {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     /// - dev
     #[arg(
         long,
@@ -271,6 +281,15 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
         let sync_metrics_listener = reth_stages::MetricsListener::new(sync_metrics_rx);
         ctx.task_executor.spawn_critical("stages metrics listener task", sync_metrics_listener);
 
+        debug!(target: "reth::cli", "Spawning bad blocks recorder task");
+        let (bad_blocks_tx, bad_blocks_rx) = bad_blocks_channel();
+        let bad_blocks_recorder = BadBlocksRecorder::new(
+            ProviderFactory::new(Arc::clone(&db), Arc::clone(&self.chain)),
+            bad_blocks_rx,
+        );
+        // the recorder blocks on the write transactions of the database
+        ctx.task_executor.spawn_critical_blocking("bad blocks recorder task", bad_blocks_recorder);
+
         let prune_config =
             self.pruning.prune_config(Arc::clone(&self.chain))?.or(config.prune.clone());
 
@@ -286,7 +305,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             BlockchainTreeConfig::default(),
             prune_config.clone().map(|config| config.segments),
         )?
-        .with_sync_metrics_tx(sync_metrics_tx.clone());
+        .with_sync_metrics_tx(sync_metrics_tx.clone())
+        .with_bad_blocks_tx(bad_blocks_tx.clone());
         let canon_state_notification_sender = tree.canon_state_notification_sender();
         let blockchain_tree = ShareableBlockchainTree::new(tree);
         debug!(target: "reth::cli", "configured blockchain tree");
@@ -304,8 +324,18 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             .with_additional_tasks(1)
             .build_with_tasks(blockchain_db.clone(), ctx.task_executor.clone(), blob_store.clone());
 
-        let transaction_pool =
-            reth_transaction_pool::Pool::eth_pool(validator, blob_store, self.txpool.pool_config());
+        // on OP Stack chains deposits are rejected and the L1 data fee is charged as well
+        #[cfg(feature = "optimism")]
+        let validator = validator.map(|validator| {
+            OpTransactionValidator::new(validator).with_head_timestamp(head.timestamp)
+        });
+
+        let transaction_pool = reth_transaction_pool::Pool::new(
+            validator,
+            CoinbaseTipOrdering::default(),
+            blob_store,
+            self.txpool.pool_config(),
+        );
         info!(target: "reth::cli", "Transaction pool initialized");
 
         // spawn txpool maintenance task
@@ -358,6 +388,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             network: network.clone(),
             task_executor: ctx.task_executor.clone(),
             events: blockchain_db.clone(),
+            bundle_pool: BundlePool::default(),
         };
         self.ext.on_components_initialized(&components)?;
 
@@ -455,7 +486,8 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
         let mut hooks = EngineHooks::new();
 
         let pruner_events = if let Some(prune_config) = prune_config {
-            let mut pruner = self.build_pruner(&prune_config, db.clone(), highest_snapshots_rx);
+            let mut pruner =
+                self.build_pruner(&prune_config, db.clone(), highest_snapshots_rx.clone());
 
             let events = pruner.events();
             hooks.add(PruneHook::new(pruner, Box::new(ctx.task_executor.clone())));
@@ -488,7 +520,9 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             consensus_engine_tx,
             consensus_engine_rx,
             hooks,
+            highest_snapshots_rx,
         )?;
+        let beacon_consensus_engine = beacon_consensus_engine.with_bad_blocks_tx(bad_blocks_tx);
         info!(target: "reth::cli", "Consensus engine initialized");
 
         let events = stream_select!(
@@ -513,7 +547,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
         let engine_api = EngineApi::new(
             blockchain_db.clone(),
             self.chain.clone(),
-            beacon_engine_handle,
+            beacon_engine_handle.clone(),
             payload_builder.into(),
             Box::new(ctx.task_executor.clone()),
         );
@@ -526,9 +560,14 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
         // adjust rpc port numbers based on instance number
         self.adjust_instance_ports();
 
+        // write trace files to the datadir, unless configured otherwise
+        self.rpc.rpc_trace_dir.get_or_insert_with(|| data_dir.traces_path());
+
         // Start RPC servers
-        let _rpc_server_handles =
-            self.rpc.start_servers(&components, engine_api, jwt_secret, &mut self.ext).await?;
+        let _rpc_server_handles = self
+            .rpc
+            .start_servers(&components, engine_api, beacon_engine_handle, jwt_secret, &mut self.ext)
+            .await?;
 
         // Run consensus engine to completion
         let (tx, rx) = oneshot::channel();
@@ -555,13 +594,15 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
 
     /// Returns the [Consensus] instance to use.
     ///
-    /// By default this will be a [BeaconConsensus] instance, but if the `--dev` flag is set, it
-    /// will be an [AutoSealConsensus] instance.
+    /// By default this will be a [BeaconConsensus](reth_beacon_consensus::BeaconConsensus)
+    /// instance, or an [OptimismBeaconConsensus](reth_beacon_consensus::OptimismBeaconConsensus)
+    /// instance for OP Stack chains, but if the `--dev` flag is set, it will be an
+    /// [AutoSealConsensus] instance.
     pub fn consensus(&self) -> Arc<dyn Consensus> {
         if self.dev.dev {
             Arc::new(AutoSealConsensus::new(Arc::clone(&self.chain)))
         } else {
-            Arc::new(BeaconConsensus::new(Arc::clone(&self.chain)))
+            consensus_for_chain(Arc::clone(&self.chain))
         }
     }
 
@@ -835,6 +876,7 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
 
         let stack_config = InspectorStackConfig {
             use_printer_tracer: self.debug.print_inspector,
+            tracing: None,
             hook: if let Some(hook_block) = self.debug.hook_block {
                 Hook::Block(hook_block)
             } else if let Some(tx) = self.debug.hook_transaction {
@@ -907,7 +949,26 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
                 .set(IndexStorageHistoryStage::new(
                     stage_config.index_storage_history.commit_threshold,
                     prune_modes.storage_history,
-                )),
+                ))
+                .add_before(
+                    TransactionTracesStage::new(
+                        stage_config.transaction_traces.commit_threshold,
+                        prune_modes.transaction_traces,
+                    ),
+                    StageId::Finish,
+                )
+                .disable_if(StageId::TransactionTraces, || !stage_config.transaction_traces.enabled)
+                // the address appearances include the internal calls of the recorded traces
+                .add_before(
+                    IndexAddressAppearancesStage::new(
+                        stage_config.index_address_appearances.commit_threshold,
+                        prune_modes.address_appearances,
+                    ),
+                    StageId::Finish,
+                )
+                .disable_if(StageId::IndexAddressAppearances, || {
+                    !stage_config.index_address_appearances.enabled
+                }),
             )
             .build(db, self.chain.clone());
 
@@ -948,6 +1009,20 @@ impl<Ext: RethCliExt> NodeCommand<Ext> {
             // Storage history
             .segment_opt(
                 config.segments.storage_history.map(reth_prune::segments::StorageHistory::new),
+            )
+            // Address appearances
+            .segment_opt(
+                config
+                    .segments
+                    .address_appearances
+                    .map(reth_prune::segments::AddressAppearances::new),
+            )
+            // Transaction traces
+            .segment_opt(
+                config
+                    .segments
+                    .transaction_traces
+                    .map(reth_prune::segments::TransactionTraces::new),
             );
 
         Pruner::new(
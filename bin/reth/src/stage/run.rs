@@ -8,7 +8,7 @@ use crate::{
     version::SHORT_VERSION,
 };
 use clap::Parser;
-use reth_beacon_consensus::BeaconConsensus;
+use reth_beacon_consensus::consensus_for_chain;
 use reth_config::Config;
 use reth_db::init_db;
 use reth_downloaders::bodies::bodies::BodiesDownloaderBuilder;
@@ -51,6 +51,9 @@ pub struct Command {
     /// - goerli
     /// - sepolia
     /// - holesky
+    #[cfg_attr(feature = "optimism", doc = " - optimism")]
+    #[cfg_attr(feature = "optimism", doc = " - optimism_goerli")]
+    #[cfg_attr(feature = "optimism", doc = " - base_goerli")]
     #[arg(
     long,
     value_name = "CHAIN_OR_PATH",
@@ -145,7 +148,7 @@ impl Command {
         let (mut exec_stage, mut unwind_stage): (Box<dyn Stage<_>>, Option<Box<dyn Stage<_>>>) =
             match self.stage {
                 StageEnum::Bodies => {
-                    let consensus = Arc::new(BeaconConsensus::new(self.chain.clone()));
+                    let consensus = consensus_for_chain(self.chain.clone());
 
                     let mut config = config;
                     config.peers.connect_trusted_nodes_only = self.network.trusted_only;
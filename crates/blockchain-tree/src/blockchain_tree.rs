@@ -6,7 +6,7 @@ use crate::{
     state::{BlockChainId, TreeState},
     AppendableChain, BlockIndices, BlockchainTreeConfig, BundleStateData, TreeExternals,
 };
-use reth_db::database::Database;
+use reth_db::{database::Database, models::StoredBadBlock};
 use reth_interfaces::{
     blockchain_tree::{
         error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
@@ -22,11 +22,11 @@ use reth_primitives::{
 };
 use reth_provider::{
     chain::{ChainSplit, SplitAt},
-    BlockExecutionWriter, BlockNumReader, BlockWriter, BundleStateWithReceipts,
+    BadBlocksSender, BlockExecutionWriter, BlockNumReader, BlockWriter, BundleStateWithReceipts,
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotifications, Chain,
     DatabaseProvider, DisplayBlocksChain, ExecutorFactory, HeaderProvider,
 };
-use reth_stages::{MetricEvent, MetricEventsSender};
+use reth_stages::{stages::record_appended_transaction_traces, MetricEvent, MetricEventsSender};
 use std::{
     collections::{BTreeMap, HashMap},
     sync::Arc,
@@ -89,6 +89,8 @@ pub struct BlockchainTree<DB: Database, EF: ExecutorFactory> {
     metrics: TreeMetrics,
     /// Metrics for sync stages.
     sync_metrics_tx: Option<MetricEventsSender>,
+    /// Sender of the blocks that were rejected as invalid, to be recorded.
+    bad_blocks_tx: Option<BadBlocksSender>,
     prune_modes: Option<PruneModes>,
 }
 
@@ -132,6 +134,7 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
             canon_state_notification_sender,
             metrics: Default::default(),
             sync_metrics_tx: None,
+            bad_blocks_tx: None,
             prune_modes,
         })
     }
@@ -142,6 +145,13 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
         self
     }
 
+    /// Set the sender of the blocks that are rejected as invalid, see
+    /// [BadBlocksRecorder](reth_provider::BadBlocksRecorder).
+    pub fn with_bad_blocks_tx(mut self, bad_blocks_tx: BadBlocksSender) -> Self {
+        self.bad_blocks_tx = Some(bad_blocks_tx);
+        self
+    }
+
     /// Check if then block is known to blockchain tree or database and return its status.
     ///
     /// Function will check:
@@ -624,13 +634,32 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
     pub fn buffer_block(&mut self, block: SealedBlockWithSenders) -> Result<(), InsertBlockError> {
         // validate block consensus rules
         if let Err(err) = self.validate_block(&block) {
-            return Err(InsertBlockError::consensus_error(err, block.block))
+            return Err(
+                self.on_insert_block_error(InsertBlockError::consensus_error(err, block.block))
+            )
         }
 
         self.state.buffered_blocks.insert_block(block);
         Ok(())
     }
 
+    /// Records the block of the error as a bad block if it was rejected for being invalid, so it
+    /// can be inspected later on.
+    ///
+    /// The block is only sent to be recorded, since the database may be locked for writing, e.g.
+    /// by the pipeline. It's dropped if the recorder can't keep up.
+    fn on_insert_block_error(&self, err: InsertBlockError) -> InsertBlockError {
+        if let Some(bad_blocks_tx) = self.bad_blocks_tx.as_ref() {
+            if err.kind().is_invalid_block() {
+                let _ = bad_blocks_tx.try_send(StoredBadBlock {
+                    block: err.block().clone(),
+                    error: err.kind().to_string(),
+                });
+            }
+        }
+        err
+    }
+
     /// Validate if block is correct and satisfies all the consensus rules that concern the header
     /// and block body itself.
     fn validate_block(&self, block: &SealedBlockWithSenders) -> Result<(), ConsensusError> {
@@ -713,11 +742,14 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
 
         // validate block consensus rules
         if let Err(err) = self.validate_block(&block) {
-            return Err(InsertBlockError::consensus_error(err, block.block))
+            return Err(
+                self.on_insert_block_error(InsertBlockError::consensus_error(err, block.block))
+            )
         }
 
         Ok(InsertPayloadOk::Inserted(
-            self.try_insert_validated_block(block, block_validation_kind)?,
+            self.try_insert_validated_block(block, block_validation_kind)
+                .map_err(|err| self.on_insert_block_error(err))?,
         ))
     }
 
@@ -1059,7 +1091,7 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
             }
         }
 
-        let head = chain_notification.tip().header.clone();
+        let head = chain_notification.tip().expect("new chain is not empty; qed").header.clone();
 
         // send notification about new canonical chain.
         let _ = self.canon_state_notification_sender.send(chain_notification);
@@ -1092,6 +1124,7 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
             self.externals.chain_spec.clone(),
         );
 
+        let range = chain.first().number..=chain.tip().number;
         let (blocks, state) = chain.into_inner();
 
         provider
@@ -1102,6 +1135,13 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
             )
             .map_err(|e| BlockExecutionError::CanonicalCommit { inner: e.to_string() })?;
 
+        // The optional transaction traces and address appearance index would otherwise only be
+        // updated when the pipeline runs. The address appearances include the internal calls of the
+        // traces, so they're updated last.
+        record_appended_transaction_traces(&provider, range.clone())
+            .map_err(|e| BlockExecutionError::CanonicalCommit { inner: e.to_string() })?;
+        provider.index_appended_address_appearances(range)?;
+
         provider.commit()?;
 
         Ok(())
@@ -1119,6 +1159,12 @@ impl<DB: Database, EF: ExecutorFactory> BlockchainTree<DB, EF> {
         // check if there is block in chain
         if let Some(old_canon_chain) = old_canon_chain {
             self.block_indices_mut().unwind_canonical_chain(unwind_to);
+
+            // send notification about the reverted canonical chain.
+            let _ = self
+                .canon_state_notification_sender
+                .send(CanonStateNotification::Revert { old: Arc::new(old_canon_chain.clone()) });
+
             // insert old canonical chain to BlockchainTree.
             self.insert_chain(AppendableChain::new(old_canon_chain));
         }
@@ -1201,18 +1247,23 @@ mod tests {
     use assert_matches::assert_matches;
     use linked_hash_set::LinkedHashSet;
     use reth_db::{
-        tables,
+        models::{AccountBeforeTx, ShardedKey, StoredTraceKind},
+        tables::{self, BlockNumberList},
         test_utils::{create_test_rw_db, TempDatabase},
         transaction::DbTxMut,
         DatabaseEnv,
     };
     use reth_interfaces::test_utils::TestConsensus;
     use reth_primitives::{
-        constants::EMPTY_ROOT_HASH, stage::StageCheckpoint, ChainSpecBuilder, B256, MAINNET,
+        constants::EMPTY_ROOT_HASH,
+        stage::{StageCheckpoint, StageId},
+        Account, ChainSpecBuilder, B256, MAINNET,
     };
     use reth_provider::{
+        bad_blocks_channel,
         test_utils::{blocks::BlockChainTestData, TestExecutorFactory},
-        BlockWriter, BundleStateWithReceipts, ProviderFactory,
+        BadBlocksReader, BlockWriter, BundleStateWithReceipts, ProviderFactory,
+        StageCheckpointReader, StageCheckpointWriter, TransactionTracesReader,
     };
     use std::{collections::HashSet, sync::Arc};
 
@@ -1328,6 +1379,107 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn records_bad_blocks() {
+        let data = BlockChainTestData::default_with_numbers(11, 12);
+        let (block1, _) = data.blocks[0].clone();
+
+        let consensus = Arc::new(TestConsensus::default());
+        let mut externals = setup_externals(vec![]);
+        externals.consensus = consensus.clone();
+        setup_genesis(externals.db.clone(), data.genesis);
+
+        let (bad_blocks_tx, mut bad_blocks_rx) = bad_blocks_channel();
+        let config = BlockchainTreeConfig::new(1, 2, 3, 2);
+        let mut tree = BlockchainTree::new(externals, config, None)
+            .expect("failed to create tree")
+            .with_bad_blocks_tx(bad_blocks_tx);
+
+        consensus.set_fail_validation(true);
+        let err = tree.insert_block(block1.clone(), BlockValidationKind::Exhaustive).unwrap_err();
+        assert!(err.kind().is_invalid_block());
+        let bad_block = bad_blocks_rx.try_recv().unwrap();
+        assert_eq!(bad_block.block, block1.block);
+        assert_eq!(bad_block.error, err.kind().to_string());
+
+        // blocks that are rejected while being buffered are sent as well
+        let err = tree.buffer_block(block1.clone()).unwrap_err();
+        let bad_block = bad_blocks_rx.try_recv().unwrap();
+        assert_eq!(bad_block.block, block1.block);
+        assert_eq!(bad_block.error, err.kind().to_string());
+
+        // the tree never writes the bad blocks itself
+        let factory = tree.externals.database();
+        assert_eq!(factory.provider().unwrap().bad_blocks().unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn records_traces_of_committed_blocks() {
+        let data = BlockChainTestData::default_with_numbers(11, 12);
+        let (block1, exec1) = data.blocks[0].clone();
+        let sender = block1.senders[0];
+
+        let externals = setup_externals(vec![exec1]);
+        setup_genesis(externals.db.clone(), data.genesis);
+
+        // fund the sender before the block and enable the optional stages
+        let factory = ProviderFactory::new(&externals.db, MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                sender,
+                Account {
+                    nonce: 0,
+                    balance: U256::from(1_000_000_000_000_000_000u64),
+                    bytecode_hash: None,
+                },
+            )
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::AccountChangeSet>(10, AccountBeforeTx { address: sender, info: None })
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::AccountHistory>(
+                ShardedKey::last(sender),
+                BlockNumberList::new([10usize]).unwrap(),
+            )
+            .unwrap();
+        provider
+            .save_stage_checkpoint(StageId::TransactionTraces, StageCheckpoint::new(10))
+            .unwrap();
+        provider
+            .save_stage_checkpoint(StageId::IndexAddressAppearances, StageCheckpoint::new(10))
+            .unwrap();
+        provider.commit().unwrap();
+
+        let config = BlockchainTreeConfig::new(1, 2, 3, 2);
+        let mut tree = BlockchainTree::new(externals, config, None).expect("failed to create tree");
+        assert_eq!(
+            tree.insert_block(block1.clone(), BlockValidationKind::Exhaustive).unwrap(),
+            InsertPayloadOk::Inserted(BlockStatus::Valid)
+        );
+        tree.make_canonical(&block1.hash).unwrap();
+
+        let provider = tree.externals.database().provider().unwrap();
+        let traces = provider.transaction_traces_by_block(11.into()).unwrap().unwrap();
+        assert_eq!(traces.len(), 1);
+        assert_eq!(
+            (traces[0].traces[0].kind, traces[0].traces[0].from),
+            (StoredTraceKind::Call, sender)
+        );
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::TransactionTraces).unwrap(),
+            Some(StageCheckpoint::new(11))
+        );
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::IndexAddressAppearances).unwrap(),
+            Some(StageCheckpoint::new(11))
+        );
+    }
+
     #[tokio::test]
     async fn sanity_path() {
         let data = BlockChainTestData::default_with_numbers(11, 12);
@@ -1634,6 +1786,11 @@ mod tests {
             .with_pending_blocks((block2.number, HashSet::from([block2.hash, block2a.hash])))
             .assert(&tree);
 
+        // check notification.
+        assert_matches!(canon_notif.try_recv(),
+            Ok(CanonStateNotification::Revert{ old})
+            if *old.blocks() == BTreeMap::from([(block2.number,block2.clone())]));
+
         // commit b2a
         tree.make_canonical(&block2.hash).unwrap();
 
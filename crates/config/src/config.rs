@@ -75,6 +75,10 @@ pub struct StageConfig {
     pub index_account_history: IndexHistoryConfig,
     /// Index Storage History stage configuration.
     pub index_storage_history: IndexHistoryConfig,
+    /// Index Address Appearances stage configuration.
+    pub index_address_appearances: IndexAddressAppearancesConfig,
+    /// Transaction Traces stage configuration.
+    pub transaction_traces: TransactionTracesConfig,
 }
 
 /// Header stage configuration.
@@ -285,6 +289,38 @@ impl Default for IndexHistoryConfig {
     }
 }
 
+/// Index Address Appearances stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct IndexAddressAppearancesConfig {
+    /// Whether the stage is enabled. The index is optional and disabled by default.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress to the database.
+    pub commit_threshold: u64,
+}
+
+impl Default for IndexAddressAppearancesConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 100_000 }
+    }
+}
+
+/// Transaction Traces stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct TransactionTracesConfig {
+    /// Whether the stage is enabled. The traces are optional and disabled by default.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress to the database.
+    pub commit_threshold: u64,
+}
+
+impl Default for TransactionTracesConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 10_000 }
+    }
+}
+
 /// Pruning configuration.
 #[derive(Debug, Clone, Deserialize, PartialEq, Serialize)]
 #[serde(default)]
@@ -41,7 +41,7 @@ impl RethNodeCommandConfig for AutoMineConfig {
                 .await
                 .expect("canon state should change before timeout")
                 .expect("canon events stream is still open");
-            let new_tip = update.tip();
+            let new_tip = update.tip().expect("a block was committed");
             let expected_tx_root: FixedBytes<32> = hex!("c79b5383458e63fb20c6a49d9ec7917195a59003a2af4b28a01d7c6fbbcd7e35").into();
             assert_eq!(new_tip.transactions_root, expected_tx_root);
             assert_eq!(new_tip.number, 1);
@@ -1,4 +1,5 @@
 //! Consensus for ethereum network
+use crate::OptimismBeaconConsensus;
 use reth_consensus_common::validation;
 use reth_interfaces::consensus::{Consensus, ConsensusError};
 use reth_primitives::{
@@ -104,11 +105,22 @@ impl Consensus for BeaconConsensus {
     }
 }
 
+/// Returns the [Consensus] implementation for the given chain.
+///
+/// This is an [OptimismBeaconConsensus] for OP Stack chains and a [BeaconConsensus] otherwise.
+pub fn consensus_for_chain(chain_spec: Arc<ChainSpec>) -> Arc<dyn Consensus> {
+    if chain_spec.is_optimism() {
+        Arc::new(OptimismBeaconConsensus::new(chain_spec))
+    } else {
+        Arc::new(BeaconConsensus::new(chain_spec))
+    }
+}
+
 /// Validates the header's extradata according to the beacon consensus rules.
 ///
 /// From yellow paper: extraData: An arbitrary byte array containing data relevant to this block.
 /// This must be 32 bytes or fewer; formally Hx.
-fn validate_header_extradata(header: &Header) -> Result<(), ConsensusError> {
+pub(crate) fn validate_header_extradata(header: &Header) -> Result<(), ConsensusError> {
     if header.extra_data.len() > MAXIMUM_EXTRA_DATA_SIZE {
         Err(ConsensusError::ExtraDataExceedsMax { len: header.extra_data.len() })
     } else {
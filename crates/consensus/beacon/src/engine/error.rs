@@ -1,5 +1,6 @@
 use crate::engine::hooks::EngineHookError;
 use reth_interfaces::RethError;
+use reth_primitives::BlockNumber;
 use reth_rpc_types::engine::ForkchoiceUpdateError;
 use reth_stages::PipelineError;
 
@@ -87,3 +88,55 @@ pub enum BeaconOnNewPayloadError {
     #[error(transparent)]
     Internal(Box<dyn std::error::Error + Send + Sync>),
 }
+
+/// Represents all error cases when unwinding the canonical chain to a given block, see
+/// [BeaconEngineMessage::SetHead](crate::BeaconEngineMessage::SetHead).
+#[derive(Debug, thiserror::Error)]
+pub enum BeaconSetHeadError {
+    /// Thrown when the pipeline or a hook with write access to the database is running.
+    #[error("cannot unwind the chain while the node is syncing")]
+    Syncing,
+    /// Thrown when the data required to revert the blocks above the new head has been pruned.
+    #[error("cannot unwind to block {block_number}, data is pruned up to block {pruned_block}")]
+    Pruned {
+        /// The requested new head.
+        block_number: BlockNumber,
+        /// The highest pruned block.
+        pruned_block: BlockNumber,
+    },
+    /// Thrown when blocks above the new head have already been moved to snapshots.
+    #[error(
+        "cannot unwind to block {block_number}, blocks up to {snapshot_block} are snapshotted"
+    )]
+    Snapshotted {
+        /// The requested new head.
+        block_number: BlockNumber,
+        /// The highest snapshotted block.
+        snapshot_block: BlockNumber,
+    },
+    /// Thrown when the new head is more than
+    /// [MAX_SET_HEAD_DEPTH](crate::MAX_SET_HEAD_DEPTH) blocks below the canonical tip.
+    #[error(
+        "cannot unwind to block {block_number}, it is more than {max_depth} blocks below {tip}"
+    )]
+    TooDeep {
+        /// The requested new head.
+        block_number: BlockNumber,
+        /// The canonical tip.
+        tip: BlockNumber,
+        /// The maximum unwind depth.
+        max_depth: u64,
+    },
+    /// Internal errors, for example, error while reading from the database.
+    #[error(transparent)]
+    Internal(Box<RethError>),
+    /// Thrown when the engine task is unavailable/stopped.
+    #[error("beacon consensus engine task stopped")]
+    EngineUnavailable,
+}
+
+impl From<RethError> for BeaconSetHeadError {
+    fn from(e: RethError) -> Self {
+        Self::Internal(Box::new(e))
+    }
+}
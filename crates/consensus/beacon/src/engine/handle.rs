@@ -2,10 +2,11 @@
 
 use crate::{
     engine::message::OnForkChoiceUpdated, BeaconConsensusEngineEvent, BeaconEngineMessage,
-    BeaconForkChoiceUpdateError, BeaconOnNewPayloadError,
+    BeaconForkChoiceUpdateError, BeaconOnNewPayloadError, BeaconSetHeadError,
 };
 use futures::TryFutureExt;
 use reth_interfaces::RethResult;
+use reth_primitives::BlockNumber;
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, ForkchoiceState, ForkchoiceUpdated, PayloadAttributes,
     PayloadStatus,
@@ -81,6 +82,16 @@ impl BeaconConsensusEngineHandle {
         let _ = self.to_engine.send(BeaconEngineMessage::TransitionConfigurationExchanged);
     }
 
+    /// Sends a message to the beacon consensus engine to unwind the canonical chain to the given
+    /// block and waits for a response.
+    ///
+    /// See also [`BeaconEngineMessage::SetHead`].
+    pub async fn set_head(&self, block_number: BlockNumber) -> Result<(), BeaconSetHeadError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(BeaconEngineMessage::SetHead { block_number, tx });
+        rx.await.map_err(|_| BeaconSetHeadError::EngineUnavailable)?
+    }
+
     /// Creates a new [`BeaconConsensusEngineEvent`] listener stream.
     pub fn event_listener(&self) -> UnboundedReceiverStream<BeaconConsensusEngineEvent> {
         let (tx, rx) = mpsc::unbounded_channel();
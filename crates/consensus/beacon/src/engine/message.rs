@@ -1,10 +1,14 @@
 use crate::{
-    engine::{error::BeaconOnNewPayloadError, forkchoice::ForkchoiceStatus},
+    engine::{
+        error::{BeaconOnNewPayloadError, BeaconSetHeadError},
+        forkchoice::ForkchoiceStatus,
+    },
     BeaconConsensusEngineEvent,
 };
 use futures::{future::Either, FutureExt};
 use reth_interfaces::{consensus::ForkchoiceState, RethResult};
 use reth_payload_builder::error::PayloadBuilderError;
+use reth_primitives::BlockNumber;
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, ForkChoiceUpdateResult, ForkchoiceUpdateError,
     ForkchoiceUpdated, PayloadAttributes, PayloadId, PayloadStatus, PayloadStatusEnum,
@@ -162,6 +166,13 @@ pub enum BeaconEngineMessage {
     },
     /// Message with exchanged transition configuration.
     TransitionConfigurationExchanged,
+    /// Message to unwind the canonical chain to the given block, which becomes the new head.
+    SetHead {
+        /// The number of the new head block.
+        block_number: BlockNumber,
+        /// The sender for returning the result of the unwind.
+        tx: oneshot::Sender<Result<(), BeaconSetHeadError>>,
+    },
     /// Add a new listener for [`BeaconEngineMessage`].
     EventListener(UnboundedSender<BeaconConsensusEngineEvent>),
 }
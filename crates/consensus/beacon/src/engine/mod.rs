@@ -8,7 +8,7 @@ use crate::{
     sync::{EngineSyncController, EngineSyncEvent},
 };
 use futures::{Future, StreamExt};
-use reth_db::database::Database;
+use reth_db::{database::Database, models::StoredBadBlock};
 use reth_interfaces::{
     blockchain_tree::{
         error::{BlockchainTreeError, CanonicalError, InsertBlockError, InsertBlockErrorKind},
@@ -23,17 +23,18 @@ use reth_interfaces::{
 use reth_payload_builder::{PayloadBuilderAttributes, PayloadBuilderHandle};
 use reth_primitives::{
     constants::EPOCH_SLOTS, stage::StageId, BlockNumHash, BlockNumber, ChainSpec, Head, Header,
-    SealedBlock, SealedHeader, B256, U256,
+    PruneSegment, SealedBlock, SealedHeader, B256, U256,
 };
 use reth_provider::{
-    BlockIdReader, BlockReader, BlockSource, CanonChainTracker, ChainSpecProvider, ProviderError,
-    StageCheckpointReader,
+    BadBlocksSender, BlockIdReader, BlockReader, BlockSource, CanonChainTracker, ChainSpecProvider,
+    ProviderError, PruneCheckpointReader, StageCheckpointReader,
 };
 use reth_rpc_types::engine::{
     CancunPayloadFields, ExecutionPayload, PayloadAttributes, PayloadError, PayloadStatus,
     PayloadStatusEnum, PayloadValidationError,
 };
-use reth_rpc_types_compat::engine::payload::{try_into_block, validate_block_hash};
+use reth_rpc_types_compat::engine::payload::{try_into_block_unchecked, validate_payload_header};
+use reth_snapshot::HighestSnapshotsTracker;
 use reth_stages::{ControlFlow, Pipeline, PipelineError};
 use reth_tasks::TaskSpawner;
 use reth_tokio_util::EventListeners;
@@ -57,7 +58,7 @@ pub use message::BeaconEngineMessage;
 mod error;
 pub use error::{
     BeaconConsensusEngineError, BeaconEngineResult, BeaconForkChoiceUpdateError,
-    BeaconOnNewPayloadError,
+    BeaconOnNewPayloadError, BeaconSetHeadError,
 };
 
 mod invalid_headers;
@@ -88,6 +89,22 @@ pub mod test_utils;
 /// The maximum number of invalid headers that can be tracked by the engine.
 const MAX_INVALID_HEADERS: u32 = 512u32;
 
+/// The prune segments whose data is required to revert canonical blocks from the database.
+const PRUNE_SEGMENTS_REQUIRED_FOR_UNWIND: [PruneSegment; 5] = [
+    PruneSegment::SenderRecovery,
+    PruneSegment::AccountHistory,
+    PruneSegment::StorageHistory,
+    PruneSegment::Headers,
+    PruneSegment::Transactions,
+];
+
+/// The maximum number of blocks that can be unwound by a single
+/// [BeaconEngineMessage::SetHead].
+///
+/// The unwind runs on the engine task and the reverted blocks are kept in memory by the tree, so
+/// the depth is capped at the default reorg depth of the tree.
+pub const MAX_SET_HEAD_DEPTH: u64 = 2 * EPOCH_SLOTS;
+
 /// The largest gap for which the tree will be used for sync. See docs for `pipeline_run_threshold`
 /// for more information.
 ///
@@ -207,6 +224,10 @@ where
     /// be used to download and execute the missing blocks.
     pipeline_run_threshold: u64,
     hooks: EngineHooksController,
+    /// Tracks the highest snapshotted blocks, the chain can't be unwound below them.
+    highest_snapshots: HighestSnapshotsTracker,
+    /// Sender of the payloads that are rejected as invalid by the engine itself, to be recorded.
+    bad_blocks_tx: Option<BadBlocksSender>,
 }
 
 impl<DB, BT, Client> BeaconConsensusEngine<DB, BT, Client>
@@ -217,6 +238,7 @@ where
         + BlockIdReader
         + CanonChainTracker
         + StageCheckpointReader
+        + PruneCheckpointReader
         + ChainSpecProvider
         + 'static,
     Client: HeadersClient + BodiesClient + Clone + Unpin + 'static,
@@ -235,6 +257,7 @@ where
         target: Option<B256>,
         pipeline_run_threshold: u64,
         hooks: EngineHooks,
+        highest_snapshots: HighestSnapshotsTracker,
     ) -> RethResult<(Self, BeaconConsensusEngineHandle)> {
         let (to_engine, rx) = mpsc::unbounded_channel();
         Self::with_channel(
@@ -251,6 +274,7 @@ where
             to_engine,
             rx,
             hooks,
+            highest_snapshots,
         )
     }
 
@@ -281,6 +305,7 @@ where
         to_engine: UnboundedSender<BeaconEngineMessage>,
         rx: UnboundedReceiver<BeaconEngineMessage>,
         hooks: EngineHooks,
+        highest_snapshots: HighestSnapshotsTracker,
     ) -> RethResult<(Self, BeaconConsensusEngineHandle)> {
         let handle = BeaconConsensusEngineHandle { to_engine };
         let sync = EngineSyncController::new(
@@ -304,6 +329,8 @@ where
             metrics: EngineMetrics::default(),
             pipeline_run_threshold,
             hooks: EngineHooksController::new(hooks),
+            highest_snapshots,
+            bad_blocks_tx: None,
         };
 
         let maybe_pipeline_target = match target {
@@ -319,6 +346,15 @@ where
         Ok((this, handle))
     }
 
+    /// Set the sender of the payloads that are rejected as invalid by the engine, see
+    /// [BadBlocksRecorder](reth_provider::BadBlocksRecorder).
+    ///
+    /// The payloads that are rejected by the blockchain tree are recorded by the tree itself.
+    pub fn with_bad_blocks_tx(mut self, bad_blocks_tx: BadBlocksSender) -> Self {
+        self.bad_blocks_tx = Some(bad_blocks_tx);
+        self
+    }
+
     /// Check if the pipeline is consistent (all stages have the checkpoint block numbers no less
     /// than the checkpoint of the first stage).
     ///
@@ -1045,7 +1081,13 @@ where
         //    forkchoiceState.headBlockHash and identified via buildProcessId value if
         //    payloadAttributes is not null and the forkchoice state has been updated successfully.
         //    The build process is specified in the Payload building section.
-        let attributes = PayloadBuilderAttributes::new(state.head_block_hash, attrs);
+        let attributes = match PayloadBuilderAttributes::try_new(state.head_block_hash, attrs) {
+            Ok(attributes) => attributes,
+            Err(err) => {
+                warn!(target: "consensus::engine", ?err, "Invalid payload attributes");
+                return OnForkChoiceUpdated::invalid_payload_attributes()
+            }
+        };
 
         // send the payload to the builder and return the receiver for the pending payload id,
         // initiating payload job is handled asynchronously
@@ -1163,40 +1205,35 @@ where
         let parent_hash = payload.parent_hash();
 
         let block_hash = payload.block_hash();
-        let block_res = match try_into_block(
+        let block = match try_into_block_unchecked(
             payload,
             cancun_fields.as_ref().map(|fields| fields.parent_beacon_block_root),
         ) {
-            Ok(block) => {
-                // make sure there are no blob transactions in the payload if it is pre-cancun
-                if !self.chain_spec().is_cancun_active_at_timestamp(block.timestamp) &&
-                    block.has_blob_transactions()
-                {
-                    Err(PayloadError::PreCancunBlockWithBlobTransactions)
-                } else {
-                    validate_block_hash(block_hash, block)
-                }
-            }
-            Err(error) => Err(error),
+            Ok(block) => block.seal_slow(),
+            // the payload can't be represented as a block, so there's nothing to record
+            Err(error) => return Err(self.invalid_payload_status(parent_hash, error)),
         };
 
-        let block = match block_res {
-            Ok(block) => block,
-            Err(error) => {
-                error!(target: "consensus::engine", ?error, "Invalid payload");
-
-                let mut latest_valid_hash = None;
-                if !error.is_block_hash_mismatch() {
-                    // Engine-API rule:
-                    // > `latestValidHash: null` if the blockHash validation has failed
-                    latest_valid_hash =
-                        self.latest_valid_hash_for_invalid_payload(parent_hash, None);
-                }
-                let status = PayloadStatusEnum::from(error);
-
-                return Err(PayloadStatus::new(status, latest_valid_hash))
+        let res = validate_payload_header(&block.header).and_then(|_| {
+            // make sure there are no blob transactions in the payload if it is pre-cancun
+            if !self.chain_spec().is_cancun_active_at_timestamp(block.timestamp) &&
+                block.body.iter().any(|tx| tx.is_eip4844())
+            {
+                Err(PayloadError::PreCancunBlockWithBlobTransactions)
+            } else if block.hash() != block_hash {
+                Err(PayloadError::BlockHash { execution: block.hash(), consensus: block_hash })
+            } else {
+                Ok(())
             }
-        };
+        });
+        if let Err(error) = res {
+            // the claimed hash doesn't commit to the payload, so the block can't be recorded under
+            // either hash
+            if !error.is_block_hash_mismatch() {
+                self.record_bad_block(block, error.to_string());
+            }
+            return Err(self.invalid_payload_status(parent_hash, error))
+        }
 
         let block_versioned_hashes = block
             .blob_transactions()
@@ -1205,11 +1242,41 @@ where
             .flatten()
             .collect::<Vec<_>>();
 
-        self.validate_versioned_hashes(parent_hash, block_versioned_hashes, cancun_fields)?;
+        if let Err(status) =
+            self.validate_versioned_hashes(parent_hash, block_versioned_hashes, cancun_fields)
+        {
+            self.record_bad_block(block, PayloadError::InvalidVersionedHashes.to_string());
+            return Err(status)
+        }
 
         Ok(block)
     }
 
+    /// Returns the status of a payload that was rejected with the given error.
+    fn invalid_payload_status(&self, parent_hash: B256, error: PayloadError) -> PayloadStatus {
+        error!(target: "consensus::engine", ?error, "Invalid payload");
+
+        let mut latest_valid_hash = None;
+        if !error.is_block_hash_mismatch() {
+            // Engine-API rule:
+            // > `latestValidHash: null` if the blockHash validation has failed
+            latest_valid_hash = self.latest_valid_hash_for_invalid_payload(parent_hash, None);
+        }
+        let status = PayloadStatusEnum::from(error);
+
+        PayloadStatus::new(status, latest_valid_hash)
+    }
+
+    /// Sends the block of a payload that was rejected as invalid to be recorded, if bad blocks are
+    /// recorded.
+    ///
+    /// The block is dropped if the recorder can't keep up, so the engine never waits for it.
+    fn record_bad_block(&self, block: SealedBlock, error: String) {
+        if let Some(bad_blocks_tx) = self.bad_blocks_tx.as_ref() {
+            let _ = bad_blocks_tx.try_send(StoredBadBlock { block, error });
+        }
+    }
+
     /// Returns the currently configured [ChainSpec].
     fn chain_spec(&self) -> Arc<ChainSpec> {
         self.blockchain.chain_spec()
@@ -1390,6 +1457,90 @@ where
         Ok(synced_to_finalized)
     }
 
+    /// Unwinds the canonical chain to the given block, see [BeaconEngineMessage::SetHead].
+    ///
+    /// The blocks above the new head are reverted from the database by the blockchain tree, which
+    /// also moves the checkpoints of all pipeline stages back to the new head. The reverted blocks
+    /// are kept in the tree as a sidechain, so a later forkchoice update can make them canonical
+    /// again.
+    fn on_set_head(&mut self, block_number: BlockNumber) -> Result<(), BeaconSetHeadError> {
+        // the database can't be modified while another component is writing to it
+        if self.sync.is_pipeline_active() || self.hooks.active_db_write_hook().is_some() {
+            return Err(BeaconSetHeadError::Syncing)
+        }
+
+        let tip = self.blockchain.canonical_tip().number;
+        if block_number >= tip {
+            // nothing to unwind
+            return Ok(())
+        }
+
+        if tip - block_number > MAX_SET_HEAD_DEPTH {
+            return Err(BeaconSetHeadError::TooDeep {
+                block_number,
+                tip,
+                max_depth: MAX_SET_HEAD_DEPTH,
+            })
+        }
+
+        self.ensure_unwind_target_available(block_number)?;
+
+        let head = self.blockchain.sealed_header(block_number)?.ok_or_else(|| {
+            RethError::Provider(ProviderError::HeaderNotFound(block_number.into()))
+        })?;
+
+        self.blockchain.unwind(block_number)?;
+        self.update_head(head.clone())?;
+
+        // the safe and finalized blocks can't be ahead of the new head
+        if self.blockchain.safe_block_number()?.is_some_and(|safe| safe > block_number) {
+            self.blockchain.set_safe(head.clone());
+        }
+        if self
+            .blockchain
+            .finalized_block_number()?
+            .is_some_and(|finalized| finalized > block_number)
+        {
+            self.blockchain.set_finalized(head.clone());
+        }
+
+        // any in-progress downloads are for the old chain
+        self.sync.clear_block_download_requests();
+
+        info!(target: "consensus::engine", number=block_number, hash=?head.hash, "Unwound canonical chain");
+        Ok(())
+    }
+
+    /// Checks that the data required to revert the blocks above the given block is neither pruned
+    /// nor snapshotted.
+    fn ensure_unwind_target_available(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<(), BeaconSetHeadError> {
+        for segment in PRUNE_SEGMENTS_REQUIRED_FOR_UNWIND {
+            let pruned_block = self
+                .blockchain
+                .get_prune_checkpoint(segment)?
+                .and_then(|checkpoint| checkpoint.block_number);
+            if let Some(pruned_block) = pruned_block.filter(|pruned| *pruned > block_number) {
+                return Err(BeaconSetHeadError::Pruned { block_number, pruned_block })
+            }
+        }
+
+        let highest_snapshots = *self.highest_snapshots.borrow();
+        let snapshot_block = highest_snapshots.and_then(|snapshots| {
+            [snapshots.headers, snapshots.receipts, snapshots.transactions]
+                .into_iter()
+                .flatten()
+                .max()
+        });
+        if let Some(snapshot_block) = snapshot_block.filter(|snapshot| *snapshot > block_number) {
+            return Err(BeaconSetHeadError::Snapshotted { block_number, snapshot_block })
+        }
+
+        Ok(())
+    }
+
     /// Invoked if we successfully downloaded a new block from the network.
     ///
     /// This will attempt to insert the block into the tree.
@@ -1795,6 +1946,7 @@ where
         + BlockIdReader
         + CanonChainTracker
         + StageCheckpointReader
+        + PruneCheckpointReader
         + ChainSpecProvider
         + Unpin
         + 'static,
@@ -1850,6 +2002,10 @@ where
                         BeaconEngineMessage::TransitionConfigurationExchanged => {
                             this.blockchain.on_transition_configuration_exchanged();
                         }
+                        BeaconEngineMessage::SetHead { block_number, tx } => {
+                            let res = this.on_set_head(block_number);
+                            let _ = tx.send(res);
+                        }
                         BeaconEngineMessage::EventListener(tx) => {
                             this.listeners.push_listener(tx);
                         }
@@ -2114,7 +2270,7 @@ mod tests {
     mod fork_choice_updated {
         use super::*;
         use reth_db::{tables, transaction::DbTxMut};
-        use reth_interfaces::test_utils::generators::random_block;
+        use reth_interfaces::test_utils::generators::{random_block, random_block_range};
         use reth_rpc_types::engine::ForkchoiceUpdateError;
 
         #[tokio::test]
@@ -2432,6 +2588,49 @@ mod tests {
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
+        #[tokio::test]
+        async fn records_invalid_payloads() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+            let (bad_blocks_tx, mut bad_blocks_rx) = reth_provider::bad_blocks_channel();
+            let _engine_rx =
+                spawn_consensus_engine(consensus_engine.with_bad_blocks_tx(bad_blocks_tx));
+
+            // The block hash of the payload doesn't match
+            let block = random_block(&mut rng, 1, None, None, Some(0));
+            let mut payload = try_block_to_payload_v1(block);
+            payload.block_hash = B256::random();
+            let res = env.send_new_payload(payload, None).await;
+            assert_matches!(res, Ok(result) => assert_matches!(result.status, PayloadStatusEnum::Invalid { .. }));
+
+            // The extra data of the payload is too long
+            let mut block = random_block(&mut rng, 1, None, None, Some(0)).unseal();
+            block.header.extra_data = vec![0; 33].into();
+            let block = block.seal_slow();
+            let res = env.send_new_payload(try_block_to_payload_v1(block.clone()), None).await;
+            assert_matches!(res, Ok(result) => assert_matches!(result.status, PayloadStatusEnum::Invalid { .. }));
+
+            let bad_block = bad_blocks_rx.recv().await.unwrap();
+            assert_eq!(bad_block.block.hash, block.hash);
+            assert!(bad_block.error.starts_with("invalid payload extra data"));
+
+            // the payload with the mismatched block hash wasn't recorded
+            assert_matches!(bad_blocks_rx.try_recv(), Err(_));
+        }
+
         #[tokio::test]
         async fn payload_known() {
             let mut rng = generators::rng();
@@ -2654,4 +2853,195 @@ mod tests {
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
     }
+
+    mod set_head {
+        use super::*;
+        use reth_db::{tables, transaction::DbTxMut};
+        use reth_interfaces::test_utils::generators::{random_block, random_block_range};
+        use reth_primitives::{
+            Header, PruneCheckpoint, PruneMode, PruneSegment, SealedBlock, B256, EMPTY_ROOT_HASH,
+        };
+        use reth_provider::{BlockNumReader, CanonStateNotification};
+
+        #[tokio::test]
+        async fn unwinds_canonical_chain() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, mut env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            // the state root of the reverted state is checked against the new head, the blocks
+            // don't change any state
+            let genesis = SealedBlock {
+                header: Header { state_root: EMPTY_ROOT_HASH, ..Default::default() }.seal_slow(),
+                ..Default::default()
+            };
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash), Some(0), Some(0));
+            insert_blocks(env.db.as_ref(), chain_spec.clone(), [&genesis, &block1].into_iter());
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::SyncStage>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash,
+                finalized_block_hash: block1.hash,
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+
+            assert_matches!(env.send_set_head(genesis.number).await, Ok(()));
+
+            // the reverted block is announced to the subscribers of the canonical chain
+            assert_matches!(
+                env.canon_state_notifications.try_recv(),
+                Ok(CanonStateNotification::Revert { old })
+                    if old.blocks().values().map(|block| block.hash).eq([block1.hash])
+            );
+
+            // the block was removed from the database
+            let provider = ProviderFactory::new(env.db.as_ref(), chain_spec).provider().unwrap();
+            assert_eq!(provider.last_block_number().unwrap(), genesis.number);
+
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn rejects_pruned_unwind_target() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash), None, Some(0));
+            insert_blocks(env.db.as_ref(), chain_spec.clone(), [&genesis, &block1].into_iter());
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::SyncStage>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(block1.number),
+                    )?;
+                    tx.put::<tables::PruneCheckpoints>(
+                        PruneSegment::SenderRecovery,
+                        PruneCheckpoint {
+                            block_number: Some(block1.number),
+                            tx_number: None,
+                            prune_mode: PruneMode::Full,
+                        },
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash,
+                finalized_block_hash: block1.hash,
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+
+            // setting the head to the current tip is a no-op
+            assert_matches!(env.send_set_head(block1.number).await, Ok(()));
+
+            // the senders of block 1 are pruned, so it can't be reverted
+            assert_matches!(
+                env.send_set_head(genesis.number).await,
+                Err(BeaconSetHeadError::Pruned { block_number: 0, pruned_block: 1 })
+            );
+
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
+        #[tokio::test]
+        async fn rejects_deep_unwind() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+
+            let blocks =
+                random_block_range(&mut rng, 0..=MAX_SET_HEAD_DEPTH + 1, B256::ZERO, 0..1);
+            let tip = blocks.last().unwrap();
+            insert_blocks(env.db.as_ref(), chain_spec.clone(), blocks.iter());
+            env.db
+                .update(|tx| {
+                    tx.put::<tables::SyncStage>(
+                        StageId::Finish.to_string(),
+                        StageCheckpoint::new(tip.number),
+                    )
+                })
+                .unwrap()
+                .unwrap();
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+
+            let forkchoice = ForkchoiceState {
+                head_block_hash: tip.hash,
+                finalized_block_hash: tip.hash,
+                ..Default::default()
+            };
+            let result = env.send_forkchoice_updated(forkchoice).await.unwrap();
+            assert_eq!(result.payload_status.status, PayloadStatusEnum::Valid);
+
+            assert_matches!(
+                env.send_set_head(0).await,
+                Err(BeaconSetHeadError::TooDeep {
+                    block_number: 0,
+                    max_depth: MAX_SET_HEAD_DEPTH,
+                    ..
+                })
+            );
+
+            // the chain wasn't touched
+            let provider = ProviderFactory::new(env.db.as_ref(), chain_spec).provider().unwrap();
+            assert_eq!(provider.last_block_number().unwrap(), tip.number);
+
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     engine::hooks::PruneHook, hooks::EngineHooks, BeaconConsensus, BeaconConsensusEngine,
     BeaconConsensusEngineError, BeaconConsensusEngineHandle, BeaconForkChoiceUpdateError,
-    BeaconOnNewPayloadError, MIN_BLOCKS_FOR_PIPELINE_RUN,
+    BeaconOnNewPayloadError, BeaconSetHeadError, MIN_BLOCKS_FOR_PIPELINE_RUN,
 };
 use reth_blockchain_tree::{
     config::BlockchainTreeConfig, externals::TreeExternals, BlockchainTree, ShareableBlockchainTree,
@@ -26,7 +26,8 @@ use reth_payload_builder::test_utils::spawn_test_payload_service;
 use reth_primitives::{BlockNumber, ChainSpec, PruneModes, B256, U256};
 use reth_provider::{
     providers::BlockchainProvider, test_utils::TestExecutorFactory, BlockExecutor,
-    BundleStateWithReceipts, ExecutorFactory, ProviderFactory, PrunableBlockExecutor,
+    BundleStateWithReceipts, CanonStateNotifications, CanonStateSubscriptions, ExecutorFactory,
+    ProviderFactory, PrunableBlockExecutor,
 };
 use reth_prune::Pruner;
 use reth_revm::Factory;
@@ -60,6 +61,8 @@ pub struct TestEnv<DB> {
     #[allow(dead_code)]
     tip_rx: watch::Receiver<B256>,
     engine_handle: BeaconConsensusEngineHandle,
+    /// Notifications about changes of the canonical chain by the blockchain tree.
+    pub canon_state_notifications: CanonStateNotifications,
 }
 
 impl<DB> TestEnv<DB> {
@@ -67,8 +70,9 @@ impl<DB> TestEnv<DB> {
         db: DB,
         tip_rx: watch::Receiver<B256>,
         engine_handle: BeaconConsensusEngineHandle,
+        canon_state_notifications: CanonStateNotifications,
     ) -> Self {
-        Self { db, tip_rx, engine_handle }
+        Self { db, tip_rx, engine_handle, canon_state_notifications }
     }
 
     pub async fn send_new_payload<T: Into<ExecutionPayload>>(
@@ -115,6 +119,10 @@ impl<DB> TestEnv<DB> {
             }
         }
     }
+
+    pub async fn send_set_head(&self, block_number: BlockNumber) -> Result<(), BeaconSetHeadError> {
+        self.engine_handle.set_head(block_number).await
+    }
 }
 
 // TODO: add with_consensus in case we want to use the TestConsensus purposeful failure - this
@@ -512,6 +520,7 @@ where
         let tree = ShareableBlockchainTree::new(
             BlockchainTree::new(externals, config, None).expect("failed to create tree"),
         );
+        let canon_state_notifications = tree.subscribe_to_canonical_state();
         let shareable_db = ProviderFactory::new(db.clone(), self.base_config.chain_spec.clone());
         let latest = self.base_config.chain_spec.genesis_header().seal_slow();
         let blockchain_provider = BlockchainProvider::with_latest(shareable_db, tree, latest);
@@ -540,6 +549,7 @@ where
             None,
             self.base_config.pipeline_run_threshold.unwrap_or(MIN_BLOCKS_FOR_PIPELINE_RUN),
             hooks,
+            watch::channel(None).1,
         )
         .expect("failed to create consensus engine");
 
@@ -547,7 +557,7 @@ where
             engine.sync.set_max_block(max_block)
         }
 
-        (engine, TestEnv::new(db, tip_rx, handle))
+        (engine, TestEnv::new(db, tip_rx, handle, canon_state_notifications))
     }
 }
 
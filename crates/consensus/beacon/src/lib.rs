@@ -10,7 +10,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 mod beacon_consensus;
-pub use beacon_consensus::BeaconConsensus;
+pub use beacon_consensus::{consensus_for_chain, BeaconConsensus};
+
+mod optimism_consensus;
+pub use optimism_consensus::OptimismBeaconConsensus;
 
 mod engine;
 pub use engine::*;
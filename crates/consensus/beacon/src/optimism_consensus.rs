@@ -0,0 +1,225 @@
+//! Consensus for OP Stack rollups
+use crate::beacon_consensus::validate_header_extradata;
+use reth_consensus_common::validation;
+use reth_interfaces::consensus::{Consensus, ConsensusError};
+use reth_primitives::{
+    ChainSpec, Hardfork, Header, SealedBlock, SealedHeader, EMPTY_OMMER_ROOT_HASH, U256,
+};
+use std::sync::Arc;
+
+/// OP Stack beacon consensus
+///
+/// Blocks of OP Stack chains are derived from L1 by the rollup node, so some of the Ethereum
+/// consensus rules of [BeaconConsensus](crate::BeaconConsensus) don't apply:
+///   - the gas limit is set by the system config on L1 and isn't bound to the parent's gas limit
+///   - the chain is post-merge from genesis, so the difficulty is always zero regardless of the
+///     total difficulty
+///   - timestamps are driven by the sequencer and aren't checked against the local clock
+#[derive(Debug)]
+pub struct OptimismBeaconConsensus {
+    /// Configuration
+    chain_spec: Arc<ChainSpec>,
+}
+
+impl OptimismBeaconConsensus {
+    /// Create a new instance of [OptimismBeaconConsensus]
+    pub fn new(chain_spec: Arc<ChainSpec>) -> Self {
+        Self { chain_spec }
+    }
+}
+
+impl Consensus for OptimismBeaconConsensus {
+    fn validate_header(&self, header: &SealedHeader) -> Result<(), ConsensusError> {
+        validation::validate_header_standalone(header, &self.chain_spec)?;
+        Ok(())
+    }
+
+    fn validate_header_against_parent(
+        &self,
+        header: &SealedHeader,
+        parent: &SealedHeader,
+    ) -> Result<(), ConsensusError> {
+        validation::validate_against_parent_hash_number(parent, header)?;
+        validation::validate_against_parent_timestamp(parent, header)?;
+
+        // the gas limit is configured on L1, so there is no bound on the change between blocks
+
+        validation::validate_against_parent_eip1559_base_fee(parent, header, &self.chain_spec)?;
+
+        if self.chain_spec.fork(Hardfork::Cancun).active_at_timestamp(header.timestamp) {
+            validation::validate_4844_header_with_parent(parent, header)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_header_with_total_difficulty(
+        &self,
+        header: &Header,
+        _total_difficulty: U256,
+    ) -> Result<(), ConsensusError> {
+        // OP Stack chains are post-merge from genesis, the merge rules apply to all blocks
+        // regardless of the total difficulty
+        if header.difficulty != U256::ZERO {
+            return Err(ConsensusError::TheMergeDifficultyIsNotZero)
+        }
+
+        if header.nonce != 0 {
+            return Err(ConsensusError::TheMergeNonceIsNotZero)
+        }
+
+        if header.ommers_hash != EMPTY_OMMER_ROOT_HASH {
+            return Err(ConsensusError::TheMergeOmmerRootIsNotEmpty)
+        }
+
+        validate_header_extradata(header)?;
+
+        Ok(())
+    }
+
+    fn validate_block(&self, block: &SealedBlock) -> Result<(), ConsensusError> {
+        validation::validate_block_standalone(block, &self.chain_spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BeaconConsensus;
+    use assert_matches::assert_matches;
+    use reth_primitives::{ChainSpecBuilder, MAINNET};
+
+    fn parent_and_child(chain_spec: &ChainSpec, gas_limit: u64) -> (SealedHeader, SealedHeader) {
+        let parent = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            timestamp: 2,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        let base_fee = parent.next_block_base_fee(chain_spec.base_fee_params(4));
+        let parent = parent.seal_slow();
+        let child = Header {
+            parent_hash: parent.hash(),
+            number: 2,
+            gas_limit,
+            timestamp: 4,
+            base_fee_per_gas: base_fee,
+            ..Default::default()
+        }
+        .seal_slow();
+        (parent, child)
+    }
+
+    #[test]
+    fn gas_limit_not_bound_to_parent() {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().paris_activated().build());
+        let (parent, child) = parent_and_child(&chain_spec, 60_000_000);
+
+        assert_matches!(
+            BeaconConsensus::new(chain_spec.clone())
+                .validate_header_against_parent(&child, &parent),
+            Err(ConsensusError::GasLimitInvalidIncrease { .. })
+        );
+        assert_matches!(
+            OptimismBeaconConsensus::new(chain_spec)
+                .validate_header_against_parent(&child, &parent),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn parent_checks() {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().paris_activated().build());
+        let (parent, child) = parent_and_child(&chain_spec, 30_000_000);
+        let consensus = OptimismBeaconConsensus::new(chain_spec);
+
+        let mut header = child.clone().unseal();
+        header.timestamp = parent.timestamp;
+        assert_matches!(
+            consensus.validate_header_against_parent(&header.seal_slow(), &parent),
+            Err(ConsensusError::TimestampIsInPast { .. })
+        );
+
+        let mut header = child.clone().unseal();
+        header.base_fee_per_gas = Some(1);
+        assert_matches!(
+            consensus.validate_header_against_parent(&header.seal_slow(), &parent),
+            Err(ConsensusError::BaseFeeDiff { .. })
+        );
+
+        let mut header = child.unseal();
+        header.number += 1;
+        assert_matches!(
+            consensus.validate_header_against_parent(&header.seal_slow(), &parent),
+            Err(ConsensusError::ParentBlockNumberMismatch { .. })
+        );
+    }
+
+    #[test]
+    fn difficulty_always_zero() {
+        let consensus = OptimismBeaconConsensus::new(MAINNET.clone());
+        let header = Header { difficulty: U256::from(1), ..Default::default() };
+
+        // mainnet isn't post-merge at a total difficulty of zero, but OP Stack chains always are
+        assert_matches!(
+            BeaconConsensus::new(MAINNET.clone())
+                .validate_header_with_total_difficulty(&header, U256::ZERO),
+            Ok(())
+        );
+        assert_matches!(
+            consensus.validate_header_with_total_difficulty(&header, U256::ZERO),
+            Err(ConsensusError::TheMergeDifficultyIsNotZero)
+        );
+
+        let header = Header { nonce: 1, ..Default::default() };
+        assert_matches!(
+            consensus.validate_header_with_total_difficulty(&header, U256::ZERO),
+            Err(ConsensusError::TheMergeNonceIsNotZero)
+        );
+
+        // sequencer timestamps aren't checked against the local clock
+        let header = Header { timestamp: u64::MAX, ..Default::default() };
+        assert_matches!(
+            consensus.validate_header_with_total_difficulty(&header, U256::ZERO),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn extradata_size_limit() {
+        let consensus = OptimismBeaconConsensus::new(MAINNET.clone());
+
+        let header = Header { extra_data: vec![0; 32].into(), ..Default::default() };
+        assert_matches!(
+            consensus.validate_header_with_total_difficulty(&header, U256::ZERO),
+            Ok(())
+        );
+
+        let header = Header { extra_data: vec![0; 33].into(), ..Default::default() };
+        assert_matches!(
+            consensus.validate_header_with_total_difficulty(&header, U256::ZERO),
+            Err(ConsensusError::ExtraDataExceedsMax { len: 33 })
+        );
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn select_consensus_from_chain_spec() {
+        use crate::consensus_for_chain;
+
+        let op_chain_spec = Arc::new(ChainSpecBuilder::mainnet().bedrock_activated().build());
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().paris_activated().build());
+        let (parent, child) = parent_and_child(&chain_spec, 60_000_000);
+
+        assert_matches!(
+            consensus_for_chain(op_chain_spec).validate_header_against_parent(&child, &parent),
+            Ok(())
+        );
+        assert_matches!(
+            consensus_for_chain(chain_spec).validate_header_against_parent(&child, &parent),
+            Err(ConsensusError::GasLimitInvalidIncrease { .. })
+        );
+    }
+}
@@ -116,6 +116,8 @@ pub fn validate_transaction_regarding_header(
 
             Some(*chain_id)
         }
+        #[cfg(feature = "optimism")]
+        Transaction::Deposit(_) => None,
     };
     if let Some(chain_id) = chain_id {
         if chain_id != chain_spec.chain().id() {
@@ -253,6 +255,31 @@ pub fn validate_header_regarding_parent(
     parent: &SealedHeader,
     child: &SealedHeader,
     chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    validate_against_parent_hash_number(parent, child)?;
+
+    validate_against_parent_timestamp(parent, child)?;
+
+    // TODO Check difficulty increment between parent and child
+    // Ace age did increment it by some formula that we need to follow.
+
+    validate_against_parent_gas_limit(parent, child, chain_spec)?;
+
+    validate_against_parent_eip1559_base_fee(parent, child, chain_spec)?;
+
+    // ensure that the blob gas fields for this block
+    if chain_spec.fork(Hardfork::Cancun).active_at_timestamp(child.timestamp) {
+        validate_4844_header_with_parent(parent, child)?;
+    }
+
+    Ok(())
+}
+
+/// Validates that the child header is the direct successor of the parent header, by number and
+/// hash.
+pub fn validate_against_parent_hash_number(
+    parent: &SealedHeader,
+    child: &SealedHeader,
 ) -> Result<(), ConsensusError> {
     // Parent number is consistent.
     if parent.number + 1 != child.number {
@@ -269,6 +296,14 @@ pub fn validate_header_regarding_parent(
         })
     }
 
+    Ok(())
+}
+
+/// Validates that the timestamp of the child header is greater than the timestamp of the parent.
+pub fn validate_against_parent_timestamp(
+    parent: &SealedHeader,
+    child: &SealedHeader,
+) -> Result<(), ConsensusError> {
     // timestamp in past check
     if child.timestamp <= parent.timestamp {
         return Err(ConsensusError::TimestampIsInPast {
@@ -277,15 +312,23 @@ pub fn validate_header_regarding_parent(
         })
     }
 
-    // TODO Check difficulty increment between parent and child
-    // Ace age did increment it by some formula that we need to follow.
+    Ok(())
+}
 
+/// Validates that the gas limit of the child header is within the allowed bounds of the parent's
+/// gas limit.
+pub fn validate_against_parent_gas_limit(
+    parent: &SealedHeader,
+    child: &SealedHeader,
+    chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
     let mut parent_gas_limit = parent.gas_limit;
 
     // By consensus, gas_limit is multiplied by elasticity (*2) on
     // on exact block that hardfork happens.
     if chain_spec.fork(Hardfork::London).transitions_at_block(child.number) {
-        parent_gas_limit = parent.gas_limit * chain_spec.base_fee_params.elasticity_multiplier;
+        parent_gas_limit =
+            parent.gas_limit * chain_spec.base_fee_params(child.timestamp).elasticity_multiplier;
     }
 
     // Check gas limit, max diff between child/parent gas_limit should be  max_diff=parent_gas/1024
@@ -303,6 +346,16 @@ pub fn validate_header_regarding_parent(
         })
     }
 
+    Ok(())
+}
+
+/// Validates the base fee of the child header against the base fee of the parent, if London is
+/// active.
+pub fn validate_against_parent_eip1559_base_fee(
+    parent: &SealedHeader,
+    child: &SealedHeader,
+    chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
     // EIP-1559 check base fee
     if chain_spec.fork(Hardfork::London).active_at_block(child.number) {
         let base_fee = child.base_fee_per_gas.ok_or(ConsensusError::BaseFeeMissing)?;
@@ -313,7 +366,7 @@ pub fn validate_header_regarding_parent(
             } else {
                 // This BaseFeeMissing will not happen as previous blocks are checked to have them.
                 parent
-                    .next_block_base_fee(chain_spec.base_fee_params)
+                    .next_block_base_fee(chain_spec.base_fee_params(child.timestamp))
                     .ok_or(ConsensusError::BaseFeeMissing)?
             };
         if expected_base_fee != base_fee {
@@ -321,11 +374,6 @@ pub fn validate_header_regarding_parent(
         }
     }
 
-    // ensure that the blob gas fields for this block
-    if chain_spec.fork(Hardfork::Cancun).active_at_timestamp(child.timestamp) {
-        validate_4844_header_with_parent(parent, child)?;
-    }
-
     Ok(())
 }
 
@@ -1,4 +1,6 @@
 use crate::RethError;
+#[cfg(feature = "optimism")]
+use reth_primitives::U256;
 use reth_primitives::{BlockNumHash, Bloom, PruneSegmentError, B256};
 use revm_primitives::EVMError;
 use thiserror::Error;
@@ -87,6 +89,34 @@ pub enum BlockValidationError {
         /// The error message.
         message: String,
     },
+    /// Error when the L1 block info can't be extracted from the first transaction of an OP Stack
+    /// block
+    #[cfg(feature = "optimism")]
+    #[error("could not get L1 block info from L2 block: {message}")]
+    L1BlockInfoError {
+        /// The error message.
+        message: String,
+    },
+    /// Error when the sender of a transaction can't pay for the L1 data fee
+    #[cfg(feature = "optimism")]
+    #[error(
+        "sender of transaction {hash} can't pay the L1 data fee {l1_cost}, balance is {balance}"
+    )]
+    InsufficientFundsForL1Cost {
+        /// The hash of the transaction
+        hash: B256,
+        /// The L1 data fee of the transaction
+        l1_cost: U256,
+        /// The balance of the sender
+        balance: U256,
+    },
+    /// Error when a system deposit transaction is included after the Regolith hardfork
+    #[cfg(feature = "optimism")]
+    #[error("system deposit transaction {hash} is not supported after Regolith")]
+    SystemTransactionPostRegolith {
+        /// The hash of the transaction
+        hash: B256,
+    },
 }
 
 /// BlockExecutor Errors
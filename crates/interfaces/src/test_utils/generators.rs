@@ -366,6 +366,10 @@ pub fn random_receipt<R: Rng>(
         } else {
             vec![]
         },
+        #[cfg(feature = "optimism")]
+        deposit_nonce: None,
+        #[cfg(feature = "optimism")]
+        deposit_receipt_version: None,
     }
 }
 
@@ -48,6 +48,10 @@ mod test {
                 success: false,
                 cumulative_gas_used: 0,
                 logs: vec![],
+                #[cfg(feature = "optimism")]
+                deposit_nonce: None,
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version: None,
             },
             bloom: Default::default(),
         }]]);
@@ -61,6 +65,30 @@ mod test {
         assert!(receipts == decoded);
     }
 
+    #[test]
+    #[cfg(feature = "optimism")]
+    fn roundtrip_deposit_with_nonce() {
+        let receipts = Receipts(vec![vec![ReceiptWithBloom {
+            receipt: Receipt {
+                tx_type: TxType::DEPOSIT,
+                success: true,
+                cumulative_gas_used: 46913,
+                logs: vec![],
+                deposit_nonce: Some(4012991),
+                deposit_receipt_version: Some(1),
+            },
+            bloom: Default::default(),
+        }]]);
+
+        let mut out = vec![];
+        receipts.encode(&mut out);
+
+        let mut out = out.as_slice();
+        let decoded = Receipts::decode(&mut out).unwrap();
+
+        assert_eq!(receipts, decoded);
+    }
+
     #[test]
     // Test vector from: https://eips.ethereum.org/EIPS/eip-2481
     fn encode_get_receipts() {
@@ -117,6 +145,10 @@ mod test {
                             },
                         ],
                         success: false,
+                        #[cfg(feature = "optimism")]
+                        deposit_nonce: None,
+                        #[cfg(feature = "optimism")]
+                        deposit_receipt_version: None,
                     },bloom: hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").into(),
                 }
                 ],
@@ -152,6 +184,10 @@ mod test {
                                     },
                                 ],
                                 success: false,
+                                #[cfg(feature = "optimism")]
+                                deposit_nonce: None,
+                                #[cfg(feature = "optimism")]
+                                deposit_receipt_version: None,
                             },
                             bloom: hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").into(),
                         },
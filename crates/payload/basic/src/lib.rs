@@ -13,10 +13,15 @@ use crate::metrics::PayloadBuilderMetrics;
 use alloy_rlp::Encodable;
 use futures_core::ready;
 use futures_util::FutureExt;
-use reth_interfaces::{RethError, RethResult};
+use reth_interfaces::{
+    executor::{BlockExecutionError, BlockValidationError},
+    RethError, RethResult,
+};
 use reth_payload_builder::{
-    database::CachedReads, error::PayloadBuilderError, BuiltPayload, KeepPayloadJobAlive,
-    PayloadBuilderAttributes, PayloadJob, PayloadJobGenerator,
+    bundle::{Bundle, BundlePool, BundleTransaction},
+    database::CachedReads,
+    error::PayloadBuilderError,
+    BuiltPayload, KeepPayloadJobAlive, PayloadBuilderAttributes, PayloadJob, PayloadJobGenerator,
 };
 use reth_primitives::{
     bytes::BytesMut,
@@ -28,7 +33,7 @@ use reth_primitives::{
     proofs,
     revm::{compat::into_reth_log, env::tx_env_with_recovered},
     Block, BlockNumberOrTag, Bytes, ChainSpec, Header, IntoRecoveredTransaction, Receipt, Receipts,
-    SealedBlock, Withdrawal, B256, EMPTY_OMMER_ROOT_HASH, U256,
+    SealedBlock, TransactionSignedEcRecovered, Withdrawal, B256, EMPTY_OMMER_ROOT_HASH, U256,
 };
 use reth_provider::{BlockReaderIdExt, BlockSource, BundleStateWithReceipts, StateProviderFactory};
 use reth_revm::{
@@ -38,11 +43,12 @@ use reth_revm::{
 use reth_tasks::TaskSpawner;
 use reth_transaction_pool::TransactionPool;
 use revm::{
-    db::states::bundle_state::BundleRetention,
+    db::{states::bundle_state::BundleRetention, CacheDB},
     primitives::{BlockEnv, CfgEnv, EVMError, Env, InvalidTransaction, ResultAndState},
     Database, DatabaseCommit, State,
 };
 use std::{
+    collections::HashSet,
     future::Future,
     pin::Pin,
     sync::{atomic::AtomicBool, Arc},
@@ -56,6 +62,11 @@ use tokio::{
 use tracing::{debug, trace};
 
 mod metrics;
+#[cfg(feature = "optimism")]
+mod optimism;
+
+#[cfg(feature = "optimism")]
+pub use optimism::optimism_payload_builder;
 
 /// The [`PayloadJobGenerator`] that creates [`BasicPayloadJob`]s.
 #[derive(Debug)]
@@ -76,6 +87,8 @@ pub struct BasicPayloadJobGenerator<Client, Pool, Tasks, Builder = ()> {
     ///
     /// See [PayloadBuilder]
     builder: Builder,
+    /// The bundles that are included at the top of the built payloads.
+    bundle_pool: BundlePool,
 }
 
 // === impl BasicPayloadJobGenerator ===
@@ -111,8 +124,15 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
             config,
             chain_spec,
             builder,
+            bundle_pool: BundlePool::default(),
         }
     }
+
+    /// Sets the [BundlePool] whose bundles are included at the top of the built payloads.
+    pub fn with_bundle_pool(mut self, bundle_pool: BundlePool) -> Self {
+        self.bundle_pool = bundle_pool;
+        self
+    }
 }
 
 // === impl BasicPayloadJobGenerator ===
@@ -170,6 +190,7 @@ where
             payload_task_guard: self.payload_task_guard.clone(),
             metrics: Default::default(),
             builder: self.builder.clone(),
+            bundle_pool: self.bundle_pool.clone(),
         })
     }
 }
@@ -291,6 +312,8 @@ pub struct BasicPayloadJob<Client, Pool, Tasks, Builder> {
     ///
     /// See [PayloadBuilder]
     builder: Builder,
+    /// The bundles that are included at the top of the payload.
+    bundle_pool: BundlePool,
 }
 
 impl<Client, Pool, Tasks, Builder> Future for BasicPayloadJob<Client, Pool, Tasks, Builder>
@@ -327,6 +350,7 @@ where
                 this.metrics.inc_initiated_payload_builds();
                 let cached_reads = this.cached_reads.take().unwrap_or_default();
                 let builder = this.builder.clone();
+                let bundle_pool = this.bundle_pool.clone();
                 this.executor.spawn_blocking(Box::pin(async move {
                     // acquire the permit for executing the task
                     let _permit = guard.0.acquire().await;
@@ -337,6 +361,7 @@ where
                         config: payload_config,
                         cancel,
                         best_payload,
+                        bundle_pool,
                     };
                     let result = builder.try_build(args);
                     let _ = tx.send(result);
@@ -598,6 +623,7 @@ pub struct BuildArguments<Pool, Client> {
     config: PayloadConfig,
     cancel: Cancelled,
     best_payload: Option<Arc<BuiltPayload>>,
+    bundle_pool: BundlePool,
 }
 
 impl<Pool, Client> BuildArguments<Pool, Client> {
@@ -610,7 +636,21 @@ impl<Pool, Client> BuildArguments<Pool, Client> {
         cancel: Cancelled,
         best_payload: Option<Arc<BuiltPayload>>,
     ) -> Self {
-        Self { client, pool, cached_reads, config, cancel, best_payload }
+        Self {
+            client,
+            pool,
+            cached_reads,
+            config,
+            cancel,
+            best_payload,
+            bundle_pool: BundlePool::default(),
+        }
+    }
+
+    /// Sets the [BundlePool] whose bundles are included at the top of the payload.
+    pub fn with_bundle_pool(mut self, bundle_pool: BundlePool) -> Self {
+        self.bundle_pool = bundle_pool;
+        self
     }
 }
 
@@ -651,6 +691,11 @@ where
         &self,
         args: BuildArguments<Pool, Client>,
     ) -> Result<BuildOutcome, PayloadBuilderError> {
+        #[cfg(feature = "optimism")]
+        if args.config.chain_spec.is_optimism() {
+            return optimism_payload_builder(args)
+        }
+
         default_payload_builder(args)
     }
 }
@@ -660,6 +705,10 @@ where
 /// Given build arguments including an Ethereum client, transaction pool,
 /// and configuration, this function creates a transaction payload. Returns
 /// a result indicating success with the payload or an error in case of failure.
+///
+/// The bundles of the [BundlePool] that are valid for the block are included at the top of the
+/// block, in the order they were added to the pool. A bundle is only included if none of its
+/// transactions is invalid or reverts without being allowed to.
 #[inline]
 pub fn default_payload_builder<Pool, Client>(
     args: BuildArguments<Pool, Client>,
@@ -668,7 +717,15 @@ where
     Client: StateProviderFactory,
     Pool: TransactionPool,
 {
-    let BuildArguments { client, pool, mut cached_reads, config, cancel, best_payload } = args;
+    let BuildArguments {
+        client,
+        pool,
+        mut cached_reads,
+        config,
+        cancel,
+        best_payload,
+        bundle_pool,
+    } = args;
 
     let state_provider = client.state_by_block_hash(config.parent_block.hash)?;
     let state = StateProviderDatabase::new(&state_provider);
@@ -707,7 +764,74 @@ where
     )?;
 
     let mut receipts = Vec::new();
+
+    let bundles = bundle_pool.bundles_for_block(block_number, attributes.timestamp);
+    let bundle_txs = if bundles.is_empty() {
+        Vec::new()
+    } else {
+        let mut sim_db = CacheDB::new(StateProviderDatabase::new(&state_provider));
+        pre_block_beacon_root_contract_call(
+            &mut sim_db,
+            &chain_spec,
+            block_number,
+            &initialized_cfg,
+            &initialized_block_env,
+            &attributes,
+        )?;
+
+        let selected = select_bundle_transactions(
+            bundles,
+            &pool,
+            &mut sim_db,
+            |db, tx| execute_transaction(db, &initialized_cfg, &initialized_block_env, tx),
+            // the blob sidecars of bundle transactions aren't available
+            |tx| !tx.is_eip4844(),
+            &cancel,
+            cumulative_gas_used,
+            block_gas_limit,
+        )?;
+        match selected {
+            Some(selected) => selected,
+            None => return Ok(BuildOutcome::Cancelled),
+        }
+    };
+
+    // the transactions of the bundles are skipped when they're yielded by the pool again
+    let mut bundle_tx_hashes = HashSet::with_capacity(bundle_txs.len());
+    for tx in bundle_txs {
+        let (ResultAndState { result, state }, gas_used) =
+            execute_transaction(&mut db, &initialized_cfg, &initialized_block_env, &tx)
+                .map_err(execution_error)?;
+        db.commit(state);
+
+        cumulative_gas_used += gas_used;
+
+        receipts.push(Some(Receipt {
+            tx_type: tx.tx_type(),
+            success: result.is_success(),
+            cumulative_gas_used,
+            logs: result.logs().into_iter().map(into_reth_log).collect(),
+            #[cfg(feature = "optimism")]
+            deposit_nonce: None,
+            #[cfg(feature = "optimism")]
+            deposit_receipt_version: None,
+        }));
+
+        let miner_fee = tx
+            .effective_tip_per_gas(Some(base_fee))
+            .expect("fee is always valid; execution succeeded");
+        total_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+        bundle_tx_hashes.insert(tx.hash);
+        executed_txs.push(tx.into_signed());
+    }
+
     while let Some(pool_tx) = best_txs.next() {
+        // the transaction was already included by a bundle, its descendants are still valid
+        if bundle_tx_hashes.contains(pool_tx.hash()) {
+            continue
+        }
+
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
             // we can't fit this transaction into the block, so we need to mark it as invalid
@@ -799,6 +923,10 @@ where
             success: result.is_success(),
             cumulative_gas_used,
             logs: result.logs().into_iter().map(into_reth_log).collect(),
+            #[cfg(feature = "optimism")]
+            deposit_nonce: None,
+            #[cfg(feature = "optimism")]
+            deposit_receipt_version: None,
         }));
 
         // update add to total fees
@@ -900,6 +1028,158 @@ where
     Ok(BuildOutcome::Better { payload, cached_reads })
 }
 
+/// Selects the transactions of the bundles that are included at the top of a payload, in the order
+/// of the given bundles.
+///
+/// Each bundle is simulated with `execute` on top of `sim_db`, which must hold the state of the
+/// payload before the bundles. A bundle is only selected, and committed to `sim_db`, if none of its
+/// transactions is invalid or reverts without being allowed to. Bundles with transactions that
+/// aren't available, that exceed the gas left in the block or that aren't `supported` are skipped.
+///
+/// Returns `None` if the job was cancelled.
+#[allow(clippy::too_many_arguments)]
+fn select_bundle_transactions<Pool, DB, F, S>(
+    bundles: Vec<Bundle>,
+    pool: &Pool,
+    sim_db: &mut DB,
+    mut execute: F,
+    supported: S,
+    cancel: &Cancelled,
+    mut cumulative_gas_used: u64,
+    block_gas_limit: u64,
+) -> Result<Option<Vec<TransactionSignedEcRecovered>>, PayloadBuilderError>
+where
+    Pool: TransactionPool,
+    DB: DatabaseCommit + Clone,
+    F: FnMut(
+        &mut DB,
+        &TransactionSignedEcRecovered,
+    ) -> Result<(ResultAndState, u64), BlockExecutionError>,
+    S: Fn(&TransactionSignedEcRecovered) -> bool,
+{
+    let mut selected = Vec::new();
+    for bundle in bundles {
+        // check if the job was cancelled, if so we can exit early
+        if cancel.is_cancelled() {
+            return Ok(None)
+        }
+
+        let Some(transactions) = bundle_transactions(&bundle, pool) else {
+            trace!(target: "payload_builder", bundle=?bundle.hash, "skipping bundle with unavailable transactions");
+            continue
+        };
+
+        let bundle_gas_limit = transactions.iter().map(|(tx, _)| tx.gas_limit()).sum::<u64>();
+        if cumulative_gas_used + bundle_gas_limit > block_gas_limit {
+            trace!(target: "payload_builder", bundle=?bundle.hash, "skipping bundle exceeding the block gas limit");
+            continue
+        }
+
+        if !transactions.iter().all(|(tx, _)| supported(tx)) {
+            trace!(target: "payload_builder", bundle=?bundle.hash, "skipping bundle with unsupported transaction types");
+            continue
+        }
+
+        let mut bundle_db = sim_db.clone();
+        let mut bundle_gas_used = 0;
+        let mut is_valid = true;
+        for (tx, can_revert) in &transactions {
+            match execute(&mut bundle_db, tx) {
+                Ok((ResultAndState { result, state }, gas_used))
+                    if result.is_success() || *can_revert =>
+                {
+                    bundle_db.commit(state);
+                    bundle_gas_used += gas_used;
+                }
+                Ok(_) |
+                Err(BlockExecutionError::Validation(
+                    BlockValidationError::InsufficientFundsForL1Cost { .. },
+                )) => {
+                    is_valid = false;
+                    break
+                }
+                Err(BlockExecutionError::Validation(BlockValidationError::EVM { error, .. }))
+                    if matches!(*error, EVMError::Transaction(_)) =>
+                {
+                    is_valid = false;
+                    break
+                }
+                Err(err) => return Err(execution_error(err)),
+            }
+        }
+        if !is_valid {
+            trace!(target: "payload_builder", bundle=?bundle.hash, "skipping invalid bundle");
+            continue
+        }
+
+        *sim_db = bundle_db;
+        cumulative_gas_used += bundle_gas_used;
+        selected.extend(transactions.into_iter().map(|(tx, _)| tx));
+        trace!(target: "payload_builder", bundle=?bundle.hash, "selected bundle");
+    }
+
+    Ok(Some(selected))
+}
+
+/// Executes a single transaction of an Ethereum payload and returns the result and state diff
+/// (without applying it), together with the gas used by the transaction.
+fn execute_transaction<DB>(
+    db: &mut DB,
+    initialized_cfg: &CfgEnv,
+    initialized_block_env: &BlockEnv,
+    tx: &TransactionSignedEcRecovered,
+) -> Result<(ResultAndState, u64), BlockExecutionError>
+where
+    DB: Database<Error = RethError>,
+{
+    let env = Env {
+        cfg: initialized_cfg.clone(),
+        block: initialized_block_env.clone(),
+        tx: tx_env_with_recovered(tx),
+    };
+    let mut evm = revm::EVM::with_env(env);
+    evm.database(db);
+
+    let out = evm
+        .transact()
+        .map_err(|err| BlockValidationError::EVM { hash: tx.hash, error: err.into() })?;
+    let gas_used = out.result.gas_used();
+    Ok((out, gas_used))
+}
+
+/// Converts an error that occurred while executing a transaction of a payload into a
+/// [PayloadBuilderError].
+fn execution_error(err: BlockExecutionError) -> PayloadBuilderError {
+    match err {
+        BlockExecutionError::Validation(BlockValidationError::EVM { error, .. }) => {
+            PayloadBuilderError::EvmExecutionError(*error)
+        }
+        err => PayloadBuilderError::Internal(err.into()),
+    }
+}
+
+/// Returns the transactions of the bundle, along with whether they're allowed to revert.
+///
+/// Transactions the bundle refers to by hash are taken from the transaction pool. Returns `None`
+/// if any of them isn't available.
+fn bundle_transactions<Pool: TransactionPool>(
+    bundle: &Bundle,
+    pool: &Pool,
+) -> Option<Vec<(TransactionSignedEcRecovered, bool)>> {
+    bundle
+        .transactions
+        .iter()
+        .map(|tx| match tx {
+            BundleTransaction::Signed { transaction, can_revert } => {
+                Some((transaction.clone(), *can_revert))
+            }
+            BundleTransaction::Hash(hash) => {
+                pool.get(hash).map(|pool_tx| (pool_tx.to_recovered_transaction(), false))
+            }
+        })
+        .collect()
+}
+
 /// Builds an empty payload without any transactions.
 fn build_empty_payload<Client>(
     client: &Client,
@@ -908,6 +1188,27 @@ fn build_empty_payload<Client>(
 where
     Client: StateProviderFactory,
 {
+    // on OP Stack chains the payload must always include the forced transactions of the
+    // attributes, so the empty payload is built from these alone
+    #[cfg(feature = "optimism")]
+    if config.chain_spec.is_optimism() {
+        let outcome = optimism::build_optimism_payload(
+            client,
+            &reth_transaction_pool::noop::NoopTransactionPool::default(),
+            CachedReads::default(),
+            config,
+            &Cancelled::default(),
+            None,
+            &BundlePool::default(),
+        )?;
+        return match outcome {
+            BuildOutcome::Better { payload, .. } => Ok(payload),
+            BuildOutcome::Aborted { .. } | BuildOutcome::Cancelled => {
+                unreachable!("building without a best payload or cancellation always succeeds")
+            }
+        }
+    }
+
     let PayloadConfig {
         initialized_block_env,
         parent_block,
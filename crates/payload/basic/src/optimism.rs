@@ -0,0 +1,758 @@
+//! Payload building for OP Stack chains.
+
+use super::*;
+use reth_interfaces::executor::{BlockExecutionError, BlockValidationError};
+use reth_primitives::{Hardfork, TransactionSignedEcRecovered};
+use reth_revm::optimism::{self, L1BlockInfo};
+
+/// Constructs an OP Stack transaction payload from the transactions forced by the payload
+/// attributes and the best transactions from the pool.
+///
+/// The forced transactions are always executed first and in order, the first one is expected to be
+/// the L1 attributes deposit transaction. Failing to execute any of them is treated as fatal.
+/// Afterwards, the bundles of the [BundlePool] that are valid for the block and the best
+/// transactions from the pool are included, unless `noTxPool` is set. Bundles are executed with the
+/// same OP Stack rules as all other transactions and are only included if none of their
+/// transactions is invalid or reverts without being allowed to.
+///
+/// The gas limit of the payload is taken from the attributes, if set, see
+/// [PayloadBuilderAttributes::cfg_and_block_env].
+#[inline]
+pub fn optimism_payload_builder<Pool, Client>(
+    args: BuildArguments<Pool, Client>,
+) -> Result<BuildOutcome, PayloadBuilderError>
+where
+    Client: StateProviderFactory,
+    Pool: TransactionPool,
+{
+    let BuildArguments { client, pool, cached_reads, config, cancel, best_payload, bundle_pool } =
+        args;
+    build_optimism_payload(
+        &client,
+        &pool,
+        cached_reads,
+        config,
+        &cancel,
+        best_payload,
+        &bundle_pool,
+    )
+}
+
+/// Builds an OP Stack payload, see [optimism_payload_builder].
+///
+/// This takes the client by reference, so that it can also be used to build the empty payload.
+pub(crate) fn build_optimism_payload<Pool, Client>(
+    client: &Client,
+    pool: &Pool,
+    mut cached_reads: CachedReads,
+    config: PayloadConfig,
+    cancel: &Cancelled,
+    best_payload: Option<Arc<BuiltPayload>>,
+    bundle_pool: &BundlePool,
+) -> Result<BuildOutcome, PayloadBuilderError>
+where
+    Client: StateProviderFactory,
+    Pool: TransactionPool,
+{
+    let state_provider = client.state_by_block_hash(config.parent_block.hash)?;
+    let state = StateProviderDatabase::new(&state_provider);
+    let mut db =
+        State::builder().with_database_ref(cached_reads.as_db(&state)).with_bundle_update().build();
+    let PayloadConfig {
+        initialized_block_env,
+        initialized_cfg,
+        parent_block,
+        extra_data,
+        attributes,
+        chain_spec,
+    } = config;
+
+    debug!(target: "payload_builder", parent_hash = ?parent_block.hash, parent_number = parent_block.number, "building new optimism payload");
+    let mut cumulative_gas_used = 0;
+    let block_gas_limit: u64 = initialized_block_env.gas_limit.try_into().unwrap_or(u64::MAX);
+    let base_fee = initialized_block_env.basefee.to::<u64>();
+
+    let mut executed_txs = Vec::new();
+    let mut total_fees = U256::ZERO;
+
+    let block_number = initialized_block_env.number.to::<u64>();
+    let is_regolith =
+        chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, attributes.timestamp);
+    let is_canyon = chain_spec.is_fork_active_at_timestamp(Hardfork::Canyon, attributes.timestamp);
+
+    // apply eip-4788 pre block contract call
+    pre_block_beacon_root_contract_call(
+        &mut db,
+        &chain_spec,
+        block_number,
+        &initialized_cfg,
+        &initialized_block_env,
+        &attributes,
+    )?;
+
+    // the first Canyon block deploys the create2deployer contract before its transactions
+    optimism::ensure_create2_deployer(&chain_spec, attributes.timestamp, &mut db)
+        .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+
+    // the bundles are included right after the forced transactions, unless the pool is excluded
+    let bundles = if attributes.optimism_payload_attributes.no_tx_pool {
+        Vec::new()
+    } else {
+        bundle_pool.bundles_for_block(block_number, attributes.timestamp)
+    };
+
+    // each bundle is simulated on top of the forced transactions and the bundles that were already
+    // included, so it's only committed if it's valid as a whole
+    let mut sim_db = if bundles.is_empty() {
+        None
+    } else {
+        let mut sim_db = CacheDB::new(StateProviderDatabase::new(&state_provider));
+        pre_block_beacon_root_contract_call(
+            &mut sim_db,
+            &chain_spec,
+            block_number,
+            &initialized_cfg,
+            &initialized_block_env,
+            &attributes,
+        )?;
+        optimism::ensure_create2_deployer(&chain_spec, attributes.timestamp, &mut sim_db)
+            .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+        Some(sim_db)
+    };
+
+    // the L1 block info is set by the L1 attributes deposit, which is the first forced transaction
+    let l1_block_info = attributes
+        .optimism_payload_attributes
+        .transactions
+        .first()
+        .map(|tx| L1BlockInfo::try_from_calldata(tx.input()))
+        .transpose()
+        .map_err(|err| PayloadBuilderError::Internal(err.into()))?
+        .unwrap_or_default();
+
+    let mut receipts = Vec::new();
+
+    // execute the transactions forced by the payload attributes
+    for sequencer_tx in &attributes.optimism_payload_attributes.transactions {
+        // check if the job was cancelled, if so we can exit early
+        if cancel.is_cancelled() {
+            return Ok(BuildOutcome::Cancelled)
+        }
+
+        // forced transactions must always fit into the block
+        if cumulative_gas_used + sequencer_tx.gas_limit() > block_gas_limit {
+            return Err(PayloadBuilderError::ForcedTransactionGasLimitExceeded(sequencer_tx.hash))
+        }
+
+        let tx = sequencer_tx
+            .clone()
+            .try_into_ecrecovered()
+            .map_err(|tx| PayloadBuilderError::TransactionEcRecoverFailed(tx.hash))?;
+
+        // The deposit nonce is the nonce of the sender before the deposit is executed.
+        let deposit_nonce = if is_regolith && tx.is_deposit() {
+            let account = optimism::load_account(&mut db, tx.signer())
+                .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+            Some(account.nonce)
+        } else {
+            None
+        };
+
+        if let Some(sim_db) = &mut sim_db {
+            let (ResultAndState { state, .. }, _) = execute_transaction(
+                sim_db,
+                &initialized_cfg,
+                &initialized_block_env,
+                &tx,
+                &l1_block_info,
+                is_regolith,
+                is_canyon,
+            )
+            .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+            sim_db.commit(state);
+        }
+
+        let (ResultAndState { result, state }, gas_used) = execute_transaction(
+            &mut db,
+            &initialized_cfg,
+            &initialized_block_env,
+            &tx,
+            &l1_block_info,
+            is_regolith,
+            is_canyon,
+        )
+        .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+
+        // commit changes
+        db.commit(state);
+
+        // add gas used by the transaction to cumulative gas used, before creating the receipt
+        cumulative_gas_used += gas_used;
+
+        // Push transaction changeset and calculate header bloom filter for receipt.
+        receipts.push(Some(Receipt {
+            tx_type: tx.tx_type(),
+            success: result.is_success(),
+            cumulative_gas_used,
+            logs: result.logs().into_iter().map(into_reth_log).collect(),
+            deposit_nonce,
+            // the deposit receipt version was introduced by Canyon
+            deposit_receipt_version: (is_canyon && tx.is_deposit()).then_some(1),
+        }));
+
+        // deposits don't pay a tip, but the sequencer may force regular transactions
+        if !tx.is_deposit() {
+            if let Some(miner_fee) = tx.effective_tip_per_gas(Some(base_fee)) {
+                total_fees += U256::from(miner_fee) * U256::from(gas_used);
+            }
+        }
+
+        // append transaction to the list of executed transactions
+        executed_txs.push(tx.into_signed());
+    }
+
+    let bundle_txs = match sim_db {
+        Some(mut sim_db) => {
+            let selected = select_bundle_transactions(
+                bundles,
+                pool,
+                &mut sim_db,
+                |db, tx| {
+                    execute_transaction(
+                        db,
+                        &initialized_cfg,
+                        &initialized_block_env,
+                        tx,
+                        &l1_block_info,
+                        is_regolith,
+                        is_canyon,
+                    )
+                },
+                // blob transactions are not supported and deposits can only be forced by the
+                // attributes
+                |tx| !tx.is_eip4844() && !tx.is_deposit(),
+                cancel,
+                cumulative_gas_used,
+                block_gas_limit,
+            )?;
+            match selected {
+                Some(selected) => selected,
+                None => return Ok(BuildOutcome::Cancelled),
+            }
+        }
+        None => Vec::new(),
+    };
+
+    // the transactions of the bundles are skipped when they're yielded by the pool again
+    let mut bundle_tx_hashes = HashSet::with_capacity(bundle_txs.len());
+    for tx in bundle_txs {
+        let (ResultAndState { result, state }, gas_used) = execute_transaction(
+            &mut db,
+            &initialized_cfg,
+            &initialized_block_env,
+            &tx,
+            &l1_block_info,
+            is_regolith,
+            is_canyon,
+        )
+        .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+        db.commit(state);
+
+        cumulative_gas_used += gas_used;
+
+        receipts.push(Some(Receipt {
+            tx_type: tx.tx_type(),
+            success: result.is_success(),
+            cumulative_gas_used,
+            logs: result.logs().into_iter().map(into_reth_log).collect(),
+            deposit_nonce: None,
+            deposit_receipt_version: None,
+        }));
+
+        let miner_fee = tx
+            .effective_tip_per_gas(Some(base_fee))
+            .expect("fee is always valid; execution succeeded");
+        total_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+        bundle_tx_hashes.insert(tx.hash);
+        executed_txs.push(tx.into_signed());
+    }
+
+    if !attributes.optimism_payload_attributes.no_tx_pool {
+        let mut best_txs = pool.best_transactions_with_base_fee(base_fee);
+
+        while let Some(pool_tx) = best_txs.next() {
+            // the transaction was already included by a bundle, its descendants are still valid
+            if bundle_tx_hashes.contains(pool_tx.hash()) {
+                continue
+            }
+
+            // ensure we still have capacity for this transaction
+            if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
+                // we can't fit this transaction into the block, so we need to mark it as invalid
+                // which also removes all dependent transaction from the iterator before we can
+                // continue
+                best_txs.mark_invalid(&pool_tx);
+                continue
+            }
+
+            // check if the job was cancelled, if so we can exit early
+            if cancel.is_cancelled() {
+                return Ok(BuildOutcome::Cancelled)
+            }
+
+            // convert tx to a signed transaction
+            let tx = pool_tx.to_recovered_transaction();
+
+            // blob transactions and deposits are not accepted from the pool
+            if tx.is_eip4844() || tx.is_deposit() {
+                trace!(target: "payload_builder", tx=?tx.hash, "skipping unsupported transaction type");
+                best_txs.mark_invalid(&pool_tx);
+                continue
+            }
+
+            let (ResultAndState { result, state }, gas_used) = match execute_transaction(
+                &mut db,
+                &initialized_cfg,
+                &initialized_block_env,
+                &tx,
+                &l1_block_info,
+                is_regolith,
+                is_canyon,
+            ) {
+                Ok(res) => res,
+                Err(BlockExecutionError::Validation(
+                    BlockValidationError::InsufficientFundsForL1Cost { .. },
+                )) => {
+                    // the sender can't afford the L1 data fee, so we can skip this transaction
+                    // and all of its descendants
+                    trace!(target: "payload_builder", ?tx, "skipping transaction that can't pay the L1 data fee");
+                    best_txs.mark_invalid(&pool_tx);
+                    continue
+                }
+                Err(BlockExecutionError::Validation(BlockValidationError::EVM {
+                    error, ..
+                })) if matches!(*error, EVMError::Transaction(_)) => {
+                    if matches!(
+                        *error,
+                        EVMError::Transaction(InvalidTransaction::NonceTooLow { .. })
+                    ) {
+                        // if the nonce is too low, we can skip this transaction
+                        trace!(target: "payload_builder", ?error, ?tx, "skipping nonce too low transaction");
+                    } else {
+                        // if the transaction is invalid, we can skip it and all of its
+                        // descendants
+                        trace!(target: "payload_builder", ?error, ?tx, "skipping invalid transaction and its descendants");
+                        best_txs.mark_invalid(&pool_tx);
+                    }
+
+                    continue
+                }
+                Err(err) => {
+                    // this is an error that we should treat as fatal for this attempt
+                    return Err(PayloadBuilderError::Internal(err.into()))
+                }
+            };
+
+            // commit changes
+            db.commit(state);
+
+            // add gas used by the transaction to cumulative gas used, before creating the receipt
+            cumulative_gas_used += gas_used;
+
+            // Push transaction changeset and calculate header bloom filter for receipt.
+            receipts.push(Some(Receipt {
+                tx_type: tx.tx_type(),
+                success: result.is_success(),
+                cumulative_gas_used,
+                logs: result.logs().into_iter().map(into_reth_log).collect(),
+                deposit_nonce: None,
+                deposit_receipt_version: None,
+            }));
+
+            // update add to total fees
+            let miner_fee = tx
+                .effective_tip_per_gas(Some(base_fee))
+                .expect("fee is always valid; execution succeeded");
+            total_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+            // append transaction to the list of executed transactions
+            executed_txs.push(tx.into_signed());
+        }
+    }
+
+    // check if we have a better block
+    if !is_better_payload(best_payload.as_deref(), total_fees) {
+        // can skip building the block
+        return Ok(BuildOutcome::Aborted { fees: total_fees, cached_reads })
+    }
+
+    let WithdrawalsOutcome { withdrawals_root, withdrawals } =
+        commit_withdrawals(&mut db, &chain_spec, attributes.timestamp, attributes.withdrawals)?;
+
+    // merge all transitions into bundle state, this would apply the withdrawal balance changes and
+    // 4788 contract call
+    db.merge_transitions(BundleRetention::PlainState);
+
+    let bundle = BundleStateWithReceipts::new(
+        db.take_bundle(),
+        Receipts::from_vec(vec![receipts]),
+        block_number,
+    );
+    let receipts_root = bundle
+        .optimism_receipts_root_slow(block_number, &chain_spec, attributes.timestamp)
+        .expect("Number is in range");
+    let logs_bloom = bundle.block_logs_bloom(block_number).expect("Number is in range");
+
+    // calculate the state root
+    let state_root = state_provider.state_root(&bundle)?;
+
+    // create the block header
+    let transactions_root = proofs::calculate_transaction_root(&executed_txs);
+
+    let header = Header {
+        parent_hash: parent_block.hash,
+        ommers_hash: EMPTY_OMMER_ROOT_HASH,
+        beneficiary: initialized_block_env.coinbase,
+        state_root,
+        transactions_root,
+        receipts_root,
+        withdrawals_root,
+        logs_bloom,
+        timestamp: attributes.timestamp,
+        mix_hash: attributes.prev_randao,
+        nonce: BEACON_NONCE,
+        base_fee_per_gas: Some(base_fee),
+        number: parent_block.number + 1,
+        gas_limit: block_gas_limit,
+        difficulty: U256::ZERO,
+        gas_used: cumulative_gas_used,
+        extra_data,
+        parent_beacon_block_root: attributes.parent_beacon_block_root,
+        blob_gas_used: None,
+        excess_blob_gas: None,
+    };
+
+    // seal the block
+    let block = Block { header, body: executed_txs, ommers: vec![], withdrawals };
+
+    let sealed_block = block.seal_slow();
+    debug!(target: "payload_builder", ?sealed_block, "sealed built optimism block");
+
+    Ok(BuildOutcome::Better {
+        payload: BuiltPayload::new(attributes.id, sealed_block, total_fees),
+        cached_reads,
+    })
+}
+
+/// Executes a single transaction of an OP Stack payload and returns the result and state diff
+/// (without applying it), together with the gas used by the transaction.
+///
+/// This applies the same OP Stack rules as block execution, see [optimism::transact].
+fn execute_transaction<DB>(
+    db: &mut DB,
+    initialized_cfg: &CfgEnv,
+    initialized_block_env: &BlockEnv,
+    tx: &TransactionSignedEcRecovered,
+    l1_block_info: &L1BlockInfo,
+    is_regolith: bool,
+    is_canyon: bool,
+) -> Result<(ResultAndState, u64), BlockExecutionError>
+where
+    DB: Database<Error = RethError> + DatabaseCommit,
+{
+    // Configure the environment for the block.
+    let env = Env {
+        cfg: initialized_cfg.clone(),
+        block: initialized_block_env.clone(),
+        tx: tx_env_with_recovered(tx),
+    };
+
+    optimism::transact(db, env, tx, tx.signer(), l1_block_info, is_regolith, is_canyon, |evm| {
+        evm.transact()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_payload_builder::{OptimismPayloadBuilderAttributes, PayloadId};
+    use reth_primitives::{
+        constants::MIN_PROTOCOL_BASE_FEE, Address, ChainSpecBuilder, Signature, Transaction,
+        TransactionKind, TransactionSigned, TxDeposit,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction},
+        TransactionOrigin,
+    };
+
+    const GAS_LIMIT: u64 = 30_000_000;
+
+    fn deposit(to: Address, mint: Option<u128>, gas_limit: u64, input: Bytes) -> TransactionSigned {
+        TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(TxDeposit {
+                source_hash: B256::random(),
+                from: Address::random(),
+                to: TransactionKind::Call(to),
+                mint,
+                value: U256::ZERO.into(),
+                gas_limit,
+                is_system_transaction: false,
+                input,
+            }),
+            Signature::default(),
+        )
+    }
+
+    /// Returns the L1 attributes deposit, with zeroed L1 fee parameters.
+    fn l1_attributes_deposit() -> TransactionSigned {
+        deposit(optimism::L1_BLOCK_CONTRACT, None, 1_000_000, Bytes::from(vec![0u8; 4 + 8 * 32]))
+    }
+
+    fn config(attributes: OptimismPayloadBuilderAttributes) -> PayloadConfig {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().regolith_activated().build());
+        let parent = Block {
+            header: Header {
+                gas_limit: GAS_LIMIT,
+                base_fee_per_gas: Some(MIN_PROTOCOL_BASE_FEE),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .seal_slow();
+        let attributes = PayloadBuilderAttributes {
+            id: PayloadId::new([0; 8]),
+            parent: parent.hash,
+            timestamp: parent.timestamp + 2,
+            suggested_fee_recipient: Address::random(),
+            prev_randao: B256::random(),
+            withdrawals: vec![],
+            parent_beacon_block_root: None,
+            optimism_payload_attributes: attributes,
+        };
+        PayloadConfig::new(Arc::new(parent), Bytes::default(), attributes, chain_spec)
+    }
+
+    /// Returns a client and a pool with a single transaction of a funded sender.
+    async fn client_and_pool() -> (MockEthProvider, reth_transaction_pool::test_utils::TestPool) {
+        let client = MockEthProvider::default();
+        let pool = testing_pool();
+
+        let tx = MockTransaction::eip1559().with_gas_limit(21_000);
+        client.add_account(tx.get_sender(), ExtendedAccount::new(0, U256::from(1e18 as u64)));
+        pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+
+        (client, pool)
+    }
+
+    fn build(
+        client: &MockEthProvider,
+        pool: &reth_transaction_pool::test_utils::TestPool,
+        config: PayloadConfig,
+    ) -> Result<BuiltPayload, PayloadBuilderError> {
+        build_with_bundles(client, pool, config, BundlePool::default())
+    }
+
+    fn build_with_bundles(
+        client: &MockEthProvider,
+        pool: &reth_transaction_pool::test_utils::TestPool,
+        config: PayloadConfig,
+        bundle_pool: BundlePool,
+    ) -> Result<BuiltPayload, PayloadBuilderError> {
+        let args = BuildArguments::new(
+            client.clone(),
+            pool.clone(),
+            CachedReads::default(),
+            config,
+            Cancelled::default(),
+            None,
+        )
+        .with_bundle_pool(bundle_pool);
+        match optimism_payload_builder(args)? {
+            BuildOutcome::Better { payload, .. } => Ok(payload),
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn forced_transactions_come_first() {
+        let (client, pool) = client_and_pool().await;
+
+        let recipient = Address::random();
+        let forced =
+            vec![l1_attributes_deposit(), deposit(recipient, Some(100), 100_000, Bytes::new())];
+        let payload = build(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: forced.clone(),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+        let body = &payload.block().body;
+        assert_eq!(body.len(), 3);
+        assert_eq!(body[..2], forced[..]);
+        assert!(!body[2].is_deposit());
+        assert_eq!(payload.block().gas_limit, GAS_LIMIT);
+    }
+
+    #[tokio::test]
+    async fn no_tx_pool_excludes_pool_transactions() {
+        let (client, pool) = client_and_pool().await;
+
+        let forced = vec![l1_attributes_deposit()];
+        let payload = build(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: forced.clone(),
+                no_tx_pool: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(payload.block().body, forced);
+    }
+
+    #[tokio::test]
+    async fn gas_limit_override() {
+        let (client, pool) = client_and_pool().await;
+
+        let payload = build(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: vec![l1_attributes_deposit()],
+                gas_limit: Some(2_000_000),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        assert_eq!(payload.block().gas_limit, 2_000_000);
+
+        // the forced transactions must fit into the overridden gas limit
+        let forced = l1_attributes_deposit();
+        let err = build(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: vec![forced.clone()],
+                gas_limit: Some(500_000),
+                ..Default::default()
+            }),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            PayloadBuilderError::ForcedTransactionGasLimitExceeded(hash) if hash == forced.hash
+        ));
+    }
+
+    #[tokio::test]
+    async fn bundle_lands_after_forced_transactions() {
+        let (client, pool) = client_and_pool().await;
+
+        let bundle_tx = MockTransaction::eip1559().with_gas_limit(21_000);
+        client
+            .add_account(bundle_tx.get_sender(), ExtendedAccount::new(0, U256::from(1e18 as u64)));
+        let bundle_tx = bundle_tx.to_recovered_transaction();
+
+        let bundle_pool = BundlePool::default();
+        bundle_pool
+            .add_bundle(Bundle {
+                hash: B256::random(),
+                transactions: vec![BundleTransaction::Signed {
+                    transaction: bundle_tx.clone(),
+                    can_revert: false,
+                }],
+                block_number: 1,
+                max_block_number: 1,
+                min_timestamp: None,
+                max_timestamp: None,
+                replacement_uuid: None,
+            })
+            .unwrap();
+
+        let forced = l1_attributes_deposit();
+        let payload = build_with_bundles(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: vec![forced.clone()],
+                ..Default::default()
+            }),
+            bundle_pool.clone(),
+        )
+        .unwrap();
+
+        // forced transactions, then the bundle, then the pool transactions
+        let body = &payload.block().body;
+        assert_eq!(body.len(), 3);
+        assert_eq!(body[0], forced);
+        assert_eq!(body[1], bundle_tx.clone().into_signed());
+        assert_ne!(body[2], body[1]);
+
+        // bundles are excluded together with the pool
+        let payload = build_with_bundles(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: vec![forced.clone()],
+                no_tx_pool: true,
+                ..Default::default()
+            }),
+            bundle_pool,
+        )
+        .unwrap();
+        assert_eq!(payload.block().body, vec![forced]);
+    }
+
+    #[tokio::test]
+    async fn bundle_pool_transaction_keeps_descendants() {
+        let client = MockEthProvider::default();
+        let pool = testing_pool();
+
+        let tx = MockTransaction::eip1559().with_gas_limit(21_000);
+        let next = tx.next();
+        client.add_account(tx.get_sender(), ExtendedAccount::new(0, U256::from(1e18 as u64)));
+        pool.add_transaction(TransactionOrigin::External, tx.clone()).await.unwrap();
+        pool.add_transaction(TransactionOrigin::External, next.clone()).await.unwrap();
+
+        // the bundle refers to the first pool transaction by hash
+        let bundle_pool = BundlePool::default();
+        bundle_pool
+            .add_bundle(Bundle {
+                hash: B256::random(),
+                transactions: vec![BundleTransaction::Hash(tx.get_hash())],
+                block_number: 1,
+                max_block_number: 1,
+                min_timestamp: None,
+                max_timestamp: None,
+                replacement_uuid: None,
+            })
+            .unwrap();
+
+        let forced = l1_attributes_deposit();
+        let payload = build_with_bundles(
+            &client,
+            &pool,
+            config(OptimismPayloadBuilderAttributes {
+                transactions: vec![forced.clone()],
+                ..Default::default()
+            }),
+            bundle_pool,
+        )
+        .unwrap();
+
+        // the pool doesn't include the bundle transaction again, nor drop its descendant
+        let body = &payload.block().body;
+        assert_eq!(body.len(), 3);
+        assert_eq!(body[0], forced);
+        assert_eq!(body[1].hash, tx.get_hash());
+        assert_eq!(body[2].hash, next.get_hash());
+    }
+}
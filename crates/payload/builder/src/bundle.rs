@@ -0,0 +1,437 @@
+//! A pool of transaction bundles that were submitted to the node, e.g. via `mev_sendBundle` or
+//! `eth_sendBundle`.
+//!
+//! The payload builder can include the bundles of the pool at the top of the block it builds.
+
+use parking_lot::RwLock;
+use reth_primitives::{Address, TransactionSignedEcRecovered, TxHash, B256};
+use std::sync::Arc;
+
+/// The maximum number of bundles a [BundlePool] holds.
+pub const MAX_BUNDLES: usize = 10_000;
+
+/// The maximum number of bundles of the same sender a [BundlePool] holds, see [Bundle::sender].
+pub const MAX_BUNDLES_PER_SENDER: usize = 100;
+
+/// The maximum number of blocks past the next block a bundle can be sent for.
+pub const MAX_FUTURE_BLOCKS: u64 = 25;
+
+/// A transaction of a [Bundle].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleTransaction {
+    /// A signed transaction that was submitted as part of the bundle.
+    Signed {
+        /// The transaction.
+        transaction: TransactionSignedEcRecovered,
+        /// Whether the transaction is allowed to revert without invalidating the bundle.
+        can_revert: bool,
+    },
+    /// The hash of a transaction that wasn't submitted with the bundle, e.g. a pending transaction
+    /// that's backrun by the bundle.
+    ///
+    /// The bundle can only be included once the transaction is available in the transaction pool.
+    Hash(TxHash),
+}
+
+impl BundleTransaction {
+    /// Returns the hash of the transaction.
+    pub fn hash(&self) -> TxHash {
+        match self {
+            BundleTransaction::Signed { transaction, .. } => transaction.hash(),
+            BundleTransaction::Hash(hash) => *hash,
+        }
+    }
+
+    /// Returns true if the transaction is allowed to revert without invalidating the bundle.
+    pub fn can_revert(&self) -> bool {
+        matches!(self, BundleTransaction::Signed { can_revert: true, .. })
+    }
+}
+
+/// A bundle of transactions that's either included as a whole, at the top of a block, or not at
+/// all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bundle {
+    /// The hash identifying the bundle.
+    pub hash: B256,
+    /// The transactions of the bundle, in the order they're executed in.
+    pub transactions: Vec<BundleTransaction>,
+    /// The first block the bundle can be included in.
+    pub block_number: u64,
+    /// The last block the bundle can be included in.
+    pub max_block_number: u64,
+    /// The minimum timestamp of the block the bundle can be included in, if any.
+    pub min_timestamp: Option<u64>,
+    /// The maximum timestamp of the block the bundle can be included in, if any.
+    pub max_timestamp: Option<u64>,
+    /// The identifier the bundle can be replaced or cancelled with, if any.
+    pub replacement_uuid: Option<String>,
+}
+
+impl Bundle {
+    /// Returns true if the bundle can be included in the block with the given number and
+    /// timestamp.
+    pub fn is_valid_for_block(&self, block_number: u64, timestamp: u64) -> bool {
+        (self.block_number..=self.max_block_number).contains(&block_number) &&
+            self.min_timestamp.map_or(true, |min_timestamp| min_timestamp <= timestamp) &&
+            self.max_timestamp.map_or(true, |max_timestamp| timestamp <= max_timestamp)
+    }
+
+    /// Returns true if the bundle can't be included in the block with the given number, nor in any
+    /// later block.
+    pub fn is_expired(&self, block_number: u64) -> bool {
+        self.max_block_number < block_number
+    }
+
+    /// Returns true if the first block of the bundle is more than [MAX_FUTURE_BLOCKS] blocks past
+    /// the given next block.
+    pub fn is_too_far_in_future(&self, next_block: u64) -> bool {
+        self.block_number > next_block + MAX_FUTURE_BLOCKS
+    }
+
+    /// Returns the signer of the first transaction that was submitted with the bundle, if any.
+    ///
+    /// The pool limits the number of bundles per sender, bundles that only refer to transactions
+    /// by hash share the same limit. Only bundles with a sender can be replaced.
+    pub fn sender(&self) -> Option<Address> {
+        self.transactions.iter().find_map(|tx| match tx {
+            BundleTransaction::Signed { transaction, .. } => Some(transaction.signer()),
+            BundleTransaction::Hash(_) => None,
+        })
+    }
+}
+
+/// Errors that can occur when adding a bundle to the [BundlePool].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BundlePoolError {
+    /// Thrown if the pool already holds [MAX_BUNDLES] bundles.
+    #[error("bundle pool is full")]
+    PoolFull,
+    /// Thrown if the pool already holds [MAX_BUNDLES_PER_SENDER] bundles of the bundle's sender.
+    #[error("too many bundles of the same sender")]
+    SenderLimitExceeded,
+    /// Thrown if the replacement uuid of the bundle is used by a bundle of another sender.
+    #[error("replacement uuid is used by a bundle of another sender")]
+    ReplacementUuidTaken,
+    /// Thrown if a bundle without a sender, see [Bundle::sender], has a replacement uuid.
+    #[error("bundle without signed transactions can't have a replacement uuid")]
+    ReplacementUuidWithoutSender,
+    /// Thrown if a bundle with the same hash that can't be replaced is already in the pool.
+    #[error("bundle already known")]
+    AlreadyKnown,
+}
+
+/// A pool of [Bundle]s that's shared between the components that submit bundles, like the `mev`
+/// RPC namespace, and the payload builder.
+///
+/// The pool is cheap to clone, all clones share the same bundles. Bundles are kept in the order
+/// they were added, until the last block they can be included in has passed.
+#[derive(Debug, Clone, Default)]
+pub struct BundlePool {
+    bundles: Arc<RwLock<Vec<Bundle>>>,
+}
+
+impl BundlePool {
+    /// Adds the bundle to the pool, replacing the bundle of the same sender with the same hash or
+    /// the same replacement uuid, if any.
+    ///
+    /// A replacement uuid can only be used by the bundles of a single sender, so it identifies
+    /// the bundles of that sender. Bundles without a sender can't be replaced and can't have a
+    /// replacement uuid.
+    pub fn add_bundle(&self, bundle: Bundle) -> Result<(), BundlePoolError> {
+        let mut bundles = self.bundles.write();
+        let sender = bundle.sender();
+        let is_same_sender = |existing: &Bundle| sender.is_some() && existing.sender() == sender;
+
+        if let Some(replacement_uuid) = &bundle.replacement_uuid {
+            if sender.is_none() {
+                return Err(BundlePoolError::ReplacementUuidWithoutSender)
+            }
+            if bundles.iter().any(|existing| {
+                existing.replacement_uuid.as_ref() == Some(replacement_uuid) &&
+                    !is_same_sender(existing)
+            }) {
+                return Err(BundlePoolError::ReplacementUuidTaken)
+            }
+        }
+
+        if let Some(existing) = bundles.iter_mut().find(|existing| {
+            (existing.hash == bundle.hash ||
+                (bundle.replacement_uuid.is_some() &&
+                    existing.replacement_uuid == bundle.replacement_uuid)) &&
+                is_same_sender(existing)
+        }) {
+            *existing = bundle;
+            return Ok(())
+        }
+        if bundles.iter().any(|existing| existing.hash == bundle.hash) {
+            return Err(BundlePoolError::AlreadyKnown)
+        }
+        if bundles.len() >= MAX_BUNDLES {
+            return Err(BundlePoolError::PoolFull)
+        }
+        if bundles.iter().filter(|existing| existing.sender() == sender).count() >=
+            MAX_BUNDLES_PER_SENDER
+        {
+            return Err(BundlePoolError::SenderLimitExceeded)
+        }
+        bundles.push(bundle);
+        Ok(())
+    }
+
+    /// Returns the bundle with the given hash, if any.
+    pub fn get(&self, hash: &B256) -> Option<Bundle> {
+        self.bundles.read().iter().find(|bundle| bundle.hash == *hash).cloned()
+    }
+
+    /// Removes the bundle with the given hash from the pool and returns it, if any.
+    pub fn remove_bundle(&self, hash: &B256) -> Option<Bundle> {
+        let mut bundles = self.bundles.write();
+        let index = bundles.iter().position(|bundle| bundle.hash == *hash)?;
+        Some(bundles.remove(index))
+    }
+
+    /// Removes the bundle with the given replacement uuid from the pool and returns it, if any.
+    ///
+    /// The replacement uuid is only used by the bundles of a single sender, see
+    /// [BundlePool::add_bundle].
+    pub fn remove_bundle_by_replacement_uuid(&self, replacement_uuid: &str) -> Option<Bundle> {
+        let mut bundles = self.bundles.write();
+        let index = bundles
+            .iter()
+            .position(|bundle| bundle.replacement_uuid.as_deref() == Some(replacement_uuid))?;
+        Some(bundles.remove(index))
+    }
+
+    /// Removes all bundles that can't be included in the block with the given number anymore.
+    pub fn remove_expired(&self, block_number: u64) {
+        self.bundles.write().retain(|bundle| !bundle.is_expired(block_number))
+    }
+
+    /// Returns all bundles that can be included in the block with the given number and timestamp,
+    /// in the order they were added.
+    ///
+    /// Bundles that expired before the block are removed from the pool.
+    pub fn bundles_for_block(&self, block_number: u64, timestamp: u64) -> Vec<Bundle> {
+        self.remove_expired(block_number);
+        self.bundles
+            .read()
+            .iter()
+            .filter(|bundle| bundle.is_valid_for_block(block_number, timestamp))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the number of bundles in the pool.
+    pub fn len(&self) -> usize {
+        self.bundles.read().len()
+    }
+
+    /// Returns true if the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bundles.read().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{TransactionSigned, U256};
+
+    fn signed(sender: Address) -> BundleTransaction {
+        BundleTransaction::Signed {
+            transaction: TransactionSignedEcRecovered::from_signed_transaction(
+                TransactionSigned::default(),
+                sender,
+            ),
+            can_revert: false,
+        }
+    }
+
+    fn bundle(hash: u8, block_number: u64, max_block_number: u64) -> Bundle {
+        Bundle {
+            hash: B256::with_last_byte(hash),
+            transactions: vec![BundleTransaction::Hash(B256::with_last_byte(hash))],
+            block_number,
+            max_block_number,
+            min_timestamp: None,
+            max_timestamp: None,
+            replacement_uuid: None,
+        }
+    }
+
+    #[test]
+    fn bundles_for_block() {
+        let pool = BundlePool::default();
+        pool.add_bundle(bundle(1, 1, 1)).unwrap();
+        pool.add_bundle(bundle(2, 1, 3)).unwrap();
+        pool.add_bundle(bundle(3, 3, 4)).unwrap();
+        assert_eq!(pool.len(), 3);
+
+        let hashes = |block_number| {
+            pool.bundles_for_block(block_number, 0).into_iter().map(|b| b.hash).collect::<Vec<_>>()
+        };
+        assert_eq!(hashes(1), vec![B256::with_last_byte(1), B256::with_last_byte(2)]);
+        assert_eq!(hashes(3), vec![B256::with_last_byte(2), B256::with_last_byte(3)]);
+        // the first bundle expired
+        assert_eq!(pool.len(), 2);
+
+        assert_eq!(hashes(5), vec![]);
+        assert!(pool.is_empty());
+    }
+
+    /// Returns a bundle of the given sender.
+    fn signed_bundle(hash: u8, sender: Address) -> Bundle {
+        Bundle { transactions: vec![signed(sender)], ..bundle(hash, 1, 1) }
+    }
+
+    #[test]
+    fn replace_and_remove_bundle() {
+        let pool = BundlePool::default();
+        let sender = Address::with_last_byte(1);
+        pool.add_bundle(signed_bundle(1, sender)).unwrap();
+        pool.add_bundle(Bundle { max_block_number: 5, ..signed_bundle(1, sender) }).unwrap();
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.get(&B256::with_last_byte(1)).unwrap().max_block_number, 5);
+
+        assert!(pool.remove_bundle(&B256::with_last_byte(2)).is_none());
+        assert!(pool.remove_bundle(&B256::with_last_byte(1)).is_some());
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn replace_and_cancel_bundle_by_uuid() {
+        let pool = BundlePool::default();
+        let sender = Address::with_last_byte(1);
+        let mut first = signed_bundle(1, sender);
+        first.replacement_uuid = Some("uuid".to_string());
+        pool.add_bundle(first).unwrap();
+        pool.add_bundle(signed_bundle(2, sender)).unwrap();
+
+        let mut replacement = signed_bundle(3, sender);
+        replacement.replacement_uuid = Some("uuid".to_string());
+        pool.add_bundle(replacement).unwrap();
+        assert_eq!(pool.len(), 2);
+        assert!(pool.get(&B256::with_last_byte(1)).is_none());
+
+        assert!(pool.remove_bundle_by_replacement_uuid("other").is_none());
+        let cancelled = pool.remove_bundle_by_replacement_uuid("uuid").unwrap();
+        assert_eq!(cancelled.hash, B256::with_last_byte(3));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn replacement_uuid_is_scoped_to_sender() {
+        let pool = BundlePool::default();
+        let sender = Address::with_last_byte(1);
+        let other_sender = Address::with_last_byte(2);
+
+        let mut first = signed_bundle(1, sender);
+        first.replacement_uuid = Some("uuid".to_string());
+        pool.add_bundle(first.clone()).unwrap();
+        let other_bundle = signed_bundle(2, other_sender);
+        pool.add_bundle(other_bundle.clone()).unwrap();
+
+        // another sender can neither replace the bundle nor share its uuid
+        let mut other = signed_bundle(3, other_sender);
+        other.replacement_uuid = Some("uuid".to_string());
+        assert_eq!(pool.add_bundle(other.clone()), Err(BundlePoolError::ReplacementUuidTaken));
+        assert_eq!(pool.get(&first.hash), Some(first.clone()));
+
+        // the first sender still replaces its own bundle
+        let mut replacement = signed_bundle(4, sender);
+        replacement.replacement_uuid = Some("uuid".to_string());
+        pool.add_bundle(replacement.clone()).unwrap();
+        assert!(pool.get(&first.hash).is_none());
+        assert_eq!(pool.get(&replacement.hash), Some(replacement.clone()));
+
+        // cancelling by the uuid only removes the bundle of the first sender
+        assert_eq!(pool.remove_bundle_by_replacement_uuid("uuid"), Some(replacement));
+        assert_eq!(pool.get(&other_bundle.hash), Some(other_bundle));
+        assert_eq!(pool.len(), 1);
+
+        // once cancelled, the uuid can be used by the other sender
+        pool.add_bundle(other.clone()).unwrap();
+        assert_eq!(pool.get(&other.hash), Some(other));
+    }
+
+    #[test]
+    fn bundles_without_sender_are_not_replaced() {
+        let pool = BundlePool::default();
+        pool.add_bundle(bundle(1, 1, 1)).unwrap();
+        assert_eq!(pool.add_bundle(bundle(1, 1, 5)), Err(BundlePoolError::AlreadyKnown));
+        assert_eq!(pool.get(&B256::with_last_byte(1)).unwrap().max_block_number, 1);
+
+        // bundles of a sender don't replace them either
+        assert_eq!(
+            pool.add_bundle(signed_bundle(1, Address::with_last_byte(1))),
+            Err(BundlePoolError::AlreadyKnown)
+        );
+
+        let mut with_uuid = bundle(2, 1, 1);
+        with_uuid.replacement_uuid = Some("uuid".to_string());
+        assert_eq!(pool.add_bundle(with_uuid), Err(BundlePoolError::ReplacementUuidWithoutSender));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn bundles_for_timestamp() {
+        let pool = BundlePool::default();
+        let mut bundle = bundle(1, 1, 1);
+        bundle.min_timestamp = Some(10);
+        bundle.max_timestamp = Some(20);
+        pool.add_bundle(bundle).unwrap();
+
+        assert!(pool.bundles_for_block(1, 9).is_empty());
+        assert_eq!(pool.bundles_for_block(1, 10).len(), 1);
+        assert_eq!(pool.bundles_for_block(1, 20).len(), 1);
+        assert!(pool.bundles_for_block(1, 21).is_empty());
+    }
+
+    #[test]
+    fn reject_bundles_if_full() {
+        let pool = BundlePool::default();
+        for i in 0..MAX_BUNDLES {
+            let mut bundle = bundle(0, 1, 1);
+            bundle.hash = B256::from(U256::from(i + 2));
+            bundle.transactions = vec![signed(Address::from_word(bundle.hash))];
+            pool.add_bundle(bundle).unwrap();
+        }
+        assert_eq!(pool.add_bundle(bundle(1, 1, 1)), Err(BundlePoolError::PoolFull));
+
+        pool.remove_expired(2);
+        assert!(pool.add_bundle(bundle(1, 2, 2)).is_ok());
+    }
+
+    #[test]
+    fn reject_bundles_of_sender_over_limit() {
+        let pool = BundlePool::default();
+        let sender = Address::with_last_byte(1);
+        for i in 0..MAX_BUNDLES_PER_SENDER {
+            let mut bundle = bundle(0, 1, 1);
+            bundle.hash = B256::from(U256::from(i + 2));
+            bundle.transactions = vec![signed(sender)];
+            pool.add_bundle(bundle).unwrap();
+        }
+
+        let mut next = bundle(1, 1, 1);
+        next.transactions = vec![signed(sender)];
+        assert_eq!(pool.add_bundle(next.clone()), Err(BundlePoolError::SenderLimitExceeded));
+
+        // replacing a bundle of the sender is still possible
+        next.hash = B256::from(U256::from(2));
+        assert!(pool.add_bundle(next).is_ok());
+
+        // as are bundles of other senders
+        let mut other = bundle(1, 1, 1);
+        other.transactions = vec![signed(Address::with_last_byte(2))];
+        assert!(pool.add_bundle(other).is_ok());
+        assert_eq!(pool.len(), MAX_BUNDLES_PER_SENDER + 1);
+    }
+
+    #[test]
+    fn bundle_too_far_in_future() {
+        assert!(!bundle(1, 1 + MAX_FUTURE_BLOCKS, 30).is_too_far_in_future(1));
+        assert!(bundle(1, 2 + MAX_FUTURE_BLOCKS, 30).is_too_far_in_future(1));
+    }
+}
@@ -27,6 +27,14 @@ pub enum PayloadBuilderError {
     /// Thrown if the payload requests withdrawals before Shanghai activation.
     #[error("withdrawals set before Shanghai activation")]
     WithdrawalsBeforeShanghai,
+    /// Thrown if the signer of a transaction forced by the payload attributes can't be recovered.
+    #[cfg(feature = "optimism")]
+    #[error("failed to recover signer of forced transaction {0}")]
+    TransactionEcRecoverFailed(B256),
+    /// Thrown if the transactions forced by the payload attributes exceed the block gas limit.
+    #[cfg(feature = "optimism")]
+    #[error("forced transaction {0} exceeds the block gas limit")]
+    ForcedTransactionGasLimitExceeded(B256),
 }
 
 impl From<oneshot::error::RecvError> for PayloadBuilderError {
@@ -1,6 +1,8 @@
 //! Contains types required for building a payload.
 
-use alloy_rlp::Encodable;
+use alloy_rlp::{Encodable, Error as DecodeError};
+#[cfg(feature = "optimism")]
+use reth_primitives::{keccak256, TransactionSigned};
 use reth_primitives::{
     revm::config::revm_spec_by_timestamp_after_merge, Address, BlobTransactionSidecar, ChainSpec,
     Header, SealedBlock, Withdrawal, B256, U256,
@@ -137,6 +139,22 @@ pub struct PayloadBuilderAttributes {
     pub withdrawals: Vec<Withdrawal>,
     /// Root of the parent beacon block
     pub parent_beacon_block_root: Option<B256>,
+    /// Optimism Payload Builder Attributes
+    #[cfg(feature = "optimism")]
+    pub optimism_payload_attributes: OptimismPayloadBuilderAttributes,
+}
+
+/// Optimism Payload Builder Attributes
+#[cfg(feature = "optimism")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimismPayloadBuilderAttributes {
+    /// Transactions that are forced into the payload, in order, before any transactions from the
+    /// pool.
+    pub transactions: Vec<TransactionSigned>,
+    /// Whether to exclude the transactions from the pool.
+    pub no_tx_pool: bool,
+    /// The gas limit of the payload, overrides the gas limit of the parent block if set.
+    pub gas_limit: Option<u64>,
 }
 
 // === impl PayloadBuilderAttributes ===
@@ -144,8 +162,10 @@ pub struct PayloadBuilderAttributes {
 impl PayloadBuilderAttributes {
     /// Creates a new payload builder for the given parent block and the attributes.
     ///
-    /// Derives the unique [PayloadId] for the given parent and attributes
-    pub fn new(parent: B256, attributes: PayloadAttributes) -> Self {
+    /// Derives the unique [PayloadId] for the given parent and attributes.
+    ///
+    /// Returns an error if one of the forced optimism transactions can't be decoded.
+    pub fn try_new(parent: B256, attributes: PayloadAttributes) -> Result<Self, DecodeError> {
         let id = payload_id(&parent, &attributes);
 
         let withdraw = attributes.withdrawals.map(
@@ -157,7 +177,22 @@ impl PayloadBuilderAttributes {
             },
         );
 
-        Self {
+        #[cfg(feature = "optimism")]
+        let optimism_payload_attributes = {
+            let attributes = attributes.optimism_payload_attributes;
+            OptimismPayloadBuilderAttributes {
+                transactions: attributes
+                    .transactions
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(TransactionSigned::decode_enveloped)
+                    .collect::<Result<_, _>>()?,
+                no_tx_pool: attributes.no_tx_pool.unwrap_or_default(),
+                gas_limit: attributes.gas_limit.map(|gas_limit| gas_limit.to()),
+            }
+        };
+
+        Ok(Self {
             id,
             parent,
             timestamp: attributes.timestamp.to(),
@@ -165,7 +200,9 @@ impl PayloadBuilderAttributes {
             prev_randao: attributes.prev_randao,
             withdrawals: withdraw.unwrap_or_default(),
             parent_beacon_block_root: attributes.parent_beacon_block_root,
-        }
+            #[cfg(feature = "optimism")]
+            optimism_payload_attributes,
+        })
     }
 
     /// Returns the configured [CfgEnv] and [BlockEnv] for the targeted payload (that has the
@@ -202,16 +239,23 @@ impl PayloadBuilderAttributes {
             )
             .map(BlobExcessGasAndPrice::new);
 
+        #[cfg(feature = "optimism")]
+        let gas_limit = self.optimism_payload_attributes.gas_limit.unwrap_or(parent.gas_limit);
+        #[cfg(not(feature = "optimism"))]
+        let gas_limit = parent.gas_limit;
+
         let block_env = BlockEnv {
             number: U256::from(parent.number + 1),
             coinbase: self.suggested_fee_recipient,
             timestamp: U256::from(self.timestamp),
             difficulty: U256::ZERO,
             prevrandao: Some(self.prev_randao),
-            gas_limit: U256::from(parent.gas_limit),
+            gas_limit: U256::from(gas_limit),
             // calculate basefee based on parent block's gas usage
             basefee: U256::from(
-                parent.next_block_base_fee(chain_spec.base_fee_params).unwrap_or_default(),
+                parent
+                    .next_block_base_fee(chain_spec.base_fee_params(self.timestamp))
+                    .unwrap_or_default(),
             ),
             // calculate excess gas based on parent block's blob gas usage
             blob_excess_gas_and_price,
@@ -244,6 +288,24 @@ pub(crate) fn payload_id(parent: &B256, attributes: &PayloadAttributes) -> Paylo
     if let Some(parent_beacon_block) = attributes.parent_beacon_block_root {
         hasher.update(parent_beacon_block);
     }
+
+    #[cfg(feature = "optimism")]
+    {
+        let attributes = &attributes.optimism_payload_attributes;
+        let no_tx_pool = attributes.no_tx_pool.unwrap_or_default();
+        if no_tx_pool || attributes.transactions.as_ref().is_some_and(|txs| !txs.is_empty()) {
+            hasher.update([no_tx_pool as u8]);
+            let txs = attributes.transactions.as_deref().unwrap_or_default();
+            hasher.update(txs.len().to_be_bytes());
+            for tx in txs {
+                hasher.update(keccak256(tx));
+            }
+        }
+        if let Some(gas_limit) = attributes.gas_limit {
+            hasher.update(gas_limit.to::<u64>().to_be_bytes());
+        }
+    }
+
     let out = hasher.finalize();
     PayloadId::new(out.as_slice()[..8].try_into().expect("sufficient length"))
 }
@@ -17,7 +17,8 @@
 /// - `gas_limit`: The gas limit of the current block.
 /// - `base_fee`: The current base fee per gas.
 /// - `base_fee_params`: Base fee parameters such as elasticity multiplier and max change
-///   denominator.
+///   denominator. These can change with hardforks, see
+///   [ChainSpec::base_fee_params](crate::ChainSpec::base_fee_params).
 ///
 /// Returns:
 /// The calculated base fee for the next block as a `u64`.
@@ -96,4 +97,22 @@ mod tests {
             );
         }
     }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn calculate_optimism_base_fee_success() {
+        let base_fee = 1_000_000_000;
+        let gas_limit = 30_000_000;
+
+        for (params, full_block, empty_block) in [
+            (crate::BaseFeeParams::optimism(), 1_100_000_000, 980_000_000),
+            (crate::BaseFeeParams::optimism_canyon(), 1_020_000_000, 996_000_000),
+        ] {
+            assert_eq!(
+                calculate_next_block_base_fee(gas_limit, gas_limit, base_fee, params),
+                full_block
+            );
+            assert_eq!(calculate_next_block_base_fee(0, gas_limit, base_fee, params), empty_block);
+        }
+    }
 }
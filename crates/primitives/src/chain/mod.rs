@@ -13,9 +13,12 @@ use strum::{AsRefStr, EnumCount, EnumIter, EnumString, EnumVariantNames};
 // The chain spec module.
 mod spec;
 pub use spec::{
-    AllGenesisFormats, BaseFeeParams, ChainSpec, ChainSpecBuilder, DisplayHardforks, ForkCondition,
-    ForkTimestamps, DEV, GOERLI, HOLESKY, MAINNET, SEPOLIA,
+    AllGenesisFormats, BaseFeeParams, BaseFeeParamsKind, ChainSpec, ChainSpecBuilder,
+    DisplayHardforks, ForkBaseFeeParams, ForkCondition, ForkTimestamps, DEV, GOERLI, HOLESKY,
+    MAINNET, SEPOLIA,
 };
+#[cfg(feature = "optimism")]
+pub use spec::{BASE_GOERLI, OP_GOERLI, OP_MAINNET};
 
 // The chain info module.
 mod info;
@@ -58,6 +61,9 @@ pub enum NamedChain {
     OptimismKovan = 69,
     OptimismGoerli = 420,
 
+    Base = 8453,
+    BaseGoerli = 84531,
+
     Arbitrum = 42161,
     ArbitrumTestnet = 421611,
     ArbitrumGoerli = 421613,
@@ -121,6 +127,21 @@ impl Chain {
         Chain::Named(NamedChain::Dev)
     }
 
+    /// Returns the optimism mainnet chain.
+    pub const fn optimism_mainnet() -> Self {
+        Chain::Named(NamedChain::Optimism)
+    }
+
+    /// Returns the optimism goerli chain.
+    pub const fn optimism_goerli() -> Self {
+        Chain::Named(NamedChain::OptimismGoerli)
+    }
+
+    /// Returns the base goerli chain.
+    pub const fn base_goerli() -> Self {
+        Chain::Named(NamedChain::BaseGoerli)
+    }
+
     /// The id of the chain
     pub fn id(&self) -> u64 {
         match self {
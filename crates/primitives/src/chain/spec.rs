@@ -2,6 +2,7 @@ use crate::{
     constants::{
         EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR, EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
         EIP1559_INITIAL_BASE_FEE, EMPTY_RECEIPTS, EMPTY_TRANSACTIONS, EMPTY_WITHDRAWALS,
+        SLOT_DURATION,
     },
     forkid::ForkFilterKey,
     header::Head,
@@ -16,6 +17,17 @@ use std::{
     collections::BTreeMap,
     fmt::{Display, Formatter},
     sync::Arc,
+    time::Duration,
+};
+
+#[cfg(feature = "optimism")]
+use crate::constants::{
+    OP_BLOCK_TIME, OP_GOERLI_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+    OP_GOERLI_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+    OP_GOERLI_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+    OP_MAINNET_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+    OP_MAINNET_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+    OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
 };
 
 /// The Ethereum mainnet spec
@@ -63,9 +75,12 @@ pub static MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             11052984,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
         )),
-        base_fee_params: BaseFeeParams::ethereum(),
+        base_fee_params: BaseFeeParams::ethereum().into(),
         prune_delete_limit: 3500,
         snapshot_block_interval: 500_000,
+        block_time: SLOT_DURATION,
+        #[cfg(feature = "optimism")]
+        optimism: false,
     }
     .into()
 });
@@ -106,9 +121,12 @@ pub static GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             4367322,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
         )),
-        base_fee_params: BaseFeeParams::ethereum(),
+        base_fee_params: BaseFeeParams::ethereum().into(),
         prune_delete_limit: 1700,
         snapshot_block_interval: 1_000_000,
+        block_time: SLOT_DURATION,
+        #[cfg(feature = "optimism")]
+        optimism: false,
     }
     .into()
 });
@@ -153,9 +171,12 @@ pub static SEPOLIA: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             1273020,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
         )),
-        base_fee_params: BaseFeeParams::ethereum(),
+        base_fee_params: BaseFeeParams::ethereum().into(),
         prune_delete_limit: 1700,
         snapshot_block_interval: 1_000_000,
+        block_time: SLOT_DURATION,
+        #[cfg(feature = "optimism")]
+        optimism: false,
     }
     .into()
 });
@@ -195,9 +216,12 @@ pub static HOLESKY: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
             0,
             b256!("649bbc62d0e31342afea4e5cd82d4049e7e1ee912fc0889aa790803be39038c5"),
         )),
-        base_fee_params: BaseFeeParams::ethereum(),
+        base_fee_params: BaseFeeParams::ethereum().into(),
         prune_delete_limit: 1700,
         snapshot_block_interval: 1_000_000,
+        block_time: SLOT_DURATION,
+        #[cfg(feature = "optimism")]
+        optimism: false,
     }
     .into()
 });
@@ -241,6 +265,204 @@ pub static DEV: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
     .into()
 });
 
+/// The Optimism Mainnet spec
+///
+/// The bundled genesis does not contain the pre-Bedrock state allocation, the state at the Bedrock
+/// block has to be imported before the node can sync.
+#[cfg(feature = "optimism")]
+pub static OP_MAINNET: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
+    ChainSpec {
+        chain: Chain::optimism_mainnet(),
+        genesis: serde_json::from_str(include_str!("../../res/genesis/optimism.json"))
+            .expect("Can't deserialize Optimism Mainnet genesis json"),
+        genesis_hash: Some(b256!(
+            "7ca38a1916c42007829c55e69d3e9a73265554b586a499015373241b8a3fa48b"
+        )),
+        // <https://optimistic.etherscan.io/block/105235063>
+        paris_block_and_final_difficulty: Some((105235063, U256::from(0))),
+        fork_timestamps: ForkTimestamps::default().shanghai(1704992401),
+        hardforks: BTreeMap::from([
+            (Hardfork::Frontier, ForkCondition::Block(0)),
+            (Hardfork::Homestead, ForkCondition::Block(0)),
+            (Hardfork::Tangerine, ForkCondition::Block(0)),
+            (Hardfork::SpuriousDragon, ForkCondition::Block(0)),
+            (Hardfork::Byzantium, ForkCondition::Block(0)),
+            (Hardfork::Constantinople, ForkCondition::Block(0)),
+            (Hardfork::Petersburg, ForkCondition::Block(0)),
+            (Hardfork::Istanbul, ForkCondition::Block(0)),
+            (Hardfork::MuirGlacier, ForkCondition::Block(0)),
+            (Hardfork::Berlin, ForkCondition::Block(3950000)),
+            (Hardfork::London, ForkCondition::Block(105235063)),
+            (Hardfork::ArrowGlacier, ForkCondition::Block(105235063)),
+            (Hardfork::GrayGlacier, ForkCondition::Block(105235063)),
+            (
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(105235063), total_difficulty: U256::from(0) },
+            ),
+            (Hardfork::Bedrock, ForkCondition::Block(105235063)),
+            (Hardfork::Regolith, ForkCondition::Timestamp(0)),
+            (Hardfork::Shanghai, ForkCondition::Timestamp(1704992401)),
+            (Hardfork::Canyon, ForkCondition::Timestamp(1704992401)),
+        ]),
+        deposit_contract: None,
+        base_fee_params: BaseFeeParamsKind::Variable(
+            vec![
+                (Hardfork::London, BaseFeeParams::optimism()),
+                (Hardfork::Canyon, BaseFeeParams::optimism_canyon()),
+            ]
+            .into(),
+        ),
+        prune_delete_limit: 1700,
+        snapshot_block_interval: 1_000_000,
+        block_time: OP_BLOCK_TIME,
+        optimism: true,
+    }
+    .into()
+});
+
+/// The Optimism Goerli spec
+///
+/// The bundled genesis does not contain the pre-Bedrock state allocation, the state at the Bedrock
+/// block has to be imported before the node can sync.
+#[cfg(feature = "optimism")]
+pub static OP_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
+    ChainSpec {
+        chain: Chain::optimism_goerli(),
+        genesis: serde_json::from_str(include_str!("../../res/genesis/goerli_op.json"))
+            .expect("Can't deserialize Optimism Goerli genesis json"),
+        genesis_hash: Some(b256!(
+            "c1fc15cd51159b1f1e5cbc4b82e85c1447ddfa33c52cf1d98d14fba0d6354be1"
+        )),
+        // <https://goerli-optimism.etherscan.io/block/4061224>
+        paris_block_and_final_difficulty: Some((4061224, U256::from(0))),
+        fork_timestamps: ForkTimestamps::default().shanghai(1699981200),
+        hardforks: BTreeMap::from([
+            (Hardfork::Frontier, ForkCondition::Block(0)),
+            (Hardfork::Homestead, ForkCondition::Block(0)),
+            (Hardfork::Tangerine, ForkCondition::Block(0)),
+            (Hardfork::SpuriousDragon, ForkCondition::Block(0)),
+            (Hardfork::Byzantium, ForkCondition::Block(0)),
+            (Hardfork::Constantinople, ForkCondition::Block(0)),
+            (Hardfork::Petersburg, ForkCondition::Block(0)),
+            (Hardfork::Istanbul, ForkCondition::Block(0)),
+            (Hardfork::MuirGlacier, ForkCondition::Block(0)),
+            (Hardfork::Berlin, ForkCondition::Block(0)),
+            (Hardfork::London, ForkCondition::Block(4061224)),
+            (Hardfork::ArrowGlacier, ForkCondition::Block(4061224)),
+            (Hardfork::GrayGlacier, ForkCondition::Block(4061224)),
+            (
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(4061224), total_difficulty: U256::from(0) },
+            ),
+            (Hardfork::Bedrock, ForkCondition::Block(4061224)),
+            (Hardfork::Regolith, ForkCondition::Timestamp(1679079600)),
+            (Hardfork::Shanghai, ForkCondition::Timestamp(1699981200)),
+            (Hardfork::Canyon, ForkCondition::Timestamp(1699981200)),
+        ]),
+        deposit_contract: None,
+        base_fee_params: BaseFeeParamsKind::Variable(
+            vec![
+                (Hardfork::London, BaseFeeParams::optimism_goerli()),
+                (Hardfork::Canyon, BaseFeeParams::optimism_goerli_canyon()),
+            ]
+            .into(),
+        ),
+        prune_delete_limit: 1700,
+        snapshot_block_interval: 1_000_000,
+        block_time: OP_BLOCK_TIME,
+        optimism: true,
+    }
+    .into()
+});
+
+/// The Base Goerli spec
+///
+/// The bundled genesis does not contain the state allocation, the genesis state has to be imported
+/// before the node can sync.
+#[cfg(feature = "optimism")]
+pub static BASE_GOERLI: Lazy<Arc<ChainSpec>> = Lazy::new(|| {
+    ChainSpec {
+        chain: Chain::base_goerli(),
+        genesis: serde_json::from_str(include_str!("../../res/genesis/goerli_base.json"))
+            .expect("Can't deserialize Base Goerli genesis json"),
+        genesis_hash: Some(b256!(
+            "a3ab140f15ea7f7443a4702da64c10314eb04d488e72974e02e2d728096b4f76"
+        )),
+        paris_block_and_final_difficulty: Some((0, U256::from(0))),
+        fork_timestamps: ForkTimestamps::default().shanghai(1699981200),
+        hardforks: BTreeMap::from([
+            (Hardfork::Frontier, ForkCondition::Block(0)),
+            (Hardfork::Homestead, ForkCondition::Block(0)),
+            (Hardfork::Tangerine, ForkCondition::Block(0)),
+            (Hardfork::SpuriousDragon, ForkCondition::Block(0)),
+            (Hardfork::Byzantium, ForkCondition::Block(0)),
+            (Hardfork::Constantinople, ForkCondition::Block(0)),
+            (Hardfork::Petersburg, ForkCondition::Block(0)),
+            (Hardfork::Istanbul, ForkCondition::Block(0)),
+            (Hardfork::MuirGlacier, ForkCondition::Block(0)),
+            (Hardfork::Berlin, ForkCondition::Block(0)),
+            (Hardfork::London, ForkCondition::Block(0)),
+            (Hardfork::ArrowGlacier, ForkCondition::Block(0)),
+            (Hardfork::GrayGlacier, ForkCondition::Block(0)),
+            (
+                Hardfork::Paris,
+                ForkCondition::TTD { fork_block: Some(0), total_difficulty: U256::from(0) },
+            ),
+            (Hardfork::Bedrock, ForkCondition::Block(0)),
+            (Hardfork::Regolith, ForkCondition::Timestamp(1683219600)),
+            (Hardfork::Shanghai, ForkCondition::Timestamp(1699981200)),
+            (Hardfork::Canyon, ForkCondition::Timestamp(1699981200)),
+        ]),
+        deposit_contract: None,
+        base_fee_params: BaseFeeParamsKind::Variable(
+            vec![
+                (Hardfork::London, BaseFeeParams::optimism_goerli()),
+                (Hardfork::Canyon, BaseFeeParams::optimism_goerli_canyon()),
+            ]
+            .into(),
+        ),
+        prune_delete_limit: 1700,
+        snapshot_block_interval: 1_000_000,
+        block_time: OP_BLOCK_TIME,
+        optimism: true,
+    }
+    .into()
+});
+
+/// The base fee parameters of a chain, which are either the same for the whole chain or change
+/// with hardforks.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum BaseFeeParamsKind {
+    /// The same [BaseFeeParams] apply to all blocks.
+    Constant(BaseFeeParams),
+    /// The [BaseFeeParams] change with hardforks, see [ForkBaseFeeParams].
+    Variable(ForkBaseFeeParams),
+}
+
+impl From<BaseFeeParams> for BaseFeeParamsKind {
+    fn from(params: BaseFeeParams) -> Self {
+        BaseFeeParamsKind::Constant(params)
+    }
+}
+
+impl From<ForkBaseFeeParams> for BaseFeeParamsKind {
+    fn from(params: ForkBaseFeeParams) -> Self {
+        BaseFeeParamsKind::Variable(params)
+    }
+}
+
+/// A list of hardforks and the [BaseFeeParams] that apply from their activation on, in
+/// activation order.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ForkBaseFeeParams(pub Vec<(Hardfork, BaseFeeParams)>);
+
+impl From<Vec<(Hardfork, BaseFeeParams)>> for ForkBaseFeeParams {
+    fn from(params: Vec<(Hardfork, BaseFeeParams)>) -> Self {
+        ForkBaseFeeParams(params)
+    }
+}
+
 /// BaseFeeParams contains the config parameters that control block base fee computation
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 pub struct BaseFeeParams {
@@ -258,6 +480,42 @@ impl BaseFeeParams {
             elasticity_multiplier: EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
         }
     }
+
+    /// Get the base fee parameters for Optimism Mainnet
+    #[cfg(feature = "optimism")]
+    pub const fn optimism() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator: OP_MAINNET_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+            elasticity_multiplier: OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+        }
+    }
+
+    /// Get the base fee parameters for Optimism Goerli
+    #[cfg(feature = "optimism")]
+    pub const fn optimism_goerli() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator: OP_GOERLI_EIP1559_DEFAULT_BASE_FEE_MAX_CHANGE_DENOMINATOR,
+            elasticity_multiplier: OP_GOERLI_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+        }
+    }
+
+    /// Get the base fee parameters for Optimism Mainnet after the Canyon hardfork
+    #[cfg(feature = "optimism")]
+    pub const fn optimism_canyon() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator: OP_MAINNET_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+            elasticity_multiplier: OP_MAINNET_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+        }
+    }
+
+    /// Get the base fee parameters for Optimism Goerli after the Canyon hardfork
+    #[cfg(feature = "optimism")]
+    pub const fn optimism_goerli_canyon() -> BaseFeeParams {
+        BaseFeeParams {
+            max_change_denominator: OP_GOERLI_EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR_CANYON,
+            elasticity_multiplier: OP_GOERLI_EIP1559_DEFAULT_ELASTICITY_MULTIPLIER,
+        }
+    }
 }
 
 /// An Ethereum chain specification.
@@ -300,7 +558,7 @@ pub struct ChainSpec {
     pub deposit_contract: Option<DepositContract>,
 
     /// The parameters that configure how a block's base fee is computed
-    pub base_fee_params: BaseFeeParams,
+    pub base_fee_params: BaseFeeParamsKind,
 
     /// The delete limit for pruner, per block. In the actual pruner run it will be multiplied by
     /// the amount of blocks between pruner runs to account for the difference in amount of new
@@ -310,6 +568,15 @@ pub struct ChainSpec {
 
     /// The block interval for creating snapshots. Each snapshot will have that much blocks in it.
     pub snapshot_block_interval: u64,
+
+    /// The expected time between two blocks, used to estimate the timestamp of the next block.
+    #[serde(default = "default_block_time")]
+    pub block_time: Duration,
+
+    /// Whether this chain is an OP Stack chain.
+    #[cfg(feature = "optimism")]
+    #[serde(default)]
+    pub optimism: bool,
 }
 
 impl Default for ChainSpec {
@@ -322,19 +589,42 @@ impl Default for ChainSpec {
             fork_timestamps: Default::default(),
             hardforks: Default::default(),
             deposit_contract: Default::default(),
-            base_fee_params: BaseFeeParams::ethereum(),
+            base_fee_params: BaseFeeParams::ethereum().into(),
             prune_delete_limit: MAINNET.prune_delete_limit,
             snapshot_block_interval: Default::default(),
+            block_time: SLOT_DURATION,
+            #[cfg(feature = "optimism")]
+            optimism: Default::default(),
         }
     }
 }
 
+/// The default time between two blocks, a slot of the beacon chain.
+fn default_block_time() -> Duration {
+    SLOT_DURATION
+}
+
 impl ChainSpec {
     /// Get information about the chain itself
     pub fn chain(&self) -> Chain {
         self.chain
     }
 
+    /// Returns `true` if this chain is an OP Stack chain.
+    ///
+    /// Always returns `false` if the `optimism` feature is disabled.
+    #[inline]
+    pub fn is_optimism(&self) -> bool {
+        #[cfg(feature = "optimism")]
+        {
+            self.optimism
+        }
+        #[cfg(not(feature = "optimism"))]
+        {
+            false
+        }
+    }
+
     /// Get the genesis block specification.
     ///
     /// To get the header for the genesis block, use [`Self::genesis_header`] instead.
@@ -366,6 +656,13 @@ impl ChainSpec {
                 (None, None, None)
             };
 
+        // Chains migrated to Bedrock don't include the state of the migration in the genesis
+        #[cfg(feature = "optimism")]
+        let state_root =
+            self.genesis.state_hash.unwrap_or_else(|| genesis_state_root(&self.genesis.alloc));
+        #[cfg(not(feature = "optimism"))]
+        let state_root = genesis_state_root(&self.genesis.alloc);
+
         Header {
             parent_hash: B256::ZERO,
             number: 0,
@@ -377,7 +674,7 @@ impl ChainSpec {
             difficulty: self.genesis.difficulty,
             nonce: self.genesis.nonce,
             extra_data: self.genesis.extra_data.clone(),
-            state_root: genesis_state_root(&self.genesis.alloc),
+            state_root,
             timestamp: self.genesis.timestamp,
             mix_hash: self.genesis.mix_hash,
             beneficiary: self.genesis.coinbase,
@@ -463,6 +760,24 @@ impl ChainSpec {
         self.fork(fork).active_at_timestamp(timestamp)
     }
 
+    /// Returns the [BaseFeeParams] that apply to the block with the given timestamp.
+    ///
+    /// For chains with [BaseFeeParamsKind::Variable] params, these are the params of the most
+    /// recent hardfork that is active at the timestamp. If none of the listed hardforks is
+    /// activated by timestamp, e.g. [Hardfork::London], the first entry applies.
+    pub fn base_fee_params(&self, timestamp: u64) -> BaseFeeParams {
+        match &self.base_fee_params {
+            BaseFeeParamsKind::Constant(params) => *params,
+            BaseFeeParamsKind::Variable(ForkBaseFeeParams(params)) => params
+                .iter()
+                .rev()
+                .find(|(fork, _)| self.is_fork_active_at_timestamp(*fork, timestamp))
+                .or_else(|| params.first())
+                .map(|(_, params)| *params)
+                .unwrap_or_else(BaseFeeParams::ethereum),
+        }
+    }
+
     /// Convenience method to check if [Hardfork::Shanghai] is active at a given timestamp.
     #[inline]
     pub fn is_shanghai_active_at_timestamp(&self, timestamp: u64) -> bool {
@@ -624,6 +939,8 @@ impl From<Genesis> for ChainSpec {
             (Hardfork::London, genesis.config.london_block),
             (Hardfork::ArrowGlacier, genesis.config.arrow_glacier_block),
             (Hardfork::GrayGlacier, genesis.config.gray_glacier_block),
+            #[cfg(feature = "optimism")]
+            (Hardfork::Bedrock, genesis.config.bedrock_block),
         ];
         let mut hardforks = hardfork_opts
             .iter()
@@ -645,6 +962,10 @@ impl From<Genesis> for ChainSpec {
         let time_hardfork_opts = [
             (Hardfork::Shanghai, genesis.config.shanghai_time),
             (Hardfork::Cancun, genesis.config.cancun_time),
+            #[cfg(feature = "optimism")]
+            (Hardfork::Regolith, genesis.config.regolith_time),
+            #[cfg(feature = "optimism")]
+            (Hardfork::Canyon, genesis.config.canyon_time),
         ];
 
         let time_hardforks = time_hardfork_opts
@@ -656,11 +977,41 @@ impl From<Genesis> for ChainSpec {
 
         hardforks.extend(time_hardforks);
 
+        // OP Stack EIP-1559 parameters
+        #[cfg(feature = "optimism")]
+        let base_fee_params = match genesis.config.optimism {
+            Some(config) => {
+                let params = BaseFeeParams {
+                    max_change_denominator: config.eip1559_denominator,
+                    elasticity_multiplier: config.eip1559_elasticity,
+                };
+                match config.eip1559_denominator_canyon {
+                    Some(max_change_denominator) => BaseFeeParamsKind::Variable(
+                        vec![
+                            (Hardfork::London, params),
+                            (Hardfork::Canyon, BaseFeeParams { max_change_denominator, ..params }),
+                        ]
+                        .into(),
+                    ),
+                    None => params.into(),
+                }
+            }
+            None => BaseFeeParams::ethereum().into(),
+        };
+        #[cfg(feature = "optimism")]
+        let optimism = hardforks.contains_key(&Hardfork::Bedrock);
+
         Self {
             chain: genesis.config.chain_id.into(),
             genesis,
             genesis_hash: None,
             fork_timestamps: ForkTimestamps::from_hardforks(&hardforks),
+            #[cfg(feature = "optimism")]
+            base_fee_params,
+            #[cfg(feature = "optimism")]
+            block_time: if optimism { OP_BLOCK_TIME } else { SLOT_DURATION },
+            #[cfg(feature = "optimism")]
+            optimism,
             hardforks,
             paris_block_and_final_difficulty: None,
             deposit_contract: None,
@@ -873,6 +1224,32 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Enable Bedrock at genesis
+    #[cfg(feature = "optimism")]
+    pub fn bedrock_activated(mut self) -> Self {
+        self = self.paris_activated();
+        self.hardforks.insert(Hardfork::Bedrock, ForkCondition::Block(0));
+        self
+    }
+
+    /// Enable Regolith at genesis
+    #[cfg(feature = "optimism")]
+    pub fn regolith_activated(mut self) -> Self {
+        self = self.bedrock_activated();
+        self.hardforks.insert(Hardfork::Regolith, ForkCondition::Timestamp(0));
+        self
+    }
+
+    /// Enable Canyon at genesis
+    #[cfg(feature = "optimism")]
+    pub fn canyon_activated(mut self) -> Self {
+        self = self.regolith_activated();
+        // Canyon also activates changes from L1's Shanghai hardfork
+        self.hardforks.insert(Hardfork::Shanghai, ForkCondition::Timestamp(0));
+        self.hardforks.insert(Hardfork::Canyon, ForkCondition::Timestamp(0));
+        self
+    }
+
     /// Build the resulting [`ChainSpec`].
     ///
     /// # Panics
@@ -885,6 +1262,14 @@ impl ChainSpecBuilder {
             genesis: self.genesis.expect("The genesis is required"),
             genesis_hash: None,
             fork_timestamps: ForkTimestamps::from_hardforks(&self.hardforks),
+            #[cfg(feature = "optimism")]
+            block_time: if self.hardforks.contains_key(&Hardfork::Bedrock) {
+                OP_BLOCK_TIME
+            } else {
+                SLOT_DURATION
+            },
+            #[cfg(feature = "optimism")]
+            optimism: self.hardforks.contains_key(&Hardfork::Bedrock),
             hardforks: self.hardforks,
             paris_block_and_final_difficulty: None,
             deposit_contract: None,
@@ -1193,6 +1578,8 @@ mod tests {
         b256, hex, ChainConfig, GenesisAccount, NamedChain, B256, DEV, GOERLI, HOLESKY, MAINNET,
         SEPOLIA, U256,
     };
+    #[cfg(feature = "optimism")]
+    use crate::{BASE_GOERLI, OP_GOERLI, OP_MAINNET};
     use alloy_rlp::Encodable;
     use bytes::BytesMut;
     use std::{collections::HashMap, str::FromStr};
@@ -1787,6 +2174,147 @@ Post-merge hard forks (timestamp based):
         );
     }
 
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn op_mainnet_forkids() {
+        test_fork_ids(
+            &OP_MAINNET,
+            &[
+                (
+                    Head { number: 0, ..Default::default() },
+                    ForkId { hash: ForkHash([0xca, 0xf5, 0x17, 0xed]), next: 3950000 },
+                ),
+                (
+                    Head { number: 3950000, ..Default::default() },
+                    ForkId { hash: ForkHash([0x52, 0x6a, 0x21, 0x71]), next: 105235063 },
+                ),
+                // First Bedrock block
+                (
+                    Head { number: 105235063, ..Default::default() },
+                    ForkId { hash: ForkHash([0xe3, 0x39, 0x8d, 0x7c]), next: 1704992401 },
+                ),
+                // First Canyon block
+                (
+                    Head { number: 105235064, timestamp: 1704992401, ..Default::default() },
+                    ForkId { hash: ForkHash([0xbd, 0xd4, 0xfd, 0xb2]), next: 0 },
+                ),
+            ],
+        );
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn op_goerli_forkids() {
+        test_fork_ids(
+            &OP_GOERLI,
+            &[
+                (
+                    Head { number: 0, ..Default::default() },
+                    ForkId { hash: ForkHash([0x6d, 0x63, 0x76, 0xbe]), next: 4061224 },
+                ),
+                (
+                    Head { number: 4061224, timestamp: 1679079599, ..Default::default() },
+                    ForkId { hash: ForkHash([0x03, 0x47, 0x85, 0x69]), next: 1679079600 },
+                ),
+                // First Regolith block
+                (
+                    Head { number: 4061225, timestamp: 1679079600, ..Default::default() },
+                    ForkId { hash: ForkHash([0x6d, 0x43, 0x1d, 0x6c]), next: 1699981200 },
+                ),
+                // First Canyon block
+                (
+                    Head { number: 4061226, timestamp: 1699981200, ..Default::default() },
+                    ForkId { hash: ForkHash([0x7f, 0x4a, 0x72, 0x1f]), next: 0 },
+                ),
+            ],
+        );
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn base_goerli_forkids() {
+        test_fork_ids(
+            &BASE_GOERLI,
+            &[
+                (
+                    Head { number: 0, ..Default::default() },
+                    ForkId { hash: ForkHash([0xd4, 0x0c, 0x23, 0x50]), next: 1683219600 },
+                ),
+                // First Regolith block
+                (
+                    Head { number: 1, timestamp: 1683219600, ..Default::default() },
+                    ForkId { hash: ForkHash([0xd5, 0x45, 0x43, 0x5d]), next: 1699981200 },
+                ),
+                // First Canyon block
+                (
+                    Head { number: 2, timestamp: 1699981200, ..Default::default() },
+                    ForkId { hash: ForkHash([0xb3, 0x29, 0x13, 0xde]), next: 0 },
+                ),
+            ],
+        );
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn op_chain_specs() {
+        for (spec, hash) in [
+            (
+                &OP_MAINNET,
+                b256!("7ca38a1916c42007829c55e69d3e9a73265554b586a499015373241b8a3fa48b"),
+            ),
+            (&OP_GOERLI, b256!("c1fc15cd51159b1f1e5cbc4b82e85c1447ddfa33c52cf1d98d14fba0d6354be1")),
+            (
+                &BASE_GOERLI,
+                b256!("a3ab140f15ea7f7443a4702da64c10314eb04d488e72974e02e2d728096b4f76"),
+            ),
+        ] {
+            assert!(spec.is_optimism());
+            assert_eq!(spec.genesis_hash(), hash);
+            assert_eq!(spec.block_time, Duration::from_secs(2));
+            let canyon = spec.fork(Hardfork::Canyon).as_timestamp().unwrap();
+            assert_eq!(spec.base_fee_params(canyon - 1).max_change_denominator, 50);
+            assert_eq!(spec.base_fee_params(canyon).max_change_denominator, 250);
+        }
+        assert!(!MAINNET.is_optimism());
+        assert_eq!(MAINNET.block_time, Duration::from_secs(12));
+
+        // the genesis headers of the chains migrated to Bedrock match their genesis hashes
+        for spec in [&OP_MAINNET, &OP_GOERLI] {
+            assert_eq!(spec.genesis_header().hash_slow(), spec.genesis_hash());
+        }
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn builder_sets_optimism() {
+        let spec = ChainSpecBuilder::mainnet().canyon_activated().build();
+        assert!(spec.is_optimism());
+        assert!(spec.fork(Hardfork::Canyon).active_at_timestamp(0));
+        assert_eq!(spec.block_time, Duration::from_secs(2));
+        assert!(!ChainSpecBuilder::mainnet().build().is_optimism());
+        assert_eq!(ChainSpecBuilder::mainnet().build().block_time, Duration::from_secs(12));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn optimism_genesis_into_chainspec() {
+        let spec = ChainSpec::from(BASE_GOERLI.genesis.clone());
+        assert!(spec.is_optimism());
+        assert_eq!(spec.block_time, Duration::from_secs(2));
+        for hardfork in
+            [Hardfork::Bedrock, Hardfork::Regolith, Hardfork::Shanghai, Hardfork::Canyon]
+        {
+            assert_eq!(spec.fork(hardfork), BASE_GOERLI.fork(hardfork));
+        }
+        let canyon = spec.fork(Hardfork::Canyon).as_timestamp().unwrap();
+        assert_eq!(spec.base_fee_params(canyon - 1), BASE_GOERLI.base_fee_params(canyon - 1));
+        assert_eq!(spec.base_fee_params(canyon), BASE_GOERLI.base_fee_params(canyon));
+
+        let spec = ChainSpec::from(MAINNET.genesis.clone());
+        assert!(!spec.is_optimism());
+        assert_eq!(spec.block_time, Duration::from_secs(12));
+    }
+
     #[test]
     fn dev_forkids() {
         test_fork_ids(
@@ -2374,4 +2902,39 @@ Post-merge hard forks (timestamp based):
             serde_json::from_str(&serialized_chain_spec).unwrap();
         assert!(matches!(deserialized_chain_spec, AllGenesisFormats::Reth(_)))
     }
+
+    #[test]
+    fn base_fee_params_by_fork() {
+        let shanghai_params =
+            BaseFeeParams { max_change_denominator: 250, elasticity_multiplier: 4 };
+        let mut spec = ChainSpecBuilder::mainnet().shanghai_activated().build();
+        spec.hardforks.insert(Hardfork::Shanghai, ForkCondition::Timestamp(100));
+        spec.base_fee_params = BaseFeeParamsKind::Variable(
+            vec![
+                (Hardfork::London, BaseFeeParams::ethereum()),
+                (Hardfork::Shanghai, shanghai_params),
+            ]
+            .into(),
+        );
+
+        assert_eq!(spec.base_fee_params(99), BaseFeeParams::ethereum());
+        assert_eq!(spec.base_fee_params(100), shanghai_params);
+
+        // constant params apply to all blocks
+        assert_eq!(MAINNET.base_fee_params(0), BaseFeeParams::ethereum());
+        assert_eq!(MAINNET.base_fee_params(u64::MAX), BaseFeeParams::ethereum());
+    }
+
+    #[test]
+    fn base_fee_params_kind_serde() {
+        let constant: BaseFeeParamsKind = BaseFeeParams::ethereum().into();
+        let json = serde_json::to_string(&constant).unwrap();
+        assert_eq!(json, r#"{"max_change_denominator":8,"elasticity_multiplier":2}"#);
+        assert_eq!(serde_json::from_str::<BaseFeeParamsKind>(&json).unwrap(), constant);
+
+        let variable: BaseFeeParamsKind =
+            ForkBaseFeeParams(vec![(Hardfork::London, BaseFeeParams::ethereum())]).into();
+        let json = serde_json::to_string(&variable).unwrap();
+        assert_eq!(serde_json::from_str::<BaseFeeParamsKind>(&json).unwrap(), variable);
+    }
 }
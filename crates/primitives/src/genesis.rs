@@ -40,6 +40,13 @@ pub struct Genesis {
     pub coinbase: Address,
     /// The initial state of accounts in the genesis block.
     pub alloc: HashMap<Address, GenesisAccount>,
+    /// The state root of the genesis header, if the genesis state isn't part of the allocation.
+    ///
+    /// This is the case for OP Stack chains that were migrated to Bedrock, whose state at the
+    /// migration has to be imported.
+    #[cfg(feature = "optimism")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_hash: Option<B256>,
     // NOTE: the following fields:
     // * base_fee_per_gas
     // * excess_blob_gas
@@ -345,6 +352,26 @@ pub struct ChainConfig {
     /// Clique parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clique: Option<CliqueConfig>,
+
+    /// The Bedrock switch block of OP Stack chains.
+    #[cfg(feature = "optimism")]
+    #[serde(skip_serializing_if = "Option::is_none", with = "u64_hex_or_decimal_opt")]
+    pub bedrock_block: Option<u64>,
+
+    /// The Regolith switch time of OP Stack chains.
+    #[cfg(feature = "optimism")]
+    #[serde(skip_serializing_if = "Option::is_none", with = "u64_hex_or_decimal_opt")]
+    pub regolith_time: Option<u64>,
+
+    /// The Canyon switch time of OP Stack chains.
+    #[cfg(feature = "optimism")]
+    #[serde(skip_serializing_if = "Option::is_none", with = "u64_hex_or_decimal_opt")]
+    pub canyon_time: Option<u64>,
+
+    /// EIP-1559 parameters of OP Stack chains.
+    #[cfg(feature = "optimism")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimism: Option<OptimismConfig>,
 }
 
 // used only for serde
@@ -369,6 +396,20 @@ pub struct CliqueConfig {
     pub epoch: Option<u64>,
 }
 
+/// EIP-1559 parameters of an OP Stack chain.
+#[cfg(feature = "optimism")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimismConfig {
+    /// The elasticity multiplier.
+    pub eip1559_elasticity: u64,
+    /// The base fee max change denominator.
+    pub eip1559_denominator: u64,
+    /// The base fee max change denominator from the Canyon hardfork on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eip1559_denominator_canyon: Option<u64>,
+}
+
 #[cfg(feature = "test-utils")]
 mod ethers_compat {
     use super::*;
@@ -399,6 +440,8 @@ mod ethers_compat {
                 excess_blob_gas: None,
                 blob_gas_used: None,
                 alloc,
+                #[cfg(feature = "optimism")]
+                state_hash: None,
             }
         }
     }
@@ -470,6 +513,14 @@ mod ethers_compat {
                 terminal_total_difficulty_passed,
                 ethash: ethash.map(Into::into),
                 clique: clique.map(Into::into),
+                #[cfg(feature = "optimism")]
+                bedrock_block: None,
+                #[cfg(feature = "optimism")]
+                regolith_time: None,
+                #[cfg(feature = "optimism")]
+                canyon_time: None,
+                #[cfg(feature = "optimism")]
+                optimism: None,
             }
         }
     }
@@ -1113,6 +1164,8 @@ mod tests {
                 base_fee_per_gas: None,
                 excess_blob_gas: None,
                 blob_gas_used: None,
+                #[cfg(feature = "optimism")]
+                state_hash: None,
                 alloc: HashMap::from_iter(vec![
                 (
                     Address::from_str("0xdbdbdb2cbd23b783741e8d7fcf51e459b497e4a6").unwrap(),
@@ -37,8 +37,17 @@ pub enum Hardfork {
     GrayGlacier,
     /// Paris.
     Paris,
+    /// Bedrock, the first OP Stack hardfork.
+    #[cfg(feature = "optimism")]
+    Bedrock,
+    /// Regolith, fixes deposit transaction processing on top of [Hardfork::Bedrock].
+    #[cfg(feature = "optimism")]
+    Regolith,
     /// Shanghai.
     Shanghai,
+    /// Canyon, the OP Stack counterpart of [Hardfork::Shanghai].
+    #[cfg(feature = "optimism")]
+    Canyon,
     /// Cancun.
     Cancun,
 }
@@ -85,6 +94,12 @@ impl FromStr for Hardfork {
             "paris" => Hardfork::Paris,
             "shanghai" => Hardfork::Shanghai,
             "cancun" => Hardfork::Cancun,
+            #[cfg(feature = "optimism")]
+            "bedrock" => Hardfork::Bedrock,
+            #[cfg(feature = "optimism")]
+            "regolith" => Hardfork::Regolith,
+            #[cfg(feature = "optimism")]
+            "canyon" => Hardfork::Canyon,
             _ => return Err(format!("Unknown hardfork: {s}")),
         };
         Ok(hardfork)
@@ -150,6 +165,18 @@ mod tests {
         assert_eq!(hardforks, expected_hardforks);
     }
 
+    #[test]
+    #[cfg(feature = "optimism")]
+    fn check_op_hardfork_from_str() {
+        let hardfork_str = ["beDrOck", "rEgOlITH", "cAnYoN"];
+        let expected_hardforks = [Hardfork::Bedrock, Hardfork::Regolith, Hardfork::Canyon];
+
+        let hardforks: Vec<Hardfork> =
+            hardfork_str.iter().map(|h| Hardfork::from_str(h).unwrap()).collect();
+
+        assert_eq!(hardforks, expected_hardforks);
+    }
+
     #[test]
     fn check_nonexistent_hardfork_from_str() {
         assert!(Hardfork::from_str("not a hardfork").is_err());
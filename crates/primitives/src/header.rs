@@ -199,6 +199,9 @@ impl Header {
 
     /// Calculate base fee for next block according to the EIP-1559 spec.
     ///
+    /// The `base_fee_params` are the params that apply to the next block, see
+    /// [ChainSpec::base_fee_params](crate::ChainSpec::base_fee_params).
+    ///
     /// Returns a `None` if no base fee is set, no EIP-1559 support
     pub fn next_block_base_fee(&self, base_fee_params: BaseFeeParams) -> Option<u64> {
         Some(calculate_next_block_base_fee(
@@ -7,6 +7,8 @@ use crate::{
     Address, GenesisAccount, Header, ReceiptWithBloom, ReceiptWithBloomRef, TransactionSigned,
     Withdrawal, B256,
 };
+#[cfg(feature = "optimism")]
+use crate::{ChainSpec, Hardfork, Receipt};
 use alloy_rlp::Encodable;
 use bytes::{BufMut, BytesMut};
 use itertools::Itertools;
@@ -73,6 +75,72 @@ pub fn calculate_receipt_root(receipts: &[ReceiptWithBloom]) -> B256 {
     ordered_trie_root_with_encoder(receipts, |r, buf| r.encode_inner(buf, false))
 }
 
+/// Calculates the receipt root for a header of an OP Stack block.
+///
+/// Between the Regolith and Canyon hardforks, op-geth did not include the deposit nonce in the
+/// receipt encoding used for the receipt root, so it is stripped from the receipts before
+/// calculating the root. This was corrected in the Canyon hardfork, which introduced the deposit
+/// receipt version: from Canyon on, the deposit nonce and the deposit receipt version of deposit
+/// receipts are part of the root.
+#[cfg(feature = "optimism")]
+pub fn calculate_receipt_root_optimism(
+    receipts: &[ReceiptWithBloom],
+    chain_spec: &ChainSpec,
+    timestamp: u64,
+) -> B256 {
+    if is_deposit_nonce_excluded_from_root(chain_spec, timestamp) {
+        let receipts = receipts
+            .iter()
+            .cloned()
+            .map(|mut receipt| {
+                receipt.receipt.deposit_nonce = None;
+                receipt.receipt.deposit_receipt_version = None;
+                receipt
+            })
+            .collect::<Vec<_>>();
+
+        return calculate_receipt_root(&receipts)
+    }
+
+    calculate_receipt_root(receipts)
+}
+
+/// Calculates the receipt root for a header of an OP Stack block for the reference type of
+/// [ReceiptWithBloom].
+///
+/// See [calculate_receipt_root_optimism] for the handling of the deposit nonce.
+#[cfg(feature = "optimism")]
+pub fn calculate_receipt_root_ref_optimism(
+    receipts: &[&Receipt],
+    chain_spec: &ChainSpec,
+    timestamp: u64,
+) -> B256 {
+    if is_deposit_nonce_excluded_from_root(chain_spec, timestamp) {
+        let receipts = receipts
+            .iter()
+            .map(|receipt| Receipt {
+                deposit_nonce: None,
+                deposit_receipt_version: None,
+                ..(*receipt).clone()
+            })
+            .collect::<Vec<_>>();
+
+        return calculate_receipt_root_ref(&receipts.iter().collect::<Vec<_>>())
+    }
+
+    calculate_receipt_root_ref(receipts)
+}
+
+/// Returns true if the deposit nonce must be excluded from the receipt root at the given
+/// timestamp, which is the case between the Regolith and Canyon hardforks.
+///
+/// The deposit receipt version is only set from Canyon on, so it is excluded as well.
+#[cfg(feature = "optimism")]
+fn is_deposit_nonce_excluded_from_root(chain_spec: &ChainSpec, timestamp: u64) -> bool {
+    chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, timestamp) &&
+        !chain_spec.is_fork_active_at_timestamp(Hardfork::Canyon, timestamp)
+}
+
 /// Calculates the receipt root for a header for the reference type of [ReceiptWithBloom].
 ///
 /// NOTE: Prefer [calculate_receipt_root] if you have log blooms memoized.
@@ -171,6 +239,10 @@ mod tests {
                 success: true,
                 cumulative_gas_used: 102068,
                 logs,
+                #[cfg(feature = "optimism")]
+                deposit_nonce: None,
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version: None,
             },
             bloom,
         };
@@ -179,6 +251,64 @@ mod tests {
         assert_eq!(root, b256!("fe70ae4a136d98944951b2123859698d59ad251a381abc9960fa81cae3d0d4a0"));
     }
 
+    #[test]
+    #[cfg(feature = "optimism")]
+    fn check_optimism_receipt_root() {
+        use crate::{ChainSpecBuilder, ForkCondition};
+
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .regolith_activated()
+            .with_fork(Hardfork::Canyon, ForkCondition::Timestamp(100))
+            .build();
+
+        let receipt = |deposit_nonce, deposit_receipt_version| ReceiptWithBloom {
+            receipt: Receipt {
+                tx_type: TxType::DEPOSIT,
+                success: true,
+                cumulative_gas_used: 46913,
+                logs: vec![],
+                deposit_nonce,
+                deposit_receipt_version,
+            },
+            bloom: Default::default(),
+        };
+        let with_nonce = vec![receipt(Some(4012991), None)];
+        let without_nonce = vec![receipt(None, None)];
+        let with_version = vec![receipt(Some(4012991), Some(1))];
+
+        // between Regolith and Canyon the deposit nonce is not part of the receipt root
+        assert_eq!(
+            calculate_receipt_root_optimism(&with_nonce, &chain_spec, 0),
+            calculate_receipt_root(&without_nonce)
+        );
+        assert_eq!(
+            calculate_receipt_root_ref_optimism(&[&with_nonce[0].receipt], &chain_spec, 0),
+            calculate_receipt_root(&without_nonce)
+        );
+
+        // after Canyon it is
+        assert_eq!(
+            calculate_receipt_root_optimism(&with_nonce, &chain_spec, 100),
+            calculate_receipt_root(&with_nonce)
+        );
+        assert_ne!(calculate_receipt_root(&with_nonce), calculate_receipt_root(&without_nonce));
+
+        // and so is the deposit receipt version, which is set from Canyon on
+        assert_eq!(
+            calculate_receipt_root_optimism(&with_version, &chain_spec, 100),
+            calculate_receipt_root(&with_version)
+        );
+        assert_eq!(
+            calculate_receipt_root_ref_optimism(&[&with_version[0].receipt], &chain_spec, 100),
+            calculate_receipt_root(&with_version)
+        );
+        assert_ne!(calculate_receipt_root(&with_version), calculate_receipt_root(&with_nonce));
+        assert_eq!(
+            calculate_receipt_root_optimism(&with_version, &chain_spec, 0),
+            calculate_receipt_root(&without_nonce)
+        );
+    }
+
     #[test]
     fn check_withdrawals_root() {
         // Single withdrawal, amount 0
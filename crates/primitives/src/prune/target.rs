@@ -39,6 +39,15 @@ pub struct PruneModes {
         deserialize_with = "deserialize_opt_prune_mode_with_min_blocks::<MINIMUM_PRUNING_DISTANCE, _>"
     )]
     pub storage_history: Option<PruneMode>,
+    /// Address Appearances pruning configuration.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_opt_prune_mode_with_min_blocks::<MINIMUM_PRUNING_DISTANCE, _>"
+    )]
+    pub address_appearances: Option<PruneMode>,
+    /// Transaction Traces pruning configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_traces: Option<PruneMode>,
     /// Receipts pruning configuration by retaining only those receipts that contain logs emitted
     /// by the specified addresses, discarding others. This setting is overridden by `receipts`.
     ///
@@ -61,6 +70,8 @@ impl PruneModes {
             receipts: Some(PruneMode::Full),
             account_history: Some(PruneMode::Full),
             storage_history: Some(PruneMode::Full),
+            address_appearances: Some(PruneMode::Full),
+            transaction_traces: Some(PruneMode::Full),
             receipts_log_filter: Default::default(),
         }
     }
@@ -4,6 +4,8 @@ use crate::{
     proofs::calculate_receipt_root_ref,
     Bloom, Log, PruneSegmentError, TxType, B256,
 };
+#[cfg(feature = "optimism")]
+use crate::{proofs::calculate_receipt_root_ref_optimism, ChainSpec};
 use alloy_rlp::{length_of_length, Decodable, Encodable};
 use bytes::{Buf, BufMut, BytesMut};
 use reth_codecs::{main_codec, Compact, CompactZstd};
@@ -32,6 +34,18 @@ pub struct Receipt {
         )
     )]
     pub logs: Vec<Log>,
+    /// Deposit nonce for OP Stack deposit transactions.
+    ///
+    /// This is the nonce of the sender before the deposit was executed, it is only set for
+    /// deposits included after the Regolith hardfork.
+    #[cfg(feature = "optimism")]
+    pub deposit_nonce: Option<u64>,
+    /// Deposit receipt version for OP Stack deposit transactions.
+    ///
+    /// The version was introduced by the Canyon hardfork and is set to `1` for deposits included
+    /// after it. When set, the deposit nonce and the version are part of the receipt root.
+    #[cfg(feature = "optimism")]
+    pub deposit_receipt_version: Option<u64>,
 }
 
 impl Receipt {
@@ -93,6 +107,23 @@ impl Receipts {
         ))
     }
 
+    /// Retrieves the receipt root of an OP Stack block for all recorded receipts from index.
+    ///
+    /// See [calculate_receipt_root_ref_optimism] for the differences to [Self::root_slow].
+    #[cfg(feature = "optimism")]
+    pub fn optimism_root_slow(
+        &self,
+        index: usize,
+        chain_spec: &ChainSpec,
+        timestamp: u64,
+    ) -> Option<B256> {
+        Some(calculate_receipt_root_ref_optimism(
+            &self.receipt_vec[index].iter().map(Option::as_ref).collect::<Option<Vec<_>>>()?,
+            chain_spec,
+            timestamp,
+        ))
+    }
+
     /// Retrieves gas spent by transactions as a vector of tuples (transaction index, gas used).
     pub fn gas_spent_by_tx(&self) -> Result<Vec<(u64, u64)>, PruneSegmentError> {
         self.last()
@@ -201,7 +232,34 @@ impl ReceiptWithBloom {
         let bloom = Decodable::decode(b)?;
         let logs = alloy_rlp::Decodable::decode(b)?;
 
-        let this = Self { receipt: Receipt { tx_type, success, cumulative_gas_used, logs }, bloom };
+        // the deposit nonce and the deposit receipt version are optional trailing fields, they
+        // are only set for deposit receipts
+        #[cfg(feature = "optimism")]
+        let deposit_nonce = if started_len - b.len() < rlp_head.payload_length {
+            Some(alloy_rlp::Decodable::decode(b)?)
+        } else {
+            None
+        };
+        #[cfg(feature = "optimism")]
+        let deposit_receipt_version = if started_len - b.len() < rlp_head.payload_length {
+            Some(alloy_rlp::Decodable::decode(b)?)
+        } else {
+            None
+        };
+
+        let this = Self {
+            receipt: Receipt {
+                tx_type,
+                success,
+                cumulative_gas_used,
+                logs,
+                #[cfg(feature = "optimism")]
+                deposit_nonce,
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version,
+            },
+            bloom,
+        };
         let consumed = started_len - b.len();
         if consumed != rlp_head.payload_length {
             return Err(alloy_rlp::Error::ListLengthMismatch {
@@ -239,17 +297,25 @@ impl Decodable for ReceiptWithBloom {
                 let receipt_type = *buf.first().ok_or(alloy_rlp::Error::Custom(
                     "typed receipt cannot be decoded from an empty slice",
                 ))?;
-                if receipt_type == 0x01 {
-                    buf.advance(1);
-                    Self::decode_receipt(buf, TxType::EIP2930)
-                } else if receipt_type == 0x02 {
-                    buf.advance(1);
-                    Self::decode_receipt(buf, TxType::EIP1559)
-                } else if receipt_type == 0x03 {
-                    buf.advance(1);
-                    Self::decode_receipt(buf, TxType::EIP4844)
-                } else {
-                    Err(alloy_rlp::Error::Custom("invalid receipt type"))
+                match receipt_type {
+                    0x01 => {
+                        buf.advance(1);
+                        Self::decode_receipt(buf, TxType::EIP2930)
+                    }
+                    0x02 => {
+                        buf.advance(1);
+                        Self::decode_receipt(buf, TxType::EIP1559)
+                    }
+                    0x03 => {
+                        buf.advance(1);
+                        Self::decode_receipt(buf, TxType::EIP4844)
+                    }
+                    #[cfg(feature = "optimism")]
+                    0x7E => {
+                        buf.advance(1);
+                        Self::decode_receipt(buf, TxType::DEPOSIT)
+                    }
+                    _ => Err(alloy_rlp::Error::Custom("invalid receipt type")),
                 }
             }
             Ordering::Equal => {
@@ -316,6 +382,14 @@ impl<'a> ReceiptWithBloomEncoder<'a> {
         rlp_head.payload_length += self.receipt.cumulative_gas_used.length();
         rlp_head.payload_length += self.bloom.length();
         rlp_head.payload_length += self.receipt.logs.length();
+        #[cfg(feature = "optimism")]
+        if let Some(deposit_nonce) = self.receipt.deposit_nonce {
+            rlp_head.payload_length += deposit_nonce.length();
+        }
+        #[cfg(feature = "optimism")]
+        if let Some(deposit_receipt_version) = self.receipt.deposit_receipt_version {
+            rlp_head.payload_length += deposit_receipt_version.length();
+        }
 
         rlp_head
     }
@@ -327,6 +401,14 @@ impl<'a> ReceiptWithBloomEncoder<'a> {
         self.receipt.cumulative_gas_used.encode(out);
         self.bloom.encode(out);
         self.receipt.logs.encode(out);
+        #[cfg(feature = "optimism")]
+        if let Some(deposit_nonce) = self.receipt.deposit_nonce {
+            deposit_nonce.encode(out);
+        }
+        #[cfg(feature = "optimism")]
+        if let Some(deposit_receipt_version) = self.receipt.deposit_receipt_version {
+            deposit_receipt_version.encode(out);
+        }
     }
 
     /// Encode receipt with or without the header data.
@@ -355,6 +437,10 @@ impl<'a> ReceiptWithBloomEncoder<'a> {
             TxType::EIP4844 => {
                 out.put_u8(0x03);
             }
+            #[cfg(feature = "optimism")]
+            TxType::DEPOSIT => {
+                out.put_u8(0x7E);
+            }
             _ => unreachable!("legacy handled; qed."),
         }
         out.put_slice(payload.as_ref());
@@ -374,7 +460,7 @@ impl<'a> Encodable for ReceiptWithBloomEncoder<'a> {
     fn length(&self) -> usize {
         let mut payload_len = self.receipt_length();
         // account for eip-2718 type prefix and set the list
-        if matches!(self.receipt.tx_type, TxType::EIP1559 | TxType::EIP2930 | TxType::EIP4844) {
+        if !matches!(self.receipt.tx_type, TxType::Legacy) {
             payload_len += 1;
             // we include a string header for typed receipts, so include the length here
             payload_len += length_of_length(payload_len);
@@ -410,6 +496,10 @@ mod tests {
                     data: bytes!("0100ff"),
                 }],
                 success: false,
+                #[cfg(feature = "optimism")]
+                deposit_nonce: None,
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version: None,
             },
             bloom: [0; 256].into(),
         };
@@ -440,6 +530,10 @@ mod tests {
                     data: bytes!("0100ff"),
                 }],
                 success: false,
+                #[cfg(feature = "optimism")]
+                deposit_nonce: None,
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version: None,
             },
             bloom: [0; 256].into(),
         };
@@ -448,6 +542,61 @@ mod tests {
         assert_eq!(receipt, expected);
     }
 
+    #[test]
+    #[cfg(feature = "optimism")]
+    fn deposit_receipt_roundtrip() {
+        let receipt = ReceiptWithBloom {
+            receipt: Receipt {
+                tx_type: TxType::DEPOSIT,
+                success: true,
+                cumulative_gas_used: 46913,
+                logs: vec![],
+                deposit_nonce: Some(4012991),
+                deposit_receipt_version: None,
+            },
+            bloom: [0; 256].into(),
+        };
+
+        let mut data = vec![];
+        receipt.encode(&mut data);
+        assert_eq!(receipt.length(), data.len());
+        let decoded = ReceiptWithBloom::decode(&mut &data[..]).unwrap();
+        assert_eq!(decoded, receipt);
+
+        // receipts without a deposit nonce don't include the field
+        let receipt = ReceiptWithBloom {
+            receipt: Receipt { deposit_nonce: None, ..receipt.receipt },
+            bloom: receipt.bloom,
+        };
+        let mut without_nonce = vec![];
+        receipt.encode(&mut without_nonce);
+        assert_eq!(without_nonce.len() + 4, data.len());
+        let decoded = ReceiptWithBloom::decode(&mut &without_nonce[..]).unwrap();
+        assert_eq!(decoded, receipt);
+
+        // the deposit receipt version follows the deposit nonce
+        let receipt = ReceiptWithBloom {
+            receipt: Receipt {
+                deposit_nonce: Some(4012991),
+                deposit_receipt_version: Some(1),
+                ..receipt.receipt
+            },
+            bloom: receipt.bloom,
+        };
+        let mut with_version = vec![];
+        receipt.encode(&mut with_version);
+        assert_eq!(receipt.length(), with_version.len());
+        assert_eq!(with_version.len(), data.len() + 1);
+        let decoded = ReceiptWithBloom::decode(&mut &with_version[..]).unwrap();
+        assert_eq!(decoded, receipt);
+
+        let receipt = receipt.receipt;
+        let mut compact = vec![];
+        let len = receipt.clone().to_compact(&mut compact);
+        let (decoded, _) = Receipt::from_compact(&compact[..], len);
+        assert_eq!(decoded, receipt);
+    }
+
     #[test]
     fn gigantic_receipt() {
         let receipt = Receipt {
@@ -470,6 +619,10 @@ mod tests {
                     data: Bytes::from(vec![1; 0xffffff]),
                 },
             ],
+            #[cfg(feature = "optimism")]
+            deposit_nonce: None,
+            #[cfg(feature = "optimism")]
+            deposit_receipt_version: None,
         };
 
         let mut data = vec![];
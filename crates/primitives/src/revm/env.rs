@@ -291,5 +291,24 @@ where
             tx_env.blob_hashes = blob_versioned_hashes.clone();
             tx_env.max_fee_per_blob_gas = Some(U256::from(*max_fee_per_blob_gas));
         }
+        #[cfg(feature = "optimism")]
+        Transaction::Deposit(tx) => {
+            // deposits are paid for on L1, so they don't pay any L2 gas and are not subject to
+            // nonce checks
+            tx_env.gas_limit = tx.gas_limit;
+            tx_env.gas_price = U256::ZERO;
+            tx_env.gas_priority_fee = None;
+            tx_env.transact_to = match tx.to {
+                TransactionKind::Call(to) => TransactTo::Call(to),
+                TransactionKind::Create => TransactTo::create(),
+            };
+            tx_env.value = tx.value.into();
+            tx_env.data = tx.input.clone();
+            tx_env.chain_id = None;
+            tx_env.nonce = None;
+            tx_env.access_list.clear();
+            tx_env.blob_hashes.clear();
+            tx_env.max_fee_per_blob_gas.take();
+        }
     }
 }
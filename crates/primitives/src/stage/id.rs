@@ -16,6 +16,14 @@ pub enum StageId {
     TransactionLookup,
     IndexStorageHistory,
     IndexAccountHistory,
+    /// Optional stage indexing the transactions each address appears in.
+    ///
+    /// Not part of [`StageId::ALL`], since it's only run if enabled.
+    IndexAddressAppearances,
+    /// Optional stage recording the parity style call traces of each transaction.
+    ///
+    /// Not part of [`StageId::ALL`], since it's only run if enabled.
+    TransactionTraces,
     Finish,
     Other(&'static str),
 }
@@ -53,6 +61,8 @@ impl StageId {
             StageId::TransactionLookup => "TransactionLookup",
             StageId::IndexAccountHistory => "IndexAccountHistory",
             StageId::IndexStorageHistory => "IndexStorageHistory",
+            StageId::IndexAddressAppearances => "IndexAddressAppearances",
+            StageId::TransactionTraces => "TransactionTraces",
             StageId::Finish => "Finish",
             StageId::Other(s) => s,
         }
@@ -93,6 +103,8 @@ mod tests {
         assert_eq!(StageId::IndexAccountHistory.to_string(), "IndexAccountHistory");
         assert_eq!(StageId::IndexStorageHistory.to_string(), "IndexStorageHistory");
         assert_eq!(StageId::TransactionLookup.to_string(), "TransactionLookup");
+        assert_eq!(StageId::IndexAddressAppearances.to_string(), "IndexAddressAppearances");
+        assert_eq!(StageId::TransactionTraces.to_string(), "TransactionTraces");
         assert_eq!(StageId::Finish.to_string(), "Finish");
 
         assert_eq!(StageId::Other("Foo").to_string(), "Foo");
@@ -21,11 +21,15 @@ pub use eip4844::TxEip4844;
 pub use error::InvalidTransactionError;
 pub use legacy::TxLegacy;
 pub use meta::TransactionMeta;
+#[cfg(feature = "optimism")]
+pub use optimism::TxDeposit;
 #[cfg(feature = "c-kzg")]
 pub use pooled::{PooledTransactionsElement, PooledTransactionsElementEcRecovered};
 #[cfg(feature = "c-kzg")]
 pub use sidecar::{BlobTransaction, BlobTransactionSidecar, BlobTransactionValidationError};
 pub use signature::Signature;
+#[cfg(feature = "optimism")]
+pub use tx_type::DEPOSIT_TX_TYPE_ID;
 pub use tx_type::{
     TxType, EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, LEGACY_TX_TYPE_ID,
 };
@@ -39,6 +43,8 @@ mod eip4844;
 mod error;
 mod legacy;
 mod meta;
+#[cfg(feature = "optimism")]
+mod optimism;
 #[cfg(feature = "c-kzg")]
 mod pooled;
 #[cfg(feature = "c-kzg")]
@@ -103,6 +109,12 @@ pub enum Transaction {
     /// EIP-4844, also known as proto-danksharding, implements the framework and logic of
     /// danksharding, introducing new transaction formats and verification rules.
     Eip4844(TxEip4844),
+    /// Optimism deposit transaction, type `0x7E`.
+    ///
+    /// Deposits are initiated on L1 and do not carry a signature, the sender is part of the
+    /// transaction itself.
+    #[cfg(feature = "optimism")]
+    Deposit(TxDeposit),
 }
 
 // === impl Transaction ===
@@ -116,6 +128,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.signature_hash(),
             Transaction::Eip1559(tx) => tx.signature_hash(),
             Transaction::Eip4844(tx) => tx.signature_hash(),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => B256::ZERO,
         }
     }
 
@@ -126,6 +140,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { chain_id, .. }) |
             Transaction::Eip1559(TxEip1559 { chain_id, .. }) |
             Transaction::Eip4844(TxEip4844 { chain_id, .. }) => Some(*chain_id),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => None,
         }
     }
 
@@ -136,6 +152,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { chain_id: ref mut c, .. }) |
             Transaction::Eip1559(TxEip1559 { chain_id: ref mut c, .. }) |
             Transaction::Eip4844(TxEip4844 { chain_id: ref mut c, .. }) => *c = chain_id,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => { /* noop */ }
         }
     }
 
@@ -147,6 +165,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { to, .. }) |
             Transaction::Eip1559(TxEip1559 { to, .. }) |
             Transaction::Eip4844(TxEip4844 { to, .. }) => to,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(TxDeposit { to, .. }) => to,
         }
     }
 
@@ -162,6 +182,8 @@ impl Transaction {
             Transaction::Eip2930(access_list_tx) => access_list_tx.tx_type(),
             Transaction::Eip1559(dynamic_fee_tx) => dynamic_fee_tx.tx_type(),
             Transaction::Eip4844(blob_tx) => blob_tx.tx_type(),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => deposit_tx.tx_type(),
         }
     }
 
@@ -172,6 +194,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { value, .. }) |
             Transaction::Eip1559(TxEip1559 { value, .. }) |
             Transaction::Eip4844(TxEip4844 { value, .. }) => value,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(TxDeposit { value, .. }) => value,
         }
     }
 
@@ -182,15 +206,20 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { nonce, .. }) |
             Transaction::Eip1559(TxEip1559 { nonce, .. }) |
             Transaction::Eip4844(TxEip4844 { nonce, .. }) => *nonce,
+            // Deposit transactions do not have nonces.
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => 0,
         }
     }
 
     /// Returns the [AccessList] of the transaction.
     ///
-    /// Returns `None` for legacy transactions.
+    /// Returns `None` for legacy and deposit transactions.
     pub fn access_list(&self) -> Option<&AccessList> {
         match self {
             Transaction::Legacy(_) => None,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => None,
             Transaction::Eip2930(tx) => Some(&tx.access_list),
             Transaction::Eip1559(tx) => Some(&tx.access_list),
             Transaction::Eip4844(tx) => Some(&tx.access_list),
@@ -204,6 +233,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { gas_limit, .. }) |
             Transaction::Eip1559(TxEip1559 { gas_limit, .. }) |
             Transaction::Eip4844(TxEip4844 { gas_limit, .. }) => *gas_limit,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(TxDeposit { gas_limit, .. }) => *gas_limit,
         }
     }
 
@@ -212,6 +243,8 @@ impl Transaction {
         match self {
             Transaction::Legacy(_) | Transaction::Eip2930(_) => false,
             Transaction::Eip1559(_) | Transaction::Eip4844(_) => true,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => false,
         }
     }
 
@@ -224,6 +257,10 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { gas_price, .. }) => *gas_price,
             Transaction::Eip1559(TxEip1559 { max_fee_per_gas, .. }) |
             Transaction::Eip4844(TxEip4844 { max_fee_per_gas, .. }) => *max_fee_per_gas,
+            // Deposit transactions buy their L2 gas on L1 and, as such, the L2 gas is not
+            // refundable.
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => 0,
         }
     }
 
@@ -238,6 +275,8 @@ impl Transaction {
             Transaction::Eip4844(TxEip4844 { max_priority_fee_per_gas, .. }) => {
                 Some(*max_priority_fee_per_gas)
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => None,
         }
     }
 
@@ -251,6 +290,8 @@ impl Transaction {
             Transaction::Eip4844(TxEip4844 { blob_versioned_hashes, .. }) => {
                 Some(blob_versioned_hashes.to_vec())
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => None,
         }
     }
 
@@ -292,6 +333,8 @@ impl Transaction {
             Transaction::Eip4844(TxEip4844 { max_priority_fee_per_gas, .. }) => {
                 *max_priority_fee_per_gas
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => 0,
         }
     }
 
@@ -304,6 +347,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.gas_price,
             Transaction::Eip1559(dynamic_tx) => dynamic_tx.effective_gas_price(base_fee),
             Transaction::Eip4844(dynamic_tx) => dynamic_tx.effective_gas_price(base_fee),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => 0,
         }
     }
 
@@ -345,6 +390,8 @@ impl Transaction {
             Transaction::Eip2930(TxEip2930 { input, .. }) |
             Transaction::Eip1559(TxEip1559 { input, .. }) |
             Transaction::Eip4844(TxEip4844 { input, .. }) => input,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(TxDeposit { input, .. }) => input,
         }
     }
 
@@ -376,6 +423,8 @@ impl Transaction {
             Transaction::Eip4844(blob_tx) => {
                 blob_tx.encode_with_signature(signature, out, with_header)
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => deposit_tx.encode(out, with_header),
         }
     }
 
@@ -386,6 +435,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.nonce = nonce,
             Transaction::Eip1559(tx) => tx.nonce = nonce,
             Transaction::Eip4844(tx) => tx.nonce = nonce,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => { /* noop */ }
         }
     }
 
@@ -396,6 +447,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.value = value,
             Transaction::Eip1559(tx) => tx.value = value,
             Transaction::Eip4844(tx) => tx.value = value,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(tx) => tx.value = value,
         }
     }
 
@@ -406,6 +459,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.input = input,
             Transaction::Eip1559(tx) => tx.input = input,
             Transaction::Eip4844(tx) => tx.input = input,
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(tx) => tx.input = input,
         }
     }
 
@@ -417,6 +472,8 @@ impl Transaction {
             Transaction::Eip2930(tx) => tx.size(),
             Transaction::Eip1559(tx) => tx.size(),
             Transaction::Eip4844(tx) => tx.size(),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(tx) => tx.size(),
         }
     }
 
@@ -444,6 +501,37 @@ impl Transaction {
         matches!(self, Transaction::Eip4844(_))
     }
 
+    /// Returns true if the transaction is a deposit transaction.
+    #[cfg(feature = "optimism")]
+    #[inline]
+    pub fn is_deposit(&self) -> bool {
+        matches!(self, Transaction::Deposit(_))
+    }
+
+    /// Returns the source hash of the transaction, which uniquely identifies its source.
+    ///
+    /// Returns `None` for non-deposit transactions.
+    #[cfg(feature = "optimism")]
+    pub fn source_hash(&self) -> Option<B256> {
+        self.as_deposit().map(|tx| tx.source_hash)
+    }
+
+    /// Returns the amount of ETH locked up on L1 that will be minted on L2.
+    ///
+    /// Returns `None` for non-deposit transactions or deposits without a mint.
+    #[cfg(feature = "optimism")]
+    pub fn mint(&self) -> Option<u128> {
+        self.as_deposit().and_then(|tx| tx.mint)
+    }
+
+    /// Returns whether or not the transaction is a system transaction.
+    ///
+    /// This is always `false` for non-deposit transactions.
+    #[cfg(feature = "optimism")]
+    pub fn is_system_transaction(&self) -> bool {
+        self.as_deposit().map_or(false, |tx| tx.is_system_transaction)
+    }
+
     /// Returns the [TxLegacy] variant if the transaction is a legacy transaction.
     pub fn as_legacy(&self) -> Option<&TxLegacy> {
         match self {
@@ -475,6 +563,15 @@ impl Transaction {
             _ => None,
         }
     }
+
+    /// Returns the [TxDeposit] variant if the transaction is a deposit transaction.
+    #[cfg(feature = "optimism")]
+    pub fn as_deposit(&self) -> Option<&TxDeposit> {
+        match self {
+            Transaction::Deposit(tx) => Some(tx),
+            _ => None,
+        }
+    }
 }
 
 impl From<TxLegacy> for Transaction {
@@ -501,51 +598,81 @@ impl From<TxEip4844> for Transaction {
     }
 }
 
+#[cfg(feature = "optimism")]
+impl From<TxDeposit> for Transaction {
+    fn from(tx: TxDeposit) -> Self {
+        Transaction::Deposit(tx)
+    }
+}
+
 impl Compact for Transaction {
+    // Serializes the TxType to the buffer if necessary, returning 2 bits of the type as an
+    // identifier instead of the length.
     fn to_compact<B>(self, buf: &mut B) -> usize
     where
         B: bytes::BufMut + AsMut<[u8]>,
     {
+        let identifier = self.tx_type().to_compact(buf);
         match self {
             Transaction::Legacy(tx) => {
                 tx.to_compact(buf);
-                0
             }
             Transaction::Eip2930(tx) => {
                 tx.to_compact(buf);
-                1
             }
             Transaction::Eip1559(tx) => {
                 tx.to_compact(buf);
-                2
             }
             Transaction::Eip4844(tx) => {
                 tx.to_compact(buf);
-                3
+            }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(tx) => {
+                tx.to_compact(buf);
             }
         }
+        identifier
     }
 
+    // For backwards compatibility purposes, only 2 bits of the type are encoded in the identifier
+    // parameter. In the case of a 3, the full transaction type is read from the buffer as a
+    // single byte.
     fn from_compact(buf: &[u8], identifier: usize) -> (Self, &[u8]) {
-        match identifier {
-            0 => {
+        Self::try_from_compact(buf, identifier)
+            .expect("Junk data in database: unknown Transaction variant")
+    }
+}
+
+impl Transaction {
+    /// Fallible version of [`Compact::from_compact`].
+    ///
+    /// Returns `None` if the transaction type encoded in the identifier and buffer is unknown.
+    pub fn try_from_compact(buf: &[u8], identifier: usize) -> Option<(Self, &[u8])> {
+        let (tx_type, buf) = TxType::try_from_compact(buf, identifier)?;
+        let decoded = match tx_type {
+            TxType::Legacy => {
                 let (tx, buf) = TxLegacy::from_compact(buf, buf.len());
                 (Transaction::Legacy(tx), buf)
             }
-            1 => {
+            TxType::EIP2930 => {
                 let (tx, buf) = TxEip2930::from_compact(buf, buf.len());
                 (Transaction::Eip2930(tx), buf)
             }
-            2 => {
+            TxType::EIP1559 => {
                 let (tx, buf) = TxEip1559::from_compact(buf, buf.len());
                 (Transaction::Eip1559(tx), buf)
             }
-            3 => {
+            TxType::EIP4844 => {
                 let (tx, buf) = TxEip4844::from_compact(buf, buf.len());
                 (Transaction::Eip4844(tx), buf)
             }
-            _ => unreachable!("Junk data in database: unknown Transaction variant"),
-        }
+            #[cfg(feature = "optimism")]
+            TxType::DEPOSIT => {
+                let (tx, buf) = TxDeposit::from_compact(buf, buf.len());
+                (Transaction::Deposit(tx), buf)
+            }
+        };
+        Some(decoded)
     }
 }
 
@@ -572,6 +699,10 @@ impl Encodable for Transaction {
             Transaction::Eip4844(blob_tx) => {
                 blob_tx.encode_for_signing(out);
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => {
+                deposit_tx.encode(out, false);
+            }
         }
     }
 
@@ -581,6 +712,8 @@ impl Encodable for Transaction {
             Transaction::Eip2930(access_list_tx) => access_list_tx.payload_len_for_signature(),
             Transaction::Eip1559(dynamic_fee_tx) => dynamic_fee_tx.payload_len_for_signature(),
             Transaction::Eip4844(blob_tx) => blob_tx.payload_len_for_signature(),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => deposit_tx.payload_len_without_header(),
         }
     }
 }
@@ -708,6 +841,12 @@ impl TransactionSignedNoHash {
     ///
     /// Returns `None` if the transaction's signature is invalid, see also [Self::recover_signer].
     pub fn recover_signer(&self) -> Option<Address> {
+        // Optimism's Deposit transaction does not have a signature. Directly return the
+        // `from` address.
+        #[cfg(feature = "optimism")]
+        if let Transaction::Deposit(TxDeposit { from, .. }) = self.transaction {
+            return Some(from)
+        }
         let signature_hash = self.signature_hash();
         self.signature.recover_signer(signature_hash)
     }
@@ -766,7 +905,17 @@ impl Compact for TransactionSignedNoHash {
         buf.as_mut().len() - start
     }
 
-    fn from_compact(mut buf: &[u8], _len: usize) -> (Self, &[u8]) {
+    fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+        Self::try_from_compact(buf, len)
+            .expect("Junk data in database: unknown Transaction variant")
+    }
+}
+
+impl TransactionSignedNoHash {
+    /// Fallible version of [`Compact::from_compact`].
+    ///
+    /// Returns `None` if the transaction type of the encoded transaction is unknown.
+    pub fn try_from_compact(mut buf: &[u8], _len: usize) -> Option<(Self, &[u8])> {
         // The first byte uses 4 bits as flags: IsCompressed[1], TxType[2], Signature[1]
         let bitflags = buf.get_u8() as usize;
 
@@ -794,16 +943,17 @@ impl Compact for TransactionSignedNoHash {
                 // TODO: enforce that zstd is only present at a "top" level type
 
                 let transaction_type = (bitflags & 0b110) >> 1;
-                let (transaction, _) = Transaction::from_compact(tmp.as_slice(), transaction_type);
+                let (transaction, _) =
+                    Transaction::try_from_compact(tmp.as_slice(), transaction_type)?;
 
-                (transaction, buf)
-            })
+                Some((transaction, buf))
+            })?
         } else {
             let transaction_type = bitflags >> 1;
-            Transaction::from_compact(buf, transaction_type)
+            Transaction::try_from_compact(buf, transaction_type)?
         };
 
-        (TransactionSignedNoHash { signature, transaction }, buf)
+        Some((TransactionSignedNoHash { signature, transaction }, buf))
     }
 }
 
@@ -861,6 +1011,12 @@ impl TransactionSigned {
     ///
     /// Returns `None` if the transaction's signature is invalid, see also [Self::recover_signer].
     pub fn recover_signer(&self) -> Option<Address> {
+        // Optimism's Deposit transaction does not have a signature. Directly return the
+        // `from` address.
+        #[cfg(feature = "optimism")]
+        if let Transaction::Deposit(TxDeposit { from, .. }) = self.transaction {
+            return Some(from)
+        }
         let signature_hash = self.signature_hash();
         self.signature.recover_signer(signature_hash)
     }
@@ -942,6 +1098,8 @@ impl TransactionSigned {
                 dynamic_fee_tx.payload_len_with_signature(&self.signature)
             }
             Transaction::Eip4844(blob_tx) => blob_tx.payload_len_with_signature(&self.signature),
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => deposit_tx.payload_len(),
         }
     }
 
@@ -1065,9 +1223,19 @@ impl TransactionSigned {
             1 => Transaction::Eip2930(TxEip2930::decode_inner(data)?),
             2 => Transaction::Eip1559(TxEip1559::decode_inner(data)?),
             3 => Transaction::Eip4844(TxEip4844::decode_inner(data)?),
+            #[cfg(feature = "optimism")]
+            DEPOSIT_TX_TYPE_ID => Transaction::Deposit(TxDeposit::decode_inner(data)?),
             _ => return Err(RlpError::Custom("unsupported typed transaction type")),
         };
 
+        // deposit transactions are not signed
+        #[cfg(feature = "optimism")]
+        let signature = if tx_type == DEPOSIT_TX_TYPE_ID {
+            Signature::optimism_deposit_tx_signature()
+        } else {
+            Signature::decode(data)?
+        };
+        #[cfg(not(feature = "optimism"))]
         let signature = Signature::decode(data)?;
 
         let bytes_consumed = remaining_len - data.len();
@@ -1127,6 +1295,8 @@ impl TransactionSigned {
             Transaction::Eip4844(blob_tx) => {
                 blob_tx.payload_len_with_signature_without_header(&self.signature)
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(deposit_tx) => deposit_tx.payload_len_without_header(),
         }
     }
 }
@@ -1212,6 +1382,14 @@ impl proptest::arbitrary::Arbitrary for TransactionSigned {
                     // Otherwise we might overflow when calculating `v` on `recalculate_hash`
                     transaction.set_chain_id(chain_id % (u64::MAX / 2 - 36));
                 }
+
+                #[cfg(feature = "optimism")]
+                let sig = if transaction.is_deposit() {
+                    Signature::optimism_deposit_tx_signature()
+                } else {
+                    sig
+                };
+
                 let mut tx =
                     TransactionSigned { hash: Default::default(), signature: sig, transaction };
                 tx.hash = tx.recalculate_hash();
@@ -1232,11 +1410,16 @@ impl<'a> arbitrary::Arbitrary<'a> for TransactionSigned {
             transaction.set_chain_id(chain_id % (u64::MAX / 2 - 36));
         }
 
-        let mut tx = TransactionSigned {
-            hash: Default::default(),
-            signature: Signature::arbitrary(u)?,
-            transaction,
+        let signature = Signature::arbitrary(u)?;
+
+        #[cfg(feature = "optimism")]
+        let signature = if transaction.is_deposit() {
+            Signature::optimism_deposit_tx_signature()
+        } else {
+            signature
         };
+
+        let mut tx = TransactionSigned { hash: Default::default(), signature, transaction };
         tx.hash = tx.recalculate_hash();
 
         Ok(tx)
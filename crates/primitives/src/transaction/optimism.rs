@@ -0,0 +1,222 @@
+use crate::{Address, Bytes, TransactionKind, TxType, TxValue, B256};
+use alloy_rlp::{
+    length_of_length, Decodable, Encodable, Error as DecodeError, Header, EMPTY_STRING_CODE,
+};
+use bytes::Buf;
+use reth_codecs::{main_codec, Compact};
+use std::mem;
+
+/// Deposited transaction.
+///
+/// Deposits are L1 initiated transactions that are executed on the L2 without a signature, see the
+/// [OP Stack deposit specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/deposits.md#the-deposited-transaction-type).
+#[main_codec]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TxDeposit {
+    /// Hash that uniquely identifies the source of the deposit.
+    pub source_hash: B256,
+    /// The address of the sender account.
+    pub from: Address,
+    /// The address of the recipient account, or the null (zero-length) address if the deposited
+    /// transaction is a contract creation.
+    pub to: TransactionKind,
+    /// The ETH value to mint on L2.
+    pub mint: Option<u128>,
+    /// The ETH value to send to the recipient account.
+    pub value: TxValue,
+    /// The gas limit for the L2 transaction.
+    pub gas_limit: u64,
+    /// Field indicating if this transaction is exempt from the L2 gas limit.
+    pub is_system_transaction: bool,
+    /// Input has two uses depending if transaction is Create or Call (if `to` field is None or
+    /// Some).
+    pub input: Bytes,
+}
+
+impl TxDeposit {
+    /// Calculates a heuristic for the in-memory size of the [TxDeposit] transaction.
+    #[inline]
+    pub fn size(&self) -> usize {
+        mem::size_of::<B256>() + // source_hash
+        mem::size_of::<Address>() + // from
+        self.to.size() + // to
+        mem::size_of::<Option<u128>>() + // mint
+        mem::size_of::<TxValue>() + // value
+        mem::size_of::<u64>() + // gas_limit
+        mem::size_of::<bool>() + // is_system_transaction
+        self.input.len() // input
+    }
+
+    /// Decodes the inner [TxDeposit] fields from RLP bytes.
+    ///
+    /// NOTE: This assumes a RLP header has already been decoded, and _just_ decodes the following
+    /// RLP fields in the following order:
+    ///
+    /// - `source_hash`
+    /// - `from`
+    /// - `to`
+    /// - `mint`
+    /// - `value`
+    /// - `gas_limit`
+    /// - `is_system_transaction`
+    /// - `input`
+    pub(crate) fn decode_inner(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self {
+            source_hash: Decodable::decode(buf)?,
+            from: Decodable::decode(buf)?,
+            to: Decodable::decode(buf)?,
+            mint: if *buf.first().ok_or(DecodeError::InputTooShort)? == EMPTY_STRING_CODE {
+                buf.advance(1);
+                None
+            } else {
+                Some(Decodable::decode(buf)?)
+            },
+            value: Decodable::decode(buf)?,
+            gas_limit: Decodable::decode(buf)?,
+            is_system_transaction: Decodable::decode(buf)?,
+            input: Decodable::decode(buf)?,
+        })
+    }
+
+    /// Outputs the length of the transaction's fields, without a RLP header or length of the
+    /// eip155 fields.
+    pub(crate) fn fields_len(&self) -> usize {
+        let mut len = 0;
+        len += self.source_hash.length();
+        len += self.from.length();
+        len += self.to.length();
+        len += self.mint.map_or(1, |mint| mint.length());
+        len += self.value.length();
+        len += self.gas_limit.length();
+        len += self.is_system_transaction.length();
+        len += self.input.0.length();
+        len
+    }
+
+    /// Encodes only the transaction's fields into the desired buffer, without a RLP header.
+    pub(crate) fn encode_fields(&self, out: &mut dyn bytes::BufMut) {
+        self.source_hash.encode(out);
+        self.from.encode(out);
+        self.to.encode(out);
+        if let Some(mint) = self.mint {
+            mint.encode(out);
+        } else {
+            out.put_u8(EMPTY_STRING_CODE);
+        }
+        self.value.encode(out);
+        self.gas_limit.encode(out);
+        self.is_system_transaction.encode(out);
+        self.input.0.encode(out);
+    }
+
+    /// Inner encoding function that is used for both rlp [`Encodable`] trait and for calculating
+    /// hash that for eip2718 does not require rlp header.
+    ///
+    /// Deposit transactions are not signed, so unlike the other typed transactions no signature is
+    /// appended to the fields.
+    pub(crate) fn encode(&self, out: &mut dyn bytes::BufMut, with_header: bool) {
+        let payload_length = self.fields_len();
+        if with_header {
+            Header {
+                list: false,
+                payload_length: 1 + length_of_length(payload_length) + payload_length,
+            }
+            .encode(out);
+        }
+        out.put_u8(self.tx_type() as u8);
+        let header = Header { list: true, payload_length };
+        header.encode(out);
+        self.encode_fields(out);
+    }
+
+    /// Output the length of the RLP encoding, _without_ a RLP string header.
+    pub(crate) fn payload_len_without_header(&self) -> usize {
+        let payload_length = self.fields_len();
+        // 'transaction type byte length' + 'header length' + 'payload length'
+        1 + length_of_length(payload_length) + payload_length
+    }
+
+    /// Output the length of the RLP encoding. This encodes with a RLP header.
+    pub(crate) fn payload_len(&self) -> usize {
+        let len = self.payload_len_without_header();
+        length_of_length(len) + len
+    }
+
+    /// Get the transaction type
+    pub(crate) fn tx_type(&self) -> TxType {
+        TxType::DEPOSIT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hex_literal::hex, Address, Bytes, Signature, Transaction, TransactionKind,
+        TransactionSigned, TxDeposit, B256,
+    };
+    use alloy_rlp::{Decodable, Encodable};
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_rlp_roundtrip_deposit() {
+        let tx = TxDeposit {
+            source_hash: B256::with_last_byte(1),
+            from: Address::with_last_byte(2),
+            to: TransactionKind::Call(Address::with_last_byte(3)),
+            mint: Some(100),
+            value: 1000_u64.into(),
+            gas_limit: 21000,
+            is_system_transaction: false,
+            input: Bytes::from(vec![1, 2, 3]),
+        };
+        let signed = TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(tx.clone()),
+            Signature::optimism_deposit_tx_signature(),
+        );
+
+        let mut buf = BytesMut::new();
+        signed.encode_enveloped(&mut buf);
+        assert_eq!(buf[0], 0x7E);
+
+        let decoded = TransactionSigned::decode_enveloped(buf.freeze().into()).unwrap();
+        assert_eq!(decoded, signed);
+        assert_eq!(decoded.recover_signer(), Some(tx.from));
+    }
+
+    #[test]
+    fn test_rlp_roundtrip_deposit_without_mint() {
+        let tx = TxDeposit {
+            source_hash: B256::with_last_byte(1),
+            from: Address::with_last_byte(2),
+            to: TransactionKind::Create,
+            mint: None,
+            value: 0_u64.into(),
+            gas_limit: 1_000_000,
+            is_system_transaction: true,
+            input: Bytes::from(vec![0xaa; 32]),
+        };
+
+        let signed = TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(tx),
+            Signature::optimism_deposit_tx_signature(),
+        );
+
+        // network encoding wraps the envelope in a RLP string header
+        let mut buf = BytesMut::new();
+        signed.encode(&mut buf);
+        assert_eq!(buf.len(), signed.length());
+        let decoded = TransactionSigned::decode(&mut &buf[..]).unwrap();
+        assert_eq!(decoded, signed);
+    }
+
+    #[test]
+    fn test_decode_deposit_from_hex() {
+        let bytes = hex!("7ef852a0000000000000000000000000000000000000000000000000000000000000000194000000000000000000000000000000000000000294000000000000000000000000000000000000000380808252088080");
+        let decoded = TransactionSigned::decode_enveloped(bytes[..].to_vec().into()).unwrap();
+        let Transaction::Deposit(tx) = &decoded.transaction else { panic!("expected deposit") };
+        assert_eq!(tx.gas_limit, 21000);
+        assert_eq!(tx.mint, None);
+        assert_eq!(tx.source_hash, B256::with_last_byte(1));
+        assert_eq!(decoded.recover_signer(), Some(Address::with_last_byte(2)));
+    }
+}
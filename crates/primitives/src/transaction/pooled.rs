@@ -194,6 +194,10 @@ impl PooledTransactionsElement {
                     Transaction::Eip4844(_) => Err(RlpError::Custom(
                         "EIP-4844 transactions can only be decoded with transaction type 0x03",
                     )),
+                    #[cfg(feature = "optimism")]
+                    Transaction::Deposit(_) => {
+                        Err(RlpError::Custom("Optimism deposit transaction cannot be pooled"))
+                    }
                     Transaction::Eip2930(tx) => Ok(PooledTransactionsElement::Eip2930 {
                         transaction: tx,
                         signature: typed_tx.signature,
@@ -391,6 +395,10 @@ impl Decodable for PooledTransactionsElement {
                     Transaction::Eip4844(_) => Err(RlpError::Custom(
                         "EIP-4844 transactions can only be decoded with transaction type 0x03",
                     )),
+                    #[cfg(feature = "optimism")]
+                    Transaction::Deposit(_) => {
+                        Err(RlpError::Custom("Optimism deposit transaction cannot be pooled"))
+                    }
                     Transaction::Eip2930(tx) => Ok(PooledTransactionsElement::Eip2930 {
                         transaction: tx,
                         signature: typed_tx.signature,
@@ -411,6 +419,10 @@ impl From<TransactionSigned> for PooledTransactionsElement {
     /// Converts from a [TransactionSigned] to a [PooledTransactionsElement].
     ///
     /// NOTE: For EIP-4844 transactions, this will return an empty sidecar.
+    ///
+    /// # Panics
+    ///
+    /// If the transaction is an Optimism deposit transaction, since those are never pooled.
     fn from(tx: TransactionSigned) -> Self {
         let TransactionSigned { transaction, signature, hash } = tx;
         match transaction {
@@ -432,6 +444,10 @@ impl From<TransactionSigned> for PooledTransactionsElement {
                     sidecar: Default::default(),
                 })
             }
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => {
+                unreachable!("Optimism deposit transactions can not be pooled")
+            }
         }
     }
 }
@@ -441,6 +457,16 @@ impl<'a> arbitrary::Arbitrary<'a> for PooledTransactionsElement {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
         let transaction = TransactionSigned::arbitrary(u)?;
 
+        // deposit transactions can not be converted into a pooled element
+        #[cfg(feature = "optimism")]
+        let transaction = {
+            let mut transaction = transaction;
+            while transaction.is_deposit() {
+                transaction = TransactionSigned::arbitrary(u)?;
+            }
+            transaction
+        };
+
         // this will have an empty sidecar
         let pooled_txs_element = PooledTransactionsElement::from(transaction);
 
@@ -460,7 +486,14 @@ impl proptest::arbitrary::Arbitrary for PooledTransactionsElement {
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
         use proptest::prelude::{any, Strategy};
 
-        any::<(TransactionSigned, crate::BlobTransactionSidecar)>()
+        let strategy = any::<(TransactionSigned, crate::BlobTransactionSidecar)>();
+
+        // deposit transactions can not be converted into a pooled element
+        #[cfg(feature = "optimism")]
+        let strategy =
+            strategy.prop_filter("deposit transactions are not pooled", |(tx, _)| !tx.is_deposit());
+
+        strategy
             .prop_map(move |(transaction, sidecar)| {
                 // this will have an empty sidecar
                 let pooled_txs_element = PooledTransactionsElement::from(transaction);
@@ -41,6 +41,12 @@ impl Compact for Signature {
 }
 
 impl Signature {
+    /// The signature used for Optimism deposit transactions, which are not signed.
+    #[cfg(feature = "optimism")]
+    pub const fn optimism_deposit_tx_signature() -> Self {
+        Signature { r: U256::ZERO, s: U256::ZERO, odd_y_parity: false }
+    }
+
     /// Output the length of the signature without the length of the RLP header, using the legacy
     /// scheme with EIP-155 support depends on chain_id.
     pub(crate) fn payload_len_with_eip155_chain_id(&self, chain_id: Option<u64>) -> usize {
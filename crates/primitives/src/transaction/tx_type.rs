@@ -1,4 +1,5 @@
 use crate::U8;
+use bytes::Buf;
 use reth_codecs::{derive_arbitrary, Compact};
 use serde::{Deserialize, Serialize};
 
@@ -15,11 +16,30 @@ pub const EIP1559_TX_TYPE_ID: u8 = 2;
 /// Identifier for [TxEip4844](crate::TxEip4844) transaction.
 pub const EIP4844_TX_TYPE_ID: u8 = 3;
 
+/// Identifier for [TxDeposit](crate::TxDeposit) transaction.
+#[cfg(feature = "optimism")]
+pub const DEPOSIT_TX_TYPE_ID: u8 = 126;
+
+/// [`Compact`] identifier for the legacy transaction type.
+pub(crate) const COMPACT_IDENTIFIER_LEGACY: usize = 0;
+
+/// [`Compact`] identifier for the EIP-2930 transaction type.
+pub(crate) const COMPACT_IDENTIFIER_EIP2930: usize = 1;
+
+/// [`Compact`] identifier for the EIP-1559 transaction type.
+pub(crate) const COMPACT_IDENTIFIER_EIP1559: usize = 2;
+
+/// For backwards compatibility purposes only 2 bits of the type are encoded in the identifier
+/// parameter. In the case of a 3, the full transaction type is read from the buffer as a single
+/// byte.
+pub(crate) const COMPACT_EXTENDED_IDENTIFIER_FLAG: usize = 3;
+
 /// Transaction Type
 ///
 /// Currently being used as 2-bit type when encoding it to [`Compact`] on
-/// [`crate::TransactionSignedNoHash`]. Adding more transaction types will break the codec and
-/// database format.
+/// [`crate::TransactionSignedNoHash`]. Types which do not fit into the 2 bits (EIP-4844 and
+/// above) are stored with [`COMPACT_EXTENDED_IDENTIFIER_FLAG`] and their full type byte is written
+/// to the buffer.
 ///
 /// Other required changes when adding a new type can be seen on [PR#3953](https://github.com/paradigmxyz/reth/pull/3953/files).
 #[derive_arbitrary(compact)]
@@ -34,6 +54,9 @@ pub enum TxType {
     EIP1559 = 2_isize,
     /// Shard Blob Transactions - EIP-4844
     EIP4844 = 3_isize,
+    /// Optimism Deposit transaction.
+    #[cfg(feature = "optimism")]
+    DEPOSIT = 126_isize,
 }
 
 impl From<TxType> for u8 {
@@ -43,6 +66,8 @@ impl From<TxType> for u8 {
             TxType::EIP2930 => EIP2930_TX_TYPE_ID,
             TxType::EIP1559 => EIP1559_TX_TYPE_ID,
             TxType::EIP4844 => EIP4844_TX_TYPE_ID,
+            #[cfg(feature = "optimism")]
+            TxType::DEPOSIT => DEPOSIT_TX_TYPE_ID,
         }
     }
 }
@@ -54,27 +79,113 @@ impl From<TxType> for U8 {
 }
 
 impl Compact for TxType {
-    fn to_compact<B>(self, _: &mut B) -> usize
+    fn to_compact<B>(self, buf: &mut B) -> usize
     where
         B: bytes::BufMut + AsMut<[u8]>,
     {
         match self {
-            TxType::Legacy => 0,
-            TxType::EIP2930 => 1,
-            TxType::EIP1559 => 2,
-            TxType::EIP4844 => 3,
+            TxType::Legacy => COMPACT_IDENTIFIER_LEGACY,
+            TxType::EIP2930 => COMPACT_IDENTIFIER_EIP2930,
+            TxType::EIP1559 => COMPACT_IDENTIFIER_EIP1559,
+            TxType::EIP4844 => {
+                buf.put_u8(EIP4844_TX_TYPE_ID);
+                COMPACT_EXTENDED_IDENTIFIER_FLAG
+            }
+            #[cfg(feature = "optimism")]
+            TxType::DEPOSIT => {
+                buf.put_u8(DEPOSIT_TX_TYPE_ID);
+                COMPACT_EXTENDED_IDENTIFIER_FLAG
+            }
         }
     }
 
+    // For backwards compatibility purposes only 2 bits of the type are encoded in the identifier
+    // parameter. In the case of a [`COMPACT_EXTENDED_IDENTIFIER_FLAG`], the full transaction type
+    // is read from the buffer as a single byte.
     fn from_compact(buf: &[u8], identifier: usize) -> (Self, &[u8]) {
-        (
-            match identifier {
-                0 => TxType::Legacy,
-                1 => TxType::EIP2930,
-                2 => TxType::EIP1559,
-                _ => TxType::EIP4844,
-            },
-            buf,
-        )
+        Self::try_from_compact(buf, identifier)
+            .unwrap_or_else(|| panic!("Unknown identifier for TxType: {identifier}"))
+    }
+}
+
+impl TxType {
+    /// Fallible version of [`Compact::from_compact`].
+    ///
+    /// Returns `None` if the identifier or the extended transaction type read from the buffer is
+    /// unknown.
+    pub fn try_from_compact(mut buf: &[u8], identifier: usize) -> Option<(Self, &[u8])> {
+        let tx_type = match identifier {
+            COMPACT_IDENTIFIER_LEGACY => TxType::Legacy,
+            COMPACT_IDENTIFIER_EIP2930 => TxType::EIP2930,
+            COMPACT_IDENTIFIER_EIP1559 => TxType::EIP1559,
+            COMPACT_EXTENDED_IDENTIFIER_FLAG => {
+                if !buf.has_remaining() {
+                    return None
+                }
+                match buf.get_u8() {
+                    EIP4844_TX_TYPE_ID => TxType::EIP4844,
+                    #[cfg(feature = "optimism")]
+                    DEPOSIT_TX_TYPE_ID => TxType::DEPOSIT,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        Some((tx_type, buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_txtype_to_compat() {
+        let cases = vec![
+            (TxType::Legacy, COMPACT_IDENTIFIER_LEGACY, vec![]),
+            (TxType::EIP2930, COMPACT_IDENTIFIER_EIP2930, vec![]),
+            (TxType::EIP1559, COMPACT_IDENTIFIER_EIP1559, vec![]),
+            (TxType::EIP4844, COMPACT_EXTENDED_IDENTIFIER_FLAG, vec![EIP4844_TX_TYPE_ID]),
+            #[cfg(feature = "optimism")]
+            (TxType::DEPOSIT, COMPACT_EXTENDED_IDENTIFIER_FLAG, vec![DEPOSIT_TX_TYPE_ID]),
+        ];
+
+        for (tx_type, expected_identifier, expected_buf) in cases {
+            let mut buf = vec![];
+            let identifier = tx_type.to_compact(&mut buf);
+            assert_eq!(
+                identifier, expected_identifier,
+                "Unexpected identifier for TxType {tx_type:?}",
+            );
+            assert_eq!(buf, expected_buf, "Unexpected buffer for TxType {tx_type:?}");
+        }
+    }
+
+    #[test]
+    fn test_txtype_from_compact() {
+        let cases = vec![
+            (TxType::Legacy, COMPACT_IDENTIFIER_LEGACY, vec![]),
+            (TxType::EIP2930, COMPACT_IDENTIFIER_EIP2930, vec![]),
+            (TxType::EIP1559, COMPACT_IDENTIFIER_EIP1559, vec![]),
+            (TxType::EIP4844, COMPACT_EXTENDED_IDENTIFIER_FLAG, vec![EIP4844_TX_TYPE_ID]),
+            #[cfg(feature = "optimism")]
+            (TxType::DEPOSIT, COMPACT_EXTENDED_IDENTIFIER_FLAG, vec![DEPOSIT_TX_TYPE_ID]),
+        ];
+
+        for (expected_type, identifier, buf) in cases {
+            let (actual_type, remaining_buf) = TxType::from_compact(&buf, identifier);
+            assert_eq!(actual_type, expected_type, "Unexpected TxType for identifier {identifier}",);
+            assert!(
+                remaining_buf.is_empty(),
+                "Buffer not fully consumed for identifier {identifier}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_txtype_try_from_compact_unknown() {
+        assert_eq!(TxType::try_from_compact(&[], 4), None);
+        assert_eq!(TxType::try_from_compact(&[], COMPACT_EXTENDED_IDENTIFIER_FLAG), None);
+        assert_eq!(TxType::try_from_compact(&[0x7f], COMPACT_EXTENDED_IDENTIFIER_FLAG), None);
     }
 }
@@ -0,0 +1,186 @@
+use crate::{
+    segments::{
+        history::prune_history_indices, PruneInput, PruneOutput, PruneOutputCheckpoint, Segment,
+    },
+    PrunerError,
+};
+use reth_db::{database::Database, models::ShardedKey, tables};
+use reth_primitives::{PruneMode, PruneSegment};
+use reth_provider::{BlockReader, DatabaseProviderRW};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct AddressAppearances {
+    mode: PruneMode,
+}
+
+impl AddressAppearances {
+    pub fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<DB: Database> Segment<DB> for AddressAppearances {
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::AddressAppearances
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(
+        &self,
+        provider: &DatabaseProviderRW<'_, DB>,
+        input: PruneInput,
+    ) -> Result<PruneOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No address appearances to prune");
+                return Ok(PruneOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        // The index is keyed by transaction numbers, so prune up to the last transaction of the
+        // target block.
+        let last_tx_number = match provider.block_body_indices(range_end)? {
+            Some(body) => body.last_tx_num(),
+            None => {
+                trace!(target: "pruner", block_number = %range_end, "Block body indices not found");
+                return Ok(PruneOutput::not_done())
+            }
+        };
+
+        // Delete the shards that only contain transactions up to the target one first, as there
+        // can be arbitrarily many of them.
+        let (pruned_shards, done) = provider.prune_table_with_range::<tables::AddressAppearances>(
+            ..,
+            input.delete_limit,
+            |row| row.0.highest_block_number > last_tx_number,
+            |_| {},
+        )?;
+        trace!(target: "pruner", pruned = %pruned_shards, %done, "Pruned address appearances (shards)");
+
+        if !done {
+            // Keep the previous checkpoint, so the rest of the shards are pruned on the next run.
+            return Ok(PruneOutput {
+                done,
+                pruned: pruned_shards,
+                checkpoint: Some(PruneOutputCheckpoint {
+                    block_number: range.start().checked_sub(1),
+                    tx_number: input
+                        .previous_checkpoint
+                        .and_then(|checkpoint| checkpoint.tx_number),
+                }),
+            })
+        }
+
+        let (processed, pruned_indices) = prune_history_indices::<DB, tables::AddressAppearances, _>(
+            provider,
+            last_tx_number,
+            |a, b| a.key == b.key,
+            |key| ShardedKey::last(key.key),
+        )?;
+        trace!(target: "pruner", %processed, pruned = %pruned_indices, "Pruned address appearances (indices)");
+
+        Ok(PruneOutput {
+            done,
+            pruned: pruned_shards + pruned_indices,
+            checkpoint: Some(PruneOutputCheckpoint {
+                block_number: Some(range_end),
+                tx_number: Some(last_tx_number),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{AddressAppearances, PruneInput, PruneOutput, Segment};
+    use assert_matches::assert_matches;
+    use reth_db::{
+        models::{ShardedKey, StoredBlockBodyIndices},
+        tables,
+        transaction::DbTxMut,
+        TxNumberList,
+    };
+    use reth_primitives::{Address, PruneMode, PruneSegment};
+    use reth_provider::PruneCheckpointReader;
+    use reth_stages::test_utils::TestTransaction;
+
+    #[test]
+    fn prune() {
+        let tx = TestTransaction::default();
+        let (first, second) = (Address::with_last_byte(1), Address::with_last_byte(2));
+
+        tx.commit(|tx| {
+            for block_number in 0..4 {
+                tx.put::<tables::BlockBodyIndices>(
+                    block_number,
+                    StoredBlockBodyIndices { first_tx_num: block_number * 2, tx_count: 2 },
+                )?;
+            }
+            tx.put::<tables::AddressAppearances>(
+                ShardedKey::new(first, 3),
+                TxNumberList::new([0, 1, 3]).unwrap(),
+            )?;
+            tx.put::<tables::AddressAppearances>(
+                ShardedKey::last(first),
+                TxNumberList::new([4, 7]).unwrap(),
+            )?;
+            tx.put::<tables::AddressAppearances>(
+                ShardedKey::new(second, 1),
+                TxNumberList::new([0, 1]).unwrap(),
+            )?;
+            tx.put::<tables::AddressAppearances>(
+                ShardedKey::last(second),
+                TxNumberList::new([2, 5]).unwrap(),
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        // prune the first two blocks, i.e. transactions 0 to 3, deleting a single shard per run
+        let prune_mode = PruneMode::Before(2);
+        let segment = AddressAppearances::new(prune_mode);
+        let test_prune = |expected_result: (bool, usize), expected_checkpoint| {
+            let input = PruneInput {
+                previous_checkpoint: tx
+                    .inner()
+                    .get_prune_checkpoint(PruneSegment::AddressAppearances)
+                    .unwrap(),
+                to_block: 1,
+                delete_limit: 1,
+            };
+            let provider = tx.inner_rw();
+            let result = segment.prune(&provider, input).unwrap();
+            assert_matches!(
+                result,
+                PruneOutput { done, pruned, checkpoint: Some(_) }
+                    if (done, pruned) == expected_result
+            );
+            let checkpoint = result.checkpoint.unwrap();
+            assert_eq!((checkpoint.block_number, checkpoint.tx_number), expected_checkpoint);
+            segment.save_checkpoint(&provider, checkpoint.as_prune_checkpoint(prune_mode)).unwrap();
+            provider.commit().expect("commit");
+        };
+
+        test_prune((false, 1), (None, None));
+        test_prune((false, 1), (None, None));
+        test_prune((true, 0), (Some(1), Some(3)));
+
+        let table = tx
+            .table::<tables::AddressAppearances>()
+            .unwrap()
+            .into_iter()
+            .map(|(key, list)| (key, list.iter(0).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            table,
+            vec![(ShardedKey::last(first), vec![4, 7]), (ShardedKey::last(second), vec![5])]
+        );
+    }
+}
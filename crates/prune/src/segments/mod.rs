@@ -1,4 +1,5 @@
 mod account_history;
+mod address_appearances;
 mod headers;
 mod history;
 mod receipts;
@@ -7,9 +8,11 @@ mod sender_recovery;
 mod set;
 mod storage_history;
 mod transaction_lookup;
+mod transaction_traces;
 mod transactions;
 
 pub use account_history::AccountHistory;
+pub use address_appearances::AddressAppearances;
 pub use headers::Headers;
 pub use receipts::Receipts;
 pub use receipts_by_logs::ReceiptsByLogs;
@@ -18,6 +21,7 @@ pub use set::SegmentSet;
 use std::fmt::Debug;
 pub use storage_history::StorageHistory;
 pub use transaction_lookup::TransactionLookup;
+pub use transaction_traces::TransactionTraces;
 pub use transactions::Transactions;
 
 use crate::PrunerError;
@@ -3,7 +3,7 @@
 //! ## Feature Flags
 //!
 //! - `js-tracer` (default): Enables a JavaScript tracer implementation. This pulls in extra
-//!   dependencies (such as `boa`, `tokio` and `serde_json`).
+//!   dependencies (such as `boa` and `tokio`).
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
@@ -1,3 +1,4 @@
+use crate::tracing::{TracingInspector, TracingInspectorConfig};
 use reth_primitives::{Address, Bytes, TxHash, B256, U256};
 use revm::{
     inspectors::CustomPrintTracer,
@@ -36,6 +37,8 @@ pub enum Hook {
 pub struct InspectorStack {
     /// An inspector that prints the opcode traces to the console.
     pub custom_print_tracer: Option<CustomPrintTracer>,
+    /// An inspector that records the call traces of the inspected transactions.
+    pub tracing: Option<TracingInspector>,
     /// The provided hook
     pub hook: Hook,
 }
@@ -44,6 +47,7 @@ impl Debug for InspectorStack {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("InspectorStack")
             .field("custom_print_tracer", &self.custom_print_tracer.is_some())
+            .field("tracing", &self.tracing.is_some())
             .field("hook", &self.hook)
             .finish()
     }
@@ -58,9 +62,22 @@ impl InspectorStack {
             stack.custom_print_tracer = Some(CustomPrintTracer::default());
         }
 
+        if let Some(config) = config.tracing {
+            stack.tracing = Some(TracingInspector::new(config));
+        }
+
         stack
     }
 
+    /// Takes the tracing inspector and the traces it recorded, replacing it with a fresh inspector
+    /// of the same config.
+    ///
+    /// This can be used to obtain the traces of each transaction separately.
+    pub fn take_tracing_inspector(&mut self) -> Option<TracingInspector> {
+        let config = *self.tracing.as_ref()?.config();
+        self.tracing.replace(TracingInspector::new(config))
+    }
+
     /// Check if the inspector should be used.
     pub fn should_inspect(&self, env: &Env, tx_hash: TxHash) -> bool {
         match self.hook {
@@ -79,6 +96,9 @@ pub struct InspectorStackConfig {
     /// In execution this will print opcode level traces directly to console.
     pub use_printer_tracer: bool,
 
+    /// Record the call traces of the inspected transactions with the given config.
+    pub tracing: Option<TracingInspectorConfig>,
+
     /// Hook on a specific block or transaction.
     pub hook: Hook,
 }
@@ -101,13 +121,13 @@ where
     DB: Database,
 {
     fn initialize_interp(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             inspector.initialize_interp(interpreter, data);
         });
     }
 
     fn step(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             inspector.step(interpreter, data);
         });
     }
@@ -119,13 +139,13 @@ where
         topics: &[B256],
         data: &Bytes,
     ) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             inspector.log(evm_data, address, topics, data);
         });
     }
 
     fn step_end(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             inspector.step_end(interpreter, data);
         });
     }
@@ -135,7 +155,7 @@ where
         data: &mut EVMData<'_, DB>,
         inputs: &mut CallInputs,
     ) -> (InstructionResult, Gas, Bytes) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             let (status, gas, retdata) = inspector.call(data, inputs);
 
             // Allow inspectors to exit early
@@ -155,7 +175,7 @@ where
         ret: InstructionResult,
         out: Bytes,
     ) -> (InstructionResult, Gas, Bytes) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             let (new_ret, new_gas, new_out) =
                 inspector.call_end(data, inputs, remaining_gas, ret, out.clone());
 
@@ -174,7 +194,7 @@ where
         data: &mut EVMData<'_, DB>,
         inputs: &mut CreateInputs,
     ) -> (InstructionResult, Option<Address>, Gas, Bytes) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             let (status, addr, gas, retdata) = inspector.create(data, inputs);
 
             // Allow inspectors to exit early
@@ -195,7 +215,7 @@ where
         remaining_gas: Gas,
         out: Bytes,
     ) -> (InstructionResult, Option<Address>, Gas, Bytes) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             let (new_ret, new_address, new_gas, new_retdata) =
                 inspector.create_end(data, inputs, ret, address, remaining_gas, out.clone());
 
@@ -208,7 +228,7 @@ where
     }
 
     fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
-        call_inspectors!(inspector, [&mut self.custom_print_tracer], {
+        call_inspectors!(inspector, [&mut self.custom_print_tracer, &mut self.tracing], {
             Inspector::<DB>::selfdestruct(inspector, contract, target, value);
         });
     }
@@ -1,14 +1,14 @@
 //! Geth trace builder
 
 use crate::tracing::{
-    types::{CallTraceNode, CallTraceStepStackItem},
+    types::{CallTraceNode, CallTraceStep, CallTraceStepStackItem},
     utils::load_account_code,
     TracingInspectorConfig,
 };
 use reth_primitives::{Address, Bytes, B256, U256};
 use reth_rpc_types::trace::geth::{
     AccountChangeKind, AccountState, CallConfig, CallFrame, DefaultFrame, DiffMode,
-    GethDefaultTracingOptions, PreStateConfig, PreStateFrame, PreStateMode, StructLog,
+    GethDefaultTracingOptions, PreStateConfig, PreStateFrame, PreStateMode, StdJsonLog, StructLog,
 };
 use revm::{db::DatabaseRef, primitives::ResultAndState};
 use std::collections::{btree_map::Entry, BTreeMap, HashMap, VecDeque};
@@ -28,14 +28,12 @@ impl GethTraceBuilder {
         Self { nodes, _config }
     }
 
-    /// Fill in the geth trace with all steps of the trace and its children traces in the order they
-    /// appear in the transaction.
-    fn fill_geth_trace(
-        &self,
-        main_trace_node: &CallTraceNode,
-        opts: &GethDefaultTracingOptions,
-        storage: &mut HashMap<Address, BTreeMap<B256, B256>>,
-        struct_logs: &mut Vec<StructLog>,
+    /// Calls the given function with all steps of the trace and its children traces in the order
+    /// they appear in the transaction, along with the trace node each step belongs to.
+    fn for_each_step<'a>(
+        &'a self,
+        main_trace_node: &'a CallTraceNode,
+        mut f: impl FnMut(&'a CallTraceNode, &'a CallTraceStep),
     ) {
         // A stack with all the steps of the trace and all its children's steps.
         // This is used to process the steps in the order they appear in the transactions.
@@ -51,6 +49,27 @@ impl GethTraceBuilder {
         while let Some(CallTraceStepStackItem { trace_node, step, call_child_id }) =
             step_stack.pop_back()
         {
+            f(trace_node, step);
+
+            // If the step is a call, we first push all the steps of the child trace on the stack,
+            // so they are processed next
+            if let Some(call_child_id) = call_child_id {
+                let child_trace = &self.nodes[call_child_id];
+                child_trace.push_steps_on_stack(&mut step_stack);
+            }
+        }
+    }
+
+    /// Fill in the geth trace with all steps of the trace and its children traces in the order they
+    /// appear in the transaction.
+    fn fill_geth_trace(
+        &self,
+        main_trace_node: &CallTraceNode,
+        opts: &GethDefaultTracingOptions,
+        storage: &mut HashMap<Address, BTreeMap<B256, B256>>,
+        struct_logs: &mut Vec<StructLog>,
+    ) {
+        self.for_each_step(main_trace_node, |trace_node, step| {
             let mut log = step.convert_to_geth_struct_log(opts);
 
             // Fill in memory and storage depending on the options
@@ -68,14 +87,7 @@ impl GethTraceBuilder {
 
             // Add step to geth trace
             struct_logs.push(log);
-
-            // If the step is a call, we first push all the steps of the child trace on the stack,
-            // so they are processed next
-            if let Some(call_child_id) = call_child_id {
-                let child_trace = &self.nodes[call_child_id];
-                child_trace.push_steps_on_stack(&mut step_stack);
-            }
-        }
+        });
     }
 
     /// Generate a geth-style trace e.g. for `debug_traceTransaction`
@@ -108,6 +120,25 @@ impl GethTraceBuilder {
         }
     }
 
+    /// Generate the steps of a standard json trace, as specified by EIP-3155, e.g. for
+    /// `debug_standardTraceBlockToFile`.
+    ///
+    /// In a trace file, the steps are followed by a summary line that is built from the result of
+    /// the transaction.
+    pub fn geth_std_json_traces(&self, opts: &GethDefaultTracingOptions) -> Vec<StdJsonLog> {
+        let mut logs = Vec::new();
+        if let Some(main_trace_node) = self.nodes.first() {
+            self.for_each_step(main_trace_node, |trace_node, step| {
+                let mut log = step.convert_to_std_json_log(opts);
+                if opts.is_return_data_enabled() {
+                    log.return_data = Some(trace_node.trace.output.clone());
+                }
+                logs.push(log);
+            });
+        }
+        logs
+    }
+
     /// Generate a geth-style traces for the call tracer.
     ///
     /// This decodes all call frames from the recorded traces.
@@ -6,5 +6,8 @@ pub mod geth;
 /// Parity style trace builders for `trace_` namespace
 pub mod parity;
 
+/// Otterscan style trace builders for `ots_` namespace
+pub mod otterscan;
+
 /// Walker types used for traversing various callgraphs
 mod walker;
@@ -0,0 +1,260 @@
+//! Otterscan trace builder
+
+use crate::tracing::types::{CallKind, CallTraceNode};
+use reth_primitives::Address;
+use reth_rpc_types::{InternalOperation, OperationType, TraceEntry};
+
+/// A type for creating otterscan style traces for the `ots_` namespace
+#[derive(Clone, Debug)]
+pub struct OtterscanTraceBuilder {
+    /// Recorded trace nodes.
+    nodes: Vec<CallTraceNode>,
+}
+
+impl OtterscanTraceBuilder {
+    /// Returns a new instance of the builder
+    pub(crate) fn new(nodes: Vec<CallTraceNode>) -> Self {
+        Self { nodes }
+    }
+
+    /// Returns all internal operations of the transaction, as returned by
+    /// `ots_getInternalOperations`.
+    ///
+    /// These are all value transfers, contract creations and selfdestructs that happened in
+    /// subcalls of the transaction. The top level call itself is not included.
+    pub fn into_internal_operations(self) -> Vec<InternalOperation> {
+        let mut operations = Vec::new();
+        for node in self.nodes.iter() {
+            let trace = &node.trace;
+            if trace.depth > 0 {
+                let r#type = match trace.kind {
+                    CallKind::Call if !trace.value.is_zero() => Some(OperationType::OpTransfer),
+                    CallKind::Create => Some(OperationType::OpCreate),
+                    CallKind::Create2 => Some(OperationType::OpCreate2),
+                    _ => None,
+                };
+                if let Some(r#type) = r#type {
+                    operations.push(InternalOperation {
+                        r#type,
+                        from: trace.caller,
+                        to: trace.address,
+                        value: trace.value,
+                    });
+                }
+            }
+
+            if node.is_selfdestruct() {
+                operations.push(InternalOperation {
+                    r#type: OperationType::OpSelfDestruct,
+                    from: trace.address,
+                    to: trace.selfdestruct_refund_target.unwrap_or_default(),
+                    value: trace.value,
+                });
+            }
+        }
+        operations
+    }
+
+    /// Returns the address that created the given contract in the transaction, if any, as used by
+    /// `ots_getContractCreator`.
+    ///
+    /// Creations in a call that was reverted, directly or by one of its parents, are ignored.
+    pub fn contract_creator(&self, address: Address) -> Option<Address> {
+        self.nodes
+            .iter()
+            .find(|node| {
+                node.trace.kind.is_any_create() &&
+                    node.trace.address == address &&
+                    self.is_persisted(node)
+            })
+            .map(|node| node.trace.caller)
+    }
+
+    /// Returns true if neither the node nor any of its parents failed.
+    fn is_persisted(&self, node: &CallTraceNode) -> bool {
+        let mut node = node;
+        loop {
+            if node.trace.is_error() {
+                return false
+            }
+            match node.parent {
+                Some(parent) => node = &self.nodes[parent],
+                None => return true,
+            }
+        }
+    }
+
+    /// Returns the compact call tree of the transaction, as returned by `ots_traceTransaction`.
+    ///
+    /// The entries are in the order the calls were made, selfdestructs are included as entries one
+    /// level deeper than the call they happened in, after all of its subcalls.
+    pub fn into_trace_entries(self) -> Vec<TraceEntry> {
+        let mut entries = Vec::with_capacity(self.nodes.len());
+        if !self.nodes.is_empty() {
+            self.fill_trace_entries(&self.nodes[0], &mut entries);
+        }
+        entries
+    }
+
+    /// Appends the entries of the given node and all of its children, depth first.
+    fn fill_trace_entries(&self, node: &CallTraceNode, entries: &mut Vec<TraceEntry>) {
+        let trace = &node.trace;
+        // static and delegate calls can't transfer value
+        let value = (!matches!(trace.kind, CallKind::StaticCall | CallKind::DelegateCall))
+            .then_some(trace.value);
+        entries.push(TraceEntry {
+            r#type: trace.kind.to_string(),
+            depth: trace.depth as u32,
+            from: trace.caller,
+            to: trace.address,
+            value,
+            input: trace.data.clone(),
+        });
+
+        for child in node.children.iter() {
+            self.fill_trace_entries(&self.nodes[*child], entries);
+        }
+
+        if node.is_selfdestruct() {
+            entries.push(TraceEntry {
+                r#type: "SELFDESTRUCT".to_string(),
+                depth: trace.depth as u32 + 1,
+                from: trace.address,
+                to: trace.selfdestruct_refund_target.unwrap_or_default(),
+                value: Some(trace.value),
+                input: Default::default(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing::types::CallTrace;
+    use reth_primitives::{Bytes, U256};
+    use revm::interpreter::InstructionResult;
+
+    fn node(
+        idx: usize,
+        parent: Option<usize>,
+        children: Vec<usize>,
+        trace: CallTrace,
+    ) -> CallTraceNode {
+        CallTraceNode { parent, children, idx, trace, ..Default::default() }
+    }
+
+    fn nodes() -> Vec<CallTraceNode> {
+        let (eoa, a, b, c) = (
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+            Address::with_last_byte(3),
+            Address::with_last_byte(4),
+        );
+        vec![
+            node(
+                0,
+                None,
+                vec![1, 2],
+                CallTrace {
+                    depth: 0,
+                    caller: eoa,
+                    address: a,
+                    value: U256::from(10),
+                    data: Bytes::from_static(&[1]),
+                    ..Default::default()
+                },
+            ),
+            node(
+                1,
+                Some(0),
+                vec![3],
+                CallTrace {
+                    depth: 1,
+                    caller: a,
+                    address: b,
+                    value: U256::from(5),
+                    status: InstructionResult::SelfDestruct,
+                    selfdestruct_refund_target: Some(eoa),
+                    ..Default::default()
+                },
+            ),
+            node(
+                2,
+                Some(0),
+                vec![],
+                CallTrace {
+                    depth: 1,
+                    kind: CallKind::StaticCall,
+                    caller: a,
+                    address: c,
+                    ..Default::default()
+                },
+            ),
+            node(
+                3,
+                Some(1),
+                vec![],
+                CallTrace {
+                    depth: 2,
+                    kind: CallKind::Create2,
+                    caller: b,
+                    address: c,
+                    ..Default::default()
+                },
+            ),
+        ]
+    }
+
+    #[test]
+    fn internal_operations() {
+        let operations = OtterscanTraceBuilder::new(nodes()).into_internal_operations();
+        let types = operations.iter().map(|op| op.r#type).collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                OperationType::OpTransfer,
+                OperationType::OpSelfDestruct,
+                OperationType::OpCreate2
+            ]
+        );
+        assert_eq!(operations[1].from, Address::with_last_byte(3));
+        assert_eq!(operations[1].to, Address::with_last_byte(1));
+    }
+
+    #[test]
+    fn trace_entries() {
+        let entries = OtterscanTraceBuilder::new(nodes()).into_trace_entries();
+        let entries = entries
+            .iter()
+            .map(|entry| (entry.r#type.as_str(), entry.depth, entry.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![
+                ("CALL", 0, Some(U256::from(10))),
+                ("CALL", 1, Some(U256::from(5))),
+                ("CREATE2", 2, Some(U256::ZERO)),
+                ("SELFDESTRUCT", 2, Some(U256::from(5))),
+                ("STATICCALL", 1, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn contract_creator() {
+        let mut nodes = nodes();
+        let builder = OtterscanTraceBuilder::new(nodes.clone());
+        assert_eq!(
+            builder.contract_creator(Address::with_last_byte(4)),
+            Some(Address::with_last_byte(3))
+        );
+        // a plain call to the address isn't a creation
+        assert_eq!(builder.contract_creator(Address::with_last_byte(2)), None);
+
+        // the creation is discarded if a parent call reverts
+        nodes[1].trace.status = InstructionResult::Revert;
+        let builder = OtterscanTraceBuilder::new(nodes);
+        assert_eq!(builder.contract_creator(Address::with_last_byte(4)), None);
+    }
+}
@@ -15,7 +15,8 @@ use std::collections::{HashSet, VecDeque};
 
 /// A type for creating parity style traces
 ///
-/// Note: Parity style traces always ignore calls to precompiles.
+/// Note: Calls to precompiles are ignored if the inspector was configured to exclude them, see
+/// [TracingInspectorConfig::exclude_precompile_calls].
 #[derive(Clone, Debug)]
 pub struct ParityTraceBuilder {
     /// Recorded trace nodes
@@ -24,7 +25,7 @@ pub struct ParityTraceBuilder {
     spec_id: Option<SpecId>,
 
     /// How the traces were recorded
-    _config: TracingInspectorConfig,
+    config: TracingInspectorConfig,
 }
 
 impl ParityTraceBuilder {
@@ -32,9 +33,23 @@ impl ParityTraceBuilder {
     pub(crate) fn new(
         nodes: Vec<CallTraceNode>,
         spec_id: Option<SpecId>,
-        _config: TracingInspectorConfig,
+        config: TracingInspectorConfig,
     ) -> Self {
-        Self { nodes, spec_id, _config }
+        Self { nodes, spec_id, config }
+    }
+
+    /// Excludes calls to precompiles from the traces, even if they were recorded.
+    ///
+    /// This is useful if the recorded calls are shared with a tracer that includes calls to
+    /// precompiles, like the `callTracer` in a `muxTracer`.
+    pub fn with_precompile_calls_excluded(mut self) -> Self {
+        self.config.exclude_precompile_calls = true;
+        self
+    }
+
+    /// Returns true if the node is a call to a precompile that should be excluded from the traces.
+    fn is_excluded_precompile(&self, node: &CallTraceNode) -> bool {
+        self.config.exclude_precompile_calls && node.is_precompile()
     }
 
     /// Returns a list of all addresses that appeared as callers.
@@ -93,7 +108,7 @@ impl ParityTraceBuilder {
         }
         let mut graph = vec![];
         let mut node = &self.nodes[idx];
-        if node.is_precompile() {
+        if self.is_excluded_precompile(node) {
             return graph
         }
         while let Some(parent) = node.parent {
@@ -116,7 +131,7 @@ impl ParityTraceBuilder {
     ///
     /// This excludes nodes that represent calls to precompiles.
     fn iter_traceable_nodes(&self) -> impl Iterator<Item = &CallTraceNode> {
-        self.nodes.iter().filter(|node| !node.is_precompile())
+        self.nodes.iter().filter(|node| !self.is_excluded_precompile(node))
     }
 
     /// Returns an iterator over all recorded traces  for `trace_transaction`
@@ -270,13 +285,14 @@ impl ParityTraceBuilder {
     /// Returns an iterator over all recorded traces  for `trace_transaction`
     pub fn into_transaction_traces_iter(self) -> impl Iterator<Item = TransactionTrace> {
         let trace_addresses = self.trace_addresses();
+        let exclude_precompile_calls = self.config.exclude_precompile_calls;
         TransactionTraceIter {
             next_selfdestruct: None,
             iter: self
                 .nodes
                 .into_iter()
                 .zip(trace_addresses)
-                .filter(|(node, _)| !node.is_precompile())
+                .filter(move |(node, _)| !(exclude_precompile_calls && node.is_precompile()))
                 .map(|(node, trace_address)| (node.parity_transaction_trace(trace_address), node)),
         }
     }
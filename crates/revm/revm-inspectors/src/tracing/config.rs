@@ -1,4 +1,4 @@
-use reth_rpc_types::trace::geth::GethDefaultTracingOptions;
+use reth_rpc_types::trace::geth::{FlatCallConfig, GethDefaultTracingOptions};
 
 /// What kind of tracing style this is.
 ///
@@ -95,6 +95,14 @@ impl TracingInspectorConfig {
         }
     }
 
+    /// Returns a config for the geth `flatCallTracer` based on the given [FlatCallConfig].
+    ///
+    /// The flat call traces are parity style traces, so this records the same as
+    /// [TracingInspectorConfig::default_parity], but may include calls to precompiles.
+    pub fn from_flat_call_config(config: &FlatCallConfig) -> Self {
+        Self::default_parity().set_exclude_precompile_calls(!config.is_include_precompiles())
+    }
+
     /// Configure whether calls to precompiles should be ignored.
     ///
     /// If set to `true`, calls to precompiles without value transfers will be ignored.
@@ -19,6 +19,7 @@ mod arena;
 mod builder;
 mod config;
 mod fourbyte;
+mod mux;
 mod opcount;
 mod types;
 mod utils;
@@ -29,10 +30,12 @@ use crate::tracing::{
 };
 pub use builder::{
     geth::{self, GethTraceBuilder},
+    otterscan::{self, OtterscanTraceBuilder},
     parity::{self, ParityTraceBuilder},
 };
 pub use config::TracingInspectorConfig;
 pub use fourbyte::FourByteInspector;
+pub use mux::MuxInspector;
 pub use opcount::OpcodeCountInspector;
 
 #[cfg(feature = "js-tracer")]
@@ -82,6 +85,12 @@ impl TracingInspector {
         }
     }
 
+    /// Returns the config of the inspector.
+    #[inline]
+    pub fn config(&self) -> &TracingInspectorConfig {
+        &self.config
+    }
+
     /// Manually the gas used of the root trace.
     ///
     /// This is useful if the root trace's gasUsed should mirror the actual gas used by the
@@ -115,6 +124,12 @@ impl TracingInspector {
         GethTraceBuilder::new(self.traces.arena, self.config)
     }
 
+    /// Consumes the Inspector and returns a [OtterscanTraceBuilder].
+    #[inline]
+    pub fn into_otterscan_builder(self) -> OtterscanTraceBuilder {
+        OtterscanTraceBuilder::new(self.traces.arena)
+    }
+
     /// Returns true if we're no longer in the context of the root call.
     fn is_deep(&self) -> bool {
         // the root call will always be the first entry in the trace stack
@@ -188,7 +203,8 @@ impl TracingInspector {
     ) {
         // This will only be true if the inspector is configured to exclude precompiles and the call
         // is to a precompile
-        let push_kind = if maybe_precompile.unwrap_or(false) {
+        let push_kind = if self.config.exclude_precompile_calls && maybe_precompile.unwrap_or(false)
+        {
             // We don't want to track precompiles
             PushTraceKind::PushOnly
         } else {
@@ -443,9 +459,9 @@ where
             inputs.transfer.value
         };
 
-        // if calls to precompiles should be excluded, check whether this is a call to a precompile
-        let maybe_precompile =
-            self.config.exclude_precompile_calls.then(|| self.is_precompile_call(data, &to, value));
+        // calls to precompiles are always marked, so that they can be excluded from the traces
+        // later even if they're attached to the call graph
+        let maybe_precompile = Some(self.is_precompile_call(data, &to, value));
 
         self.start_trace_on_call(
             data,
@@ -0,0 +1,369 @@
+use crate::{
+    call_inspectors,
+    tracing::{FourByteInspector, TracingInspector, TracingInspectorConfig},
+};
+use reth_primitives::{Address, Bytes, B256, U256};
+use reth_rpc_types::{
+    trace::geth::{
+        CallConfig, FlatCallConfig, FourByteFrame, GethDebugBuiltInTracerType, GethTrace,
+        MuxConfig, MuxFrame, NoopFrame, PreStateConfig,
+    },
+    TransactionInfo,
+};
+use revm::{
+    db::DatabaseRef,
+    interpreter::{CallInputs, CreateInputs, Gas, InstructionResult, Interpreter},
+    primitives::ResultAndState,
+    Database, EVMData, Inspector,
+};
+use std::collections::HashMap;
+
+/// An inspector for the geth `muxTracer`, which runs several built-in tracers in one execution of
+/// a transaction.
+///
+/// All tracers that are built from call traces share the call arena of a single
+/// [TracingInspector], which records everything these tracers need. Calls to precompiles are
+/// recorded if any of these tracers needs them, the `flatCallTracer` excludes them again according
+/// to its own config.
+#[derive(Debug, Clone)]
+pub struct MuxInspector {
+    /// The inspector of the `4byteTracer`, if configured.
+    four_byte: Option<FourByteInspector>,
+    /// The inspector shared by all tracers that are built from call traces, if any is configured.
+    tracing: Option<TracingInspector>,
+    /// The configured tracers and their configs.
+    tracers: Vec<(GethDebugBuiltInTracerType, MuxTracerConfig)>,
+}
+
+/// The config of a tracer run by the [MuxInspector].
+#[derive(Debug, Clone)]
+enum MuxTracerConfig {
+    FourByte,
+    Call(CallConfig),
+    PreState(PreStateConfig),
+    FlatCall(FlatCallConfig),
+    Noop,
+}
+
+impl MuxInspector {
+    /// Creates a new inspector that runs the tracers of the given [MuxConfig].
+    ///
+    /// Returns an error if the config of a tracer is invalid or if the config contains the
+    /// `muxTracer` itself.
+    pub fn try_from_config(config: MuxConfig) -> Result<Self, serde_json::Error> {
+        let mut four_byte = None;
+        let mut tracing_config: Option<TracingInspectorConfig> = None;
+        let mut tracers = Vec::with_capacity(config.0.len());
+
+        for (tracer, tracer_config) in config.0 {
+            let mux_config = match tracer {
+                GethDebugBuiltInTracerType::FourByteTracer => {
+                    four_byte = Some(FourByteInspector::default());
+                    MuxTracerConfig::FourByte
+                }
+                GethDebugBuiltInTracerType::CallTracer => {
+                    let call_config = tracer_config.into_call_config()?;
+                    let config = tracing_config
+                        .unwrap_or_else(TracingInspectorConfig::default_parity)
+                        .set_exclude_precompile_calls(false);
+                    let record_logs =
+                        config.record_logs || call_config.with_log.unwrap_or_default();
+                    tracing_config = Some(config.set_record_logs(record_logs));
+                    MuxTracerConfig::Call(call_config)
+                }
+                GethDebugBuiltInTracerType::PreStateTracer => {
+                    let prestate_config = tracer_config.into_pre_state_config()?;
+                    let config = tracing_config
+                        .unwrap_or_else(TracingInspectorConfig::default_parity)
+                        .set_exclude_precompile_calls(false);
+                    // if in default mode, we need to return all touched storages, for which we
+                    // need to record steps and statediff
+                    tracing_config = Some(if prestate_config.is_default_mode() {
+                        config.set_steps_and_state_diffs(true)
+                    } else {
+                        config
+                    });
+                    MuxTracerConfig::PreState(prestate_config)
+                }
+                GethDebugBuiltInTracerType::FlatCallTracer => {
+                    let flat_call_config = tracer_config.into_flat_call_config()?;
+                    let config = tracing_config.unwrap_or_else(|| {
+                        TracingInspectorConfig::from_flat_call_config(&flat_call_config)
+                    });
+                    tracing_config = Some(if flat_call_config.is_include_precompiles() {
+                        config.set_exclude_precompile_calls(false)
+                    } else {
+                        config
+                    });
+                    MuxTracerConfig::FlatCall(flat_call_config)
+                }
+                GethDebugBuiltInTracerType::NoopTracer => MuxTracerConfig::Noop,
+                GethDebugBuiltInTracerType::MuxTracer => {
+                    return Err(serde::de::Error::custom("muxTracer can't be nested"))
+                }
+            };
+            tracers.push((tracer, mux_config));
+        }
+
+        Ok(Self { four_byte, tracing: tracing_config.map(TracingInspector::new), tracers })
+    }
+
+    /// Consumes the inspector and returns the results of all configured tracers.
+    ///
+    /// This expects the result of the executed transaction, the database with the state _before_
+    /// the transaction, which is used by the `prestateTracer`, and the info of the transaction,
+    /// which is used by the `flatCallTracer`.
+    pub fn try_into_mux_frame<DB: DatabaseRef>(
+        self,
+        res: &ResultAndState,
+        db: &DB,
+        tx_info: TransactionInfo,
+    ) -> Result<MuxFrame, DB::Error> {
+        let Self { mut four_byte, tracing, tracers } = self;
+        let gas_used = res.result.gas_used();
+
+        let geth_builder = tracing.as_ref().map(|inspector| inspector.clone().into_geth_builder());
+        let mut parity_builder = tracing
+            .map(|inspector| inspector.with_transaction_gas_used(gas_used).into_parity_builder());
+
+        let mut frame = HashMap::with_capacity(tracers.len());
+        for (tracer, config) in tracers {
+            let trace: GethTrace = match config {
+                MuxTracerConfig::FourByte => {
+                    four_byte.take().map(FourByteFrame::from).unwrap_or_default().into()
+                }
+                MuxTracerConfig::Call(call_config) => geth_builder
+                    .as_ref()
+                    .expect("configured for call tracer")
+                    .geth_call_traces(call_config, gas_used)
+                    .into(),
+                MuxTracerConfig::PreState(prestate_config) => geth_builder
+                    .as_ref()
+                    .expect("configured for prestate tracer")
+                    .geth_prestate_traces(res, prestate_config, db)?
+                    .into(),
+                MuxTracerConfig::FlatCall(flat_call_config) => {
+                    let mut builder =
+                        parity_builder.take().expect("configured for flat call tracer");
+                    if !flat_call_config.is_include_precompiles() {
+                        builder = builder.with_precompile_calls_excluded();
+                    }
+                    builder.into_localized_transaction_traces(tx_info).into()
+                }
+                MuxTracerConfig::Noop => NoopFrame::default().into(),
+            };
+            frame.insert(tracer, trace);
+        }
+
+        Ok(MuxFrame(frame))
+    }
+}
+
+impl<DB> Inspector<DB> for MuxInspector
+where
+    DB: Database,
+{
+    fn initialize_interp(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.initialize_interp(interpreter, data);
+        });
+    }
+
+    fn step(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.step(interpreter, data);
+        });
+    }
+
+    fn log(
+        &mut self,
+        evm_data: &mut EVMData<'_, DB>,
+        address: &Address,
+        topics: &[B256],
+        data: &Bytes,
+    ) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.log(evm_data, address, topics, data);
+        });
+    }
+
+    fn step_end(&mut self, interpreter: &mut Interpreter<'_>, data: &mut EVMData<'_, DB>) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.step_end(interpreter, data);
+        });
+    }
+
+    fn call(
+        &mut self,
+        data: &mut EVMData<'_, DB>,
+        inputs: &mut CallInputs,
+    ) -> (InstructionResult, Gas, Bytes) {
+        call_inspectors!(inspector, [&mut self.four_byte, &mut self.tracing], {
+            inspector.call(data, inputs);
+        });
+
+        (InstructionResult::Continue, Gas::new(inputs.gas_limit), Bytes::new())
+    }
+
+    fn call_end(
+        &mut self,
+        data: &mut EVMData<'_, DB>,
+        inputs: &CallInputs,
+        remaining_gas: Gas,
+        ret: InstructionResult,
+        out: Bytes,
+    ) -> (InstructionResult, Gas, Bytes) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.call_end(data, inputs, remaining_gas, ret, out.clone());
+        });
+
+        (ret, remaining_gas, out)
+    }
+
+    fn create(
+        &mut self,
+        data: &mut EVMData<'_, DB>,
+        inputs: &mut CreateInputs,
+    ) -> (InstructionResult, Option<Address>, Gas, Bytes) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.create(data, inputs);
+        });
+
+        (InstructionResult::Continue, None, Gas::new(inputs.gas_limit), Bytes::new())
+    }
+
+    fn create_end(
+        &mut self,
+        data: &mut EVMData<'_, DB>,
+        inputs: &CreateInputs,
+        ret: InstructionResult,
+        address: Option<Address>,
+        remaining_gas: Gas,
+        out: Bytes,
+    ) -> (InstructionResult, Option<Address>, Gas, Bytes) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            inspector.create_end(data, inputs, ret, address, remaining_gas, out.clone());
+        });
+
+        (ret, address, remaining_gas, out)
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        call_inspectors!(inspector, [&mut self.tracing], {
+            Inspector::<DB>::selfdestruct(inspector, contract, target, value);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_rpc_types::trace::geth::GethDebugTracerConfig;
+    use revm::{
+        db::{CacheDB, EmptyDB},
+        primitives::{AccountInfo, Bytecode, Env, TransactTo},
+        EVM,
+    };
+
+    const CONTRACT: Address = Address::with_last_byte(0xaa);
+
+    /// Executes a call to a contract that calls the identity precompile with the given tracers.
+    fn trace_precompile_call(tracers: serde_json::Value) -> MuxFrame {
+        // PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0x04 GAS STATICCALL POP STOP
+        let code = Bytecode::new_raw(Bytes::from_static(&[
+            0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x04, 0x5a, 0xfa, 0x50, 0x00,
+        ]));
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            CONTRACT,
+            AccountInfo { code_hash: code.hash_slow(), code: Some(code), ..Default::default() },
+        );
+
+        let mut env = Env::default();
+        env.tx.caller = Address::with_last_byte(1);
+        env.tx.transact_to = TransactTo::Call(CONTRACT);
+        env.tx.gas_limit = 1_000_000;
+
+        let config: MuxConfig = serde_json::from_value(tracers).unwrap();
+        let mut inspector = MuxInspector::try_from_config(config).unwrap();
+        let mut evm = EVM::with_env(env);
+        evm.database(db);
+        let res = evm.inspect(&mut inspector).unwrap();
+        let db = evm.db.take().unwrap();
+
+        inspector.try_into_mux_frame(&res, &db, TransactionInfo::default()).unwrap()
+    }
+
+    fn flat_call_traces(frame: &MuxFrame) -> usize {
+        match &frame.0[&GethDebugBuiltInTracerType::FlatCallTracer] {
+            GethTrace::FlatCallTracer(traces) => traces.len(),
+            trace => panic!("unexpected trace {trace:?}"),
+        }
+    }
+
+    fn call_tracer_calls(frame: &MuxFrame) -> usize {
+        match &frame.0[&GethDebugBuiltInTracerType::CallTracer] {
+            GethTrace::CallTracer(call) => call.calls.len(),
+            trace => panic!("unexpected trace {trace:?}"),
+        }
+    }
+
+    #[test]
+    fn test_mux_flat_call_excludes_precompiles() {
+        // the flat call tracer excludes precompiles on its own
+        let frame = trace_precompile_call(serde_json::json!({ "flatCallTracer": {} }));
+        assert_eq!(flat_call_traces(&frame), 1);
+
+        // the call tracer needs the calls to precompiles, but the flat call tracer still excludes
+        // them
+        let frame =
+            trace_precompile_call(serde_json::json!({ "callTracer": {}, "flatCallTracer": {} }));
+        assert_eq!(call_tracer_calls(&frame), 1);
+        assert_eq!(flat_call_traces(&frame), 1);
+
+        let frame = trace_precompile_call(serde_json::json!({
+            "callTracer": {},
+            "flatCallTracer": { "includePrecompiles": true },
+        }));
+        assert_eq!(call_tracer_calls(&frame), 1);
+        assert_eq!(flat_call_traces(&frame), 2);
+
+        let frame = trace_precompile_call(serde_json::json!({
+            "prestateTracer": {},
+            "flatCallTracer": {},
+        }));
+        assert_eq!(flat_call_traces(&frame), 1);
+        assert!(matches!(
+            frame.0[&GethDebugBuiltInTracerType::PreStateTracer],
+            GethTrace::PreStateTracer(_)
+        ));
+    }
+
+    #[test]
+    fn test_mux_tracing_config() {
+        let config = MuxConfig(HashMap::from([
+            (GethDebugBuiltInTracerType::FlatCallTracer, GethDebugTracerConfig::default()),
+            (GethDebugBuiltInTracerType::CallTracer, serde_json::json!({ "withLog": true }).into()),
+        ]));
+        let inspector = MuxInspector::try_from_config(config).unwrap();
+        assert!(inspector.four_byte.is_none());
+        let tracing_config = inspector.tracing.unwrap().config;
+        assert!(tracing_config.record_logs);
+        assert!(!tracing_config.record_steps);
+        assert!(!tracing_config.exclude_precompile_calls);
+
+        let config = MuxConfig(HashMap::from([
+            (GethDebugBuiltInTracerType::FourByteTracer, GethDebugTracerConfig::default()),
+            (GethDebugBuiltInTracerType::FlatCallTracer, GethDebugTracerConfig::default()),
+        ]));
+        let inspector = MuxInspector::try_from_config(config).unwrap();
+        assert!(inspector.four_byte.is_some());
+        assert_eq!(inspector.tracing.unwrap().config, TracingInspectorConfig::default_parity());
+
+        let config = MuxConfig(HashMap::from([(
+            GethDebugBuiltInTracerType::MuxTracer,
+            GethDebugTracerConfig::default(),
+        )]));
+        assert!(MuxInspector::try_from_config(config).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use crate::tracing::{config::TraceStyle, utils::convert_memory};
 use alloy_sol_types::decode_revert_reason;
 use reth_primitives::{Address, Bytes, B256, U256, U64};
 use reth_rpc_types::trace::{
-    geth::{CallFrame, CallLogFrame, GethDefaultTracingOptions, StructLog},
+    geth::{CallFrame, CallLogFrame, GethDefaultTracingOptions, StdJsonLog, StructLog},
     parity::{
         Action, ActionType, CallAction, CallOutput, CallType, CreateAction, CreateOutput,
         SelfdestructAction, TraceOutput, TransactionTrace,
@@ -574,6 +574,29 @@ impl CallTraceStep {
         log
     }
 
+    /// Converts this step into a [StdJsonLog] of a standard json trace
+    ///
+    /// This sets memory and stack capture based on the `opts` parameter.
+    pub(crate) fn convert_to_std_json_log(&self, opts: &GethDefaultTracingOptions) -> StdJsonLog {
+        StdJsonLog {
+            pc: self.pc as u64,
+            op: self.op.get(),
+            gas: self.gas_remaining,
+            gas_cost: self.gas_cost,
+            memory: opts
+                .is_memory_enabled()
+                .then(|| Bytes::copy_from_slice(self.memory.as_bytes())),
+            mem_size: self.memory_size as u64,
+            stack: opts.is_stack_enabled().then(|| self.stack.data().clone()),
+            // Filled via trace object
+            return_data: None,
+            depth: self.depth,
+            refund: self.gas_refund_counter,
+            op_name: self.op.to_string(),
+            error: self.as_error(),
+        }
+    }
+
     /// Returns true if the step is a STOP opcode
     #[inline]
     pub(crate) fn is_stop(&self) -> bool {
@@ -32,3 +32,7 @@ pub use revm::{self, *};
 
 /// Ethereum DAO hardfork state change data.
 pub mod eth_dao_fork;
+
+/// OP Stack specific execution changes, such as the L1 data fee.
+#[cfg(feature = "optimism")]
+pub mod optimism;
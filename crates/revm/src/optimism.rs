@@ -0,0 +1,510 @@
+use reth_interfaces::{
+    executor::{BlockExecutionError, BlockValidationError},
+    RethError, RethResult,
+};
+use reth_primitives::{
+    address, hex_literal::hex, Address, Block, Bytes, ChainSpec, Hardfork, TransactionSigned,
+    B256, U256,
+};
+use reth_provider::StateProvider;
+use revm::{
+    primitives::{
+        hash_map::Entry, Account, AccountInfo, AccountStatus, Bytecode, EVMError, Env,
+        ExecutionResult, ResultAndState, State,
+    },
+    Database, DatabaseCommit, EVM,
+};
+use tracing::debug;
+
+/// The address of the `L1Block` predeploy, which holds the L1 block attributes of the current L2
+/// block.
+pub const L1_BLOCK_CONTRACT: Address = address!("4200000000000000000000000000000000000015");
+
+/// The address of the `BaseFeeVault` predeploy, which receives the base fee of all non-deposit
+/// transactions.
+pub const BASE_FEE_RECIPIENT: Address = address!("4200000000000000000000000000000000000019");
+
+/// The address of the `L1FeeVault` predeploy, which receives the L1 data fee of all non-deposit
+/// transactions.
+pub const L1_FEE_RECIPIENT: Address = address!("420000000000000000000000000000000000001A");
+
+/// The address of the `create2deployer` contract, which is deployed by the Canyon hardfork.
+pub const CREATE_2_DEPLOYER_ADDR: Address = address!("13b0D85CcB8bf860b6b79AF3029fCA081AE9beF2");
+
+/// The runtime bytecode of the `create2deployer` contract.
+pub const CREATE_2_DEPLOYER_BYTECODE: [u8; 1584] = hex!("6080604052600436106100435760003560e01c8063076c37b21461004f578063481286e61461007157806356299481146100ba57806366cfa057146100da57600080fd5b3661004a57005b600080fd5b34801561005b57600080fd5b5061006f61006a366004610327565b6100fa565b005b34801561007d57600080fd5b5061009161008c366004610327565b61014a565b60405173ffffffffffffffffffffffffffffffffffffffff909116815260200160405180910390f35b3480156100c657600080fd5b506100916100d5366004610349565b61015d565b3480156100e657600080fd5b5061006f6100f53660046103ca565b610172565b61014582826040518060200161010f9061031a565b7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe082820381018352601f90910116604052610183565b505050565b600061015683836102e7565b9392505050565b600061016a8484846102f0565b949350505050565b61017d838383610183565b50505050565b6000834710156101f4576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601d60248201527f437265617465323a20696e73756666696369656e742062616c616e636500000060448201526064015b60405180910390fd5b815160000361025f576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820181905260248201527f437265617465323a2062797465636f6465206c656e677468206973207a65726f60448201526064016101eb565b8282516020840186f5905073ffffffffffffffffffffffffffffffffffffffff8116610156576040517f08c379a000000000000000000000000000000000000000000000000000000000815260206004820152601960248201527f437265617465323a204661696c6564206f6e206465706c6f790000000000000060448201526064016101eb565b60006101568383305b6000604051836040820152846020820152828152600b8101905060ff815360559020949350505050565b61014e806104ad83390190565b6000806040838503121561033a57600080fd5b50508035926020909101359150565b60008060006060848603121561035e57600080fd5b8335925060208401359150604084013573ffffffffffffffffffffffffffffffffffffffff8116811461039057600080fd5b809150509250925092565b7f4e487b7100000000000000000000000000000000000000000000000000000000600052604160045260246000fd5b6000806000606084860312156103df57600080fd5b8335925060208401359150604084013567ffffffffffffffff8082111561040557600080fd5b818601915086601f83011261041957600080fd5b81358181111561042b5761042b61039b565b604051601f82017fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe0908116603f011681019083821181831017156104715761047161039b565b8160405282815289602084870101111561048a57600080fd5b826020860160208301376000602084830101528095505050505050925092509256fe608060405234801561001057600080fd5b5061012e806100206000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063249cb3fa14602d575b600080fd5b603c603836600460b1565b604e565b60405190815260200160405180910390f35b60008281526020818152604080832073ffffffffffffffffffffffffffffffffffffffff8516845290915281205460ff16608857600060aa565b7fa2ef4600d742022d532d4747cb3547474667d6f13804902513b2ec01c848f4b45b9392505050565b6000806040838503121560c357600080fd5b82359150602083013573ffffffffffffffffffffffffffffffffffffffff8116811460e557600080fd5b80915050925092905056fea26469706673582212205ffd4e6cede7d06a5daf93d48d0541fc68189eeb16608c1999a82063b666eb1164736f6c63430008130033a2646970667358221220fdc4a0fe96e3b21c108ca155438d37c9143fb01278a3c1d274948bad89c564ba64736f6c63430008130033");
+
+/// The storage slot of the L1 base fee in the `L1Block` predeploy.
+const L1_BASE_FEE_SLOT: u8 = 1;
+
+/// The storage slot of the L1 fee overhead in the `L1Block` predeploy.
+const L1_FEE_OVERHEAD_SLOT: u8 = 5;
+
+/// The storage slot of the L1 fee scalar in the `L1Block` predeploy.
+const L1_FEE_SCALAR_SLOT: u8 = 6;
+
+/// The gas cost of a zero byte in the L1 calldata of a transaction.
+const ZERO_BYTE_COST: u64 = 4;
+
+/// The gas cost of a non-zero byte in the L1 calldata of a transaction.
+const NON_ZERO_BYTE_COST: u64 = 16;
+
+/// Before Regolith, the L1 data gas of a transaction included 68 additional non-zero bytes to
+/// account for the signature.
+const PRE_REGOLITH_EXTRA_NON_ZERO_BYTES: u64 = 68;
+
+/// The divisor of the L1 fee scalar, which is stored with 6 decimals.
+const L1_FEE_SCALAR_DIVISOR: u64 = 1_000_000;
+
+/// The L1 block attributes that are used to compute the L1 data fee of a transaction.
+///
+/// These are set by the L1 attributes deposit transaction, which is always the first transaction
+/// of an OP Stack block, see the
+/// [L1 attributes deposit specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/deposits.md#l1-attributes-deposited-transaction).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct L1BlockInfo {
+    /// The base fee of the L1 origin block.
+    pub l1_base_fee: U256,
+    /// The current L1 fee overhead.
+    pub l1_fee_overhead: U256,
+    /// The current L1 fee scalar.
+    pub l1_fee_scalar: U256,
+}
+
+impl L1BlockInfo {
+    /// Parses the L1 block info from the calldata of the L1 attributes deposit transaction.
+    ///
+    /// The calldata is a call to `setL1BlockValues`, after the 4 byte selector it contains the
+    /// following 32 byte words:
+    ///
+    /// - `number`
+    /// - `timestamp`
+    /// - `basefee`
+    /// - `hash`
+    /// - `sequenceNumber`
+    /// - `batcherHash`
+    /// - `l1FeeOverhead`
+    /// - `l1FeeScalar`
+    pub fn try_from_calldata(calldata: &[u8]) -> Result<Self, BlockExecutionError> {
+        // skip the function selector
+        let data = calldata.get(4..).filter(|data| data.len() >= 8 * 32).ok_or_else(|| {
+            BlockValidationError::L1BlockInfoError {
+                message: format!("invalid L1 attributes calldata length: {}", calldata.len()),
+            }
+        })?;
+
+        let word = |index: usize| U256::from_be_slice(&data[index * 32..(index + 1) * 32]);
+
+        Ok(Self { l1_base_fee: word(2), l1_fee_overhead: word(6), l1_fee_scalar: word(7) })
+    }
+
+    /// Reads the L1 block info from the storage of the `L1Block` predeploy in the given state.
+    ///
+    /// Unset slots are treated as zero.
+    pub fn try_from_state<S: StateProvider + ?Sized>(state: &S) -> RethResult<Self> {
+        let slot = |slot: u8| -> RethResult<U256> {
+            Ok(state.storage(L1_BLOCK_CONTRACT, B256::with_last_byte(slot))?.unwrap_or_default())
+        };
+
+        Ok(Self {
+            l1_base_fee: slot(L1_BASE_FEE_SLOT)?,
+            l1_fee_overhead: slot(L1_FEE_OVERHEAD_SLOT)?,
+            l1_fee_scalar: slot(L1_FEE_SCALAR_SLOT)?,
+        })
+    }
+
+    /// Returns the L1 data gas of the given EIP-2718 encoded transaction.
+    pub fn data_gas(&self, enveloped_tx: &[u8], is_regolith: bool) -> U256 {
+        let mut data_gas = enveloped_tx.iter().fold(0u64, |gas, byte| {
+            gas + if *byte == 0 { ZERO_BYTE_COST } else { NON_ZERO_BYTE_COST }
+        });
+
+        if !is_regolith {
+            data_gas += NON_ZERO_BYTE_COST * PRE_REGOLITH_EXTRA_NON_ZERO_BYTES;
+        }
+
+        U256::from(data_gas)
+    }
+
+    /// Calculates the L1 data fee of the given EIP-2718 encoded transaction.
+    ///
+    /// Deposit transactions are paid for on L1 and are never charged the L1 data fee.
+    pub fn calculate_tx_l1_cost(
+        &self,
+        enveloped_tx: &[u8],
+        is_deposit: bool,
+        is_regolith: bool,
+    ) -> U256 {
+        if is_deposit || enveloped_tx.is_empty() {
+            return U256::ZERO
+        }
+
+        (self.data_gas(enveloped_tx, is_regolith) + self.l1_fee_overhead) *
+            self.l1_base_fee *
+            self.l1_fee_scalar /
+            U256::from(L1_FEE_SCALAR_DIVISOR)
+    }
+}
+
+/// Extracts the [L1BlockInfo] from the L1 attributes deposit transaction, which is the first
+/// transaction of every OP Stack block.
+pub fn extract_l1_info(block: &Block) -> Result<L1BlockInfo, BlockExecutionError> {
+    let l1_info_tx = block.body.first().ok_or_else(|| BlockValidationError::L1BlockInfoError {
+        message: "could not find L1 attributes deposit transaction in the L2 block".to_string(),
+    })?;
+
+    L1BlockInfo::try_from_calldata(l1_info_tx.input())
+}
+
+/// Deploys the `create2deployer` contract if the block with the given timestamp is the first
+/// Canyon block.
+///
+/// Like op-geth, the code is set before the transactions of the block with a timestamp equal to
+/// the Canyon activation time are executed, see the
+/// [Canyon specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/superchain-upgrades.md#canyon).
+pub fn ensure_create2_deployer<DB>(
+    chain_spec: &ChainSpec,
+    timestamp: u64,
+    db: &mut DB,
+) -> Result<(), BlockExecutionError>
+where
+    DB: Database + DatabaseCommit,
+{
+    if chain_spec.is_optimism() &&
+        chain_spec.fork(Hardfork::Canyon).as_timestamp() == Some(timestamp)
+    {
+        let code = Bytecode::new_raw(Bytes::from_static(&CREATE_2_DEPLOYER_BYTECODE));
+        modify_account(db, CREATE_2_DEPLOYER_ADDR, |info| {
+            info.code_hash = code.hash_slow();
+            info.code = Some(code);
+        })?;
+    }
+    Ok(())
+}
+
+/// Executes a transaction of an OP Stack block on top of `db` and returns the result and state
+/// diff (without applying it), together with the gas used by the transaction.
+///
+/// `env` must be configured for the block and the transaction. `transact` runs the EVM that is set
+/// up for the transaction, so the caller decides whether the execution is inspected. On top of the
+/// L2 execution, this applies the OP Stack rules:
+///
+/// - Deposits mint their value to the sender before execution, are exempt from the base fee and
+///   report their gas usage depending on the active hardfork. A failing deposit is still
+///   included, the mint is kept and the nonce of the sender is incremented.
+/// - All other transactions are charged the L1 data fee of their EIP-2718 encoding. The L1 data
+///   fee and the base fee are credited to the fee vaults.
+///
+/// Only the mint of a deposit is committed to `db` up front, the L1 data fee is part of the
+/// returned state diff. So nothing is committed if a non-deposit transaction fails to execute.
+#[allow(clippy::too_many_arguments)]
+pub fn transact<DB, F>(
+    db: &mut DB,
+    mut env: Env,
+    transaction: &TransactionSigned,
+    sender: Address,
+    l1_block_info: &L1BlockInfo,
+    is_regolith: bool,
+    is_canyon: bool,
+    transact: F,
+) -> Result<(ResultAndState, u64), BlockExecutionError>
+where
+    DB: Database<Error = RethError> + DatabaseCommit,
+    F: FnOnce(&mut EVM<L1CostDatabase<'_, DB>>) -> Result<ResultAndState, EVMError<RethError>>,
+{
+    let hash = transaction.hash();
+
+    if transaction.is_deposit() {
+        apply_deposit_mint(db, transaction, sender, is_regolith)?;
+
+        // Deposits are paid for on L1 and have a gas price of zero.
+        env.cfg.disable_base_fee = true;
+        let out = {
+            let mut evm = EVM::with_env(env);
+            evm.database(L1CostDatabase { db: &mut *db, sender, l1_cost: U256::ZERO });
+            transact(&mut evm)
+        };
+
+        return match out {
+            Ok(ResultAndState { result, state }) => {
+                let gas_used = deposit_gas_used(transaction, &result, is_regolith, is_canyon);
+                Ok((ResultAndState { result, state }, gas_used))
+            }
+            Err(EVMError::Transaction(error)) => {
+                debug!(target: "evm", ?hash, ?error, "Deposit transaction failed");
+                failed_deposit_result(db, transaction, sender, is_regolith)
+            }
+            Err(error) => Err(BlockValidationError::EVM { hash, error: error.into() }.into()),
+        }
+    }
+
+    // The L1 data fee is charged before execution, so the transaction only sees the balance of the
+    // sender after the charge.
+    let l1_cost = l1_cost(db, transaction, sender, l1_block_info, is_regolith)?;
+    let base_fee = env.block.basefee;
+
+    let out = {
+        let mut evm = EVM::with_env(env);
+        evm.database(L1CostDatabase { db: &mut *db, sender, l1_cost });
+        transact(&mut evm)
+    };
+
+    let ResultAndState { result, mut state } =
+        out.map_err(|error| BlockValidationError::EVM { hash, error: error.into() })?;
+    let gas_used = result.gas_used();
+
+    credit_fee_vaults(db, &mut state, base_fee * U256::from(gas_used), l1_cost)?;
+
+    Ok((ResultAndState { result, state }, gas_used))
+}
+
+/// A [Database] that serves the balance of the sender of a transaction with its L1 data fee
+/// already deducted, see [transact].
+///
+/// The sender is always part of the state diff of an executed transaction, so the charge is
+/// committed together with the transaction.
+#[derive(Debug)]
+pub struct L1CostDatabase<'a, DB> {
+    db: &'a mut DB,
+    sender: Address,
+    l1_cost: U256,
+}
+
+impl<'a, DB: Database> Database for L1CostDatabase<'a, DB> {
+    type Error = DB::Error;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        let mut info = self.db.basic(address)?;
+        if address == self.sender {
+            if let Some(info) = &mut info {
+                info.balance = info.balance.saturating_sub(self.l1_cost);
+            }
+        }
+        Ok(info)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.db.code_by_hash(code_hash)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.db.storage(address, index)
+    }
+
+    fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+        self.db.block_hash(number)
+    }
+}
+
+/// Applies the mint of the given deposit transaction to the balance of its sender.
+///
+/// The mint is committed to the database before the deposit is executed, so it is persisted even
+/// if the deposit fails. Returns an error if the deposit is a system transaction and Regolith is
+/// active.
+pub fn apply_deposit_mint<DB>(
+    db: &mut DB,
+    transaction: &TransactionSigned,
+    sender: Address,
+    is_regolith: bool,
+) -> Result<(), BlockExecutionError>
+where
+    DB: Database + DatabaseCommit,
+{
+    if transaction.is_system_transaction() && is_regolith {
+        return Err(
+            BlockValidationError::SystemTransactionPostRegolith { hash: transaction.hash() }.into()
+        )
+    }
+
+    if let Some(mint) = transaction.mint() {
+        modify_account(db, sender, |info| {
+            info.balance = info.balance.saturating_add(U256::from(mint))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Returns the gas used by a successfully executed deposit transaction.
+///
+/// Before Regolith, deposits report their gas limit as the gas used, except successful system
+/// transactions which report no gas used. From Regolith on, deposits report the gas they actually
+/// used, but gas refunds are only applied to them from Canyon on.
+pub fn deposit_gas_used(
+    transaction: &TransactionSigned,
+    result: &ExecutionResult,
+    is_regolith: bool,
+    is_canyon: bool,
+) -> u64 {
+    if is_canyon {
+        result.gas_used()
+    } else if is_regolith {
+        match result {
+            ExecutionResult::Success { gas_used, gas_refunded, .. } => gas_used + gas_refunded,
+            _ => result.gas_used(),
+        }
+    } else if result.is_success() && transaction.is_system_transaction() {
+        0
+    } else {
+        transaction.gas_limit()
+    }
+}
+
+/// Returns the result of a deposit transaction that failed to execute, together with its gas
+/// used.
+///
+/// A failed deposit is still included in the block as a reverted transaction that consumes its
+/// whole gas limit and increments the nonce of the sender.
+pub fn failed_deposit_result<DB: Database>(
+    db: &mut DB,
+    transaction: &TransactionSigned,
+    sender: Address,
+    is_regolith: bool,
+) -> Result<(ResultAndState, u64), BlockExecutionError> {
+    let mut info = load_account(db, sender)?;
+    info.nonce += 1;
+    let state =
+        [(sender, Account { info, storage: Default::default(), status: AccountStatus::Touched })]
+            .into_iter()
+            .collect();
+    let gas_used = if is_regolith || !transaction.is_system_transaction() {
+        transaction.gas_limit()
+    } else {
+        0
+    };
+    let result = ExecutionResult::Revert { gas_used, output: Default::default() };
+    Ok((ResultAndState { result, state }, gas_used))
+}
+
+/// Returns the L1 data fee of the given non-deposit transaction.
+///
+/// Returns an error if the sender can't afford the fee.
+pub fn l1_cost<DB: Database>(
+    db: &mut DB,
+    transaction: &TransactionSigned,
+    sender: Address,
+    l1_block_info: &L1BlockInfo,
+    is_regolith: bool,
+) -> Result<U256, BlockExecutionError> {
+    let l1_cost =
+        l1_block_info.calculate_tx_l1_cost(&transaction.envelope_encoded(), false, is_regolith);
+    let balance = load_account(db, sender)?.balance;
+    if balance < l1_cost {
+        return Err(BlockValidationError::InsufficientFundsForL1Cost {
+            hash: transaction.hash(),
+            l1_cost,
+            balance,
+        }
+        .into())
+    }
+    Ok(l1_cost)
+}
+
+/// Credits the base fee and the L1 data fee of an executed non-deposit transaction to the fee
+/// vaults.
+///
+/// The fees are merged into the state diff of the transaction, in case it touched one of the
+/// vaults.
+pub fn credit_fee_vaults<DB: Database>(
+    db: &mut DB,
+    state: &mut State,
+    base_fee: U256,
+    l1_cost: U256,
+) -> Result<(), BlockExecutionError> {
+    for (vault, amount) in [(BASE_FEE_RECIPIENT, base_fee), (L1_FEE_RECIPIENT, l1_cost)] {
+        let account = match state.entry(vault) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let info = load_account(db, vault)?;
+                entry.insert(Account {
+                    info,
+                    storage: Default::default(),
+                    status: AccountStatus::Touched,
+                })
+            }
+        };
+        account.info.balance = account.info.balance.saturating_add(amount);
+        account.status |= AccountStatus::Touched;
+    }
+    Ok(())
+}
+
+/// Loads the given account from the database, applies `f` to its info and commits the change.
+///
+/// This is used for the OP Stack state changes that happen outside of the EVM, e.g. minting the
+/// value of a deposit or deploying the `create2deployer` contract.
+pub fn modify_account<DB>(
+    db: &mut DB,
+    address: Address,
+    f: impl FnOnce(&mut AccountInfo),
+) -> Result<(), BlockExecutionError>
+where
+    DB: Database + DatabaseCommit,
+{
+    let mut info = load_account(db, address)?;
+    f(&mut info);
+    db.commit(
+        [(address, Account { info, storage: Default::default(), status: AccountStatus::Touched })]
+            .into_iter()
+            .collect(),
+    );
+    Ok(())
+}
+
+/// Loads the info of the given account from the database, defaulting to an empty account.
+pub fn load_account<DB: Database>(
+    db: &mut DB,
+    address: Address,
+) -> Result<AccountInfo, BlockExecutionError> {
+    Ok(db.basic(address).map_err(|_| BlockExecutionError::ProviderError)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{Header, TransactionSigned};
+
+    #[test]
+    fn sanity_l1_block() {
+        let bytes = hex!("7ef9015aa044bae9d41b8380d781187b426c6fe43df5fb2fb57bd4466ef6a701e1f01e015694deaddeaddeaddeaddeaddeaddeaddeaddead000194420000000000000000000000000000000000001580808408f0d18001b90104015d8eb900000000000000000000000000000000000000000000000000000000008057650000000000000000000000000000000000000000000000000000000063d96d10000000000000000000000000000000000000000000000000000000000009f35273d89754a1e0387b89520d989d3be9c37c1f32495a88faf1ea05c61121ab0d1900000000000000000000000000000000000000000000000000000000000000010000000000000000000000002d679b567db6187c0c8323fa982cfb19454ad9a300000000000000000000000000000000000000000000000000000000000000bc00000000000000000000000000000000000000000000000000000000000a6fe0");
+        let l1_info_tx = TransactionSigned::decode_enveloped(bytes[..].to_vec().into()).unwrap();
+        let block = Block {
+            header: Header::default(),
+            body: vec![l1_info_tx],
+            ommers: Vec::new(),
+            withdrawals: None,
+        };
+
+        let l1_info = extract_l1_info(&block).unwrap();
+        assert_eq!(l1_info.l1_base_fee, U256::from(652_114));
+        assert_eq!(l1_info.l1_fee_overhead, U256::from(188));
+        assert_eq!(l1_info.l1_fee_scalar, U256::from(684_000));
+    }
+
+    #[test]
+    fn l1_info_missing() {
+        assert!(extract_l1_info(&Block::default()).is_err());
+        assert!(L1BlockInfo::try_from_calldata(&[0u8; 100]).is_err());
+    }
+
+    #[test]
+    fn calculate_l1_cost() {
+        let l1_info = L1BlockInfo {
+            l1_base_fee: U256::from(1_000),
+            l1_fee_overhead: U256::from(188),
+            l1_fee_scalar: U256::from(684_000),
+        };
+        let tx = [0x00, 0x01, 0x02, 0x00];
+
+        // 2 zero bytes and 2 non-zero bytes
+        assert_eq!(l1_info.data_gas(&tx, true), U256::from(40));
+        assert_eq!(l1_info.data_gas(&tx, false), U256::from(40 + 68 * 16));
+
+        // (40 + 188) * 1000 * 684000 / 1e6
+        assert_eq!(l1_info.calculate_tx_l1_cost(&tx, false, true), U256::from(155_952));
+        // (1128 + 188) * 1000 * 684000 / 1e6
+        assert_eq!(l1_info.calculate_tx_l1_cost(&tx, false, false), U256::from(900_144));
+
+        assert_eq!(l1_info.calculate_tx_l1_cost(&tx, true, true), U256::ZERO);
+    }
+}
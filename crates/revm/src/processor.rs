@@ -23,12 +23,15 @@ use reth_provider::{
 };
 use revm::{
     db::{states::bundle_state::BundleRetention, StateDBBox},
-    primitives::ResultAndState,
+    primitives::{ExecutionResult, ResultAndState},
     DatabaseCommit, State, EVM,
 };
 use std::{sync::Arc, time::Instant};
 use tracing::{debug, trace};
 
+#[cfg(feature = "optimism")]
+use crate::optimism;
+
 /// EVMProcessor is a block executor that uses revm to execute blocks or multiple blocks.
 ///
 /// Output is obtained by calling `take_output_state` function.
@@ -135,6 +138,11 @@ impl<'a> EVMProcessor<'a> {
         self.stack = stack;
     }
 
+    /// Returns a mutable reference to the inspectors of the executor.
+    pub fn stack_mut(&mut self) -> &mut InspectorStack {
+        &mut self.stack
+    }
+
     /// Configure the executor with the given block.
     pub fn set_first_block(&mut self, num: BlockNumber) {
         self.first_block = Some(num);
@@ -271,6 +279,44 @@ impl<'a> EVMProcessor<'a> {
         out.map_err(|e| BlockValidationError::EVM { hash, error: e.into() }.into())
     }
 
+    /// Runs a single transaction of an OP Stack block and proceeds to return the result and state
+    /// diff (without applying it), together with the gas used by the transaction.
+    ///
+    /// Unlike [Self::transact], this applies the OP Stack rules on top of the L2 execution, see
+    /// [optimism::transact].
+    #[cfg(feature = "optimism")]
+    pub fn transact_optimism(
+        &mut self,
+        transaction: &TransactionSigned,
+        sender: Address,
+        l1_block_info: &optimism::L1BlockInfo,
+        is_regolith: bool,
+        is_canyon: bool,
+    ) -> Result<(ResultAndState, u64), BlockExecutionError> {
+        let mut env = self.evm.env.clone();
+        fill_tx_env(&mut env.tx, transaction, sender);
+        let should_inspect = self.stack.should_inspect(&env, transaction.hash());
+
+        let stack = &mut self.stack;
+        let db = self.evm.db.as_mut().expect("Database inside EVM is always set");
+        optimism::transact(
+            db,
+            env,
+            transaction,
+            sender,
+            l1_block_info,
+            is_regolith,
+            is_canyon,
+            |evm| {
+                if should_inspect {
+                    evm.inspect(stack)
+                } else {
+                    evm.transact()
+                }
+            },
+        )
+    }
+
     /// Runs the provided transactions and commits their state to the run-time database.
     ///
     /// The returned [BundleStateWithReceipts] can be used to persist the changes to disk, and
@@ -287,7 +333,51 @@ impl<'a> EVMProcessor<'a> {
         total_difficulty: U256,
         senders: Option<Vec<Address>>,
     ) -> Result<(Vec<Receipt>, u64), BlockExecutionError> {
+        self.execute_transactions_with(block, total_difficulty, senders, |_, _, _| {})
+    }
+
+    /// Executes the block on top of the current state like [BlockExecutor::execute] would, but
+    /// stops after the transactions: the EIP-4788 beacon root contract call is applied, while the
+    /// post-block state changes are not.
+    ///
+    /// `on_transaction` is called with the processor after each transaction has been executed and
+    /// committed, so the inspectors or the state changes can be drained per transaction.
+    pub fn inspect_transactions<F>(
+        &mut self,
+        block: &Block,
+        total_difficulty: U256,
+        senders: Option<Vec<Address>>,
+        on_transaction: F,
+    ) -> Result<(), BlockExecutionError>
+    where
+        F: FnMut(&mut Self, &TransactionSigned, &ExecutionResult),
+    {
         self.init_env(&block.header, total_difficulty);
+        self.apply_beacon_root_contract_call(block)?;
+        self.execute_transactions_with(block, total_difficulty, senders, on_transaction)?;
+        Ok(())
+    }
+
+    /// Runs the provided transactions like [Self::execute_transactions] and calls `on_transaction`
+    /// with the processor and the result of each transaction after it has been committed.
+    fn execute_transactions_with<F>(
+        &mut self,
+        block: &Block,
+        total_difficulty: U256,
+        senders: Option<Vec<Address>>,
+        mut on_transaction: F,
+    ) -> Result<(Vec<Receipt>, u64), BlockExecutionError>
+    where
+        F: FnMut(&mut Self, &TransactionSigned, &ExecutionResult),
+    {
+        self.init_env(&block.header, total_difficulty);
+
+        // The first Canyon block deploys the create2deployer contract before its transactions.
+        #[cfg(feature = "optimism")]
+        {
+            let chain_spec = self.chain_spec.clone();
+            optimism::ensure_create2_deployer(&chain_spec, block.timestamp, self.db_mut())?;
+        }
 
         // perf: do not execute empty blocks
         if block.body.is_empty() {
@@ -296,6 +386,20 @@ impl<'a> EVMProcessor<'a> {
 
         let senders = self.recover_senders(&block.body, senders)?;
 
+        // The L1 block info is only available on OP Stack chains.
+        #[cfg(feature = "optimism")]
+        let l1_block_info = if self.chain_spec.is_optimism() {
+            Some(optimism::extract_l1_info(block)?)
+        } else {
+            None
+        };
+        #[cfg(feature = "optimism")]
+        let is_regolith =
+            self.chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, block.timestamp);
+        #[cfg(feature = "optimism")]
+        let is_canyon =
+            self.chain_spec.is_fork_active_at_timestamp(Hardfork::Canyon, block.timestamp);
+
         let mut cumulative_gas_used = 0;
         let mut receipts = Vec::with_capacity(block.body.len());
         for (transaction, sender) in block.body.iter().zip(senders) {
@@ -311,7 +415,34 @@ impl<'a> EVMProcessor<'a> {
                 .into())
             }
             // Execute transaction.
-            let ResultAndState { result, state } = self.transact(transaction, sender)?;
+            #[cfg(not(feature = "optimism"))]
+            let (ResultAndState { result, state }, gas_used) = {
+                let out = self.transact(transaction, sender)?;
+                let gas_used = out.result.gas_used();
+                (out, gas_used)
+            };
+            // The deposit nonce is the nonce of the sender before the deposit is executed.
+            #[cfg(feature = "optimism")]
+            let deposit_nonce = if is_regolith && transaction.is_deposit() {
+                Some(optimism::load_account(self.db_mut(), sender)?.nonce)
+            } else {
+                None
+            };
+            #[cfg(feature = "optimism")]
+            let (ResultAndState { result, state }, gas_used) = match &l1_block_info {
+                Some(l1_block_info) => self.transact_optimism(
+                    transaction,
+                    sender,
+                    l1_block_info,
+                    is_regolith,
+                    is_canyon,
+                )?,
+                None => {
+                    let out = self.transact(transaction, sender)?;
+                    let gas_used = out.result.gas_used();
+                    (out, gas_used)
+                }
+            };
             trace!(
                 target: "evm",
                 ?transaction, ?result, ?state,
@@ -324,8 +455,10 @@ impl<'a> EVMProcessor<'a> {
 
             self.stats.apply_state_duration += time.elapsed();
 
+            on_transaction(self, transaction, &result);
+
             // append gas used
-            cumulative_gas_used += result.gas_used();
+            cumulative_gas_used += gas_used;
 
             // Push transaction changeset and calculate header bloom filter for receipt.
             receipts.push(Receipt {
@@ -336,6 +469,11 @@ impl<'a> EVMProcessor<'a> {
                 cumulative_gas_used,
                 // convert to reth log
                 logs: result.into_logs().into_iter().map(into_reth_log).collect(),
+                #[cfg(feature = "optimism")]
+                deposit_nonce,
+                // The deposit receipt version was introduced by Canyon.
+                #[cfg(feature = "optimism")]
+                deposit_receipt_version: (is_canyon && transaction.is_deposit()).then_some(1),
             });
         }
 
@@ -483,9 +621,21 @@ impl<'a> BlockExecutor for EVMProcessor<'a> {
         // See more about EIP here: https://eips.ethereum.org/EIPS/eip-658
         if self.chain_spec.fork(Hardfork::Byzantium).active_at_block(block.header.number) {
             let time = Instant::now();
-            if let Err(error) =
-                verify_receipt(block.header.receipts_root, block.header.logs_bloom, receipts.iter())
-            {
+            #[cfg(not(feature = "optimism"))]
+            let res = verify_receipt(
+                block.header.receipts_root,
+                block.header.logs_bloom,
+                receipts.iter(),
+            );
+            #[cfg(feature = "optimism")]
+            let res = verify_receipt_optimism(
+                block.header.receipts_root,
+                block.header.logs_bloom,
+                receipts.iter(),
+                &self.chain_spec,
+                block.timestamp,
+            );
+            if let Err(error) = res {
                 debug!(target: "evm", ?error, ?receipts, "receipts verification failed");
                 return Err(error)
             };
@@ -532,6 +682,50 @@ pub fn verify_receipt<'a>(
     // Check receipts root.
     let receipts_with_bloom = receipts.map(|r| r.clone().into()).collect::<Vec<ReceiptWithBloom>>();
     let receipts_root = reth_primitives::proofs::calculate_receipt_root(&receipts_with_bloom);
+    compare_receipts_root_and_logs_bloom(
+        receipts_root,
+        &receipts_with_bloom,
+        expected_receipts_root,
+        expected_logs_bloom,
+    )
+}
+
+/// Verify receipts of a block on an OP Stack chain.
+///
+/// The receipt root is calculated with
+/// [calculate_receipt_root_optimism](reth_primitives::proofs::calculate_receipt_root_optimism),
+/// which accounts for the deposit nonce of the receipts depending on the active hardfork.
+#[cfg(feature = "optimism")]
+pub fn verify_receipt_optimism<'a>(
+    expected_receipts_root: B256,
+    expected_logs_bloom: Bloom,
+    receipts: impl Iterator<Item = &'a Receipt> + Clone,
+    chain_spec: &ChainSpec,
+    timestamp: u64,
+) -> Result<(), BlockExecutionError> {
+    // Check receipts root.
+    let receipts_with_bloom = receipts.map(|r| r.clone().into()).collect::<Vec<ReceiptWithBloom>>();
+    let receipts_root = reth_primitives::proofs::calculate_receipt_root_optimism(
+        &receipts_with_bloom,
+        chain_spec,
+        timestamp,
+    );
+    compare_receipts_root_and_logs_bloom(
+        receipts_root,
+        &receipts_with_bloom,
+        expected_receipts_root,
+        expected_logs_bloom,
+    )
+}
+
+/// Compares the calculated receipts root and the logs bloom of the receipts with the expected
+/// values of the header.
+fn compare_receipts_root_and_logs_bloom(
+    receipts_root: B256,
+    receipts_with_bloom: &[ReceiptWithBloom],
+    expected_receipts_root: B256,
+    expected_logs_bloom: Bloom,
+) -> Result<(), BlockExecutionError> {
     if receipts_root != expected_receipts_root {
         return Err(BlockValidationError::ReceiptRootDiff {
             got: Box::new(receipts_root),
@@ -625,6 +819,10 @@ mod tests {
         fn state_root(&self, _bundle_state: &BundleStateWithReceipts) -> RethResult<B256> {
             unimplemented!("state root computation is not supported")
         }
+
+        fn storage_root(&self, _address: Address) -> RethResult<B256> {
+            unimplemented!("storage root computation is not supported")
+        }
     }
 
     impl StateProvider for StateProviderTest {
@@ -969,4 +1167,292 @@ mod tests {
             .unwrap();
         assert_eq!(parent_beacon_block_root_storage, U256::from(0x69));
     }
+
+    /// Returns the L1 attributes deposit with the given L1 fee parameters.
+    #[cfg(feature = "optimism")]
+    fn l1_attributes_deposit(
+        l1_base_fee: u64,
+        l1_fee_overhead: u64,
+        l1_fee_scalar: u64,
+    ) -> TransactionSigned {
+        use reth_primitives::{Signature, Transaction, TransactionKind, TxDeposit};
+
+        let mut input = vec![0u8; 4 + 8 * 32];
+        for (index, value) in [(2, l1_base_fee), (6, l1_fee_overhead), (7, l1_fee_scalar)] {
+            let offset = 4 + index * 32;
+            input[offset..offset + 32].copy_from_slice(&U256::from(value).to_be_bytes::<32>());
+        }
+
+        TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(TxDeposit {
+                source_hash: B256::random(),
+                from: Address::random(),
+                to: TransactionKind::Call(optimism::L1_BLOCK_CONTRACT),
+                mint: None,
+                value: U256::ZERO.into(),
+                gas_limit: 1_000_000,
+                is_system_transaction: false,
+                input: input.into(),
+            }),
+            Signature::default(),
+        )
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn optimism_deposit_transaction() {
+        use reth_primitives::{Signature, Transaction, TransactionKind, TxDeposit};
+
+        let depositor = Address::random();
+        let recipient = Address::random();
+        let initial_balance = U256::from(1_000_000_000_000_000_000u128);
+        let mint = 500_000_000_000_000_000u128;
+
+        let mut db = StateProviderTest::default();
+        db.insert_account(
+            depositor,
+            Account { balance: initial_balance, nonce: 5, bytecode_hash: None },
+            None,
+            HashMap::new(),
+        );
+
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().canyon_activated().build());
+
+        let l1_info_tx = l1_attributes_deposit(0, 0, 0);
+        let deposit = TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(TxDeposit {
+                source_hash: B256::random(),
+                from: depositor,
+                to: TransactionKind::Call(recipient),
+                mint: Some(mint),
+                value: U256::ZERO.into(),
+                gas_limit: 100_000,
+                is_system_transaction: false,
+                input: Default::default(),
+            }),
+            Signature::default(),
+        );
+        let l1_info_sender = l1_info_tx.recover_signer().unwrap();
+
+        let block = Block {
+            header: Header {
+                number: 1,
+                timestamp: 1,
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                ..Default::default()
+            },
+            body: vec![l1_info_tx, deposit],
+            ommers: vec![],
+            withdrawals: Some(vec![]),
+        };
+
+        let mut executor = EVMProcessor::new_with_db(chain_spec, StateProviderDatabase::new(db));
+        let (receipts, gas_used) = executor
+            .execute_transactions(&block, U256::ZERO, Some(vec![l1_info_sender, depositor]))
+            .unwrap();
+
+        // after Regolith, deposits report the gas they actually used and the nonce of the sender
+        // before the deposit
+        assert_eq!(receipts.len(), 2);
+        assert!(receipts[1].success);
+        assert_eq!(receipts[1].cumulative_gas_used - receipts[0].cumulative_gas_used, 21_000);
+        assert_eq!(receipts[1].deposit_nonce, Some(5));
+        assert_eq!(receipts[1].deposit_receipt_version, Some(1));
+        assert_eq!(gas_used, receipts[1].cumulative_gas_used);
+
+        // the mint is credited and deposits don't pay for gas
+        let account = executor.db_mut().basic(depositor).unwrap().unwrap();
+        assert_eq!(account.balance, initial_balance + U256::from(mint));
+        assert_eq!(account.nonce, 6);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn optimism_deposit_gas_refund() {
+        use reth_primitives::{Signature, Transaction, TransactionKind, TxDeposit};
+
+        // clears storage slot 0, which refunds 4800 gas
+        let contract = Address::random();
+        let mut db = StateProviderTest::default();
+        db.insert_account(
+            contract,
+            Account::default(),
+            Some(bytes!("6000600055")),
+            HashMap::from([(B256::ZERO, U256::from(1))]),
+        );
+
+        let deposit_gas_used = |chain_spec: ChainSpec| {
+            let l1_info_tx = l1_attributes_deposit(0, 0, 0);
+            let l1_info_sender = l1_info_tx.recover_signer().unwrap();
+            let deposit = TransactionSigned::from_transaction_and_signature(
+                Transaction::Deposit(TxDeposit {
+                    source_hash: B256::random(),
+                    from: Address::random(),
+                    to: TransactionKind::Call(contract),
+                    mint: None,
+                    value: U256::ZERO.into(),
+                    gas_limit: 100_000,
+                    is_system_transaction: false,
+                    input: Default::default(),
+                }),
+                Signature::default(),
+            );
+            let depositor = deposit.recover_signer().unwrap();
+            let block = Block {
+                header: Header {
+                    number: 1,
+                    timestamp: 1,
+                    gas_limit: 30_000_000,
+                    base_fee_per_gas: Some(1_000_000_000),
+                    ..Default::default()
+                },
+                body: vec![l1_info_tx, deposit],
+                ommers: vec![],
+                withdrawals: Some(vec![]),
+            };
+
+            let mut executor = EVMProcessor::new_with_db(
+                Arc::new(chain_spec),
+                StateProviderDatabase::new(db.clone()),
+            );
+            let (receipts, _) = executor
+                .execute_transactions(&block, U256::ZERO, Some(vec![l1_info_sender, depositor]))
+                .unwrap();
+            assert!(receipts[1].success);
+            receipts[1].cumulative_gas_used - receipts[0].cumulative_gas_used
+        };
+
+        // after Regolith deposits report the gas used before refunds, refunds are only applied
+        // from Canyon on
+        let regolith = deposit_gas_used(ChainSpecBuilder::mainnet().regolith_activated().build());
+        let canyon = deposit_gas_used(ChainSpecBuilder::mainnet().canyon_activated().build());
+        assert_eq!(regolith, 21_000 + 3 + 3 + 2_100 + 2_900);
+        assert_eq!(canyon, regolith - 4_800);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn optimism_regular_transaction() {
+        use reth_primitives::{Signature, Transaction, TransactionKind, TxLegacy};
+
+        let sender = Address::random();
+        let recipient = Address::random();
+        let initial_balance = U256::from(1_000_000_000_000_000_000u128);
+        let base_fee = 1_000_000_000u64;
+        let value = 1_000u128;
+
+        let mut db = StateProviderTest::default();
+        db.insert_account(
+            sender,
+            Account { balance: initial_balance, nonce: 0, bytecode_hash: None },
+            None,
+            HashMap::new(),
+        );
+
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().canyon_activated().build());
+
+        let l1_info_tx = l1_attributes_deposit(1_000, 188, 684_000);
+        let transaction = TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy {
+                chain_id: Some(chain_spec.chain.id()),
+                nonce: 0,
+                gas_price: base_fee as u128,
+                gas_limit: 21_000,
+                to: TransactionKind::Call(recipient),
+                value: value.into(),
+                input: Default::default(),
+            }),
+            Signature::default(),
+        );
+        let l1_cost = optimism::extract_l1_info(&Block {
+            body: vec![l1_info_tx.clone()],
+            ..Default::default()
+        })
+        .unwrap()
+        .calculate_tx_l1_cost(&transaction.envelope_encoded(), false, true);
+        assert!(l1_cost > U256::ZERO);
+        let l1_info_sender = l1_info_tx.recover_signer().unwrap();
+
+        let block = Block {
+            header: Header {
+                number: 1,
+                timestamp: 1,
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(base_fee),
+                ..Default::default()
+            },
+            body: vec![l1_info_tx, transaction],
+            ommers: vec![],
+            withdrawals: Some(vec![]),
+        };
+
+        let mut executor = EVMProcessor::new_with_db(chain_spec, StateProviderDatabase::new(db));
+        let (receipts, _) = executor
+            .execute_transactions(&block, U256::ZERO, Some(vec![l1_info_sender, sender]))
+            .unwrap();
+        assert!(receipts[1].success);
+        assert_eq!(receipts[1].deposit_nonce, None);
+        assert_eq!(receipts[1].deposit_receipt_version, None);
+
+        // the sender pays the value, the gas and the L1 data fee
+        let base_fee_cost = U256::from(base_fee) * U256::from(21_000);
+        let account = executor.db_mut().basic(sender).unwrap().unwrap();
+        assert_eq!(account.balance, initial_balance - U256::from(value) - base_fee_cost - l1_cost);
+        assert_eq!(account.nonce, 1);
+
+        // the fees are credited to the fee vaults
+        let base_fee_vault = executor.db_mut().basic(optimism::BASE_FEE_RECIPIENT).unwrap();
+        assert_eq!(base_fee_vault.unwrap().balance, base_fee_cost);
+        let l1_fee_vault = executor.db_mut().basic(optimism::L1_FEE_RECIPIENT).unwrap();
+        assert_eq!(l1_fee_vault.unwrap().balance, l1_cost);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[test]
+    fn optimism_canyon_create2_deployer() {
+        let canyon = 10;
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::mainnet()
+                .regolith_activated()
+                .with_fork(Hardfork::Canyon, ForkCondition::Timestamp(canyon))
+                .build(),
+        );
+
+        let execute_block = |timestamp| {
+            let l1_info_tx = l1_attributes_deposit(0, 0, 0);
+            let l1_info_sender = l1_info_tx.recover_signer().unwrap();
+            let block = Block {
+                header: Header {
+                    number: 1,
+                    timestamp,
+                    gas_limit: 30_000_000,
+                    base_fee_per_gas: Some(1_000_000_000),
+                    ..Default::default()
+                },
+                body: vec![l1_info_tx],
+                ommers: vec![],
+                withdrawals: None,
+            };
+
+            let mut executor = EVMProcessor::new_with_db(
+                chain_spec.clone(),
+                StateProviderDatabase::new(StateProviderTest::default()),
+            );
+            executor.execute_transactions(&block, U256::ZERO, Some(vec![l1_info_sender])).unwrap();
+            executor.db_mut().basic(optimism::CREATE_2_DEPLOYER_ADDR).unwrap()
+        };
+
+        // the contract is deployed at the first Canyon block
+        let account = execute_block(canyon).unwrap();
+        assert_eq!(account.code_hash, keccak256(optimism::CREATE_2_DEPLOYER_BYTECODE));
+        assert_eq!(
+            account.code.unwrap().original_bytes(),
+            Bytes::from_static(&optimism::CREATE_2_DEPLOYER_BYTECODE)
+        );
+
+        // but not before or after it
+        assert_eq!(execute_block(canyon - 2), None);
+        assert_eq!(execute_block(canyon + 2), None);
+    }
 }
@@ -24,6 +24,7 @@ mod eth_filter;
 mod eth_pubsub;
 mod mev;
 mod net;
+mod optimism;
 mod otterscan;
 mod reth;
 mod rpc;
@@ -46,6 +47,7 @@ pub mod servers {
         eth_pubsub::EthPubSubApiServer,
         mev::MevApiServer,
         net::NetApiServer,
+        optimism::OptimismApiServer,
         otterscan::OtterscanServer,
         reth::RethApiServer,
         rpc::RpcApiServer,
@@ -71,6 +73,7 @@ pub mod clients {
         eth_filter::EthFilterApiClient,
         mev::MevApiClient,
         net::NetApiClient,
+        optimism::OptimismApiClient,
         otterscan::OtterscanClient,
         rpc::RpcApiServer,
         trace::TraceApiClient,
@@ -0,0 +1,21 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_primitives::BlockNumberOrTag;
+use reth_rpc_types::{OutputResponse, RollupConfig, RollupSyncStatus};
+
+/// Optimism rollup namespace, which serves a subset of the `optimism_` methods of the OP Stack
+/// rollup node.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "optimism"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "optimism"))]
+pub trait OptimismApi {
+    /// Returns the L2 output root of the given block.
+    #[method(name = "outputAtBlock")]
+    async fn output_at_block(&self, block_number: BlockNumberOrTag) -> RpcResult<OutputResponse>;
+
+    /// Returns the L2 sync status of the node.
+    #[method(name = "syncStatus")]
+    async fn sync_status(&self) -> RpcResult<RollupSyncStatus>;
+
+    /// Returns the rollup config of the chain.
+    #[method(name = "rollupConfig")]
+    async fn rollup_config(&self) -> RpcResult<RollupConfig>;
+}
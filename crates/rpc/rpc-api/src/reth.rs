@@ -1,5 +1,6 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_primitives::{Address, BlockId, U256};
+use reth_rpc_types::trace::parity::StateDiff;
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -12,4 +13,14 @@ pub trait RethApi {
         &self,
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Returns the changes of all accounts modified in the blocks from `from_block` up to and
+    /// including `to_block`, as the difference between the state before `from_block` and the
+    /// state after `to_block`. With one parameter, returns the changes of the specified block.
+    #[method(name = "getStateDiff")]
+    async fn reth_get_state_diff(
+        &self,
+        from_block: BlockId,
+        to_block: Option<BlockId>,
+    ) -> RpcResult<StateDiff>;
 }
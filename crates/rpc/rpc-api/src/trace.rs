@@ -72,6 +72,20 @@ pub trait TraceApi {
     #[method(name = "filter")]
     async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>>;
 
+    /// Streams the traces matching the given filter, in order.
+    ///
+    /// Unlike `trace_filter`, the block range isn't limited, so this can be used to backfill large
+    /// ranges. No more items are sent once the whole range has been traced.
+    #[subscription(
+        name = "subscribeFilter" => "filterSubscription",
+        unsubscribe = "unsubscribeFilter",
+        item = LocalizedTransactionTrace
+    )]
+    async fn trace_subscribe_filter(
+        &self,
+        filter: TraceFilter,
+    ) -> jsonrpsee::core::SubscriptionResult;
+
     /// Returns transaction trace at given index.
     ///
     /// `indices` represent the index positions of the traces.
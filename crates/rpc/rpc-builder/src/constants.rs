@@ -16,6 +16,12 @@ pub const DEFAULT_MAX_LOGS_PER_RESPONSE: usize = 20_000;
 /// The default maximum number of concurrently executed tracing calls
 pub const DEFAULT_MAX_TRACING_REQUESTS: u32 = 25;
 
+/// The default maximum block range allowed to read changesets of in a single request
+pub const DEFAULT_MAX_CHANGESET_BLOCKS: u64 = 1_000;
+
+/// The default maximum block range allowed to trace in a single `trace_filter` request
+pub const DEFAULT_MAX_TRACE_FILTER_BLOCKS: u64 = 100;
+
 /// The default IPC endpoint
 #[cfg(windows)]
 pub const DEFAULT_IPC_ENDPOINT: &str = r"\\.\pipe\reth.ipc";
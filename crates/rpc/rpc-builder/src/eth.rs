@@ -5,12 +5,15 @@ use reth_rpc::{
     eth::{
         cache::{EthStateCache, EthStateCacheConfig},
         gas_oracle::GasPriceOracleConfig,
-        EthFilterConfig, RPC_DEFAULT_GAS_CAP,
+        EthFilterConfig, DEFAULT_ETH_PROOF_WINDOW, RPC_DEFAULT_GAS_CAP,
     },
     BlockingTaskPool, EthApi, EthFilter, EthPubSub,
 };
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "optimism")]
+use reth_rpc::eth::SequencerConfig;
+
 /// All handlers for the `eth` namespace
 #[derive(Debug, Clone)]
 pub struct EthHandlers<Provider, Pool, Network, Events> {
@@ -43,9 +46,16 @@ pub struct EthConfig {
     ///
     /// Defaults to [RPC_DEFAULT_GAS_CAP]
     pub rpc_gas_cap: u64,
+    /// Maximum number of blocks behind the tip that `eth_getProof` can generate proofs for.
+    ///
+    /// Defaults to [DEFAULT_ETH_PROOF_WINDOW]
+    pub eth_proof_window: u64,
     ///
     /// Sets TTL for stale filters
     pub stale_filter_ttl: std::time::Duration,
+    /// Forwarding of raw transactions to the sequencer of an OP Stack chain, if any.
+    #[cfg(feature = "optimism")]
+    pub sequencer: Option<SequencerConfig>,
 }
 
 impl EthConfig {
@@ -70,7 +80,10 @@ impl Default for EthConfig {
             max_blocks_per_filter: DEFAULT_MAX_BLOCKS_PER_FILTER,
             max_logs_per_response: DEFAULT_MAX_LOGS_PER_RESPONSE,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
+            eth_proof_window: DEFAULT_ETH_PROOF_WINDOW,
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
+            #[cfg(feature = "optimism")]
+            sequencer: None,
         }
     }
 }
@@ -111,4 +124,17 @@ impl EthConfig {
         self.rpc_gas_cap = rpc_gas_cap;
         self
     }
+
+    /// Configures the maximum number of blocks behind the tip for `eth_getProof`
+    pub fn eth_proof_window(mut self, window: u64) -> Self {
+        self.eth_proof_window = window;
+        self
+    }
+
+    /// Configures the sequencer raw transactions are forwarded to
+    #[cfg(feature = "optimism")]
+    pub fn sequencer(mut self, sequencer: Option<SequencerConfig>) -> Self {
+        self.sequencer = sequencer;
+        self
+    }
 }
@@ -18,13 +18,13 @@
 //!
 //! ```
 //! use reth_network_api::{NetworkInfo, Peers};
-//! use reth_provider::{AccountReader, BlockReaderIdExt, ChainSpecProvider, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider, ChangeSetReader};
+//! use reth_provider::{AccountReader, BlockReaderIdExt, ChainSpecProvider, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider, ChangeSetReader, BadBlocksReader, AddressAppearancesReader, TransactionTracesReader};
 //! use reth_rpc_builder::{RethRpcModule, RpcModuleBuilder, RpcServerConfig, ServerBuilder, TransportRpcModuleConfig};
 //! use reth_tasks::TokioTaskExecutor;
 //! use reth_transaction_pool::TransactionPool;
 //! pub async fn launch<Provider, Pool, Network, Events>(provider: Provider, pool: Pool, network: Network, events: Events)
 //! where
-//!     Provider: AccountReader + BlockReaderIdExt + ChainSpecProvider + ChangeSetReader + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+//!     Provider: AccountReader + BlockReaderIdExt + ChainSpecProvider + ChangeSetReader + BadBlocksReader + AddressAppearancesReader + TransactionTracesReader + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
 //!     Pool: TransactionPool + Clone + 'static,
 //!     Network: NetworkInfo + Peers + Clone + 'static,
 //!     Events: CanonStateSubscriptions +  Clone + 'static,
@@ -51,7 +51,7 @@
 //! ```
 //! use tokio::try_join;
 //! use reth_network_api::{NetworkInfo, Peers};
-//! use reth_provider::{AccountReader, BlockReaderIdExt, ChainSpecProvider, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider, ChangeSetReader};
+//! use reth_provider::{AccountReader, BlockReaderIdExt, ChainSpecProvider, CanonStateSubscriptions, StateProviderFactory, EvmEnvProvider, ChangeSetReader, BadBlocksReader, AddressAppearancesReader, TransactionTracesReader};
 //! use reth_rpc::JwtSecret;
 //! use reth_rpc_builder::{RethRpcModule, RpcModuleBuilder, RpcServerConfig, TransportRpcModuleConfig};
 //! use reth_tasks::TokioTaskExecutor;
@@ -60,7 +60,7 @@
 //! use reth_rpc_builder::auth::AuthServerConfig;
 //! pub async fn launch<Provider, Pool, Network, Events, EngineApi>(provider: Provider, pool: Pool, network: Network, events: Events, engine_api: EngineApi)
 //! where
-//!     Provider: AccountReader + BlockReaderIdExt + ChainSpecProvider + ChangeSetReader + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
+//!     Provider: AccountReader + BlockReaderIdExt + ChainSpecProvider + ChangeSetReader + BadBlocksReader + AddressAppearancesReader + TransactionTracesReader + StateProviderFactory + EvmEnvProvider + Clone + Unpin + 'static,
 //!     Pool: TransactionPool + Clone + 'static,
 //!     Network: NetworkInfo + Peers + Clone + 'static,
 //!     Events: CanonStateSubscriptions +  Clone + 'static,
@@ -106,29 +106,37 @@ use jsonrpsee::{
     server::{IdProvider, Server, ServerHandle},
     Methods, RpcModule,
 };
+use reth_beacon_consensus::BeaconConsensusEngineHandle;
 use reth_ipc::server::IpcServer;
 use reth_network_api::{NetworkInfo, Peers};
+use reth_payload_builder::bundle::BundlePool;
 use reth_provider::{
-    AccountReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-    ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+    AccountReader, AddressAppearancesReader, BadBlocksReader, BlockReader, BlockReaderIdExt,
+    CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    StateProviderFactory,
 };
 use reth_rpc::{
     eth::{
         cache::{cache_new_blocks_task, EthStateCache},
         gas_oracle::GasPriceOracle,
+        EthSigner, KeystoreSigner,
     },
     AdminApi, AuthLayer, BlockingTaskGuard, BlockingTaskPool, Claims, DebugApi, EngineEthApi,
-    EthApi, EthFilter, EthPubSub, EthSubscriptionIdProvider, JwtAuthValidator, JwtSecret, NetApi,
-    OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
+    EthApi, EthFilter, EthPubSub, EthSubscriptionIdProvider, JwtAuthValidator, JwtSecret, MevApi,
+    NetApi, OptimismApi, OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
 };
 use reth_rpc_api::{servers::*, EngineApiServer};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::TransactionPool;
 use serde::{Deserialize, Serialize, Serializer};
+
+#[cfg(feature = "optimism")]
+use reth_rpc::eth::SequencerClient;
 use std::{
     collections::{HashMap, HashSet},
     fmt,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    path::PathBuf,
     str::FromStr,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -180,6 +188,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + BadBlocksReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
         + Clone
         + Unpin
         + 'static,
@@ -211,6 +222,12 @@ pub struct RpcModuleBuilder<Provider, Pool, Network, Tasks, Events> {
     executor: Tasks,
     /// Provides access to chain events, such as new blocks, required by pubsub.
     events: Events,
+    /// The handle of the consensus engine, required by `debug_setHead`.
+    engine_handle: Option<BeaconConsensusEngineHandle>,
+    /// Signs for the unlocked keystore accounts in the `eth` namespace.
+    keystore_signer: Option<KeystoreSigner>,
+    /// The pool the bundles sent via the `mev` and `eth` bundle namespaces are added to.
+    bundle_pool: BundlePool,
 }
 
 // === impl RpcBuilder ===
@@ -226,7 +243,16 @@ impl<Provider, Pool, Network, Tasks, Events>
         executor: Tasks,
         events: Events,
     ) -> Self {
-        Self { provider, pool, network, executor, events }
+        Self {
+            provider,
+            pool,
+            network,
+            executor,
+            events,
+            engine_handle: None,
+            keystore_signer: None,
+            bundle_pool: BundlePool::default(),
+        }
     }
 
     /// Configure the provider instance.
@@ -234,8 +260,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     where
         P: BlockReader + StateProviderFactory + EvmEnvProvider + 'static,
     {
-        let Self { pool, network, executor, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events }
+        let Self {
+            pool,
+            network,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure the transaction pool instance.
@@ -243,8 +287,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     where
         P: TransactionPool + 'static,
     {
-        let Self { provider, network, executor, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events }
+        let Self {
+            provider,
+            network,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure a [NoopTransactionPool] instance.
@@ -255,13 +317,25 @@ impl<Provider, Pool, Network, Tasks, Events>
     pub fn with_noop_pool(
         self,
     ) -> RpcModuleBuilder<Provider, NoopTransactionPool, Network, Tasks, Events> {
-        let Self { provider, executor, events, network, .. } = self;
+        let Self {
+            provider,
+            executor,
+            events,
+            network,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             executor,
             events,
             network,
             pool: NoopTransactionPool::default(),
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
         }
     }
 
@@ -270,8 +344,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     where
         N: NetworkInfo + Peers + 'static,
     {
-        let Self { provider, pool, executor, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events }
+        let Self {
+            provider,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure a [NoopNetwork] instance.
@@ -280,8 +372,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     /// This is only intended for allow easier setup of namespaces that depend on the [EthApi] which
     /// requires a [NetworkInfo] implementation.
     pub fn with_noop_network(self) -> RpcModuleBuilder<Provider, Pool, NoopNetwork, Tasks, Events> {
-        let Self { provider, pool, executor, events, .. } = self;
-        RpcModuleBuilder { provider, pool, executor, events, network: NoopNetwork::default() }
+        let Self {
+            provider,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            pool,
+            executor,
+            events,
+            network: NoopNetwork::default(),
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure the task executor to use for additional tasks.
@@ -292,8 +402,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     where
         T: TaskSpawner + 'static,
     {
-        let Self { pool, network, provider, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events }
+        let Self {
+            pool,
+            network,
+            provider,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure [TokioTaskExecutor] as the task executor to use for additional tasks.
@@ -303,8 +431,26 @@ impl<Provider, Pool, Network, Tasks, Events>
     pub fn with_tokio_executor(
         self,
     ) -> RpcModuleBuilder<Provider, Pool, Network, TokioTaskExecutor, Events> {
-        let Self { pool, network, provider, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, events, executor: TokioTaskExecutor::default() }
+        let Self {
+            pool,
+            network,
+            provider,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            events,
+            executor: TokioTaskExecutor::default(),
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
     }
 
     /// Configure the event subscriber instance
@@ -312,8 +458,46 @@ impl<Provider, Pool, Network, Tasks, Events>
     where
         E: CanonStateSubscriptions + 'static,
     {
-        let Self { provider, pool, executor, network, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events }
+        let Self {
+            provider,
+            pool,
+            executor,
+            network,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        }
+    }
+
+    /// Configure the handle of the consensus engine, which is required by `debug_setHead`.
+    pub fn with_engine_handle(mut self, engine_handle: BeaconConsensusEngineHandle) -> Self {
+        self.engine_handle = Some(engine_handle);
+        self
+    }
+
+    /// Configure the signer of the unlocked keystore accounts, which are used by `eth_sign`,
+    /// `eth_signTransaction`, `eth_signTypedData` and `eth_sendTransaction`.
+    pub fn with_keystore_signer(mut self, keystore_signer: KeystoreSigner) -> Self {
+        self.keystore_signer = Some(keystore_signer);
+        self
+    }
+
+    /// Configure the pool the bundles sent via `mev_sendBundle` and `eth_sendBundle` are added to,
+    /// which should be shared with the payload builder.
+    pub fn with_bundle_pool(mut self, bundle_pool: BundlePool) -> Self {
+        self.bundle_pool = bundle_pool;
+        self
     }
 }
 
@@ -326,6 +510,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + BadBlocksReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
         + Clone
         + Unpin
         + 'static,
@@ -353,7 +540,16 @@ where
     {
         let mut modules = TransportRpcModules::default();
 
-        let Self { provider, pool, network, executor, events } = self;
+        let Self {
+            provider,
+            pool,
+            network,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        } = self;
 
         let TransportRpcModuleConfig { http, ws, ipc, config } = module_config.clone();
 
@@ -365,6 +561,9 @@ where
             events,
             config.unwrap_or_default(),
         );
+        registry.set_engine_handle(engine_handle);
+        registry.set_keystore_signer(keystore_signer);
+        registry.set_bundle_pool(bundle_pool);
 
         modules.config = module_config;
         modules.http = registry.maybe_module(http.as_ref());
@@ -383,7 +582,16 @@ where
     pub fn build(self, module_config: TransportRpcModuleConfig) -> TransportRpcModules<()> {
         let mut modules = TransportRpcModules::default();
 
-        let Self { provider, pool, network, executor, events } = self;
+        let Self {
+            provider,
+            pool,
+            network,
+            executor,
+            events,
+            engine_handle,
+            keystore_signer,
+            bundle_pool,
+        } = self;
 
         if !module_config.is_empty() {
             let TransportRpcModuleConfig { http, ws, ipc, config } = module_config.clone();
@@ -396,6 +604,9 @@ where
                 events,
                 config.unwrap_or_default(),
             );
+            registry.set_engine_handle(engine_handle);
+            registry.set_keystore_signer(keystore_signer);
+            registry.set_bundle_pool(bundle_pool);
 
             modules.config = module_config;
             modules.http = registry.maybe_module(http.as_ref());
@@ -418,6 +629,14 @@ impl Default for RpcModuleBuilder<(), (), (), (), ()> {
 pub struct RpcModuleConfig {
     /// `eth` namespace settings
     eth: EthConfig,
+    /// Directory the `debug` namespace writes trace files to, the methods that write trace files
+    /// are unsupported if it isn't set
+    trace_dir: Option<PathBuf>,
+    /// Maximum block range of the requests that read changesets, e.g.
+    /// `debug_getModifiedAccountsByNumber` and `reth_getStateDiff`
+    max_changeset_blocks: Option<u64>,
+    /// Maximum block range of `trace_filter`
+    max_trace_filter_blocks: Option<u64>,
 }
 
 // === impl RpcModuleConfig ===
@@ -429,7 +648,22 @@ impl RpcModuleConfig {
     }
     /// Returns a new RPC module config given the eth namespace config
     pub fn new(eth: EthConfig) -> Self {
-        Self { eth }
+        Self { eth, trace_dir: None, max_changeset_blocks: None, max_trace_filter_blocks: None }
+    }
+
+    /// Returns the directory the `debug` namespace writes trace files to, if any.
+    pub fn trace_dir(&self) -> Option<&PathBuf> {
+        self.trace_dir.as_ref()
+    }
+
+    /// Returns the maximum block range of the requests that read changesets.
+    pub fn max_changeset_blocks(&self) -> u64 {
+        self.max_changeset_blocks.unwrap_or(DEFAULT_MAX_CHANGESET_BLOCKS)
+    }
+
+    /// Returns the maximum block range of `trace_filter`.
+    pub fn max_trace_filter_blocks(&self) -> u64 {
+        self.max_trace_filter_blocks.unwrap_or(DEFAULT_MAX_TRACE_FILTER_BLOCKS)
     }
 }
 
@@ -437,6 +671,9 @@ impl RpcModuleConfig {
 #[derive(Clone, Debug, Default)]
 pub struct RpcModuleConfigBuilder {
     eth: Option<EthConfig>,
+    trace_dir: Option<PathBuf>,
+    max_changeset_blocks: Option<u64>,
+    max_trace_filter_blocks: Option<u64>,
 }
 
 // === impl RpcModuleConfigBuilder ===
@@ -448,10 +685,38 @@ impl RpcModuleConfigBuilder {
         self
     }
 
+    /// Configures the directory the `debug` namespace writes trace files to
+    pub fn trace_dir(mut self, trace_dir: PathBuf) -> Self {
+        self.trace_dir = Some(trace_dir);
+        self
+    }
+
+    /// Configures the maximum block range of the requests that read changesets
+    pub fn max_changeset_blocks(mut self, max_blocks: u64) -> Self {
+        self.max_changeset_blocks = Some(max_blocks);
+        self
+    }
+
+    /// Configures the maximum block range of `trace_filter`
+    pub fn max_trace_filter_blocks(mut self, max_blocks: u64) -> Self {
+        self.max_trace_filter_blocks = Some(max_blocks);
+        self
+    }
+
     /// Consumes the type and creates the [RpcModuleConfig]
     pub fn build(self) -> RpcModuleConfig {
-        let RpcModuleConfigBuilder { eth } = self;
-        RpcModuleConfig { eth: eth.unwrap_or_default() }
+        let RpcModuleConfigBuilder {
+            eth,
+            trace_dir,
+            max_changeset_blocks,
+            max_trace_filter_blocks,
+        } = self;
+        RpcModuleConfig {
+            eth: eth.unwrap_or_default(),
+            trace_dir,
+            max_changeset_blocks,
+            max_trace_filter_blocks,
+        }
     }
 }
 
@@ -575,6 +840,9 @@ impl RpcModuleSelection {
             + EvmEnvProvider
             + ChainSpecProvider
             + ChangeSetReader
+            + BadBlocksReader
+            + AddressAppearancesReader
+            + TransactionTracesReader
             + Clone
             + Unpin
             + 'static,
@@ -682,6 +950,12 @@ pub enum RethRpcModule {
     Reth,
     /// `ots_` module
     Ots,
+    /// `optimism_` module
+    Optimism,
+    /// `mev_` module
+    Mev,
+    /// `eth_` bundle module, e.g. `eth_callBundle` and `eth_sendBundle`
+    EthBundle,
 }
 
 // === impl RethRpcModule ===
@@ -718,6 +992,12 @@ pub struct RethModuleRegistry<Provider, Pool, Network, Tasks, Events> {
     events: Events,
     /// Additional settings for handlers.
     config: RpcModuleConfig,
+    /// The handle of the consensus engine, required by `debug_setHead`.
+    engine_handle: Option<BeaconConsensusEngineHandle>,
+    /// Signs for the unlocked keystore accounts in the `eth` namespace.
+    keystore_signer: Option<KeystoreSigner>,
+    /// The pool the bundles sent via the `mev` and `eth` bundle namespaces are added to.
+    bundle_pool: BundlePool,
     /// Holds a clone of all the eth namespace handlers
     eth: Option<EthHandlers<Provider, Pool, Network, Events>>,
     /// to put trace calls behind semaphore
@@ -750,9 +1030,27 @@ impl<Provider, Pool, Network, Tasks, Events>
             blocking_pool_guard: BlockingTaskGuard::new(config.eth.max_tracing_requests),
             config,
             events,
+            engine_handle: None,
+            keystore_signer: None,
+            bundle_pool: BundlePool::default(),
         }
     }
 
+    /// Sets the handle of the consensus engine, which is required by `debug_setHead`.
+    pub fn set_engine_handle(&mut self, engine_handle: Option<BeaconConsensusEngineHandle>) {
+        self.engine_handle = engine_handle;
+    }
+
+    /// Sets the signer of the unlocked keystore accounts, which is used by the `eth` namespace.
+    pub fn set_keystore_signer(&mut self, keystore_signer: Option<KeystoreSigner>) {
+        self.keystore_signer = keystore_signer;
+    }
+
+    /// Sets the pool the bundles sent via the `mev` and `eth` bundle namespaces are added to.
+    pub fn set_bundle_pool(&mut self, bundle_pool: BundlePool) {
+        self.bundle_pool = bundle_pool;
+    }
+
     /// Returns a reference to the pool
     pub fn pool(&self) -> &Pool {
         &self.pool
@@ -827,6 +1125,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + BadBlocksReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
         + Clone
         + Unpin
         + 'static,
@@ -898,6 +1199,27 @@ where
         self
     }
 
+    /// Register Optimism namespace
+    pub fn register_optimism(&mut self) -> &mut Self {
+        let optimism_api = self.optimism_api();
+        self.modules.insert(RethRpcModule::Optimism, optimism_api.into_rpc().into());
+        self
+    }
+
+    /// Register Mev namespace
+    pub fn register_mev(&mut self) -> &mut Self {
+        let mev_api = self.mev_api();
+        self.modules.insert(RethRpcModule::Mev, mev_api.into_rpc().into());
+        self
+    }
+
+    /// Register Eth bundle namespace
+    pub fn register_eth_bundle(&mut self) -> &mut Self {
+        let bundle_api = self.bundle_api();
+        self.modules.insert(RethRpcModule::EthBundle, bundle_api.into_rpc().into());
+        self
+    }
+
     /// Helper function to create a [RpcModule] if it's not `None`
     fn maybe_module(&mut self, config: Option<&RpcModuleSelection>) -> Option<RpcModule<()>> {
         let config = config?;
@@ -949,6 +1271,9 @@ where
                             eth_api.clone(),
                             Box::new(self.executor.clone()),
                             self.blocking_pool_guard.clone(),
+                            self.config.trace_dir().cloned(),
+                            self.config.max_changeset_blocks(),
+                            self.engine_handle.clone(),
                         )
                         .into_rpc()
                         .into(),
@@ -967,6 +1292,7 @@ where
                             self.provider.clone(),
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
+                            self.config.max_trace_filter_blocks(),
                         )
                         .into_rpc()
                         .into(),
@@ -982,12 +1308,38 @@ where
                         )
                         .into_rpc()
                         .into(),
-                        RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
-                        RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+                        RethRpcModule::Ots => {
+                            OtterscanApi::new(self.provider.clone(), eth_api.clone())
+                                .into_rpc()
+                                .into()
+                        }
+                        RethRpcModule::Reth => RethApi::new(
+                            self.provider.clone(),
+                            Box::new(self.executor.clone()),
+                            self.config.max_changeset_blocks(),
+                        )
+                        .into_rpc()
+                        .into(),
+                        RethRpcModule::Optimism => {
+                            OptimismApi::new(self.provider.clone(), Box::new(self.executor.clone()))
                                 .into_rpc()
                                 .into()
                         }
+                        RethRpcModule::Mev => MevApi::new(
+                            self.provider.clone(),
+                            eth_api.clone(),
+                            self.bundle_pool.clone(),
+                            self.blocking_pool_guard.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
+                        RethRpcModule::EthBundle => EthBundle::new(
+                            eth_api.clone(),
+                            self.bundle_pool.clone(),
+                            self.blocking_pool_guard.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
                     })
                     .clone()
             })
@@ -1037,8 +1389,16 @@ where
                 cache.clone(),
                 gas_oracle,
                 self.config.eth.rpc_gas_cap,
+                self.config.eth.eth_proof_window,
+                self.keystore_signer
+                    .clone()
+                    .map(|signer| Box::new(signer) as Box<dyn EthSigner>)
+                    .into_iter()
+                    .collect(),
                 executor.clone(),
                 blocking_task_pool.clone(),
+                #[cfg(feature = "optimism")]
+                self.config.eth.sequencer.clone().map(SequencerClient::new),
             );
             let filter = EthFilter::new(
                 self.provider.clone(),
@@ -1074,19 +1434,24 @@ where
     /// Instantiates TraceApi
     pub fn trace_api(&mut self) -> TraceApi<Provider, EthApi<Provider, Pool, Network>> {
         let eth = self.eth_handlers();
-        TraceApi::new(self.provider.clone(), eth.api, self.blocking_pool_guard.clone())
+        TraceApi::new(
+            self.provider.clone(),
+            eth.api,
+            self.blocking_pool_guard.clone(),
+            self.config.max_trace_filter_blocks(),
+        )
     }
 
     /// Instantiates [EthBundle] Api
     pub fn bundle_api(&mut self) -> EthBundle<EthApi<Provider, Pool, Network>> {
         let eth_api = self.eth_api();
-        EthBundle::new(eth_api, self.blocking_pool_guard.clone())
+        EthBundle::new(eth_api, self.bundle_pool.clone(), self.blocking_pool_guard.clone())
     }
 
     /// Instantiates OtterscanApi
-    pub fn otterscan_api(&mut self) -> OtterscanApi<EthApi<Provider, Pool, Network>> {
+    pub fn otterscan_api(&mut self) -> OtterscanApi<Provider, EthApi<Provider, Pool, Network>> {
         let eth_api = self.eth_api();
-        OtterscanApi::new(eth_api)
+        OtterscanApi::new(self.provider.clone(), eth_api)
     }
 
     /// Instantiates DebugApi
@@ -1097,6 +1462,9 @@ where
             eth_api,
             Box::new(self.executor.clone()),
             self.blocking_pool_guard.clone(),
+            self.config.trace_dir().cloned(),
+            self.config.max_changeset_blocks(),
+            self.engine_handle.clone(),
         )
     }
 
@@ -1108,7 +1476,27 @@ where
 
     /// Instantiates RethApi
     pub fn reth_api(&mut self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+        RethApi::new(
+            self.provider.clone(),
+            Box::new(self.executor.clone()),
+            self.config.max_changeset_blocks(),
+        )
+    }
+
+    /// Instantiates OptimismApi
+    pub fn optimism_api(&mut self) -> OptimismApi<Provider> {
+        OptimismApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+    }
+
+    /// Instantiates MevApi
+    pub fn mev_api(&mut self) -> MevApi<Provider, EthApi<Provider, Pool, Network>> {
+        let eth_api = self.eth_api();
+        MevApi::new(
+            self.provider.clone(),
+            eth_api,
+            self.bundle_pool.clone(),
+            self.blocking_pool_guard.clone(),
+        )
     }
 }
 
@@ -2032,6 +2420,9 @@ mod tests {
                 "rpc" => RethRpcModule::Rpc,
                 "ots" => RethRpcModule::Ots,
                 "reth" => RethRpcModule::Reth,
+                "optimism" => RethRpcModule::Optimism,
+                "mev" => RethRpcModule::Mev,
+                "eth-bundle" => RethRpcModule::EthBundle,
             );
     }
 
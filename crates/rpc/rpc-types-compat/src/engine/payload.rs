@@ -12,14 +12,29 @@ use reth_rpc_types::engine::{
 
 /// Converts [ExecutionPayloadV1] to [Block]
 pub fn try_payload_v1_to_block(payload: ExecutionPayloadV1) -> Result<Block, PayloadError> {
-    if payload.extra_data.len() > MAXIMUM_EXTRA_DATA_SIZE {
-        return Err(PayloadError::ExtraData(payload.extra_data))
+    let block = payload_v1_to_block_unchecked(payload)?;
+    validate_payload_header(&block.header)?;
+    Ok(block)
+}
+
+/// Validates the fields of the header of a block converted from a payload, i.e. that the extra
+/// data isn't too long and the base fee isn't below the minimum.
+pub fn validate_payload_header(header: &Header) -> Result<(), PayloadError> {
+    if header.extra_data.len() > MAXIMUM_EXTRA_DATA_SIZE {
+        return Err(PayloadError::ExtraData(header.extra_data.clone()))
     }
 
-    if payload.base_fee_per_gas < MIN_PROTOCOL_BASE_FEE_U256 {
-        return Err(PayloadError::BaseFee(payload.base_fee_per_gas))
+    let base_fee = U256::from(header.base_fee_per_gas.unwrap_or_default());
+    if base_fee < MIN_PROTOCOL_BASE_FEE_U256 {
+        return Err(PayloadError::BaseFee(base_fee))
     }
 
+    Ok(())
+}
+
+/// Converts [ExecutionPayloadV1] to [Block], without validating the header with
+/// [validate_payload_header].
+fn payload_v1_to_block_unchecked(payload: ExecutionPayloadV1) -> Result<Block, PayloadError> {
     let transactions = payload
         .transactions
         .into_iter()
@@ -61,9 +76,17 @@ pub fn try_payload_v1_to_block(payload: ExecutionPayloadV1) -> Result<Block, Pay
 
 /// Converts [ExecutionPayloadV2] to [Block]
 pub fn try_payload_v2_to_block(payload: ExecutionPayloadV2) -> Result<Block, PayloadError> {
+    let block = payload_v2_to_block_unchecked(payload)?;
+    validate_payload_header(&block.header)?;
+    Ok(block)
+}
+
+/// Converts [ExecutionPayloadV2] to [Block], without validating the header with
+/// [validate_payload_header].
+fn payload_v2_to_block_unchecked(payload: ExecutionPayloadV2) -> Result<Block, PayloadError> {
     // this performs the same conversion as the underlying V1 payload, but calculates the
     // withdrawals root and adds withdrawals
-    let mut base_sealed_block = try_payload_v1_to_block(payload.payload_inner)?;
+    let mut base_sealed_block = payload_v1_to_block_unchecked(payload.payload_inner)?;
     let withdrawals: Vec<_> = payload
         .withdrawals
         .iter()
@@ -77,9 +100,17 @@ pub fn try_payload_v2_to_block(payload: ExecutionPayloadV2) -> Result<Block, Pay
 
 /// Converts [ExecutionPayloadV3] to [Block]
 pub fn try_payload_v3_to_block(payload: ExecutionPayloadV3) -> Result<Block, PayloadError> {
+    let block = payload_v3_to_block_unchecked(payload)?;
+    validate_payload_header(&block.header)?;
+    Ok(block)
+}
+
+/// Converts [ExecutionPayloadV3] to [Block], without validating the header with
+/// [validate_payload_header].
+fn payload_v3_to_block_unchecked(payload: ExecutionPayloadV3) -> Result<Block, PayloadError> {
     // this performs the same conversion as the underlying V2 payload, but inserts the blob gas
     // used and excess blob gas
-    let mut base_block = try_payload_v2_to_block(payload.payload_inner)?;
+    let mut base_block = payload_v2_to_block_unchecked(payload.payload_inner)?;
 
     base_block.header.blob_gas_used = Some(payload.blob_gas_used.to());
     base_block.header.excess_blob_gas = Some(payload.excess_blob_gas.to());
@@ -266,11 +297,25 @@ pub fn convert_block_to_payload_input_v2(value: SealedBlock) -> ExecutionPayload
 pub fn try_into_block(
     value: ExecutionPayload,
     parent_beacon_block_root: Option<B256>,
+) -> Result<Block, PayloadError> {
+    let block = try_into_block_unchecked(value, parent_beacon_block_root)?;
+    validate_payload_header(&block.header)?;
+    Ok(block)
+}
+
+/// Converts the [ExecutionPayload] to a [Block] like [try_into_block], but without validating the
+/// header with [validate_payload_header].
+///
+/// This only fails if the payload can't be represented as a block, e.g. if one of its transactions
+/// can't be decoded, so the block of a payload that is invalid otherwise is still available.
+pub fn try_into_block_unchecked(
+    value: ExecutionPayload,
+    parent_beacon_block_root: Option<B256>,
 ) -> Result<Block, PayloadError> {
     let mut base_payload = match value {
-        ExecutionPayload::V1(payload) => try_payload_v1_to_block(payload)?,
-        ExecutionPayload::V2(payload) => try_payload_v2_to_block(payload)?,
-        ExecutionPayload::V3(payload) => try_payload_v3_to_block(payload)?,
+        ExecutionPayload::V1(payload) => payload_v1_to_block_unchecked(payload)?,
+        ExecutionPayload::V2(payload) => payload_v2_to_block_unchecked(payload)?,
+        ExecutionPayload::V3(payload) => payload_v3_to_block_unchecked(payload)?,
     };
 
     base_payload.header.parent_beacon_block_root = parent_beacon_block_root;
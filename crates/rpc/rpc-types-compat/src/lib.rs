@@ -15,4 +15,5 @@ pub mod block;
 pub mod engine;
 pub mod log;
 pub mod proof;
+pub mod state;
 pub mod transaction;
@@ -0,0 +1,29 @@
+//! Compatibility functions for rpc state dump related types.
+
+use reth_primitives::{Account, Bytes, StorageEntry, B256};
+use reth_rpc_types::DumpAccount;
+
+/// Creates a new rpc state dump account from a primitive account, with its storage root and,
+/// if requested, its code and storage.
+///
+/// The storage entries are expected to be keyed by the hashes of their keys.
+pub fn from_primitive_dump_account(
+    hashed_address: B256,
+    account: Account,
+    storage_root: B256,
+    code: Option<Bytes>,
+    storage: Option<Vec<StorageEntry>>,
+) -> DumpAccount {
+    DumpAccount {
+        balance: account.balance,
+        nonce: account.nonce,
+        root: storage_root,
+        code_hash: account.get_bytecode_hash(),
+        code,
+        storage: storage.map(|storage| {
+            storage.into_iter().map(|entry| (entry.key, entry.value.into())).collect()
+        }),
+        address: None,
+        address_hash: Some(hashed_address),
+    }
+}
@@ -62,6 +62,9 @@ fn fill(
 
             (Some(U128::from(gas_price)), Some(U128::from(signed_tx.max_fee_per_gas())))
         }
+        // deposit transactions do not pay for L2 gas
+        #[cfg(feature = "optimism")]
+        TxType::DEPOSIT => (None, None),
     };
 
     let chain_id = signed_tx.chain_id().map(U64::from);
@@ -104,6 +107,8 @@ fn fill(
                     .collect(),
             )
         }
+        #[cfg(feature = "optimism")]
+        PrimitiveTransaction::Deposit(_) => None,
     };
 
     let signature =
@@ -133,6 +138,14 @@ fn fill(
         // EIP-4844 fields
         max_fee_per_blob_gas: signed_tx.max_fee_per_blob_gas().map(U128::from),
         blob_versioned_hashes,
+
+        // Optimism fields
+        #[cfg(feature = "optimism")]
+        optimism: reth_rpc_types::OptimismTransactionFields {
+            source_hash: signed_tx.source_hash(),
+            mint: signed_tx.mint().map(U128::from),
+            is_system_tx: signed_tx.is_deposit().then_some(signed_tx.is_system_transaction()),
+        },
     }
 }
 
@@ -46,6 +46,11 @@ pub(crate) fn from_primitive_signature(
 ) -> Signature {
     match tx_type {
         TxType::Legacy => from_legacy_primitive_signature(signature, chain_id),
+        // deposit transactions are not signed, the zeroed signature is returned as is
+        #[cfg(feature = "optimism")]
+        TxType::DEPOSIT => {
+            Signature { r: U256::ZERO, s: U256::ZERO, v: U256::ZERO, y_parity: None }
+        }
         _ => from_typed_primitive_signature(signature),
     }
 }
@@ -0,0 +1,106 @@
+//! Types for the `debug` API.
+
+use crate::RichBlock;
+use alloy_primitives::{Address, Bytes, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An account of a state dump, as returned by `debug_accountRange`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpAccount {
+    /// Balance of the account
+    pub balance: U256,
+    /// Nonce of the account
+    pub nonce: u64,
+    /// Storage root of the account
+    pub root: B256,
+    /// Hash of the code of the account
+    pub code_hash: B256,
+    /// Code of the account, if requested and the account has code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// Non-zero storage slots of the account by the hash of their key, if requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<B256, B256>>,
+    /// Address of the account, if it's known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// Hash of the address, which is the key of the account in the state trie
+    #[serde(default, rename = "key", skip_serializing_if = "Option::is_none")]
+    pub address_hash: Option<B256>,
+}
+
+/// A page of the accounts of the state at a block, as returned by `debug_accountRange`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountRange {
+    /// State root of the block
+    pub root: B256,
+    /// Accounts of the page, by the hash of their address
+    pub accounts: BTreeMap<B256, DumpAccount>,
+    /// Key to request the next page with, if there are more accounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Bytes>,
+}
+
+/// A storage slot, as returned by `debug_storageRangeAt`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageRangeEntry {
+    /// Key of the storage slot
+    pub key: Option<B256>,
+    /// Value of the storage slot
+    pub value: B256,
+}
+
+/// A page of the storage of an account, as returned by `debug_storageRangeAt`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRange {
+    /// Storage slots of the page, by the hash of their key
+    pub storage: BTreeMap<B256, StorageRangeEntry>,
+    /// Key of the first slot of the next page, if there are more slots
+    pub next_key: Option<B256>,
+}
+
+/// A block that was rejected as invalid, as returned by `debug_getBadBlocks`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadBlock {
+    /// Hash of the block
+    pub hash: B256,
+    /// The block, with full transactions if their signatures are valid
+    pub block: RichBlock,
+    /// RLP encoding of the block
+    pub rlp: Bytes,
+    /// The reason the block was rejected
+    pub error: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn serde_account_range() {
+        let s = r#"{"root":"0x0000000000000000000000000000000000000000000000000000000000000001","accounts":{"0xd52688a8f926c816ca1e079067caba944f158e764817b83fc43594370ca9cf62":{"balance":"0x1","nonce":1,"root":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","codeHash":"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470","storage":{"0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563":"0x0000000000000000000000000000000000000000000000000000000000000005"},"key":"0xd52688a8f926c816ca1e079067caba944f158e764817b83fc43594370ca9cf62"}},"next":"0xd52688a8f926c816ca1e079067caba944f158e764817b83fc43594370ca9cf63"}"#;
+        let range: AccountRange = serde_json::from_str(s).unwrap();
+        let hashed_address =
+            b256!("d52688a8f926c816ca1e079067caba944f158e764817b83fc43594370ca9cf62");
+        let hashed_key = b256!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563");
+        let next = b256!("d52688a8f926c816ca1e079067caba944f158e764817b83fc43594370ca9cf63");
+        assert_eq!(range.next, Some(next.to_vec().into()));
+        let account = &range.accounts[&hashed_address];
+        assert_eq!(account.code, None);
+        assert_eq!(account.address_hash, Some(hashed_address));
+        assert_eq!(account.storage.as_ref().unwrap()[&hashed_key], B256::from(U256::from(5)));
+        assert_eq!(serde_json::to_string(&range).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_storage_range() {
+        let s = r#"{"storage":{"0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563":{"key":"0x0000000000000000000000000000000000000000000000000000000000000000","value":"0x0000000000000000000000000000000000000000000000000000000000000005"}},"nextKey":null}"#;
+        let range: StorageRange = serde_json::from_str(s).unwrap();
+        assert_eq!(range.next_key, None);
+        assert_eq!(serde_json::to_string(&range).unwrap(), s);
+    }
+}
@@ -378,6 +378,32 @@ pub struct PayloadAttributes {
     /// See also <https://github.com/ethereum/execution-apis/blob/main/src/engine/cancun.md#payloadattributesv3>
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_beacon_block_root: Option<B256>,
+    /// Optimism Payload Attributes
+    #[cfg(feature = "optimism")]
+    #[serde(flatten)]
+    pub optimism_payload_attributes: OptimismPayloadAttributes,
+}
+
+/// Optimism Payload Attributes
+///
+/// These are sent by the rollup node (op-node) to drive the execution engine as a sequencer or
+/// verifier, see the
+/// [OP Stack engine API specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/exec-engine.md#extended-payloadattributesv1).
+#[cfg(feature = "optimism")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimismPayloadAttributes {
+    /// EIP-2718 encoded transactions to force into the block, in order, before any transactions
+    /// from the transaction pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Vec<Bytes>>,
+    /// If true, the transaction pool is skipped and the block only contains the forced
+    /// `transactions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_tx_pool: Option<bool>,
+    /// If set, this sets the exact gas limit the block produced with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<U64>,
 }
 
 #[serde_as]
@@ -433,6 +459,8 @@ pub mod beacon_api_payload_attributes {
             suggested_fee_recipient: beacon_api_payload_attributes.suggested_fee_recipient,
             withdrawals: beacon_api_payload_attributes.withdrawals,
             parent_beacon_block_root: beacon_api_payload_attributes.parent_beacon_block_root,
+            #[cfg(feature = "optimism")]
+            optimism_payload_attributes: Default::default(),
         })
     }
 }
@@ -905,4 +933,22 @@ mod tests {
         let json = serde_json::to_value(event).unwrap();
         assert_eq!(input, json);
     }
+
+    #[test]
+    #[cfg(feature = "optimism")]
+    fn serde_optimism_payload_attributes() {
+        let s = r#"{"timestamp":"0x1235","prevRandao":"0xf343b00e02dc34ec0124241f74f32191be28fb370bb48060f5fa4df99bda774c","suggestedFeeRecipient":"0x0000000000000000000000000000000000000000","withdrawals":[],"transactions":["0x7ef852a0000000000000000000000000000000000000000000000000000000000000000194000000000000000000000000000000000000000294000000000000000000000000000000000000000380808252088080"],"noTxPool":true,"gasLimit":"0x1c9c380"}"#;
+        let attributes: PayloadAttributes = serde_json::from_str(s).unwrap();
+        let optimism = &attributes.optimism_payload_attributes;
+        assert_eq!(optimism.transactions.as_ref().map(Vec::len), Some(1));
+        assert_eq!(optimism.no_tx_pool, Some(true));
+        assert_eq!(optimism.gas_limit, Some(U64::from(30_000_000)));
+        assert_eq!(serde_json::to_string(&attributes).unwrap(), s);
+
+        // the optimism fields are optional
+        let s = r#"{"timestamp":"0x1235","prevRandao":"0xf343b00e02dc34ec0124241f74f32191be28fb370bb48060f5fa4df99bda774c","suggestedFeeRecipient":"0x0000000000000000000000000000000000000000"}"#;
+        let attributes: PayloadAttributes = serde_json::from_str(s).unwrap();
+        assert_eq!(attributes.optimism_payload_attributes, OptimismPayloadAttributes::default());
+        assert_eq!(serde_json::to_string(&attributes).unwrap(), s);
+    }
 }
@@ -1,5 +1,11 @@
 //! `trace_filter` types and support
-use crate::serde_helpers::num::u64_hex_or_decimal_opt;
+use crate::{
+    serde_helpers::num::u64_hex_or_decimal_opt,
+    trace::parity::{
+        Action, CallAction, CreateAction, CreateOutput, RewardAction, SelfdestructAction,
+        TraceOutput, TransactionTrace,
+    },
+};
 use alloy_primitives::Address;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -63,19 +69,43 @@ pub struct TraceFilterMatcher {
 impl TraceFilterMatcher {
     /// Returns `true` if the given `from` and `to` addresses match this filter.
     pub fn matches(&self, from: Address, to: Option<Address>) -> bool {
+        self.matches_addresses(Some(from), to)
+    }
+
+    /// Returns `true` if the addresses of the given trace match this filter.
+    ///
+    /// This can be applied to every call frame of a transaction, so internal calls are matched as
+    /// well. The `to` address of a create is the created contract, the `from` and `to` addresses
+    /// of a selfdestruct are the destroyed contract and the refund address, and rewards only have
+    /// a `to` address, the author.
+    pub fn matches_trace(&self, trace: &TransactionTrace) -> bool {
+        let (from, to) = match &trace.action {
+            Action::Call(CallAction { from, to, .. }) => (Some(*from), Some(*to)),
+            Action::Create(CreateAction { from, .. }) => {
+                let to = match &trace.result {
+                    Some(TraceOutput::Create(CreateOutput { address, .. })) => Some(*address),
+                    _ => None,
+                };
+                (Some(*from), to)
+            }
+            Action::Selfdestruct(SelfdestructAction { address, refund_address, .. }) => {
+                (Some(*address), Some(*refund_address))
+            }
+            Action::Reward(RewardAction { author, .. }) => (None, Some(*author)),
+        };
+        self.matches_addresses(from, to)
+    }
+
+    fn matches_addresses(&self, from: Option<Address>, to: Option<Address>) -> bool {
+        let from_matches = || from.map_or(false, |from| self.from_addresses.contains(&from));
+        let to_matches = || to.map_or(false, |to| self.to_addresses.contains(&to));
         match (self.from_addresses.is_empty(), self.to_addresses.is_empty()) {
             (true, true) => true,
-            (false, true) => self.from_addresses.contains(&from),
-            (true, false) => to.map_or(false, |to_addr| self.to_addresses.contains(&to_addr)),
+            (false, true) => from_matches(),
+            (true, false) => to_matches(),
             (false, false) => match self.mode {
-                TraceFilterMode::Union => {
-                    self.from_addresses.contains(&from) ||
-                        to.map_or(false, |to_addr| self.to_addresses.contains(&to_addr))
-                }
-                TraceFilterMode::Intersection => {
-                    self.from_addresses.contains(&from) &&
-                        to.map_or(false, |to_addr| self.to_addresses.contains(&to_addr))
-                }
+                TraceFilterMode::Union => from_matches() || to_matches(),
+                TraceFilterMode::Intersection => from_matches() && to_matches(),
             },
         }
     }
@@ -180,4 +210,54 @@ mod tests {
         assert!(!matcher.matches(test_addr_d8, Some(test_addr_d8)));
         assert!(!matcher.matches(test_addr_d8, Some(test_addr_16)));
     }
+
+    #[test]
+    fn test_filter_matcher_traces() {
+        let test_addr_d8 = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".parse().unwrap();
+        let test_addr_16 = "0x160f5f00288e9e1cc8655b327e081566e580a71d".parse().unwrap();
+        let filter_json = json!({
+            "fromBlock": "0x3",
+            "toBlock": "0x5",
+            "toAddress": [test_addr_d8],
+        });
+        let filter: TraceFilter = serde_json::from_value(filter_json).unwrap();
+        let matcher = filter.matcher();
+
+        let call = |to| TransactionTrace {
+            action: Action::Call(CallAction {
+                from: test_addr_16,
+                call_type: Default::default(),
+                gas: Default::default(),
+                input: Default::default(),
+                to,
+                value: Default::default(),
+            }),
+            error: None,
+            result: None,
+            subtraces: 0,
+            trace_address: vec![0],
+        };
+        assert!(matcher.matches_trace(&call(test_addr_d8)));
+        assert!(!matcher.matches_trace(&call(test_addr_16)));
+
+        let create = |result| TransactionTrace {
+            action: Action::Create(CreateAction {
+                from: test_addr_16,
+                value: Default::default(),
+                gas: Default::default(),
+                init: Default::default(),
+            }),
+            error: None,
+            result,
+            subtraces: 0,
+            trace_address: vec![],
+        };
+        let output = CreateOutput {
+            gas_used: Default::default(),
+            code: Default::default(),
+            address: test_addr_d8,
+        };
+        assert!(matcher.matches_trace(&create(Some(TraceOutput::Create(output)))));
+        assert!(!matcher.matches_trace(&create(None)));
+    }
 }
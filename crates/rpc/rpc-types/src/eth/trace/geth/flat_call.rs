@@ -0,0 +1,83 @@
+use crate::trace::parity::LocalizedTransactionTrace;
+use serde::{Deserialize, Serialize};
+
+/// The response object for `debug_traceTransaction` with `"tracer": "flatCallTracer"`, which are
+/// the parity style traces of all calls
+///
+/// <https://github.com/ethereum/go-ethereum/blob/91cb6f863a965481e51d5d9c0e5ccd54796fd967/eth/tracers/native/call_flat.go#L53-L61>
+pub type FlatCallFrame = Vec<LocalizedTransactionTrace>;
+
+/// The config of the `flatCallTracer`
+///
+/// <https://github.com/ethereum/go-ethereum/blob/91cb6f863a965481e51d5d9c0e5ccd54796fd967/eth/tracers/native/call_flat.go#L120-L123>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlatCallConfig {
+    /// Whether calls to precompiles are included, they're excluded by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_precompiles: Option<bool>,
+    /// Whether error messages are converted to parity's format.
+    ///
+    /// Note: errors are always reported in parity's format, so this has no effect.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convert_parity_errors: Option<bool>,
+}
+
+impl FlatCallConfig {
+    /// Returns true if calls to precompiles should be included
+    pub fn is_include_precompiles(&self) -> bool {
+        self.include_precompiles.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::geth::*;
+
+    const DEFAULT: &str = r#"[{
+        "action": {
+            "from": "0x0000000000000000000000000000000000000001",
+            "callType": "call",
+            "gas": "0x5208",
+            "input": "0x",
+            "to": "0x0000000000000000000000000000000000000002",
+            "value": "0x0"
+        },
+        "blockHash": null,
+        "blockNumber": null,
+        "result": {"gasUsed": "0x0", "output": "0x"},
+        "subtraces": 0,
+        "traceAddress": [],
+        "transactionHash": null,
+        "transactionPosition": null,
+        "type": "call"
+    }]"#;
+
+    #[test]
+    fn test_serialize_flat_call_trace() {
+        let mut opts = GethDebugTracingCallOptions::default();
+        opts.tracing_options.tracer =
+            Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::FlatCallTracer));
+        opts.tracing_options.tracer_config = serde_json::to_value(FlatCallConfig {
+            include_precompiles: Some(true),
+            ..Default::default()
+        })
+        .unwrap()
+        .into();
+
+        assert_eq!(
+            serde_json::to_string(&opts).unwrap(),
+            r#"{"tracer":"flatCallTracer","tracerConfig":{"includePrecompiles":true}}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_flat_call_trace() {
+        let trace: GethTrace = serde_json::from_str(DEFAULT).unwrap();
+        match trace {
+            GethTrace::FlatCallTracer(traces) => assert_eq!(traces.len(), 1),
+            _ => panic!("expected flat call traces"),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::trace::geth::{GethDebugBuiltInTracerType, GethDebugTracerConfig, GethTrace};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The config of the `muxTracer`, which maps the tracers to run to their configs
+///
+/// <https://github.com/ethereum/go-ethereum/blob/91cb6f863a965481e51d5d9c0e5ccd54796fd967/eth/tracers/native/mux.go#L37-L62>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuxConfig(pub HashMap<GethDebugBuiltInTracerType, GethDebugTracerConfig>);
+
+/// The response object for `debug_traceTransaction` with `"tracer": "muxTracer"`, which maps the
+/// tracers to their results
+///
+/// <https://github.com/ethereum/go-ethereum/blob/91cb6f863a965481e51d5d9c0e5ccd54796fd967/eth/tracers/native/mux.go#L167-L181>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MuxFrame(pub HashMap<GethDebugBuiltInTracerType, GethTrace>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::geth::*;
+
+    const CONFIG: &str = r#"{
+        "4byteTracer": null,
+        "callTracer": {"onlyTopCall": true},
+        "prestateTracer": {"diffMode": true}
+    }"#;
+
+    #[test]
+    fn test_serialize_mux_trace() {
+        let mut opts = GethDebugTracingCallOptions::default();
+        opts.tracing_options.tracer =
+            Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::MuxTracer));
+
+        assert_eq!(serde_json::to_string(&opts).unwrap(), r#"{"tracer":"muxTracer"}"#);
+    }
+
+    #[test]
+    fn test_deserialize_mux_config() {
+        let config: MuxConfig = serde_json::from_str(CONFIG).unwrap();
+        assert_eq!(config.0.len(), 3);
+        assert!(config.0[&GethDebugBuiltInTracerType::FourByteTracer].is_null());
+
+        let call_config =
+            config.0[&GethDebugBuiltInTracerType::CallTracer].clone().into_call_config().unwrap();
+        assert_eq!(call_config.only_top_call, Some(true));
+
+        let prestate_config = config.0[&GethDebugBuiltInTracerType::PreStateTracer]
+            .clone()
+            .into_pre_state_config()
+            .unwrap();
+        assert_eq!(prestate_config.diff_mode, Some(true));
+    }
+
+    #[test]
+    fn test_mux_frame_roundtrip() {
+        let frame = MuxFrame(HashMap::from([
+            (GethDebugBuiltInTracerType::NoopTracer, NoopFrame::default().into()),
+            (
+                GethDebugBuiltInTracerType::FourByteTracer,
+                FourByteFrame([("0x27dc297e-128".to_string(), 1)].into()).into(),
+            ),
+        ]));
+        let s = serde_json::to_string(&frame).unwrap();
+        let trace: GethTrace = serde_json::from_str(&s).unwrap();
+        assert_eq!(trace, GethTrace::MuxTracer(frame));
+    }
+}
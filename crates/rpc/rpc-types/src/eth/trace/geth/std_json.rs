@@ -0,0 +1,102 @@
+//! Types for the standard json traces, as written by `debug_standardTraceBlockToFile`.
+
+use crate::trace::geth::GethDefaultTracingOptions;
+use alloy_primitives::{Bytes, B256, U256};
+use serde::{Deserialize, Serialize};
+
+/// The config of `debug_standardTraceBlockToFile`.
+///
+/// <https://github.com/ethereum/go-ethereum/blob/fef9ee19f72a0ef7a2f389a03c3a5cd1658e1e23/eth/tracers/api.go#L150-L154>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StdTraceConfig {
+    /// The options of the struct logger
+    #[serde(flatten)]
+    pub tracing_options: GethDefaultTracingOptions,
+    /// Only trace the transaction with this hash, if set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<B256>,
+}
+
+/// A single step of a standard json trace, as specified by
+/// [EIP-3155](https://eips.ethereum.org/EIPS/eip-3155).
+///
+/// <https://github.com/ethereum/go-ethereum/blob/fef9ee19f72a0ef7a2f389a03c3a5cd1658e1e23/eth/tracers/logger/gen_structlog.go#L19-L34>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StdJsonLog {
+    /// Program counter
+    pub pc: u64,
+    /// Opcode to be executed
+    pub op: u8,
+    /// Remaining gas
+    #[serde(with = "crate::serde_helpers::u64_hex")]
+    pub gas: u64,
+    /// Cost for executing the opcode
+    #[serde(with = "crate::serde_helpers::u64_hex")]
+    pub gas_cost: u64,
+    /// Memory, only set if memory capture is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Bytes>,
+    /// Size of the memory
+    pub mem_size: u64,
+    /// EVM stack, only set if stack capture isn't disabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<U256>>,
+    /// Data returned by the last call, only set if return data capture is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_data: Option<Bytes>,
+    /// Current call depth
+    pub depth: u64,
+    /// Refund counter
+    pub refund: u64,
+    /// Name of the opcode
+    pub op_name: String,
+    /// Error message if the step failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The last line of the standard json trace of a transaction.
+///
+/// <https://github.com/ethereum/go-ethereum/blob/fef9ee19f72a0ef7a2f389a03c3a5cd1658e1e23/eth/tracers/logger/logger.go#L476-L480>
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StdJsonSummary {
+    /// Output of the transaction
+    #[serde(serialize_with = "crate::serde_helpers::serialize_hex_string_no_prefix")]
+    pub output: Bytes,
+    /// Gas used by the transaction
+    #[serde(with = "crate::serde_helpers::u64_hex")]
+    pub gas_used: u64,
+    /// Error message if the transaction failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_std_trace_config() {
+        let s = r#"{"enableMemory":true,"txHash":"0x0000000000000000000000000000000000000000000000000000000000000001"}"#;
+        let config: StdTraceConfig = serde_json::from_str(s).unwrap();
+        assert_eq!(config.tracing_options.enable_memory, Some(true));
+        assert_eq!(config.tx_hash, Some(B256::with_last_byte(1)));
+        assert_eq!(serde_json::to_string(&config).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_std_json_log() {
+        let s = r#"{"pc":0,"op":96,"gas":"0x2540be400","gasCost":"0x3","memSize":0,"stack":[],"depth":1,"refund":0,"opName":"PUSH1"}"#;
+        let log: StdJsonLog = serde_json::from_str(s).unwrap();
+        assert_eq!(log.gas, 10_000_000_000);
+        assert_eq!(serde_json::to_string(&log).unwrap(), s);
+
+        let s = r#"{"output":"","gasUsed":"0x5208"}"#;
+        let summary: StdJsonSummary = serde_json::from_str(s).unwrap();
+        assert_eq!(summary.gas_used, 21_000);
+        assert_eq!(serde_json::to_string(&summary).unwrap(), s);
+    }
+}
@@ -1,6 +1,8 @@
 pub use access_list::{AccessList, AccessListItem, AccessListWithGasUsed};
 use alloy_primitives::{Address, Bytes, B256, U128, U256, U64};
 pub use common::TransactionInfo;
+#[cfg(feature = "optimism")]
+pub use optimism::{OptimismTransactionFields, OptimismTransactionReceiptFields};
 pub use receipt::TransactionReceipt;
 pub use request::TransactionRequest;
 use serde::{Deserialize, Serialize};
@@ -9,6 +11,8 @@ pub use typed::*;
 
 mod access_list;
 mod common;
+#[cfg(feature = "optimism")]
+mod optimism;
 mod receipt;
 mod request;
 mod signature;
@@ -71,6 +75,11 @@ pub struct Transaction {
     /// Some(1) for AccessList transaction, None for Legacy
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<U64>,
+
+    /// Optimism specific transaction fields
+    #[cfg(feature = "optimism")]
+    #[serde(flatten)]
+    pub optimism: OptimismTransactionFields,
 }
 
 #[cfg(test)]
@@ -105,6 +114,8 @@ mod tests {
             max_fee_per_gas: Some(U128::from(21)),
             max_priority_fee_per_gas: Some(U128::from(22)),
             max_fee_per_blob_gas: None,
+            #[cfg(feature = "optimism")]
+            optimism: Default::default(),
         };
         let serialized = serde_json::to_string(&transaction).unwrap();
         assert_eq!(
@@ -142,6 +153,8 @@ mod tests {
             max_fee_per_gas: Some(U128::from(21)),
             max_priority_fee_per_gas: Some(U128::from(22)),
             max_fee_per_blob_gas: None,
+            #[cfg(feature = "optimism")]
+            optimism: Default::default(),
         };
         let serialized = serde_json::to_string(&transaction).unwrap();
         assert_eq!(
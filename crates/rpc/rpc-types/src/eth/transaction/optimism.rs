@@ -0,0 +1,47 @@
+//! Optimism specific types related to transactions.
+
+use alloy_primitives::{B256, U128, U256, U64};
+use serde::{Deserialize, Serialize};
+
+/// Optimism specific transaction fields, only set for deposit transactions.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimismTransactionFields {
+    /// Hash that uniquely identifies the source of the deposit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_hash: Option<B256>,
+    /// The ETH value to mint on L2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mint: Option<U128>,
+    /// Field indicating whether the transaction is a system transaction, and therefore
+    /// exempt from the L2 gas limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_system_tx: Option<bool>,
+}
+
+/// Optimism specific transaction receipt fields.
+///
+/// The deposit nonce and the deposit receipt version are only set for deposit transactions, the L1
+/// fee fields are only set for all other transactions.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptimismTransactionReceiptFields {
+    /// Deposit nonce for deposit transactions post-regolith
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_nonce: Option<U64>,
+    /// Deposit receipt version for deposit transactions post-canyon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_receipt_version: Option<U64>,
+    /// L1 fee for the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1_fee: Option<U256>,
+    /// L1 fee scalar for the transaction, as a decimal string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1_fee_scalar: Option<String>,
+    /// L1 gas price for the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1_gas_price: Option<U256>,
+    /// L1 gas used for the transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1_gas_used: Option<U256>,
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "optimism")]
+use super::OptimismTransactionReceiptFields;
 use crate::Log;
 use alloy_primitives::{Address, Bloom, B256, U128, U256, U64, U8};
 use serde::{Deserialize, Serialize};
@@ -51,4 +53,8 @@ pub struct TransactionReceipt {
     /// EIP-2718 Transaction type, Some(1) for AccessList transaction, None for Legacy
     #[serde(rename = "type")]
     pub transaction_type: U8,
+    /// Optimism specific transaction receipt fields
+    #[cfg(feature = "optimism")]
+    #[serde(flatten)]
+    pub optimism: OptimismTransactionReceiptFields,
 }
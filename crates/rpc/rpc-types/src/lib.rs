@@ -12,18 +12,22 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 mod admin;
+mod debug;
 mod eth;
 mod mev;
 mod net;
+mod optimism;
 mod otterscan;
 mod peer;
 mod rpc;
 mod serde_helpers;
 
 pub use admin::*;
+pub use debug::*;
 pub use eth::*;
 pub use mev::*;
 pub use net::*;
+pub use optimism::*;
 pub use otterscan::*;
 pub use peer::*;
 pub use rpc::*;
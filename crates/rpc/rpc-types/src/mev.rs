@@ -79,6 +79,12 @@ pub enum BundleItem {
         /// If true, the transaction can revert without the bundle being considered invalid.
         can_revert: bool,
     },
+    /// A nested bundle, which is executed atomically as part of this bundle, e.g. the bundle of a
+    /// user transaction that's backrun.
+    Bundle {
+        /// The nested bundle.
+        bundle: Box<SendBundleRequest>,
+    },
 }
 
 /// Requirements for the bundle to be included in the block.
@@ -798,6 +804,57 @@ mod tests {
         assert_eq!(bundle, expected[0]);
     }
 
+    #[test]
+    fn can_deserialize_nested_bundle() {
+        let str = r#"
+        {
+            "version": "v0.1",
+            "inclusion": {
+                "block": "0x1",
+                "maxBlock": "0x5"
+            },
+            "body": [{
+                "bundle": {
+                    "version": "v0.1",
+                    "inclusion": {
+                        "block": "0x1"
+                    },
+                    "body": [{
+                        "hash": "0xe3a4cd3ee4ff6b3ac5ff1a4f5b2bc6c1e2c7e3ab3ab57e0b0bbeb3f0d3b8b0e5"
+                    }],
+                    "privacy": {
+                        "hints": ["calldata", "logs"]
+                    }
+                }
+            }, {
+                "tx": "0x02f86b0180843b9aca00852ecc889a0082520894c87037874aed04e51c29f582394217a0a2b89d808080c080a0a463985c616dd8ee17d7ef9112af4e6e06a27b071525b42182fe7b0b5c8b4925a00af5ca177ffef2ff28449292505d41be578bebb77110dfc09361d2fb56998260",
+                "canRevert": true
+            }],
+            "validity": {
+                "refund": [{
+                    "bodyIdx": 0,
+                    "percent": 90
+                }]
+            }
+        }
+        "#;
+        let bundle: SendBundleRequest = serde_json::from_str(str).unwrap();
+        assert_eq!(bundle.inclusion.max_block_number(), Some(5));
+        assert_eq!(bundle.bundle_body.len(), 2);
+
+        let BundleItem::Bundle { bundle: nested } = &bundle.bundle_body[0] else {
+            panic!("expected nested bundle")
+        };
+        assert!(matches!(nested.bundle_body[0], BundleItem::Hash { .. }));
+        assert!(nested.privacy.as_ref().unwrap().hints.as_ref().unwrap().has_logs());
+        assert!(matches!(bundle.bundle_body[1], BundleItem::Tx { can_revert: true, .. }));
+        assert_eq!(bundle.validity.as_ref().unwrap().refund.as_ref().unwrap()[0].percent, 90);
+
+        let serialized = serde_json::to_value(&bundle).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(str).unwrap();
+        assert_eq!(serialized, expected);
+    }
+
     #[test]
     fn can_serialize_privacy_hint() {
         let hint = PrivacyHint {
@@ -0,0 +1,123 @@
+//! Types for the `optimism_` rollup namespace.
+//!
+//! These mirror the types served by the OP Stack rollup node (`op-node`).
+
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+
+/// Identifies a block by hash and number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollupBlockId {
+    /// The hash of the block.
+    pub hash: B256,
+    /// The number of the block.
+    pub number: u64,
+}
+
+/// A reference to an L2 block, including its L1 origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct L2BlockRef {
+    /// The hash of the block.
+    pub hash: B256,
+    /// The number of the block.
+    pub number: u64,
+    /// The hash of the parent block.
+    pub parent_hash: B256,
+    /// The timestamp of the block.
+    pub timestamp: u64,
+    /// The L1 block the L2 block was derived from.
+    #[serde(rename = "l1origin")]
+    pub l1_origin: RollupBlockId,
+    /// The position of the block within the epoch of its L1 origin.
+    pub sequence_number: u64,
+}
+
+/// The L2 output of a block, as proposed to L1.
+///
+/// See also the [L2 output commitment specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/proposals.md#l2-output-commitment-construction).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputResponse {
+    /// The version of the output root.
+    pub version: B256,
+    /// The output root of the block.
+    pub output_root: B256,
+    /// The block the output root was computed for.
+    pub block_ref: L2BlockRef,
+    /// The storage root of the `L2ToL1MessagePasser` predeploy at the block.
+    pub withdrawal_storage_root: B256,
+    /// The state root of the block.
+    pub state_root: B256,
+}
+
+/// The L2 sync status of the node.
+///
+/// Unlike the sync status of the rollup node, this does not include the L1 view of the node,
+/// since the execution client does not track L1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollupSyncStatus {
+    /// The latest L2 block.
+    pub unsafe_l2: L2BlockRef,
+    /// The latest L2 block that is derived from L1.
+    pub safe_l2: L2BlockRef,
+    /// The latest L2 block that is derived from finalized L1 data.
+    pub finalized_l2: L2BlockRef,
+}
+
+/// The genesis of a rollup, which is the first block of the chain that is derived from L1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollupGenesis {
+    /// The first L2 block of the rollup.
+    pub l2: RollupBlockId,
+    /// The timestamp of the first L2 block of the rollup.
+    pub l2_time: u64,
+}
+
+/// The rollup configuration of the chain, as far as it is known to the execution client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollupConfig {
+    /// The genesis of the rollup.
+    pub genesis: RollupGenesis,
+    /// The chain id of the L2 chain.
+    pub l2_chain_id: u64,
+    /// The activation timestamp of the Regolith hardfork, if scheduled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regolith_time: Option<u64>,
+    /// The activation timestamp of the Canyon hardfork, if scheduled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canyon_time: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_output_response() {
+        let s = r#"{"version":"0x0000000000000000000000000000000000000000000000000000000000000000","outputRoot":"0x61da4b7a9ec4e3c63d2b4b0b27a5b50e5e4cfc6c8e6b1ff9ef1e8f2d0e4cb3a1","blockRef":{"hash":"0x1c8b0d1ae8c0c5b0bd9ec1ec8f5c1b7b06b2a6f4b5b8d6f2a3dc0f8e0b3f7a92","number":105235064,"parentHash":"0xdbf6a80fef073de06add9b0d14026d6e5a86c85f6d102c36d3d8e9cf89c2afd3","timestamp":1686068905,"l1origin":{"hash":"0x438335a20d98863a4c0c97999eb2481921ccd28553eac6f913af7c12aec04108","number":17422590},"sequenceNumber":1},"withdrawalStorageRoot":"0x8ed4baae3a927be3dea54996b4d5899f8c01e7594bf50b17dc1e741388ce3d12","stateRoot":"0xeddb4c1786789419153a27c4c80ff44a2226b6eda04f7e22ce5bae892ea568eb"}"#;
+        let output: OutputResponse = serde_json::from_str(s).unwrap();
+        assert_eq!(output.block_ref.l1_origin.number, 17422590);
+        assert_eq!(output.block_ref.sequence_number, 1);
+        assert_eq!(serde_json::to_string(&output).unwrap(), s);
+    }
+
+    #[test]
+    fn serde_rollup_config() {
+        let config = RollupConfig {
+            genesis: RollupGenesis {
+                l2: RollupBlockId { hash: B256::ZERO, number: 105235063 },
+                l2_time: 1686068903,
+            },
+            l2_chain_id: 10,
+            regolith_time: Some(0),
+            canyon_time: None,
+        };
+        let s = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            s,
+            r#"{"genesis":{"l2":{"hash":"0x0000000000000000000000000000000000000000000000000000000000000000","number":105235063},"l2_time":1686068903},"l2_chain_id":10,"regolith_time":0}"#
+        );
+        assert_eq!(serde_json::from_str::<RollupConfig>(&s).unwrap(), config);
+    }
+}
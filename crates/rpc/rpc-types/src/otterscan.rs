@@ -1,9 +1,11 @@
 use crate::{Block, BlockTransactions, Rich, Transaction, TransactionReceipt};
 use alloy_primitives::{Address, Bytes, U256};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Operation type enum for `InternalOperation` struct
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+///
+/// Serialized as its numeric value, as expected by Otterscan.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum OperationType {
     /// Operation Transfer
     OpTransfer = 0,
@@ -15,24 +17,58 @@ pub enum OperationType {
     OpCreate2 = 3,
 }
 
+impl Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            0 => Ok(Self::OpTransfer),
+            1 => Ok(Self::OpSelfDestruct),
+            2 => Ok(Self::OpCreate),
+            3 => Ok(Self::OpCreate2),
+            ty => Err(serde::de::Error::custom(format!("invalid operation type {ty}"))),
+        }
+    }
+}
+
 /// Custom struct for otterscan `getInternalOperations` RPC response
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct InternalOperation {
-    r#type: OperationType,
-    from: Address,
-    to: Address,
-    value: u128,
+    /// The type of the operation.
+    pub r#type: OperationType,
+    /// The address the value is sent from, or the creator of a contract.
+    pub from: Address,
+    /// The address the value is sent to, or the created contract.
+    pub to: Address,
+    /// The value transferred by the operation.
+    pub value: U256,
 }
 
 /// Custom struct for otterscan `traceTransaction` RPC response
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TraceEntry {
-    r#type: String,
-    depth: u32,
-    from: Address,
-    to: Address,
-    value: u128,
-    input: Bytes,
+    /// The kind of the call, e.g. `CALL`, `DELEGATECALL`, `CREATE2` or `SELFDESTRUCT`.
+    pub r#type: String,
+    /// The depth of the call, where the top level call has a depth of 0.
+    pub depth: u32,
+    /// The caller.
+    pub from: Address,
+    /// The callee, or the created contract.
+    pub to: Address,
+    /// The value transferred by the call, `None` for static and delegate calls.
+    pub value: Option<U256>,
+    /// The input of the call, or the init code of a contract creation.
+    pub input: Bytes,
 }
 
 /// Internal issuance struct for `BlockDetails` struct
@@ -66,9 +102,11 @@ pub struct BlockDetails {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OtsTransactionReceipt {
+    /// The transaction receipt.
     #[serde(flatten)]
-    receipt: TransactionReceipt,
-    timestamp: u64,
+    pub receipt: TransactionReceipt,
+    /// The timestamp of the block the transaction is included in.
+    pub timestamp: u64,
 }
 
 /// Custom struct for otterscan `getBlockTransactions` RPC response
@@ -83,17 +121,24 @@ pub struct OtsBlockTransactions {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionsWithReceipts {
-    txs: Vec<Transaction>,
-    receipts: Vec<OtsTransactionReceipt>,
-    first_page: bool,
-    last_page: bool,
+    /// The transactions, from the newest to the oldest.
+    pub txs: Vec<Transaction>,
+    /// The receipts of the transactions, in the same order.
+    pub receipts: Vec<OtsTransactionReceipt>,
+    /// Whether this page contains the newest transactions.
+    pub first_page: bool,
+    /// Whether this page contains the oldest transactions.
+    pub last_page: bool,
 }
 
 /// Custom struct for otterscan `getContractCreator` RPC responses
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ContractCreator {
-    tx: Transaction,
-    creator: Address,
+    /// The transaction that created the contract
+    pub tx: Transaction,
+    /// The address that created the contract, which is the contract the creation was made from
+    /// for internal creations.
+    pub creator: Address,
 }
 
 impl From<Block> for OtsBlock {
@@ -117,3 +162,26 @@ impl From<Rich<Block>> for BlockDetails {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_internal_operation() {
+        let s = r#"{"type":3,"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","value":"0x0"}"#;
+        let operation: InternalOperation = serde_json::from_str(s).unwrap();
+        assert_eq!(operation.r#type, OperationType::OpCreate2);
+        assert_eq!(serde_json::to_string(&operation).unwrap(), s);
+
+        assert!(serde_json::from_str::<OperationType>("4").is_err());
+    }
+
+    #[test]
+    fn serde_trace_entry() {
+        let s = r#"{"type":"STATICCALL","depth":1,"from":"0x0000000000000000000000000000000000000001","to":"0x0000000000000000000000000000000000000002","value":null,"input":"0x70a08231"}"#;
+        let entry: TraceEntry = serde_json::from_str(s).unwrap();
+        assert_eq!(entry.value, None);
+        assert_eq!(serde_json::to_string(&entry).unwrap(), s);
+    }
+}
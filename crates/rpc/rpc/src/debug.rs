@@ -7,45 +7,75 @@ use crate::{
         },
         EthTransactions, TransactionSource,
     },
-    result::{internal_rpc_err, ToRpcResult},
+    result::{internal_rpc_err, invalid_params_rpc_err, ToRpcResult},
     BlockingTaskGuard, EthApiSpec,
 };
 use alloy_rlp::{Decodable, Encodable};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
+use reth_beacon_consensus::{BeaconConsensusEngineHandle, BeaconSetHeadError};
+use reth_interfaces::{RethError, RethResult};
 use reth_primitives::{
-    revm::env::tx_env_with_recovered, Account, Address, Block, BlockId, BlockNumberOrTag, Bytes,
-    TransactionSigned, B256,
+    hex, keccak256, revm::env::tx_env_with_recovered, Account, Address, Block, BlockId,
+    BlockNumber, BlockNumberOrTag, Bytes, Header, Receipts, SealedBlock, TransactionSigned, B256,
+    U256,
+};
+use reth_provider::{
+    BadBlocksReader, BlockReaderIdExt, BundleStateWithReceipts, ChainSpecProvider, ChangeSetReader,
+    HashedAccountEntry, HeaderProvider, StateProvider, StateProviderBox, StateRootProvider,
 };
-use reth_provider::{BlockReaderIdExt, HeaderProvider, StateProviderBox};
 use reth_revm::{
     database::{StateProviderDatabase, SubState},
+    processor::EVMProcessor,
+    stack::{Hook, InspectorStack, InspectorStackConfig},
     tracing::{
         js::{JsDbRequest, JsInspector},
-        FourByteInspector, TracingInspector, TracingInspectorConfig,
+        FourByteInspector, MuxInspector, TracingInspector, TracingInspectorConfig,
     },
 };
 use reth_rpc_api::DebugApiServer;
 use reth_rpc_types::{
     trace::geth::{
         BlockTraceResult, FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerType,
-        GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, NoopFrame, TraceResult,
+        GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, NoopFrame, StdJsonLog,
+        StdJsonSummary, StdTraceConfig, TraceResult,
     },
-    BlockError, Bundle, CallRequest, RichBlock, StateContext,
+    AccountRange, BadBlock, BlockError, BlockTransactionsKind, Bundle, CallRequest, DumpAccount,
+    StateContext, StorageRange, StorageRangeEntry, TransactionInfo,
+};
+use reth_rpc_types_compat::{
+    block::{from_block, from_block_with_tx_hashes},
+    state::from_primitive_dump_account,
 };
 use reth_tasks::TaskSpawner;
 use revm::{
-    db::{CacheDB, EmptyDB},
+    db::{
+        states::{bundle_state::BundleRetention, BundleState},
+        CacheDB, EmptyDB,
+    },
     primitives::Env,
 };
 use revm_primitives::{
     db::{DatabaseCommit, DatabaseRef},
-    BlockEnv, CfgEnv,
+    BlockEnv, CfgEnv, ExecutionResult,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
 };
-use std::sync::Arc;
 use tokio::sync::{mpsc, AcquireError, OwnedSemaphorePermit};
 use tokio_stream::{wrappers::ReceiverStream, StreamExt};
 
+/// The maximum number of accounts returned by `debug_accountRange`, same as geth.
+const ACCOUNT_RANGE_MAX_RESULTS: usize = 256;
+
+/// The maximum number of storage slots of each account returned by `debug_accountRange`, the rest
+/// of the storage can be paged through with `debug_storageRangeAt`.
+const ACCOUNT_RANGE_MAX_STORAGE_SLOTS: usize = 1024;
+
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
@@ -57,14 +87,29 @@ pub struct DebugApi<Provider, Eth> {
 
 impl<Provider, Eth> DebugApi<Provider, Eth> {
     /// Create a new instance of the [DebugApi]
+    ///
+    /// Trace files, e.g. of `debug_standardTraceBlockToFile`, are written to `trace_dir`, the
+    /// methods that write them are unsupported if it isn't set.
+    /// `max_changeset_blocks` is the maximum block range of `debug_getModifiedAccountsBy*`.
+    /// `debug_setHead` is only available if the handle of the consensus `engine` is set.
     pub fn new(
         provider: Provider,
         eth: Eth,
         task_spawner: Box<dyn TaskSpawner>,
         blocking_task_guard: BlockingTaskGuard,
+        trace_dir: Option<PathBuf>,
+        max_changeset_blocks: u64,
+        engine: Option<BeaconConsensusEngineHandle>,
     ) -> Self {
-        let inner =
-            Arc::new(DebugApiInner { provider, eth_api: eth, task_spawner, blocking_task_guard });
+        let inner = Arc::new(DebugApiInner {
+            provider,
+            eth_api: eth,
+            task_spawner,
+            blocking_task_guard,
+            trace_dir,
+            max_changeset_blocks,
+            engine,
+        });
         Self { inner }
     }
 }
@@ -73,7 +118,12 @@ impl<Provider, Eth> DebugApi<Provider, Eth> {
 
 impl<Provider, Eth> DebugApi<Provider, Eth>
 where
-    Provider: BlockReaderIdExt + HeaderProvider + 'static,
+    Provider: BlockReaderIdExt
+        + HeaderProvider
+        + ChainSpecProvider
+        + ChangeSetReader
+        + BadBlocksReader
+        + 'static,
     Eth: EthTransactions + 'static,
 {
     /// Acquires a permit to execute a tracing call.
@@ -81,10 +131,23 @@ where
         self.inner.blocking_task_guard.clone().acquire_owned().await
     }
 
+    /// Returns the total difficulty at the given block.
+    ///
+    /// It's derived from the parent, so that it's also known for blocks that were rejected.
+    fn total_difficulty(&self, header: &Header) -> EthResult<U256> {
+        let parent_td = self
+            .inner
+            .provider
+            .header_td(&header.parent_hash)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        Ok(parent_td + header.difficulty)
+    }
+
     /// Trace the entire block asynchronously
     async fn trace_block_with(
         &self,
         at: BlockId,
+        block_hash: B256,
         transactions: Vec<TransactionSigned>,
         cfg: CfgEnv,
         block_env: BlockEnv,
@@ -98,13 +161,20 @@ where
                 let mut results = Vec::with_capacity(transactions.len());
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
-                let mut transactions = transactions.into_iter().peekable();
-                while let Some(tx) = transactions.next() {
+                let mut transactions = transactions.into_iter().enumerate().peekable();
+                while let Some((index, tx)) = transactions.next() {
                     let tx = tx.into_ecrecovered().ok_or(BlockError::InvalidSignature)?;
+                    let tx_info = TransactionInfo {
+                        hash: Some(tx.hash()),
+                        index: Some(index as u64),
+                        block_hash: Some(block_hash),
+                        block_number: Some(block_env.number.try_into().unwrap_or(u64::MAX)),
+                        base_fee: Some(block_env.basefee.try_into().unwrap_or(u64::MAX)),
+                    };
                     let tx = tx_env_with_recovered(&tx);
                     let env = Env { cfg: cfg.clone(), block: block_env.clone(), tx };
                     let (result, state_changes) =
-                        this.trace_transaction(opts.clone(), env, at, &mut db)?;
+                        this.trace_transaction(opts.clone(), env, at, tx_info, &mut db)?;
                     results.push(TraceResult::Success { result });
 
                     if transactions.peek().is_some() {
@@ -119,6 +189,300 @@ where
             .await
     }
 
+    /// Returns up to `max_results` accounts of the state at the given block, in the order of the
+    /// hashes of their addresses, starting at the hash `start`.
+    async fn account_range(
+        &self,
+        block_number: BlockNumberOrTag,
+        start: Bytes,
+        max_results: u64,
+        nocode: bool,
+        nostorage: bool,
+    ) -> EthResult<AccountRange> {
+        let block_number = self
+            .inner
+            .provider
+            .convert_block_number(block_number)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let root = self
+            .inner
+            .provider
+            .header_by_number(block_number)?
+            .ok_or(EthApiError::UnknownBlockNumber)?
+            .state_root;
+
+        // `start` can be a prefix of the hashed address
+        let mut start_key = B256::ZERO;
+        let len = start.len().min(start_key.len());
+        start_key[..len].copy_from_slice(&start[..len]);
+
+        let limit = match max_results as usize {
+            0 => ACCOUNT_RANGE_MAX_RESULTS,
+            max_results => max_results.min(ACCOUNT_RANGE_MAX_RESULTS),
+        };
+        let storage_limit = if nostorage { 0 } else { ACCOUNT_RANGE_MAX_STORAGE_SLOTS };
+
+        self.inner
+            .eth_api
+            .spawn_with_state_at_block(block_number.into(), move |state| {
+                let mut accounts = state.account_range(start_key, limit + 1, storage_limit)?;
+                let next = if accounts.len() > limit {
+                    accounts
+                        .pop()
+                        .map(|entry| Bytes::copy_from_slice(entry.hashed_address.as_slice()))
+                } else {
+                    None
+                };
+
+                let accounts = accounts
+                    .into_iter()
+                    .map(|entry| {
+                        let hashed_address = entry.hashed_address;
+                        Ok((hashed_address, dump_account(&state, entry, nocode, nostorage)?))
+                    })
+                    .collect::<RethResult<_>>()?;
+                Ok(AccountRange { root, accounts, next })
+            })
+            .await
+    }
+
+    /// Returns up to `max_result` storage slots of the account, in the order of the hashes of
+    /// their keys, starting at the hash `key_start`, in the state before the transaction at the
+    /// given index of the block is executed.
+    async fn storage_range_at(
+        &self,
+        block_hash: B256,
+        tx_idx: usize,
+        address: Address,
+        key_start: B256,
+        max_result: u64,
+    ) -> EthResult<StorageRange> {
+        let block = self
+            .inner
+            .eth_api
+            .block_by_id(block_hash.into())
+            .await?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        if tx_idx > block.body.len() {
+            return Err(EthApiError::UnknownBlockOrTxIndex)
+        }
+        let total_difficulty = self.total_difficulty(&block.header)?;
+        let limit = max_result as usize;
+        let chain_spec = self.inner.provider.chain_spec();
+
+        // replay the transactions before the given index on top of the parent block's state
+        let mut block = block.unseal();
+        block.body.truncate(tx_idx);
+        self.inner
+            .eth_api
+            .spawn_with_state_at_block(block.parent_hash.into(), move |state| {
+                let mut executor =
+                    EVMProcessor::new_with_db(chain_spec, StateProviderDatabase::new(&state));
+                executor
+                    .inspect_transactions(&block, total_difficulty, None, |_, _, _| {})
+                    .map_err(RethError::from)?;
+
+                let db = executor.db_mut();
+                db.merge_transitions(BundleRetention::PlainState);
+                let changes = db.take_bundle();
+                storage_range(&state, &changes, address, key_start, limit)
+            })
+            .await
+    }
+
+    /// Returns the addresses of the accounts modified after block `start_number` up to and
+    /// including block `end_number`, or in block `start_number` if no end block is given.
+    ///
+    /// The accounts are read from the account and storage changesets of the blocks, so this fails
+    /// if the changesets of the range were pruned.
+    fn modified_accounts(
+        &self,
+        start_number: BlockNumber,
+        end_number: Option<BlockNumber>,
+    ) -> EthResult<Vec<Address>> {
+        let range = match end_number {
+            Some(end_number) if start_number >= end_number => {
+                return Err(EthApiError::InvalidBlockRange)
+            }
+            Some(end_number) => start_number + 1..=end_number,
+            None => start_number..=start_number,
+        };
+        let max_blocks = self.inner.max_changeset_blocks;
+        if range.end() - range.start() >= max_blocks {
+            return Err(EthApiError::BlockRangeTooLarge(max_blocks))
+        }
+        if *range.end() > self.inner.provider.best_block_number()? {
+            return Err(EthApiError::UnknownBlockNumber)
+        }
+
+        let mut accounts = BTreeSet::new();
+        for block_number in range {
+            let account_changes = self.inner.provider.account_block_changeset(block_number)?;
+            accounts.extend(account_changes.into_iter().map(|account| account.address));
+            let storage_changes = self.inner.provider.storage_block_changeset(block_number)?;
+            accounts.extend(storage_changes.into_iter().map(|(address, _)| address));
+        }
+        Ok(accounts.into_iter().collect())
+    }
+
+    /// Replays the block with the given hash and returns the state root after each transaction.
+    async fn intermediate_roots(&self, block_hash: B256) -> EthResult<Vec<B256>> {
+        let block = self
+            .inner
+            .eth_api
+            .block_by_id(block_hash.into())
+            .await?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let total_difficulty = self.total_difficulty(&block.header)?;
+        let chain_spec = self.inner.provider.chain_spec();
+
+        let block = block.unseal();
+        self.inner
+            .eth_api
+            .spawn_with_state_at_block(block.parent_hash.into(), move |state| {
+                let mut executor =
+                    EVMProcessor::new_with_db(chain_spec, StateProviderDatabase::new(&state));
+
+                // the changes of each transaction, the roots are computed on top of each other
+                let mut changes = Vec::with_capacity(block.body.len());
+                executor
+                    .inspect_transactions(&block, total_difficulty, None, |executor, _, _| {
+                        let db = executor.db_mut();
+                        db.merge_transitions(BundleRetention::PlainState);
+                        changes.push(BundleStateWithReceipts::new(
+                            db.take_bundle(),
+                            Receipts::new(),
+                            block.number,
+                        ));
+                    })
+                    .map_err(RethError::from)?;
+                Ok(state.state_roots(changes)?)
+            })
+            .await
+    }
+
+    /// Replays the block with the given hash and writes a standard json trace of each transaction,
+    /// or only of the configured one, to a file in the trace directory.
+    ///
+    /// Returns the paths of the written files.
+    async fn standard_trace_block_to_file(
+        &self,
+        block_hash: B256,
+        config: StdTraceConfig,
+    ) -> EthResult<Vec<PathBuf>> {
+        let block = self
+            .inner
+            .eth_api
+            .block_by_id(block_hash.into())
+            .await?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        self.std_json_trace_block_to_files(block, config).await
+    }
+
+    /// Same as [Self::standard_trace_block_to_file], but for a block that was rejected as invalid.
+    async fn standard_trace_bad_block_to_file(
+        &self,
+        block_hash: B256,
+        config: StdTraceConfig,
+    ) -> EthResult<Vec<PathBuf>> {
+        let block = self.bad_block(block_hash)?;
+        self.std_json_trace_block_to_files(block, config).await
+    }
+
+    /// Replays the block on top of its parent's state and writes the standard json traces of its
+    /// transactions to the trace directory.
+    async fn std_json_trace_block_to_files(
+        &self,
+        block: SealedBlock,
+        config: StdTraceConfig,
+    ) -> EthResult<Vec<PathBuf>> {
+        let StdTraceConfig { tracing_options, tx_hash } = config;
+        let traced_tx_index = match tx_hash {
+            Some(tx_hash) => Some(
+                block
+                    .body
+                    .iter()
+                    .position(|tx| tx.hash() == tx_hash)
+                    .ok_or(EthApiError::TransactionNotFound)?,
+            ),
+            None => None,
+        };
+        let trace_dir = self
+            .inner
+            .trace_dir
+            .clone()
+            .ok_or(EthApiError::Unsupported("no trace directory is configured"))?;
+        let total_difficulty = self.total_difficulty(&block.header)?;
+        let chain_spec = self.inner.provider.chain_spec();
+
+        let block_hash = block.hash;
+        let mut block = block.unseal();
+        if let Some(index) = traced_tx_index {
+            // the transactions after the traced one don't need to be executed
+            block.body.truncate(index + 1);
+        }
+        self.inner
+            .eth_api
+            .spawn_with_state_at_block(block.parent_hash.into(), move |state| {
+                std::fs::create_dir_all(&trace_dir).map_err(trace_file_error)?;
+
+                let mut executor =
+                    EVMProcessor::new_with_db(chain_spec, StateProviderDatabase::new(state));
+                executor.set_stack(InspectorStack::new(InspectorStackConfig {
+                    tracing: Some(TracingInspectorConfig::from_geth_config(&tracing_options)),
+                    hook: tx_hash.map_or(Hook::All, Hook::Transaction),
+                    ..Default::default()
+                }));
+
+                let mut files = Vec::new();
+                let mut write_result = Ok(());
+                let mut index = 0;
+                executor
+                    .inspect_transactions(&block, total_difficulty, None, |executor, tx, result| {
+                        let inspector = executor
+                            .stack_mut()
+                            .take_tracing_inspector()
+                            .expect("tracing inspector is set");
+                        let tx_index = index;
+                        index += 1;
+                        if write_result.is_err() || traced_tx_index.is_some_and(|i| i != tx_index)
+                        {
+                            return
+                        }
+
+                        let logs =
+                            inspector.into_geth_builder().geth_std_json_traces(&tracing_options);
+                        let summary = StdJsonSummary {
+                            gas_used: result.gas_used(),
+                            error: match result {
+                                ExecutionResult::Success { .. } => None,
+                                ExecutionResult::Revert { .. } => {
+                                    Some("execution reverted".to_string())
+                                }
+                                ExecutionResult::Halt { reason, .. } => {
+                                    Some(format!("{reason:?}"))
+                                }
+                            },
+                            output: result.output().cloned().unwrap_or_default(),
+                        };
+
+                        // same naming scheme as geth
+                        let hash = tx.hash();
+                        let path = trace_dir.join(format!(
+                            "block_0x{}-{tx_index}-0x{}.jsonl",
+                            hex::encode(&block_hash[..4]),
+                            hex::encode(&hash[..4])
+                        ));
+                        write_result = write_std_json_trace(&path, &logs, &summary);
+                        files.push(path);
+                    })
+                    .map_err(RethError::from)?;
+                write_result.map_err(trace_file_error)?;
+                Ok(files)
+            })
+            .await
+    }
+
     /// Replays the given block and returns the trace of each transaction.
     ///
     /// This expects a rlp encoded block
@@ -136,7 +500,60 @@ where
 
         // we trace on top the block's parent block
         let parent = block.parent_hash;
-        self.trace_block_with(parent.into(), block.body, cfg, block_env, opts).await
+        let block_hash = block.header.hash_slow();
+        self.trace_block_with(parent.into(), block_hash, block.body, cfg, block_env, opts).await
+    }
+
+    /// Replays the bad block with the given hash on top of its parent's state and returns the
+    /// trace of each transaction.
+    pub async fn debug_trace_bad_block(
+        &self,
+        block_hash: B256,
+        opts: GethDebugTracingOptions,
+    ) -> EthResult<Vec<TraceResult>> {
+        let block = self.bad_block(block_hash)?;
+        let (cfg, block_env) = self.inner.eth_api.evm_env_for_raw_block(&block.header).await?;
+
+        let parent = block.parent_hash;
+        self.trace_block_with(parent.into(), block.hash, block.unseal().body, cfg, block_env, opts)
+            .await
+    }
+
+    /// Returns the recent blocks that were rejected as invalid.
+    fn bad_blocks(&self) -> EthResult<Vec<BadBlock>> {
+        self.inner
+            .provider
+            .bad_blocks()?
+            .into_iter()
+            .map(|bad_block| {
+                let (block, error) = (bad_block.block, bad_block.error);
+                let hash = block.hash;
+                let total_difficulty = self
+                    .inner
+                    .provider
+                    .header_td(&block.parent_hash)?
+                    .map(|td| td + block.difficulty)
+                    .unwrap_or_default();
+                let block = block.unseal();
+                let rlp = alloy_rlp::encode(&block).into();
+                // the block may have been rejected because of an invalid signature
+                let block = from_block(
+                    block.clone(),
+                    total_difficulty,
+                    BlockTransactionsKind::Full,
+                    Some(hash),
+                )
+                .unwrap_or_else(|_| from_block_with_tx_hashes(block, total_difficulty, Some(hash)));
+                Ok(BadBlock { hash, block: block.into(), rlp, error })
+            })
+            .collect()
+    }
+
+    /// Returns the bad block with the given hash.
+    fn bad_block(&self, block_hash: B256) -> EthResult<SealedBlock> {
+        let bad_block =
+            self.inner.provider.bad_block(block_hash)?.ok_or(EthApiError::UnknownBlockNumber)?;
+        Ok(bad_block.block)
     }
 
     /// Replays a block and returns the trace of each transaction.
@@ -161,7 +578,7 @@ where
         // its parent block's state
         let state_at = block.parent_hash;
 
-        self.trace_block_with(state_at.into(), block.body, cfg, block_env, opts).await
+        self.trace_block_with(state_at.into(), block.hash, block.body, cfg, block_env, opts).await
     }
 
     /// Trace the transaction according to the provided options.
@@ -188,7 +605,7 @@ where
             .eth_api
             .spawn_with_state_at_block(state_at, move |state| {
                 // configure env for the target transaction
-                let tx = transaction.into_recovered();
+                let (tx, tx_info) = transaction.split();
 
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
                 // replay all transactions prior to the targeted transaction
@@ -201,7 +618,8 @@ where
                 )?;
 
                 let env = Env { cfg, block: block_env, tx: tx_env_with_recovered(&tx) };
-                this.trace_transaction(opts, env, state_at, &mut db).map(|(trace, _)| trace)
+                this.trace_transaction(opts, env, state_at, tx_info, &mut db)
+                    .map(|(trace, _)| trace)
             })
             .await
     }
@@ -283,6 +701,52 @@ where
                                 .await?;
                         return Ok(frame.into())
                     }
+                    GethDebugBuiltInTracerType::FlatCallTracer => {
+                        let flat_call_config = tracer_config
+                            .into_flat_call_config()
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+
+                        let mut inspector = TracingInspector::new(
+                            TracingInspectorConfig::from_flat_call_config(&flat_call_config),
+                        );
+
+                        let frame = self
+                            .inner
+                            .eth_api
+                            .spawn_with_call_at(call, at, overrides, move |db, env| {
+                                let (res, _) = inspect(db, env, &mut inspector)?;
+                                let frame = inspector
+                                    .with_transaction_gas_used(res.result.gas_used())
+                                    .into_parity_builder()
+                                    .into_localized_transaction_traces(TransactionInfo::default());
+                                Ok(frame)
+                            })
+                            .await?;
+                        return Ok(frame.into())
+                    }
+                    GethDebugBuiltInTracerType::MuxTracer => {
+                        let mux_config = tracer_config
+                            .into_mux_config()
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+
+                        let mut inspector = MuxInspector::try_from_config(mux_config)
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+
+                        let frame = self
+                            .inner
+                            .eth_api
+                            .spawn_with_call_at(call, at, overrides, move |db, env| {
+                                let (res, _, db) = inspect_and_return_db(db, env, &mut inspector)?;
+                                let frame = inspector.try_into_mux_frame(
+                                    &res,
+                                    &db,
+                                    TransactionInfo::default(),
+                                )?;
+                                Ok(frame)
+                            })
+                            .await?;
+                        return Ok(frame.into())
+                    }
                     GethDebugBuiltInTracerType::NoopTracer => Ok(NoopFrame::default().into()),
                 },
                 GethDebugTracerType::JsTracer(code) => {
@@ -428,6 +892,7 @@ where
                             tracing_options.clone(),
                             env,
                             target_block,
+                            TransactionInfo::default(),
                             &mut db,
                         )?;
 
@@ -458,6 +923,7 @@ where
         opts: GethDebugTracingOptions,
         env: Env,
         at: BlockId,
+        tx_info: TransactionInfo,
         db: &mut SubState<StateProviderBox<'_>>,
     ) -> EthResult<(GethTrace, revm_primitives::State)> {
         let GethDebugTracingOptions { config, tracer, tracer_config, .. } = opts;
@@ -509,6 +975,36 @@ where
 
                         return Ok((frame.into(), res.state))
                     }
+                    GethDebugBuiltInTracerType::FlatCallTracer => {
+                        let flat_call_config = tracer_config
+                            .into_flat_call_config()
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+
+                        let mut inspector = TracingInspector::new(
+                            TracingInspectorConfig::from_flat_call_config(&flat_call_config),
+                        );
+                        let (res, _) = inspect(db, env, &mut inspector)?;
+
+                        let frame = inspector
+                            .with_transaction_gas_used(res.result.gas_used())
+                            .into_parity_builder()
+                            .into_localized_transaction_traces(tx_info);
+
+                        return Ok((frame.into(), res.state))
+                    }
+                    GethDebugBuiltInTracerType::MuxTracer => {
+                        let mux_config = tracer_config
+                            .into_mux_config()
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+
+                        let mut inspector = MuxInspector::try_from_config(mux_config)
+                            .map_err(|_| EthApiError::InvalidTracerConfig)?;
+                        let (res, _) = inspect(&mut *db, env, &mut inspector)?;
+
+                        let frame = inspector.try_into_mux_frame(&res, &*db, tx_info)?;
+
+                        return Ok((frame.into(), res.state))
+                    }
                     GethDebugBuiltInTracerType::NoopTracer => {
                         Ok((NoopFrame::default().into(), Default::default()))
                     }
@@ -641,7 +1137,12 @@ where
 #[async_trait]
 impl<Provider, Eth> DebugApiServer for DebugApi<Provider, Eth>
 where
-    Provider: BlockReaderIdExt + HeaderProvider + 'static,
+    Provider: BlockReaderIdExt
+        + HeaderProvider
+        + ChainSpecProvider
+        + ChangeSetReader
+        + BadBlocksReader
+        + 'static,
     Eth: EthApiSpec + 'static,
 {
     /// Handler for `debug_getRawHeader`
@@ -671,16 +1172,17 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_accountRange`
     async fn debug_account_range(
         &self,
-        _block_number: BlockNumberOrTag,
-        _start: Bytes,
-        _max_results: u64,
-        _nocode: bool,
-        _nostorage: bool,
+        block_number: BlockNumberOrTag,
+        start: Bytes,
+        max_results: u64,
+        nocode: bool,
+        nostorage: bool,
         _incompletes: bool,
-    ) -> RpcResult<()> {
-        Ok(())
+    ) -> RpcResult<AccountRange> {
+        Ok(Self::account_range(self, block_number, start, max_results, nocode, nostorage).await?)
     }
 
     async fn debug_block_profile(&self, _file: String, _seconds: u64) -> RpcResult<()> {
@@ -735,32 +1237,41 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_getModifiedAccountsByHash`
     async fn debug_get_modified_accounts_by_hash(
         &self,
-        _start_hash: B256,
-        _end_hash: B256,
-    ) -> RpcResult<()> {
-        Ok(())
+        start_hash: B256,
+        end_hash: Option<B256>,
+    ) -> RpcResult<Vec<Address>> {
+        let block_number = |hash| -> EthResult<BlockNumber> {
+            self.inner.provider.block_number(hash)?.ok_or(EthApiError::UnknownBlockNumber)
+        };
+        let start_number = block_number(start_hash)?;
+        let end_number = end_hash.map(block_number).transpose()?;
+        Ok(Self::modified_accounts(self, start_number, end_number)?)
     }
 
+    /// Handler for `debug_getModifiedAccountsByNumber`
     async fn debug_get_modified_accounts_by_number(
         &self,
-        _start_number: u64,
-        _end_number: u64,
-    ) -> RpcResult<()> {
-        Ok(())
+        start_number: u64,
+        end_number: Option<u64>,
+    ) -> RpcResult<Vec<Address>> {
+        Ok(Self::modified_accounts(self, start_number, end_number)?)
     }
 
     async fn debug_go_trace(&self, _file: String, _seconds: u64) -> RpcResult<()> {
         Ok(())
     }
 
+    /// Handler for `debug_intermediateRoots`
     async fn debug_intermediate_roots(
         &self,
-        _block_hash: B256,
+        block_hash: B256,
         _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+    ) -> RpcResult<Vec<B256>> {
+        let _permit = self.acquire_trace_permit().await;
+        Ok(Self::intermediate_roots(self, block_hash).await?)
     }
 
     async fn debug_mem_stats(&self) -> RpcResult<()> {
@@ -791,8 +1302,22 @@ where
         Ok(())
     }
 
-    async fn debug_set_head(&self, _number: u64) -> RpcResult<()> {
-        Ok(())
+    /// Handler for `debug_setHead`
+    async fn debug_set_head(&self, number: u64) -> RpcResult<()> {
+        let engine = self
+            .inner
+            .engine
+            .as_ref()
+            .ok_or_else(|| internal_rpc_err("consensus engine is not available"))?;
+        engine.set_head(number).await.map_err(|err| match err {
+            BeaconSetHeadError::Syncing |
+            BeaconSetHeadError::Pruned { .. } |
+            BeaconSetHeadError::Snapshotted { .. } |
+            BeaconSetHeadError::TooDeep { .. } => invalid_params_rpc_err(err.to_string()),
+            BeaconSetHeadError::Internal(_) | BeaconSetHeadError::EngineUnavailable => {
+                internal_rpc_err(err.to_string())
+            }
+        })
     }
 
     async fn debug_set_mutex_profile_fraction(&self, _rate: i32) -> RpcResult<()> {
@@ -807,20 +1332,29 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_standardTraceBadBlockToFile`
     async fn debug_standard_trace_bad_block_to_file(
         &self,
-        _block: BlockNumberOrTag,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>> {
+        let _permit = self.acquire_trace_permit().await;
+        let files =
+            Self::standard_trace_bad_block_to_file(self, block_hash, opts.unwrap_or_default())
+                .await?;
+        Ok(files.into_iter().map(|path| path.display().to_string()).collect())
     }
 
+    /// Handler for `debug_standardTraceBlockToFile`
     async fn debug_standard_trace_block_to_file(
         &self,
-        _block: BlockNumberOrTag,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<StdTraceConfig>,
+    ) -> RpcResult<Vec<String>> {
+        let _permit = self.acquire_trace_permit().await;
+        let files =
+            Self::standard_trace_block_to_file(self, block_hash, opts.unwrap_or_default()).await?;
+        Ok(files.into_iter().map(|path| path.display().to_string()).collect())
     }
 
     async fn debug_start_cpu_profile(&self, _file: String) -> RpcResult<()> {
@@ -839,23 +1373,34 @@ where
         Ok(())
     }
 
+    /// Handler for `debug_storageRangeAt`
     async fn debug_storage_range_at(
         &self,
-        _block_hash: B256,
-        _tx_idx: usize,
-        _contract_address: Address,
-        _key_start: B256,
-        _max_result: u64,
-    ) -> RpcResult<()> {
-        Ok(())
-    }
-
+        block_hash: B256,
+        tx_idx: usize,
+        contract_address: Address,
+        key_start: B256,
+        max_result: u64,
+    ) -> RpcResult<StorageRange> {
+        Ok(Self::storage_range_at(
+            self,
+            block_hash,
+            tx_idx,
+            contract_address,
+            key_start,
+            max_result,
+        )
+        .await?)
+    }
+
+    /// Handler for `debug_traceBadBlock`
     async fn debug_trace_bad_block(
         &self,
-        _block_hash: B256,
-        _opts: Option<GethDebugTracingCallOptions>,
-    ) -> RpcResult<()> {
-        Ok(())
+        block_hash: B256,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<Vec<TraceResult>> {
+        let _permit = self.acquire_trace_permit().await;
+        Ok(Self::debug_trace_bad_block(self, block_hash, opts.unwrap_or_default()).await?)
     }
 
     async fn debug_verbosity(&self, _level: usize) -> RpcResult<()> {
@@ -921,8 +1466,8 @@ where
     }
 
     /// Handler for `debug_getBadBlocks`
-    async fn bad_blocks(&self) -> RpcResult<Vec<RichBlock>> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn bad_blocks(&self) -> RpcResult<Vec<BadBlock>> {
+        Ok(Self::bad_blocks(self)?)
     }
 
     /// Handler for `debug_traceChain`
@@ -997,6 +1542,102 @@ where
     }
 }
 
+/// Writes the steps of a standard json trace to the file at the given path, one per line, followed
+/// by the summary.
+fn write_std_json_trace(
+    path: &Path,
+    logs: &[StdJsonLog],
+    summary: &StdJsonSummary,
+) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for log in logs {
+        serde_json::to_writer(&mut writer, log)?;
+        writer.write_all(b"\n")?;
+    }
+    serde_json::to_writer(&mut writer, summary)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+/// Converts an error of writing a trace file into an [EthApiError].
+fn trace_file_error(err: std::io::Error) -> EthApiError {
+    EthApiError::Internal(RethError::Custom(format!("failed to write trace file: {err}")))
+}
+
+/// Returns the account as part of a state dump, with its code and storage if requested.
+fn dump_account(
+    state: &impl StateProvider,
+    entry: HashedAccountEntry,
+    nocode: bool,
+    nostorage: bool,
+) -> RethResult<DumpAccount> {
+    let HashedAccountEntry { hashed_address, account, storage_root, storage } = entry;
+    let code = match account.bytecode_hash {
+        Some(code_hash) if !nocode => {
+            state.bytecode_by_hash(code_hash)?.map(|code| code.original_bytes())
+        }
+        _ => None,
+    };
+    let storage = (!nostorage).then_some(storage);
+    Ok(from_primitive_dump_account(hashed_address, account, storage_root, code, storage))
+}
+
+/// Returns up to `limit` non-zero storage slots of the account with a hashed key greater than or
+/// equal to `start`, with the changes of the bundle state applied.
+///
+/// Only the keys of the changed slots are known, the storage of the state provider is keyed by the
+/// hashes of the keys.
+fn storage_range(
+    state: &StateProviderBox<'_>,
+    changes: &BundleState,
+    address: Address,
+    start: B256,
+    limit: usize,
+) -> EthResult<StorageRange> {
+    let changed = changes.state().get(&address);
+    let mut slots = BTreeMap::new();
+
+    // the storage of the state provider is void if the account was destroyed
+    let mut end = None;
+    if !changed.is_some_and(|account| account.info.is_none() || account.status.was_destroyed()) {
+        // each changed slot can clear at most one of the slots of the state provider, so this
+        // yields enough non-zero slots to fill the page and find the next key
+        let changed_len = changed.map_or(0, |account| account.storage.len());
+        let fetch_limit = limit.saturating_add(changed_len).saturating_add(1);
+        let entries = state.storage_range(address, start, fetch_limit)?;
+        if entries.len() == fetch_limit {
+            // there may be slots after the last fetched one that aren't known
+            end = entries.last().map(|entry| entry.key);
+        }
+        slots.extend(entries.into_iter().map(|entry| (entry.key, (None, entry.value))));
+    }
+    if let Some(account) = changed {
+        slots.extend(
+            account
+                .storage
+                .iter()
+                .map(|(key, slot)| {
+                    let key = B256::from(*key);
+                    (keccak256(key), (Some(key), slot.present_value))
+                })
+                .filter(|(hashed_key, _)| {
+                    *hashed_key >= start && end.map_or(true, |end| *hashed_key <= end)
+                }),
+        );
+    }
+
+    let mut slots = slots.into_iter().filter(|(_, (_, value))| *value != U256::ZERO);
+    let storage = slots
+        .by_ref()
+        .take(limit)
+        .map(|(hashed_key, (key, value))| {
+            (hashed_key, StorageRangeEntry { key, value: value.into() })
+        })
+        .collect();
+    let next_key = slots.next().map(|(hashed_key, _)| hashed_key);
+    Ok(StorageRange { storage, next_key })
+}
+
 impl<Provider, Eth> std::fmt::Debug for DebugApi<Provider, Eth> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DebugApi").finish_non_exhaustive()
@@ -1018,4 +1659,216 @@ struct DebugApiInner<Provider, Eth> {
     blocking_task_guard: BlockingTaskGuard,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
+    /// The directory trace files are written to, if any.
+    trace_dir: Option<PathBuf>,
+    /// The maximum number of blocks whose changesets are read in a single request.
+    max_changeset_blocks: u64,
+    /// The handle of the consensus engine, used to unwind the chain.
+    engine: Option<BeaconConsensusEngineHandle>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        BlockingTaskPool, EthApi,
+    };
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{
+        constants::ETHEREUM_BLOCK_GAS_LIMIT, sign_message, ChainSpecBuilder, Transaction,
+        TransactionKind, TxLegacy,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_tasks::TokioTaskExecutor;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    /// The value transferred by each transaction of the test block.
+    const VALUE: u128 = 1_000;
+
+    /// The initial balance of the sender of the transactions of the test block.
+    const BALANCE: u128 = 1_000_000;
+
+    type TestDebugApi = DebugApi<MockEthProvider, EthApi<MockEthProvider, TestPool, NoopNetwork>>;
+
+    fn debug_api(provider: MockEthProvider, trace_dir: Option<PathBuf>) -> TestDebugApi {
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+        );
+        DebugApi::new(
+            provider,
+            eth_api,
+            Box::<TokioTaskExecutor>::default(),
+            BlockingTaskGuard::new(1),
+            trace_dir,
+            1,
+            None,
+        )
+    }
+
+    /// Signs the transaction with the key of the sender of the test transactions.
+    fn sign(tx: TxLegacy) -> TransactionSigned {
+        let tx = Transaction::Legacy(tx);
+        let signature = sign_message(B256::with_last_byte(1), tx.signature_hash()).unwrap();
+        TransactionSigned::from_transaction_and_signature(tx, signature)
+    }
+
+    /// Returns a transfer of [VALUE] to the recipient, at a gas price equal to the base fee, so
+    /// the beneficiary isn't paid.
+    fn transfer(nonce: u64, to: Address) -> TransactionSigned {
+        sign(TxLegacy {
+            chain_id: Some(1),
+            nonce,
+            gas_price: 1,
+            gas_limit: 21_000,
+            to: TransactionKind::Call(to),
+            value: VALUE.into(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a provider of a chain that's post-merge from genesis, whose sender of the test
+    /// transactions has a balance of [BALANCE].
+    fn provider() -> MockEthProvider {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().paris_activated().build());
+        let provider = MockEthProvider { chain_spec, ..Default::default() };
+        let sender = transfer(0, Address::ZERO).recover_signer().unwrap();
+        provider.add_account(sender, ExtendedAccount::new(0, U256::from(BALANCE)));
+        provider
+    }
+
+    /// Adds a block with the transactions on top of an empty genesis block, and returns its hash.
+    fn add_block(
+        provider: &MockEthProvider,
+        body: Vec<TransactionSigned>,
+        state_root: B256,
+    ) -> B256 {
+        let parent = Header::default();
+        let parent_hash = parent.hash_slow();
+        provider.add_header(parent_hash, parent);
+        let header = Header {
+            parent_hash,
+            number: 1,
+            gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
+            base_fee_per_gas: Some(1),
+            state_root,
+            ..Default::default()
+        };
+        let block_hash = header.hash_slow();
+        provider.add_block(block_hash, Block { header, body, ..Default::default() });
+        block_hash
+    }
+
+    /// Returns a provider with a block of two transfers, and the hash of the block.
+    fn provider_with_block() -> (MockEthProvider, B256) {
+        let provider = provider();
+        let recipient = Address::random();
+        let body = vec![transfer(0, recipient), transfer(1, recipient)];
+        let sender = body[0].recover_signer().unwrap();
+
+        // the state after the block, the transactions only pay the base fee
+        let expected = MockEthProvider::default();
+        let fees = 2 * (21_000 + VALUE);
+        expected.add_account(sender, ExtendedAccount::new(2, U256::from(BALANCE - fees)));
+        expected.add_account(recipient, ExtendedAccount::new(0, U256::from(2 * VALUE)));
+        let state_root = expected.state_root(&BundleStateWithReceipts::default()).unwrap();
+
+        let block_hash = add_block(&provider, body, state_root);
+        (provider, block_hash)
+    }
+
+    /// Returns the name geth gives the trace file of the transaction.
+    fn trace_file_name(block_hash: B256, index: usize, tx_hash: B256) -> String {
+        format!(
+            "block_0x{}-{index}-0x{}.jsonl",
+            hex::encode(&block_hash[..4]),
+            hex::encode(&tx_hash[..4])
+        )
+    }
+
+    #[tokio::test]
+    async fn intermediate_roots_end_with_block_state_root() {
+        let (provider, block_hash) = provider_with_block();
+        let block = provider.blocks.lock()[&block_hash].clone();
+        let debug_api = debug_api(provider, None);
+
+        let roots = debug_api.intermediate_roots(block_hash).await.unwrap();
+        assert_eq!(roots.len(), 2);
+        assert_ne!(roots[0], roots[1]);
+        assert_eq!(roots.last(), Some(&block.header.state_root));
+    }
+
+    #[tokio::test]
+    async fn standard_trace_block_writes_file_per_transaction() {
+        let (provider, block_hash) = provider_with_block();
+        let block = provider.blocks.lock()[&block_hash].clone();
+        let trace_dir = tempfile::tempdir().unwrap();
+        let debug_api = debug_api(provider, Some(trace_dir.path().to_path_buf()));
+
+        let files =
+            debug_api.standard_trace_block_to_file(block_hash, Default::default()).await.unwrap();
+        assert_eq!(files.len(), block.body.len());
+        for (index, (path, tx)) in files.iter().zip(&block.body).enumerate() {
+            assert_eq!(*path, trace_dir.path().join(trace_file_name(block_hash, index, tx.hash())));
+
+            // a transfer executes no opcodes, only the summary is written
+            let trace = std::fs::read_to_string(path).unwrap();
+            let summary: serde_json::Value = serde_json::from_str(trace.trim_end()).unwrap();
+            assert_eq!(summary["gasUsed"], "0x5208");
+        }
+
+        // only the selected transaction is traced
+        let tx_hash = block.body[1].hash();
+        let config = StdTraceConfig { tx_hash: Some(tx_hash), ..Default::default() };
+        let files = debug_api.standard_trace_block_to_file(block_hash, config).await.unwrap();
+        assert_eq!(files, vec![trace_dir.path().join(trace_file_name(block_hash, 1, tx_hash))]);
+    }
+
+    #[tokio::test]
+    async fn storage_range_at_replays_previous_transactions() {
+        let provider = provider();
+        let contract = Address::random();
+        let slot = B256::with_last_byte(1);
+        // PUSH1 1 PUSH1 0 SSTORE
+        let code = Bytes::from_static(&[0x60, 0x01, 0x60, 0x00, 0x55]);
+        let account = ExtendedAccount::new(0, U256::ZERO)
+            .with_bytecode(code)
+            .extend_storage([(slot, U256::from(5))]);
+        provider.add_account(contract, account);
+        let call = sign(TxLegacy {
+            chain_id: Some(1),
+            gas_price: 1,
+            gas_limit: 100_000,
+            to: TransactionKind::Call(contract),
+            ..Default::default()
+        });
+        let block_hash = add_block(&provider, vec![call], B256::ZERO);
+        let debug_api = debug_api(provider, None);
+
+        // only the keys of the slots written by the replayed transactions are known
+        let stored =
+            (keccak256(slot), StorageRangeEntry { key: None, value: U256::from(5).into() });
+        let range = debug_api.storage_range_at(block_hash, 0, contract, B256::ZERO, 10).await;
+        assert_eq!(
+            range.unwrap(),
+            StorageRange { storage: BTreeMap::from([stored.clone()]), next_key: None }
+        );
+
+        let written = (
+            keccak256(B256::ZERO),
+            StorageRangeEntry { key: Some(B256::ZERO), value: U256::from(1).into() },
+        );
+        let range = debug_api.storage_range_at(block_hash, 1, contract, B256::ZERO, 10).await;
+        assert_eq!(
+            range.unwrap(),
+            StorageRange { storage: BTreeMap::from([stored, written]), next_key: None }
+        );
+    }
 }
@@ -7,6 +7,9 @@ use crate::{
     },
     EthApi,
 };
+
+#[cfg(feature = "optimism")]
+use crate::eth::optimism::OptimismTxMeta;
 use reth_network_api::NetworkInfo;
 use reth_primitives::{BlockId, TransactionMeta};
 
@@ -74,6 +77,11 @@ where
             let base_fee = block.base_fee_per_gas;
             let block_hash = block.hash;
             let excess_blob_gas = block.excess_blob_gas;
+
+            #[cfg(feature = "optimism")]
+            let optimism_tx_meta =
+                OptimismTxMeta::new(&self.provider().chain_spec(), block.timestamp, &block.body)?;
+
             let receipts = block
                 .body
                 .into_iter()
@@ -88,7 +96,14 @@ where
                         base_fee,
                         excess_blob_gas,
                     };
-                    build_transaction_receipt_with_block_receipts(tx, meta, receipt, &receipts)
+                    build_transaction_receipt_with_block_receipts(
+                        tx,
+                        meta,
+                        receipt,
+                        &receipts,
+                        #[cfg(feature = "optimism")]
+                        &optimism_tx_meta,
+                    )
                 })
                 .collect::<EthResult<Vec<_>>>();
             return receipts.map(Some)
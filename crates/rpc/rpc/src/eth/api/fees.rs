@@ -6,10 +6,10 @@ use crate::{
 };
 use reth_network_api::NetworkInfo;
 use reth_primitives::{
-    basefee::calculate_next_block_base_fee, BlockNumberOrTag, SealedHeader, U256,
+    basefee::calculate_next_block_base_fee, BlockNumberOrTag, SealedHeader, U128, U256,
 };
 use reth_provider::{BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, StateProviderFactory};
-use reth_rpc_types::{FeeHistory, TxGasAndReward};
+use reth_rpc_types::{FeeHistory, TransactionRequest, TxGasAndReward};
 use reth_transaction_pool::TransactionPool;
 use tracing::debug;
 
@@ -36,6 +36,38 @@ where
         self.gas_oracle().suggest_tip_cap().await
     }
 
+    /// Fills in the fee fields of the request that aren't set with the suggestions of the gas
+    /// price oracle.
+    ///
+    /// Requests without a gas price become EIP-1559 transactions once the latest block has a base
+    /// fee.
+    pub(crate) async fn fill_fees(&self, request: &mut TransactionRequest) -> EthResult<()> {
+        if request.gas_price.is_some() {
+            return Ok(())
+        }
+
+        match self.provider().latest_header()?.and_then(|header| header.base_fee_per_gas) {
+            Some(base_fee) => {
+                let max_priority_fee_per_gas = match request.max_priority_fee_per_gas {
+                    Some(tip) => tip,
+                    None => {
+                        let tip = U128::saturating_from(self.suggested_priority_fee().await?);
+                        request.max_fee_per_gas.map_or(tip, |max_fee| tip.min(max_fee))
+                    }
+                };
+                if request.max_fee_per_gas.is_none() {
+                    // leave room for the base fee to double, same as geth
+                    request.max_fee_per_gas =
+                        Some(max_priority_fee_per_gas + U128::from(base_fee) * U128::from(2));
+                }
+                request.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+            }
+            None => request.gas_price = Some(U128::saturating_from(self.gas_price().await?)),
+        }
+
+        Ok(())
+    }
+
     /// Reports the fee history, for the given amount of blocks, up until the newest block
     /// provided.
     pub(crate) async fn fee_history(
@@ -117,11 +149,13 @@ where
         // The unwrap is safe since we checked earlier that we got at least 1 header.
         let last_header = headers.last().unwrap();
         let chain_spec = self.provider().chain_spec();
+        // the next block is assumed to follow after the block time of the chain
+        let next_timestamp = last_header.timestamp + chain_spec.block_time.as_secs();
         base_fee_per_gas.push(U256::from(calculate_next_block_base_fee(
             last_header.gas_used,
             last_header.gas_limit,
             last_header.base_fee_per_gas.unwrap_or_default(),
-            chain_spec.base_fee_params,
+            chain_spec.base_fee_params(next_timestamp),
         )));
 
         Ok(FeeHistory {
@@ -197,3 +231,86 @@ where
         Ok(rewards_in_block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        BlockingTaskPool,
+    };
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{constants::ETHEREUM_BLOCK_GAS_LIMIT, Header, B256};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    fn eth_api(base_fee_per_gas: Option<u64>) -> EthApi<MockEthProvider, TestPool, NoopNetwork> {
+        let provider = MockEthProvider::default();
+        provider.add_header(B256::random(), Header { base_fee_per_gas, ..Default::default() });
+
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+        )
+    }
+
+    #[tokio::test]
+    async fn fill_fees_keeps_gas_price() {
+        let eth_api = eth_api(Some(7));
+        let mut request =
+            TransactionRequest { gas_price: Some(U128::from(3)), ..Default::default() };
+        eth_api.fill_fees(&mut request).await.unwrap();
+
+        assert_eq!(request.gas_price, Some(U128::from(3)));
+        assert_eq!(request.max_fee_per_gas, None);
+        assert_eq!(request.max_priority_fee_per_gas, None);
+    }
+
+    #[tokio::test]
+    async fn fill_fees_pre_london() {
+        let eth_api = eth_api(None);
+        let mut request = TransactionRequest::default();
+        eth_api.fill_fees(&mut request).await.unwrap();
+
+        let gas_price = U128::saturating_from(eth_api.gas_price().await.unwrap());
+        assert_eq!(request.gas_price, Some(gas_price));
+        assert_eq!(request.max_fee_per_gas, None);
+    }
+
+    #[tokio::test]
+    async fn fill_fees_eip1559() {
+        let base_fee = 7;
+        let eth_api = eth_api(Some(base_fee));
+        let tip = U128::saturating_from(eth_api.suggested_priority_fee().await.unwrap());
+
+        // the max fee leaves room for the base fee to double
+        let mut request = TransactionRequest::default();
+        eth_api.fill_fees(&mut request).await.unwrap();
+        assert_eq!(request.gas_price, None);
+        assert_eq!(request.max_priority_fee_per_gas, Some(tip));
+        assert_eq!(request.max_fee_per_gas, Some(tip + U128::from(2 * base_fee)));
+
+        // a set tip is kept
+        let mut request = TransactionRequest {
+            max_priority_fee_per_gas: Some(U128::from(1)),
+            ..Default::default()
+        };
+        eth_api.fill_fees(&mut request).await.unwrap();
+        assert_eq!(request.max_priority_fee_per_gas, Some(U128::from(1)));
+        assert_eq!(request.max_fee_per_gas, Some(U128::from(1 + 2 * base_fee)));
+
+        // the suggested tip is capped by a set max fee
+        let max_fee = tip.saturating_sub(U128::from(1));
+        let mut request =
+            TransactionRequest { max_fee_per_gas: Some(max_fee), ..Default::default() };
+        eth_api.fill_fees(&mut request).await.unwrap();
+        assert_eq!(request.max_priority_fee_per_gas, Some(max_fee));
+        assert_eq!(request.max_fee_per_gas, Some(max_fee));
+    }
+}
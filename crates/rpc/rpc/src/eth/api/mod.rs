@@ -30,6 +30,9 @@ use std::{
 };
 use tokio::sync::{oneshot, Mutex};
 
+#[cfg(feature = "optimism")]
+use crate::eth::optimism::SequencerClient;
+
 mod block;
 mod call;
 mod fees;
@@ -100,8 +103,12 @@ where
             eth_cache,
             gas_oracle,
             gas_cap.into().into(),
+            DEFAULT_ETH_PROOF_WINDOW,
+            Vec::new(),
             Box::<TokioTaskExecutor>::default(),
             blocking_task_pool,
+            #[cfg(feature = "optimism")]
+            None,
         )
     }
 
@@ -114,8 +121,11 @@ where
         eth_cache: EthStateCache,
         gas_oracle: GasPriceOracle<Provider>,
         gas_cap: u64,
+        eth_proof_window: u64,
+        signers: Vec<Box<dyn EthSigner>>,
         task_spawner: Box<dyn TaskSpawner>,
         blocking_task_pool: BlockingTaskPool,
+        #[cfg(feature = "optimism")] sequencer_client: Option<SequencerClient>,
     ) -> Self {
         // get the block number of the latest block
         let latest_block = provider
@@ -129,14 +139,17 @@ where
             provider,
             pool,
             network,
-            signers: Default::default(),
+            signers,
             eth_cache,
             gas_oracle,
             gas_cap,
+            eth_proof_window,
             starting_block: U256::from(latest_block),
             task_spawner,
             pending_block: Default::default(),
             blocking_task_pool,
+            #[cfg(feature = "optimism")]
+            sequencer_client,
         };
         Self { inner: Arc::new(inner) }
     }
@@ -190,6 +203,12 @@ where
     pub fn pool(&self) -> &Pool {
         &self.inner.pool
     }
+
+    /// Returns the client that forwards raw transactions to the sequencer, if configured.
+    #[cfg(feature = "optimism")]
+    pub fn sequencer_client(&self) -> Option<&SequencerClient> {
+        self.inner.sequencer_client.as_ref()
+    }
 }
 
 // === State access helpers ===
@@ -250,13 +269,14 @@ where
             let mut latest =
                 self.provider().latest_header()?.ok_or_else(|| EthApiError::UnknownBlockNumber)?;
 
+            let chain_spec = self.provider().chain_spec();
             // child block
             latest.number += 1;
-            // assumed child block is in the next slot
-            latest.timestamp += 12;
+            // assumed child block follows after the block time of the chain
+            latest.timestamp += chain_spec.block_time.as_secs();
             // base fee of the child block
-            let chain_spec = self.provider().chain_spec();
-            latest.base_fee_per_gas = latest.next_block_base_fee(chain_spec.base_fee_params);
+            latest.base_fee_per_gas =
+                latest.next_block_base_fee(chain_spec.base_fee_params(latest.timestamp));
 
             PendingBlockEnvOrigin::DerivedFromLatest(latest)
         };
@@ -392,6 +412,15 @@ where
 /// more complex calls.
 pub const RPC_DEFAULT_GAS_CAP: GasCap = GasCap(50_000_000);
 
+/// The default number of blocks behind the tip that `eth_getProof` can generate proofs for.
+///
+/// Historical proofs are expensive, so only proofs for the latest block are served by default.
+pub const DEFAULT_ETH_PROOF_WINDOW: u64 = 0;
+
+/// The maximum configurable number of blocks behind the tip that `eth_getProof` can generate
+/// proofs for, which is 28 days worth of 12 second blocks.
+pub const MAX_ETH_PROOF_WINDOW: u64 = 28 * 24 * 60 * 60 / 12;
+
 /// The wrapper type for gas limit
 #[derive(Debug, Clone, Copy)]
 pub struct GasCap(u64);
@@ -430,6 +459,8 @@ struct EthApiInner<Provider, Pool, Network> {
     gas_oracle: GasPriceOracle<Provider>,
     /// Maximum gas limit for `eth_call` and call tracing RPC methods.
     gas_cap: u64,
+    /// Maximum number of blocks behind the tip that `eth_getProof` can generate proofs for.
+    eth_proof_window: u64,
     /// The block number at which the node started
     starting_block: U256,
     /// The type that can spawn tasks which would otherwise block.
@@ -438,4 +469,7 @@ struct EthApiInner<Provider, Pool, Network> {
     pending_block: Mutex<Option<PendingBlock>>,
     /// A pool dedicated to blocking tasks.
     blocking_task_pool: BlockingTaskPool,
+    /// Forwards raw transactions to the sequencer of an OP Stack chain
+    #[cfg(feature = "optimism")]
+    sequencer_client: Option<SequencerClient>,
 }
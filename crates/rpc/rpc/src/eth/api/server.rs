@@ -5,7 +5,6 @@ use super::EthApiSpec;
 use crate::{
     eth::{
         api::{EthApi, EthTransactions},
-        error::EthApiError,
         revm_utils::EvmOverrides,
     },
     result::{internal_rpc_err, ToRpcResult},
@@ -353,8 +352,10 @@ where
     }
 
     /// Handler for: `eth_signTransaction`
-    async fn sign_transaction(&self, _transaction: CallRequest) -> Result<Bytes> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn sign_transaction(&self, request: TransactionRequest) -> Result<Bytes> {
+        trace!(target: "rpc::eth", ?request, "Serving eth_signTransaction");
+        let signed_tx = EthTransactions::sign_transaction(self, request).await?;
+        Ok(signed_tx.envelope_encoded())
     }
 
     /// Handler for: `eth_signTypedData`
@@ -363,6 +364,12 @@ where
         Ok(EthApi::sign_typed_data(self, data, address).await?)
     }
 
+    /// Handler for: `eth_signTypedData_v4`
+    async fn sign_typed_data_v4(&self, address: Address, data: Value) -> Result<Bytes> {
+        trace!(target: "rpc::eth", ?address, ?data, "Serving eth_signTypedData_v4");
+        Ok(EthApi::sign_typed_data(self, data, address).await?)
+    }
+
     /// Handler for: `eth_getProof`
     async fn get_proof(
         &self,
@@ -371,14 +378,7 @@ where
         block_number: Option<BlockId>,
     ) -> Result<EIP1186AccountProofResponse> {
         trace!(target: "rpc::eth", ?address, ?keys, ?block_number, "Serving eth_getProof");
-        let res = EthApi::get_proof(self, address, keys, block_number).await;
-
-        Ok(res.map_err(|e| match e {
-            EthApiError::InvalidBlockRange => {
-                internal_rpc_err("eth_getProof is unimplemented for historical blocks")
-            }
-            _ => e.into(),
-        })?)
+        Ok(EthApi::get_proof(self, address, keys, block_number).await?)
     }
 }
 
@@ -102,9 +102,17 @@ where
             _ => false,
         };
 
-        // TODO: remove when HistoricalStateProviderRef::proof is implemented
+        // historical proofs revert the changes of all blocks up to the tip, so how far back they
+        // can be generated is limited
         if !is_latest_block {
-            return Err(EthApiError::InvalidBlockRange)
+            let block_number = self
+                .provider()
+                .block_number_for_id(block_id)?
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+            let max_window = self.inner.eth_proof_window;
+            if chain_info.best_number.saturating_sub(block_number) > max_window {
+                return Err(EthApiError::ExceedsMaxProofWindow(max_window))
+            }
         }
 
         let this = self.clone();
@@ -11,6 +11,9 @@ use crate::{
     },
     EthApi, EthApiSpec,
 };
+
+#[cfg(feature = "optimism")]
+use crate::eth::optimism::OptimismTxMeta;
 use async_trait::async_trait;
 use reth_network_api::NetworkInfo;
 use reth_primitives::{
@@ -135,6 +138,10 @@ pub trait EthTransactions: Send + Sync {
     /// Returns the hash of the signed transaction.
     async fn send_transaction(&self, request: TransactionRequest) -> EthResult<B256>;
 
+    /// Fills in the missing nonce, gas limit and fee fields of the request and signs it with the
+    /// signer of its `from` account.
+    async fn sign_transaction(&self, request: TransactionRequest) -> EthResult<TransactionSigned>;
+
     /// Prepares the state and env for the given [CallRequest] at the given [BlockId] and executes
     /// the closure on a new task returning the result of the closure.
     async fn spawn_with_call_at<F, R>(
@@ -480,6 +487,26 @@ where
     }
 
     async fn send_raw_transaction(&self, tx: Bytes) -> EthResult<B256> {
+        // On an OP Stack verifier the transaction needs to reach the sequencer to be included
+        #[cfg(feature = "optimism")]
+        if let Some(client) = self.sequencer_client() {
+            let recovered = recover_raw_transaction(tx.clone())?;
+            let hash = client.forward_raw_transaction(&tx).await?;
+
+            if client.local_pool_copy() {
+                let pool_transaction = <Pool::Transaction>::from_recovered_transaction(recovered);
+                // the sequencer accepted the transaction, so a failure to insert the local copy
+                // is not an error for the caller
+                if let Err(err) =
+                    self.pool().add_transaction(TransactionOrigin::Local, pool_transaction).await
+                {
+                    tracing::debug!(target: "rpc::eth", %err, %hash, "failed to add forwarded transaction to the pool");
+                }
+            }
+
+            return Ok(hash)
+        }
+
         let recovered = recover_raw_transaction(tx)?;
 
         let pool_transaction = <Pool::Transaction>::from_recovered_transaction(recovered);
@@ -490,11 +517,30 @@ where
         Ok(hash)
     }
 
-    async fn send_transaction(&self, mut request: TransactionRequest) -> EthResult<B256> {
+    async fn send_transaction(&self, request: TransactionRequest) -> EthResult<B256> {
+        let signed_tx = self.sign_transaction(request).await?;
+
+        let recovered =
+            signed_tx.into_ecrecovered().ok_or(EthApiError::InvalidTransactionSignature)?;
+
+        let pool_transaction = <Pool::Transaction>::from_recovered_transaction(recovered.into());
+
+        // submit the transaction to the pool with a `Local` origin
+        let hash = self.pool().add_transaction(TransactionOrigin::Local, pool_transaction).await?;
+
+        Ok(hash)
+    }
+
+    async fn sign_transaction(
+        &self,
+        mut request: TransactionRequest,
+    ) -> EthResult<TransactionSigned> {
         let from = match request.from {
             Some(from) => from,
             None => return Err(SignError::NoAccount.into()),
         };
+        // fail early if the account can't be signed for
+        self.find_signer(&from)?;
 
         // set nonce if not already set before
         if request.nonce.is_none() {
@@ -504,77 +550,64 @@ where
             request.nonce = Some(U64::from(nonce.to::<u64>()));
         }
 
+        self.fill_fees(&mut request).await?;
+
         let chain_id = self.chain_id();
-        // TODO: we need an oracle to fetch the gas price of the current chain
-        let gas_price = request.gas_price.unwrap_or_default();
-        let max_fee_per_gas = request.max_fee_per_gas.unwrap_or_default();
-
-        let estimated_gas = self
-            .estimate_gas_at(
-                CallRequest {
-                    from: Some(from),
-                    to: request.to,
-                    gas: request.gas,
-                    gas_price: Some(U256::from(gas_price)),
-                    max_fee_per_gas: Some(U256::from(max_fee_per_gas)),
-                    value: request.value,
-                    input: request.data.clone().into(),
-                    nonce: request.nonce,
-                    chain_id: Some(chain_id),
-                    access_list: request.access_list.clone(),
-                    max_priority_fee_per_gas: Some(U256::from(max_fee_per_gas)),
-                    transaction_type: None,
-                    blob_versioned_hashes: None,
-                    max_fee_per_blob_gas: None,
-                },
-                BlockId::Number(BlockNumberOrTag::Pending),
-            )
-            .await?;
-        let gas_limit = estimated_gas;
+        let gas_limit = match request.gas {
+            Some(gas) => gas,
+            None => {
+                self.estimate_gas_at(
+                    CallRequest {
+                        from: Some(from),
+                        to: request.to,
+                        gas: None,
+                        gas_price: request.gas_price.map(U256::from),
+                        max_fee_per_gas: request.max_fee_per_gas.map(U256::from),
+                        value: request.value,
+                        input: request.data.clone().into(),
+                        nonce: request.nonce,
+                        chain_id: Some(chain_id),
+                        access_list: request.access_list.clone(),
+                        max_priority_fee_per_gas: request.max_priority_fee_per_gas.map(U256::from),
+                        transaction_type: None,
+                        blob_versioned_hashes: None,
+                        max_fee_per_blob_gas: None,
+                    },
+                    BlockId::Number(BlockNumberOrTag::Pending),
+                )
+                .await?
+            }
+        };
 
         let transaction = match request.into_typed_request() {
             Some(TypedTransactionRequest::Legacy(mut m)) => {
                 m.chain_id = Some(chain_id.to());
                 m.gas_limit = gas_limit;
-                m.gas_price = gas_price;
 
                 TypedTransactionRequest::Legacy(m)
             }
             Some(TypedTransactionRequest::EIP2930(mut m)) => {
                 m.chain_id = chain_id.to();
                 m.gas_limit = gas_limit;
-                m.gas_price = gas_price;
 
                 TypedTransactionRequest::EIP2930(m)
             }
             Some(TypedTransactionRequest::EIP1559(mut m)) => {
                 m.chain_id = chain_id.to();
                 m.gas_limit = gas_limit;
-                m.max_fee_per_gas = max_fee_per_gas;
 
                 TypedTransactionRequest::EIP1559(m)
             }
             Some(TypedTransactionRequest::EIP4844(mut m)) => {
                 m.chain_id = chain_id.to();
                 m.gas_limit = gas_limit;
-                m.gas_price = gas_price;
 
                 TypedTransactionRequest::EIP4844(m)
             }
             None => return Err(EthApiError::ConflictingFeeFieldsInRequest),
         };
 
-        let signed_tx = self.sign_request(&from, transaction)?;
-
-        let recovered =
-            signed_tx.into_ecrecovered().ok_or(EthApiError::InvalidTransactionSignature)?;
-
-        let pool_transaction = <Pool::Transaction>::from_recovered_transaction(recovered.into());
-
-        // submit the transaction to the pool with a `Local` origin
-        let hash = self.pool().add_transaction(TransactionOrigin::Local, pool_transaction).await?;
-
-        Ok(hash)
+        self.sign_request(&from, transaction)
     }
 
     async fn spawn_with_call_at<F, R>(
@@ -859,11 +892,33 @@ where
         receipt: Receipt,
     ) -> EthResult<TransactionReceipt> {
         // get all receipts for the block
-        let all_receipts = match self.cache().get_receipts(meta.block_hash).await? {
-            Some(recpts) => recpts,
-            None => return Err(EthApiError::UnknownBlockNumber),
-        };
-        build_transaction_receipt_with_block_receipts(tx, meta, receipt, &all_receipts)
+        #[cfg(not(feature = "optimism"))]
+        {
+            let all_receipts = match self.cache().get_receipts(meta.block_hash).await? {
+                Some(recpts) => recpts,
+                None => return Err(EthApiError::UnknownBlockNumber),
+            };
+            build_transaction_receipt_with_block_receipts(tx, meta, receipt, &all_receipts)
+        }
+
+        // the OP Stack fields of the receipt also need the block of the transaction
+        #[cfg(feature = "optimism")]
+        {
+            let (block, all_receipts) =
+                match self.cache().get_block_and_receipts(meta.block_hash).await? {
+                    Some(block_and_receipts) => block_and_receipts,
+                    None => return Err(EthApiError::UnknownBlockNumber),
+                };
+            let optimism_tx_meta =
+                OptimismTxMeta::new(&self.provider().chain_spec(), block.timestamp, &block.body)?;
+            build_transaction_receipt_with_block_receipts(
+                tx,
+                meta,
+                receipt,
+                &all_receipts,
+                &optimism_tx_meta,
+            )
+        }
     }
 }
 
@@ -1014,6 +1069,7 @@ pub(crate) fn build_transaction_receipt_with_block_receipts(
     meta: TransactionMeta,
     receipt: Receipt,
     all_receipts: &[Receipt],
+    #[cfg(feature = "optimism")] optimism_tx_meta: &OptimismTxMeta,
 ) -> EthResult<TransactionReceipt> {
     let transaction =
         tx.clone().into_ecrecovered().ok_or(EthApiError::InvalidTransactionSignature)?;
@@ -1050,6 +1106,10 @@ pub(crate) fn build_transaction_receipt_with_block_receipts(
         // EIP-4844 fields
         blob_gas_price: meta.excess_blob_gas.map(calc_blob_gasprice).map(U128::from),
         blob_gas_used: transaction.transaction.blob_gas_used().map(U128::from),
+
+        // OP Stack fields
+        #[cfg(feature = "optimism")]
+        optimism: optimism_tx_meta.receipt_fields(&tx, &receipt),
     };
 
     match tx.transaction.kind() {
@@ -1090,13 +1150,20 @@ pub(crate) fn build_transaction_receipt_with_block_receipts(
 mod tests {
     use super::*;
     use crate::{
-        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        eth::{
+            api::DEFAULT_ETH_PROOF_WINDOW, cache::EthStateCache, gas_oracle::GasPriceOracle,
+            signer::DevSigner,
+        },
         BlockingTaskPool, EthApi,
     };
     use reth_network_api::noop::NoopNetwork;
-    use reth_primitives::{constants::ETHEREUM_BLOCK_GAS_LIMIT, hex_literal::hex, Bytes};
-    use reth_provider::test_utils::NoopProvider;
+    use reth_primitives::{
+        constants::ETHEREUM_BLOCK_GAS_LIMIT, hex_literal::hex, public_key_to_address, Bytes, TxType,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider, NoopProvider};
+    use reth_tasks::TokioTaskExecutor;
     use reth_transaction_pool::{test_utils::testing_pool, TransactionPool};
+    use secp256k1::{PublicKey, SecretKey, SECP256K1};
 
     #[tokio::test]
     async fn send_raw_transaction() {
@@ -1141,4 +1208,56 @@ mod tests {
         assert!(pool.get(&tx_1_result).is_some(), "tx1 not found in the pool");
         assert!(pool.get(&tx_2_result).is_some(), "tx2 not found in the pool");
     }
+
+    #[tokio::test]
+    async fn sign_transaction_fills_request() {
+        let key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let from = public_key_to_address(PublicKey::from_secret_key(SECP256K1, &key));
+
+        let base_fee = 7;
+        let provider = MockEthProvider::default();
+        provider.add_header(
+            B256::random(),
+            Header { base_fee_per_gas: Some(base_fee), ..Default::default() },
+        );
+        provider.add_account(from, ExtendedAccount::new(5, U256::from(1e18 as u64)));
+
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::with_spawner(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            DEFAULT_ETH_PROOF_WINDOW,
+            vec![Box::new(DevSigner::from_keys([key]))],
+            Box::<TokioTaskExecutor>::default(),
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            #[cfg(feature = "optimism")]
+            None,
+        );
+        let tip = eth_api.suggested_priority_fee().await.unwrap();
+
+        // the nonce and the fees are filled in, the gas limit is kept
+        let request = TransactionRequest {
+            from: Some(from),
+            to: Some(Address::random()),
+            gas: Some(U256::from(21_000)),
+            value: Some(U256::from(1)),
+            ..Default::default()
+        };
+        let tx = eth_api.sign_transaction(request).await.unwrap();
+        assert_eq!(tx.recover_signer(), Some(from));
+        assert_eq!(tx.tx_type(), TxType::EIP1559);
+        assert_eq!(tx.chain_id(), Some(1));
+        assert_eq!(tx.nonce(), 5);
+        assert_eq!(tx.gas_limit(), 21_000);
+        assert_eq!(tx.max_priority_fee_per_gas(), Some(tip.to::<u128>()));
+        assert_eq!(tx.max_fee_per_gas(), tip.to::<u128>() + 2 * base_fee as u128);
+
+        // unknown accounts can't be signed for
+        let request = TransactionRequest { from: Some(Address::random()), ..Default::default() };
+        assert!(eth_api.sign_transaction(request).await.is_err());
+    }
 }
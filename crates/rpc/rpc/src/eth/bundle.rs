@@ -5,13 +5,23 @@ use crate::{
         error::{EthApiError, EthResult, RpcInvalidTransactionError},
         revm_utils::FillableTransaction,
         utils::recover_raw_transaction,
-        EthTransactions,
+        EthApiSpec, EthTransactions,
     },
     BlockingTaskGuard,
 };
-use reth_primitives::{keccak256, U256};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_payload_builder::bundle::{
+    Bundle, BundlePool, BundlePoolError, BundleTransaction, MAX_FUTURE_BLOCKS,
+};
+use reth_primitives::{keccak256, Bytes, TransactionSignedEcRecovered, B256, U256};
 use reth_revm::database::StateProviderDatabase;
-use reth_rpc_types::{EthCallBundle, EthCallBundleResponse, EthCallBundleTransactionResult};
+use reth_rpc_api::EthBundleApiServer;
+use reth_rpc_types::{
+    CancelBundleRequest, CancelPrivateTransactionRequest, EthBundleHash, EthCallBundle,
+    EthCallBundleResponse, EthCallBundleTransactionResult, EthSendBundle,
+    PrivateTransactionRequest,
+};
 use revm::{
     db::CacheDB,
     primitives::{Env, ResultAndState, TxEnv},
@@ -19,7 +29,13 @@ use revm::{
 use revm_primitives::db::{DatabaseCommit, DatabaseRef};
 use std::sync::Arc;
 
+/// The number of blocks a private transaction can be included in, starting with the next block.
+const PRIVATE_TX_MAX_BLOCKS: u64 = 25;
+
 /// `Eth` bundle implementation.
+///
+/// Bundles and private transactions that are sent to the node are added to a local [BundlePool]
+/// the payload builder includes bundles from.
 pub struct EthBundle<Eth> {
     /// All nested fields bundled together.
     inner: Arc<EthBundleInner<Eth>>,
@@ -27,8 +43,17 @@ pub struct EthBundle<Eth> {
 
 impl<Eth> EthBundle<Eth> {
     /// Create a new `EthBundle` instance.
-    pub fn new(eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        Self { inner: Arc::new(EthBundleInner { eth_api, blocking_task_guard }) }
+    pub fn new(
+        eth_api: Eth,
+        bundle_pool: BundlePool,
+        blocking_task_guard: BlockingTaskGuard,
+    ) -> Self {
+        Self { inner: Arc::new(EthBundleInner { eth_api, bundle_pool, blocking_task_guard }) }
+    }
+
+    /// The pool the bundles sent to the node are added to.
+    pub fn bundle_pool(&self) -> &BundlePool {
+        &self.inner.bundle_pool
     }
 }
 
@@ -172,11 +197,200 @@ where
     }
 }
 
+impl<Eth> EthBundle<Eth>
+where
+    Eth: EthApiSpec + 'static,
+{
+    /// Adds the bundle to the bundle pool, so it can be included in the block with the bundle's
+    /// block number.
+    ///
+    /// The bundle replaces a previously sent bundle with the same replacement uuid, if any.
+    pub async fn send_bundle(&self, bundle: EthSendBundle) -> EthResult<EthBundleHash> {
+        let EthSendBundle {
+            txs,
+            block_number,
+            min_timestamp,
+            max_timestamp,
+            reverting_tx_hashes,
+            replacement_uuid,
+        } = bundle;
+        if txs.is_empty() {
+            return Err(EthBundleError::EmptyBundleTransactions.into())
+        }
+        let block_number = block_number.to::<u64>();
+        if block_number == 0 {
+            return Err(EthBundleError::BundleMissingBlockNumber.into())
+        }
+        if let (Some(min_timestamp), Some(max_timestamp)) = (min_timestamp, max_timestamp) {
+            if max_timestamp < min_timestamp {
+                return Err(EthBundleError::InvalidTimestampRange.into())
+            }
+        }
+
+        let mut transactions = Vec::with_capacity(txs.len());
+        let mut hash_bytes = Vec::with_capacity(32 * txs.len());
+        for tx in txs {
+            let transaction = recover_bundle_transaction(tx)?;
+            hash_bytes.extend_from_slice(transaction.hash().as_slice());
+            let can_revert = reverting_tx_hashes.contains(&transaction.hash());
+            transactions.push(BundleTransaction::Signed { transaction, can_revert });
+        }
+
+        let bundle = Bundle {
+            hash: keccak256(&hash_bytes),
+            transactions,
+            block_number,
+            max_block_number: block_number,
+            min_timestamp,
+            max_timestamp,
+            replacement_uuid,
+        };
+        let bundle_hash = bundle.hash;
+        self.add_bundle(bundle)?;
+
+        Ok(EthBundleHash { bundle_hash })
+    }
+
+    /// Removes the bundle with the given replacement uuid from the bundle pool.
+    ///
+    /// A replacement uuid can only be used by the bundles of a single sender, so only the sender
+    /// that knows it can cancel the bundle. Bundles can't be cancelled by their hash.
+    pub fn cancel_bundle(&self, request: CancelBundleRequest) -> EthResult<()> {
+        let CancelBundleRequest { bundle_hash: replacement_uuid } = request;
+        self.bundle_pool().remove_bundle_by_replacement_uuid(&replacement_uuid);
+        Ok(())
+    }
+
+    /// Adds the transaction to the bundle pool as a bundle of its own, so it can be included in
+    /// one of the next blocks up to the given max block number, but isn't broadcast to the
+    /// network.
+    ///
+    /// Returns the hash of the transaction. The preferences of the request aren't supported by
+    /// the local payload builder.
+    pub async fn send_private_transaction(
+        &self,
+        request: PrivateTransactionRequest,
+    ) -> EthResult<B256> {
+        let PrivateTransactionRequest { tx, max_block_number, .. } = request;
+        let transaction = recover_bundle_transaction(tx)?;
+
+        let next_block = self.inner.eth_api.chain_info()?.best_number + 1;
+        let max_block_number = max_block_number
+            .map(|number| number.to::<u64>())
+            .unwrap_or(next_block + PRIVATE_TX_MAX_BLOCKS - 1);
+        if max_block_number >= next_block + PRIVATE_TX_MAX_BLOCKS {
+            return Err(EthBundleError::MaxBlockNumberTooHigh(PRIVATE_TX_MAX_BLOCKS).into())
+        }
+
+        // the bundle of a private transaction is identified by the hash of the transaction
+        let hash = transaction.hash();
+        let bundle = Bundle {
+            hash,
+            transactions: vec![BundleTransaction::Signed { transaction, can_revert: false }],
+            block_number: next_block,
+            max_block_number,
+            min_timestamp: None,
+            max_timestamp: None,
+            replacement_uuid: None,
+        };
+        self.add_bundle(bundle)?;
+
+        Ok(hash)
+    }
+
+    /// Removes the private transaction with the given hash from the bundle pool.
+    ///
+    /// Returns true if the transaction was pending.
+    pub fn cancel_private_transaction(
+        &self,
+        request: CancelPrivateTransactionRequest,
+    ) -> EthResult<bool> {
+        Ok(self.bundle_pool().remove_bundle(&request.tx_hash).is_some())
+    }
+
+    /// Adds the bundle to the bundle pool, if it can still be included in the next block or any
+    /// later block, and isn't sent for a block too far past the next block.
+    fn add_bundle(&self, bundle: Bundle) -> EthResult<()> {
+        let next_block = self.inner.eth_api.chain_info()?.best_number + 1;
+        if bundle.is_expired(next_block) {
+            return Err(EthBundleError::BundleExpired(bundle.max_block_number).into())
+        }
+        if bundle.is_too_far_in_future(next_block) {
+            return Err(EthBundleError::BlockNumberTooHigh(MAX_FUTURE_BLOCKS).into())
+        }
+
+        let pool = self.bundle_pool();
+        pool.remove_expired(next_block);
+        pool.add_bundle(bundle).map_err(EthBundleError::from)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Eth> EthBundleApiServer for EthBundle<Eth>
+where
+    Eth: EthApiSpec + 'static,
+{
+    /// Handler for `eth_sendBundle`
+    async fn send_bundle(&self, bundle: EthSendBundle) -> RpcResult<EthBundleHash> {
+        Ok(EthBundle::send_bundle(self, bundle).await?)
+    }
+
+    /// Handler for `eth_callBundle`
+    async fn call_bundle(&self, request: EthCallBundle) -> RpcResult<EthCallBundleResponse> {
+        Ok(EthBundle::call_bundle(self, request).await?)
+    }
+
+    /// Handler for `eth_cancelBundle`
+    async fn cancel_bundle(&self, request: CancelBundleRequest) -> RpcResult<()> {
+        Ok(EthBundle::cancel_bundle(self, request)?)
+    }
+
+    /// Handler for `eth_sendPrivateTransaction`
+    async fn send_private_transaction(
+        &self,
+        request: PrivateTransactionRequest,
+    ) -> RpcResult<B256> {
+        Ok(EthBundle::send_private_transaction(self, request).await?)
+    }
+
+    /// Handler for `eth_sendPrivateRawTransaction`
+    async fn send_private_raw_transaction(&self, bytes: Bytes) -> RpcResult<B256> {
+        let request = PrivateTransactionRequest {
+            tx: bytes,
+            max_block_number: None,
+            preferences: Default::default(),
+        };
+        Ok(EthBundle::send_private_transaction(self, request).await?)
+    }
+
+    /// Handler for `eth_cancelPrivateTransaction`
+    async fn cancel_private_transaction(
+        &self,
+        request: CancelPrivateTransactionRequest,
+    ) -> RpcResult<bool> {
+        Ok(EthBundle::cancel_private_transaction(self, request)?)
+    }
+}
+
+/// Decodes and recovers the raw transaction of a bundle.
+///
+/// Blob transactions are rejected, since their sidecars can't be included by the payload builder.
+fn recover_bundle_transaction(tx: Bytes) -> EthResult<TransactionSignedEcRecovered> {
+    let transaction = recover_raw_transaction(tx)?.into_ecrecovered_transaction();
+    if transaction.is_eip4844() {
+        return Err(EthBundleError::BlobTransaction(transaction.hash()).into())
+    }
+    Ok(transaction)
+}
+
 /// Container type for  `EthBundle` internals
 #[derive(Debug)]
 struct EthBundleInner<Eth> {
     /// Access to commonly used code of the `eth` namespace
     eth_api: Eth,
+    /// The pool the bundles sent to the node are added to.
+    bundle_pool: BundlePool,
     // restrict the number of concurrent tracing calls.
     #[allow(unused)]
     blocking_task_guard: BlockingTaskGuard,
@@ -203,4 +417,194 @@ pub enum EthBundleError {
     /// Thrown if the bundle does not contain a block number, or block number is 0.
     #[error("bundle missing blockNumber")]
     BundleMissingBlockNumber,
+    /// Thrown if the max timestamp of the bundle is before its min timestamp.
+    #[error("bundle maxTimestamp is before minTimestamp")]
+    InvalidTimestampRange,
+    /// Thrown if the bundle can't be included in the next block or any later block.
+    #[error("bundle expired at block {0}")]
+    BundleExpired(u64),
+    /// Thrown if the bundle is sent for a block too far past the next block.
+    #[error("bundle can't be sent more than {0} blocks in advance")]
+    BlockNumberTooHigh(u64),
+    /// Thrown if a private transaction is valid for too many blocks.
+    #[error("private transaction can't be valid for more than {0} blocks")]
+    MaxBlockNumberTooHigh(u64),
+    /// Thrown if the bundle contains a blob transaction.
+    #[error("blob transaction {0:?} can't be part of a bundle")]
+    BlobTransaction(B256),
+    /// Thrown if the bundle can't be added to the bundle pool.
+    #[error(transparent)]
+    BundlePool(#[from] BundlePoolError),
+}
+
+impl From<EthBundleError> for EthApiError {
+    fn from(err: EthBundleError) -> Self {
+        EthApiError::InvalidParams(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        BlockingTaskPool, EthApi,
+    };
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{
+        constants::ETHEREUM_BLOCK_GAS_LIMIT, sign_message, Header, Transaction, TransactionSigned,
+        TxEip1559, U64,
+    };
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    /// The number of the latest block of the test chain.
+    const HEAD: u64 = 10;
+
+    fn eth_bundle() -> EthBundle<EthApi<MockEthProvider, TestPool, NoopNetwork>> {
+        let provider = MockEthProvider::default();
+        provider.add_header(B256::random(), Header { number: HEAD, ..Default::default() });
+
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+        );
+        EthBundle::new(eth_api, BundlePool::default(), BlockingTaskGuard::new(1))
+    }
+
+    /// Returns a raw signed transaction and its hash.
+    fn raw_tx(nonce: u64) -> (Bytes, B256) {
+        raw_tx_signed_by(1, nonce)
+    }
+
+    /// Returns a raw transaction signed with the given key and its hash.
+    fn raw_tx_signed_by(key: u8, nonce: u64) -> (Bytes, B256) {
+        let tx = Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1,
+            ..Default::default()
+        });
+        let signature = sign_message(B256::with_last_byte(key), tx.signature_hash()).unwrap();
+        let tx = TransactionSigned::from_transaction_and_signature(tx, signature);
+        (tx.envelope_encoded(), tx.hash())
+    }
+
+    fn send_bundle_request(block_number: u64, txs: Vec<Bytes>) -> EthSendBundle {
+        EthSendBundle { txs, block_number: U64::from(block_number), ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn send_bundle_with_reverting_transactions() {
+        let bundle_api = eth_bundle();
+        let (tx1, hash1) = raw_tx(0);
+        let (tx2, hash2) = raw_tx(1);
+
+        let mut request = send_bundle_request(HEAD + 1, vec![tx1, tx2]);
+        request.reverting_tx_hashes = vec![hash2];
+        let EthBundleHash { bundle_hash } = bundle_api.send_bundle(request).await.unwrap();
+
+        let bundle = bundle_api.bundle_pool().get(&bundle_hash).unwrap();
+        assert_eq!(bundle_hash, keccak256([hash1.as_slice(), hash2.as_slice()].concat()));
+        assert_eq!(
+            bundle.transactions.iter().map(|tx| (tx.hash(), tx.can_revert())).collect::<Vec<_>>(),
+            vec![(hash1, false), (hash2, true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn send_bundle_rejects_invalid_blocks() {
+        let bundle_api = eth_bundle();
+        let (tx, _) = raw_tx(0);
+
+        let err = bundle_api.send_bundle(send_bundle_request(HEAD, vec![tx.clone()])).await;
+        assert_eq!(err.unwrap_err().to_string(), EthBundleError::BundleExpired(HEAD).to_string());
+
+        let err = bundle_api
+            .send_bundle(send_bundle_request(HEAD + 2 + MAX_FUTURE_BLOCKS, vec![tx.clone()]))
+            .await;
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            EthBundleError::BlockNumberTooHigh(MAX_FUTURE_BLOCKS).to_string()
+        );
+
+        assert!(bundle_api.send_bundle(send_bundle_request(HEAD + 1, vec![tx])).await.is_ok());
+        assert_eq!(bundle_api.bundle_pool().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn cancel_bundle_by_uuid() {
+        let bundle_api = eth_bundle();
+        let (tx1, _) = raw_tx(0);
+        let (tx2, _) = raw_tx(1);
+        let (other_tx1, _) = raw_tx_signed_by(2, 0);
+        let (other_tx2, _) = raw_tx_signed_by(2, 1);
+
+        let mut request = send_bundle_request(HEAD + 1, vec![tx1]);
+        request.replacement_uuid = Some("uuid".to_string());
+        let EthBundleHash { bundle_hash: first } = bundle_api.send_bundle(request).await.unwrap();
+        let EthBundleHash { bundle_hash: second } =
+            bundle_api.send_bundle(send_bundle_request(HEAD + 1, vec![tx2])).await.unwrap();
+        let EthBundleHash { bundle_hash: other } =
+            bundle_api.send_bundle(send_bundle_request(HEAD + 1, vec![other_tx1])).await.unwrap();
+
+        // another sender can't use the uuid of the first sender
+        let mut request = send_bundle_request(HEAD + 1, vec![other_tx2]);
+        request.replacement_uuid = Some("uuid".to_string());
+        let err = bundle_api.send_bundle(request).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            EthBundleError::BundlePool(BundlePoolError::ReplacementUuidTaken).to_string()
+        );
+
+        // cancelling by the uuid only removes the bundle of the first sender
+        bundle_api.cancel_bundle(CancelBundleRequest { bundle_hash: "uuid".to_string() }).unwrap();
+        assert!(bundle_api.bundle_pool().get(&first).is_none());
+        assert!(bundle_api.bundle_pool().get(&second).is_some());
+        assert!(bundle_api.bundle_pool().get(&other).is_some());
+
+        // bundles can't be cancelled by their hash
+        bundle_api
+            .cancel_bundle(CancelBundleRequest { bundle_hash: format!("{second:?}") })
+            .unwrap();
+        assert_eq!(bundle_api.bundle_pool().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn send_and_cancel_private_transaction() {
+        let bundle_api = eth_bundle();
+        let (tx, hash) = raw_tx(0);
+
+        // the transaction can be included in at most the next PRIVATE_TX_MAX_BLOCKS blocks
+        let request = |max_block_number: Option<u64>| PrivateTransactionRequest {
+            tx: tx.clone(),
+            max_block_number: max_block_number.map(U64::from),
+            preferences: Default::default(),
+        };
+        let err = bundle_api
+            .send_private_transaction(request(Some(HEAD + 1 + PRIVATE_TX_MAX_BLOCKS)))
+            .await;
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            EthBundleError::MaxBlockNumberTooHigh(PRIVATE_TX_MAX_BLOCKS).to_string()
+        );
+
+        assert_eq!(bundle_api.send_private_transaction(request(None)).await.unwrap(), hash);
+        let bundle = bundle_api.bundle_pool().get(&hash).unwrap();
+        assert_eq!(
+            (bundle.block_number, bundle.max_block_number),
+            (HEAD + 1, HEAD + PRIVATE_TX_MAX_BLOCKS)
+        );
+
+        let cancel = CancelPrivateTransactionRequest { tx_hash: hash };
+        assert!(bundle_api.cancel_private_transaction(cancel.clone()).unwrap());
+        assert!(!bundle_api.cancel_private_transaction(cancel).unwrap());
+    }
 }
@@ -16,7 +16,10 @@ use reth_transaction_pool::error::{
 };
 use revm::primitives::{EVMError, ExecutionResult, Halt, OutOfGasError};
 use revm_primitives::InvalidHeader;
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
+
+#[cfg(feature = "optimism")]
+use crate::eth::optimism::SequencerRpcError;
 
 /// Result alias
 pub type EthResult<T> = Result<T, EthApiError>;
@@ -44,6 +47,13 @@ pub enum EthApiError {
     UnknownBlockOrTxIndex,
     #[error("invalid block range")]
     InvalidBlockRange,
+    /// Thrown when the requested block range exceeds the configured maximum
+    #[error("block range exceeds the maximum of {0} blocks")]
+    BlockRangeTooLarge(u64),
+    /// Thrown when a proof is requested for a block further behind the tip than the configured
+    /// proof window
+    #[error("distance to target block exceeds the maximum proof window of {0} blocks")]
+    ExceedsMaxProofWindow(u64),
     /// An internal error where prevrandao is not set in the evm's environment
     #[error("prevrandao not in the EVM's environment after merge")]
     PrevrandaoNotSet,
@@ -101,6 +111,10 @@ pub enum EthApiError {
     InternalJsTracerError(String),
     #[error(transparent)]
     CallInputError(#[from] CallInputError),
+    /// Thrown when forwarding a transaction to the sequencer failed
+    #[cfg(feature = "optimism")]
+    #[error(transparent)]
+    Sequencer(#[from] SequencerRpcError),
 }
 
 impl From<EthApiError> for ErrorObject<'static> {
@@ -110,6 +124,8 @@ impl From<EthApiError> for ErrorObject<'static> {
             EthApiError::InvalidTransactionSignature |
             EthApiError::EmptyRawTransactionData |
             EthApiError::InvalidBlockRange |
+            EthApiError::BlockRangeTooLarge(_) |
+            EthApiError::ExceedsMaxProofWindow(_) |
             EthApiError::ConflictingFeeFieldsInRequest |
             EthApiError::Signing(_) |
             EthApiError::BothStateAndStateDiffInOverride(_) |
@@ -137,6 +153,19 @@ impl From<EthApiError> for ErrorObject<'static> {
             err @ EthApiError::InternalBlockingTaskError => internal_rpc_err(err.to_string()),
             err @ EthApiError::InternalEthError => internal_rpc_err(err.to_string()),
             err @ EthApiError::CallInputError(_) => invalid_params_rpc_err(err.to_string()),
+            #[cfg(feature = "optimism")]
+            EthApiError::Sequencer(err) => match err {
+                // relay the rejection of the sequencer as is
+                SequencerRpcError::Rpc { code, message, data } => ErrorObject::owned(
+                    code,
+                    message,
+                    data.map(|data| {
+                        jsonrpsee::core::to_json_raw_value(&data)
+                            .expect("serializing json value can't fail")
+                    }),
+                ),
+                err => internal_rpc_err(err.to_string()),
+            },
         }
     }
 }
@@ -617,6 +646,35 @@ pub enum SignError {
     NoChainId,
 }
 
+/// Errors returned when unlocking the accounts of keystore files.
+#[derive(Debug, thiserror::Error)]
+pub enum KeystoreError {
+    /// The keystore directory could not be read.
+    #[error("failed to read keystore directory {path:?}: {err}")]
+    Io {
+        /// The keystore directory.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        err: std::io::Error,
+    },
+    /// A keystore file could not be decrypted, e.g. because the password is wrong.
+    #[error("failed to decrypt keystore file {path:?}: {err}")]
+    Decrypt {
+        /// The keystore file.
+        path: PathBuf,
+        /// The underlying error.
+        #[source]
+        err: eth_keystore::KeystoreError,
+    },
+    /// A keystore file does not contain a valid secp256k1 private key.
+    #[error("keystore file {0:?} does not contain a valid private key")]
+    InvalidKey(PathBuf),
+    /// No keystore files were found at the given path.
+    #[error("no keystore files found at {0:?}")]
+    NoKeystoreFiles(PathBuf),
+}
+
 /// Converts the evm [ExecutionResult] into a result where `Ok` variant is the output bytes if it is
 /// [ExecutionResult::Success].
 pub(crate) fn ensure_success(result: ExecutionResult) -> EthResult<Bytes> {
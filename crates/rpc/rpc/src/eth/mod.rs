@@ -8,13 +8,23 @@ mod filter;
 pub mod gas_oracle;
 mod id_provider;
 mod logs_utils;
+#[cfg(feature = "optimism")]
+mod optimism;
 mod pubsub;
 pub mod revm_utils;
 mod signer;
 pub(crate) mod utils;
 
-pub use api::{EthApi, EthApiSpec, EthTransactions, TransactionSource, RPC_DEFAULT_GAS_CAP};
+pub use api::{
+    EthApi, EthApiSpec, EthTransactions, TransactionSource, DEFAULT_ETH_PROOF_WINDOW,
+    MAX_ETH_PROOF_WINDOW, RPC_DEFAULT_GAS_CAP,
+};
 pub use bundle::EthBundle;
 pub use filter::{EthFilter, EthFilterConfig};
 pub use id_provider::EthSubscriptionIdProvider;
+#[cfg(feature = "optimism")]
+pub use optimism::{
+    SequencerClient, SequencerConfig, SequencerRpcError, DEFAULT_SEQUENCER_TIMEOUT,
+};
 pub use pubsub::EthPubSub;
+pub use signer::{EthSigner, KeystoreSigner};
@@ -0,0 +1,351 @@
+//! OP Stack support for the `eth_` namespace.
+
+use crate::eth::error::{EthApiError, EthResult};
+use reqwest::header::CONTENT_TYPE;
+use reth_primitives::{ChainSpec, Hardfork, Receipt, TransactionSigned, B256, U256, U64};
+use reth_revm::optimism::L1BlockInfo;
+use reth_rpc_types::OptimismTransactionReceiptFields;
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// The number of decimals of the L1 fee scalar.
+const L1_FEE_SCALAR_DECIMALS: usize = 6;
+
+/// The default timeout of the requests to the sequencer, see [SequencerConfig::timeout].
+pub const DEFAULT_SEQUENCER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OP Stack specific data of the block of a transaction, which is required to build the OP Stack
+/// fields of its receipt.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct OptimismTxMeta {
+    /// The L1 block info of the block, `None` on non OP Stack chains.
+    l1_block_info: Option<L1BlockInfo>,
+    /// Whether the Regolith hardfork is active in the block.
+    is_regolith: bool,
+}
+
+impl OptimismTxMeta {
+    /// Extracts the OP Stack data of the block with the given timestamp and transactions.
+    pub(crate) fn new(
+        chain_spec: &ChainSpec,
+        timestamp: u64,
+        transactions: &[TransactionSigned],
+    ) -> EthResult<Self> {
+        if !chain_spec.is_optimism() {
+            return Ok(Self::default())
+        }
+
+        // the L1 attributes deposit is always the first transaction of the block
+        let l1_block_info = transactions
+            .first()
+            .map(|tx| L1BlockInfo::try_from_calldata(tx.input()))
+            .transpose()
+            .map_err(|err| EthApiError::Internal(err.into()))?;
+
+        Ok(Self {
+            l1_block_info,
+            is_regolith: chain_spec.is_fork_active_at_timestamp(Hardfork::Regolith, timestamp),
+        })
+    }
+
+    /// Returns the OP Stack fields of the receipt of the given transaction.
+    pub(crate) fn receipt_fields(
+        &self,
+        tx: &TransactionSigned,
+        receipt: &Receipt,
+    ) -> OptimismTransactionReceiptFields {
+        let mut fields = OptimismTransactionReceiptFields {
+            deposit_nonce: receipt.deposit_nonce.map(U64::from),
+            deposit_receipt_version: receipt.deposit_receipt_version.map(U64::from),
+            ..Default::default()
+        };
+
+        // deposits are paid for on L1 and are not charged the L1 data fee
+        if let Some(l1_block_info) = self.l1_block_info.as_ref().filter(|_| !tx.is_deposit()) {
+            let envelope = tx.envelope_encoded();
+            fields.l1_fee =
+                Some(l1_block_info.calculate_tx_l1_cost(&envelope, false, self.is_regolith));
+            fields.l1_gas_used = Some(
+                l1_block_info.data_gas(&envelope, self.is_regolith) + l1_block_info.l1_fee_overhead,
+            );
+            fields.l1_gas_price = Some(l1_block_info.l1_base_fee);
+            fields.l1_fee_scalar = Some(format_l1_fee_scalar(l1_block_info.l1_fee_scalar));
+        }
+
+        fields
+    }
+}
+
+/// Formats the L1 fee scalar, which is stored with 6 decimals, as a decimal string the same way
+/// op-geth does, e.g. `684000` as `0.684`.
+fn format_l1_fee_scalar(scalar: U256) -> String {
+    let divisor = U256::from(10u64.pow(L1_FEE_SCALAR_DECIMALS as u32));
+    let (integer, fraction) = scalar.div_rem(divisor);
+    if fraction == U256::ZERO {
+        return integer.to_string()
+    }
+    let fraction = format!("{:0>width$}", fraction.to_string(), width = L1_FEE_SCALAR_DECIMALS);
+    format!("{integer}.{}", fraction.trim_end_matches('0'))
+}
+
+/// Settings for forwarding raw transactions to the sequencer of an OP Stack chain.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SequencerConfig {
+    /// The HTTP endpoint of the sequencer.
+    pub endpoint: String,
+    /// Whether forwarded transactions are also inserted into the local pool.
+    pub local_pool_copy: bool,
+    /// The timeout of a forwarded request, which also bounds connecting to the sequencer.
+    pub timeout: Duration,
+}
+
+impl SequencerConfig {
+    /// Creates a new config that forwards to the given endpoint and keeps a local pool copy, with
+    /// the [DEFAULT_SEQUENCER_TIMEOUT].
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            local_pool_copy: true,
+            timeout: DEFAULT_SEQUENCER_TIMEOUT,
+        }
+    }
+
+    /// Configures whether forwarded transactions are also inserted into the local pool.
+    pub fn local_pool_copy(mut self, local_pool_copy: bool) -> Self {
+        self.local_pool_copy = local_pool_copy;
+        self
+    }
+
+    /// Configures the timeout of the requests to the sequencer.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// A client that forwards raw transactions to the sequencer of an OP Stack chain.
+///
+/// Verifier nodes don't build blocks, so transactions submitted to them must be relayed to the
+/// sequencer to be included.
+#[derive(Debug, Clone)]
+pub struct SequencerClient {
+    inner: Arc<SequencerClientInner>,
+}
+
+impl SequencerClient {
+    /// Creates a new client for the given config.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client can't be initialized, like [reqwest::Client::new].
+    pub fn new(config: SequencerConfig) -> Self {
+        // a sequencer that doesn't respond must not hold up the `eth_sendRawTransaction` calls
+        let http_client = reqwest::Client::builder()
+            .connect_timeout(config.timeout)
+            .timeout(config.timeout)
+            .build()
+            .expect("failed to build the sequencer HTTP client");
+        let inner = SequencerClientInner { config, http_client, id: AtomicUsize::new(0) };
+        Self { inner: Arc::new(inner) }
+    }
+
+    /// Returns the HTTP endpoint of the sequencer.
+    pub fn endpoint(&self) -> &str {
+        &self.inner.config.endpoint
+    }
+
+    /// Returns `true` if forwarded transactions should also be inserted into the local pool.
+    pub fn local_pool_copy(&self) -> bool {
+        self.inner.config.local_pool_copy
+    }
+
+    /// Forwards the raw transaction to the sequencer via `eth_sendRawTransaction` and returns the
+    /// transaction hash reported by the sequencer.
+    pub async fn forward_raw_transaction(&self, tx: &[u8]) -> Result<B256, SequencerRpcError> {
+        let id = self.inner.id.fetch_add(1, Ordering::SeqCst);
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "eth_sendRawTransaction",
+            "params": [reth_primitives::hex::encode_prefixed(tx)],
+            "id": id,
+        });
+
+        let response = self
+            .inner
+            .http_client
+            .post(self.endpoint())
+            .header(CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .send()
+            .await?
+            .bytes()
+            .await?;
+
+        let response: SequencerResponse = serde_json::from_slice(&response)?;
+        match (response.result, response.error) {
+            (_, Some(err)) => {
+                Err(SequencerRpcError::Rpc { code: err.code, message: err.message, data: err.data })
+            }
+            (Some(hash), None) => Ok(hash),
+            (None, None) => Err(SequencerRpcError::EmptyResponse),
+        }
+    }
+}
+
+/// Container type for [SequencerClient].
+#[derive(Debug)]
+struct SequencerClientInner {
+    /// The forwarding settings.
+    config: SequencerConfig,
+    /// The HTTP client used to reach the sequencer.
+    http_client: reqwest::Client,
+    /// The id of the next JSON-RPC request.
+    id: AtomicUsize,
+}
+
+/// The JSON-RPC response of the sequencer.
+#[derive(Debug, Deserialize)]
+struct SequencerResponse {
+    #[serde(default)]
+    result: Option<B256>,
+    #[serde(default)]
+    error: Option<SequencerErrorObject>,
+}
+
+/// The JSON-RPC error object returned by the sequencer.
+#[derive(Debug, Deserialize)]
+struct SequencerErrorObject {
+    code: i32,
+    message: String,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+/// Errors that can occur when forwarding a transaction to the sequencer.
+#[derive(Debug, thiserror::Error)]
+pub enum SequencerRpcError {
+    /// Thrown when the sequencer can't be reached.
+    #[error("failed to forward transaction to the sequencer: {0}")]
+    Http(#[from] reqwest::Error),
+    /// Thrown when the response of the sequencer is not valid JSON-RPC.
+    #[error("invalid sequencer response: {0}")]
+    InvalidResponse(#[from] serde_json::Error),
+    /// Thrown when the response of the sequencer contains neither a result nor an error.
+    #[error("sequencer response contains neither a result nor an error")]
+    EmptyResponse,
+    /// The sequencer rejected the transaction.
+    #[error("{message}")]
+    Rpc {
+        /// The JSON-RPC error code of the sequencer.
+        code: i32,
+        /// The error message of the sequencer.
+        message: String,
+        /// Additional error data of the sequencer.
+        data: Option<serde_json::Value>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use jsonrpsee::{
+        server::{ServerBuilder, ServerHandle},
+        types::ErrorObjectOwned,
+        RpcModule,
+    };
+    use reth_primitives::{keccak256, Bytes};
+    use std::net::SocketAddr;
+
+    /// Spawns a mock sequencer that accepts every transaction except empty ones.
+    async fn spawn_sequencer() -> (ServerHandle, SocketAddr) {
+        let server = ServerBuilder::default().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let mut module = RpcModule::new(());
+        module
+            .register_method("eth_sendRawTransaction", |params, _| {
+                let (tx,): (Bytes,) = params.parse()?;
+                if tx.is_empty() {
+                    return Err(ErrorObjectOwned::owned(-32000, "empty transaction", None::<()>))
+                }
+                Ok(keccak256(&tx))
+            })
+            .unwrap();
+
+        (server.start(module), addr)
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction() {
+        let (_handle, addr) = spawn_sequencer().await;
+        let client = SequencerClient::new(SequencerConfig::new(format!("http://{addr}")));
+
+        let tx = [0x02, 0xf8, 0x6a];
+        let hash = client.forward_raw_transaction(&tx).await.unwrap();
+        assert_eq!(hash, keccak256(tx));
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction_rejected() {
+        let (_handle, addr) = spawn_sequencer().await;
+        let client = SequencerClient::new(SequencerConfig::new(format!("http://{addr}")));
+
+        let err = client.forward_raw_transaction(&[]).await.unwrap_err();
+        match err {
+            SequencerRpcError::Rpc { code, message, .. } => {
+                assert_eq!(code, -32000);
+                assert_eq!(message, "empty transaction");
+            }
+            err => panic!("unexpected error: {err:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction_unreachable() {
+        let (handle, addr) = spawn_sequencer().await;
+        handle.stop().unwrap();
+        handle.stopped().await;
+
+        let client = SequencerClient::new(SequencerConfig::new(format!("http://{addr}")));
+        let err = client.forward_raw_transaction(&[0x01]).await.unwrap_err();
+        assert!(matches!(err, SequencerRpcError::Http(_)));
+    }
+
+    #[tokio::test]
+    async fn forward_raw_transaction_timeout() {
+        // a sequencer that accepts connections but never responds
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                connections.push(stream);
+            }
+        });
+
+        let client = SequencerClient::new(
+            SequencerConfig::new(format!("http://{addr}")).timeout(Duration::from_millis(100)),
+        );
+        let err =
+            tokio::time::timeout(Duration::from_secs(5), client.forward_raw_transaction(&[0x01]))
+                .await
+                .expect("request to the sequencer should time out")
+                .unwrap_err();
+        assert_matches!(err, SequencerRpcError::Http(err) if err.is_timeout());
+    }
+
+    #[test]
+    fn format_fee_scalar() {
+        assert_eq!(format_l1_fee_scalar(U256::from(684_000)), "0.684");
+        assert_eq!(format_l1_fee_scalar(U256::from(1_000_000)), "1");
+        assert_eq!(format_l1_fee_scalar(U256::from(1_500_001)), "1.500001");
+        assert_eq!(format_l1_fee_scalar(U256::from(5)), "0.000005");
+    }
+}
@@ -1,21 +1,22 @@
 //! An abstraction over ethereum signers.
 
-use crate::eth::error::SignError;
+use crate::eth::error::{KeystoreError, SignError};
 use alloy_dyn_abi::TypedData;
 use reth_primitives::{
-    eip191_hash_message, sign_message, Address, Signature, TransactionSigned, B256,
+    eip191_hash_message, public_key_to_address, sign_message, Address, Signature,
+    TransactionSigned, B256,
 };
 use reth_rpc_types::TypedTransactionRequest;
 
 use reth_rpc_types_compat::transaction::to_primitive_transaction;
-use secp256k1::SecretKey;
-use std::collections::HashMap;
+use secp256k1::{PublicKey, SecretKey, SECP256K1};
+use std::{collections::HashMap, fmt, path::Path};
 
 type Result<T> = std::result::Result<T, SignError>;
 
 /// An Ethereum Signer used via RPC.
 #[async_trait::async_trait]
-pub(crate) trait EthSigner: Send + Sync {
+pub trait EthSigner: Send + Sync {
     /// Returns the available accounts for this signer.
     fn accounts(&self) -> Vec<Address>;
 
@@ -39,12 +40,24 @@ pub(crate) trait EthSigner: Send + Sync {
 }
 
 /// Holds developer keys
+#[derive(Clone)]
 pub(crate) struct DevSigner {
     addresses: Vec<Address>,
     accounts: HashMap<Address, SecretKey>,
 }
 
 impl DevSigner {
+    /// Creates a signer for the accounts of the given keys.
+    pub(crate) fn from_keys(keys: impl IntoIterator<Item = SecretKey>) -> Self {
+        let accounts = keys
+            .into_iter()
+            .map(|key| (public_key_to_address(PublicKey::from_secret_key(SECP256K1, &key)), key))
+            .collect::<HashMap<_, _>>();
+        let mut addresses = accounts.keys().copied().collect::<Vec<_>>();
+        addresses.sort_unstable();
+        Self { addresses, accounts }
+    }
+
     fn get_key(&self, account: Address) -> Result<&SecretKey> {
         self.accounts.get(&account).ok_or(SignError::NoAccount)
     }
@@ -94,10 +107,98 @@ impl EthSigner for DevSigner {
     }
 }
 
+/// Signs with the keys of accounts that were unlocked from encrypted
+/// [Web3 Secret Storage](https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage-definition/)
+/// (V3) keystore files.
+#[derive(Clone)]
+pub struct KeystoreSigner {
+    signer: DevSigner,
+}
+
+impl KeystoreSigner {
+    /// Decrypts the keystore file at the given path with the password, or all keystore files if
+    /// the path is a directory.
+    pub fn unlock(
+        path: impl AsRef<Path>,
+        password: impl AsRef<[u8]>,
+    ) -> std::result::Result<Self, KeystoreError> {
+        let path = path.as_ref();
+        let files = if path.is_dir() {
+            let mut files = Vec::new();
+            for entry in std::fs::read_dir(path)
+                .map_err(|err| KeystoreError::Io { path: path.to_path_buf(), err })?
+            {
+                let entry =
+                    entry.map_err(|err| KeystoreError::Io { path: path.to_path_buf(), err })?;
+                if entry.path().is_file() {
+                    files.push(entry.path());
+                }
+            }
+            files.sort_unstable();
+            files
+        } else {
+            vec![path.to_path_buf()]
+        };
+
+        let mut keys = Vec::with_capacity(files.len());
+        for file in files {
+            let key = eth_keystore::decrypt_key(&file, password.as_ref())
+                .map_err(|err| KeystoreError::Decrypt { path: file.clone(), err })?;
+            let key =
+                SecretKey::from_slice(&key).map_err(|_| KeystoreError::InvalidKey(file.clone()))?;
+            keys.push(key);
+        }
+        if keys.is_empty() {
+            return Err(KeystoreError::NoKeystoreFiles(path.to_path_buf()))
+        }
+
+        Ok(Self { signer: DevSigner::from_keys(keys) })
+    }
+
+    /// Returns the addresses of the unlocked accounts.
+    pub fn addresses(&self) -> &[Address] {
+        &self.signer.addresses
+    }
+}
+
+impl fmt::Debug for KeystoreSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // never print the keys
+        f.debug_struct("KeystoreSigner").field("addresses", &self.signer.addresses).finish()
+    }
+}
+
+#[async_trait::async_trait]
+impl EthSigner for KeystoreSigner {
+    fn accounts(&self) -> Vec<Address> {
+        self.signer.accounts()
+    }
+
+    fn is_signer_for(&self, addr: &Address) -> bool {
+        self.signer.is_signer_for(addr)
+    }
+
+    async fn sign(&self, address: Address, message: &[u8]) -> Result<Signature> {
+        self.signer.sign(address, message).await
+    }
+
+    fn sign_transaction(
+        &self,
+        request: TypedTransactionRequest,
+        address: &Address,
+    ) -> Result<TransactionSigned> {
+        self.signer.sign_transaction(request, address)
+    }
+
+    fn sign_typed_data(&self, address: Address, payload: &TypedData) -> Result<Signature> {
+        self.signer.sign_typed_data(address, payload)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use reth_primitives::U256;
+    use reth_primitives::{address, U256};
     use std::str::FromStr;
     fn build_signer() -> DevSigner {
         let addresses = vec![];
@@ -195,6 +296,51 @@ mod test {
         assert_eq!(sig, expected)
     }
 
+    /// A pbkdf2 keystore of the key `0x4646..46`, encrypted with the password `password`.
+    const KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "000102030405060708090a0b0c0d0e0f" },
+            "ciphertext": "f3d25f0033b384b3d3f1822183b9321ec192a72495217f0e6e9bcc2cc0fb934b",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 1024,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+            },
+            "mac": "f6fb66351d14c9ab5463508c1363a6b1167ee300cde0d0be8d0870b122118199"
+        },
+        "id": "8b5b2c3e-6f2a-4b8e-9a8c-2d1f0e3c4b5a",
+        "version": 3
+    }"#;
+
+    #[tokio::test]
+    async fn test_keystore_signer() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("key.json"), KEYSTORE).unwrap();
+
+        let err = KeystoreSigner::unlock(dir.path(), "wrong password").unwrap_err();
+        assert!(matches!(err, KeystoreError::Decrypt { .. }));
+
+        let signer = KeystoreSigner::unlock(dir.path(), "password").unwrap();
+        let address = address!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f");
+        assert_eq!(signer.accounts(), vec![address]);
+
+        // signs like any other signer with the same key
+        let message = b"Test message";
+        let expected = build_signer().sign(Address::default(), message).await.unwrap();
+        assert_eq!(signer.sign(address, message).await.unwrap(), expected);
+        assert!(matches!(
+            signer.sign(Address::default(), message).await,
+            Err(SignError::NoAccount)
+        ));
+
+        let empty = tempfile::tempdir().unwrap();
+        let err = KeystoreSigner::unlock(empty.path(), "password").unwrap_err();
+        assert!(matches!(err, KeystoreError::NoKeystoreFiles(_)));
+    }
+
     #[tokio::test]
     async fn test_signer() {
         let message = b"Test message";
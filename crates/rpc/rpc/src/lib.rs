@@ -31,7 +31,9 @@ mod debug;
 mod engine;
 pub mod eth;
 mod layers;
+mod mev;
 mod net;
+mod optimism;
 mod otterscan;
 mod reth;
 mod rpc;
@@ -44,7 +46,9 @@ pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiSpec, EthFilter, EthPubSub, EthSubscriptionIdProvider};
 pub use layers::{AuthLayer, AuthValidator, Claims, JwtAuthValidator, JwtError, JwtSecret};
+pub use mev::{MevApi, MevBundleError};
 pub use net::NetApi;
+pub use optimism::{OptimismApi, L2_TO_L1_MESSAGE_PASSER};
 pub use otterscan::OtterscanApi;
 pub use reth::RethApi;
 pub use rpc::RPCApi;
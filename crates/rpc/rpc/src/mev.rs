@@ -0,0 +1,904 @@
+//! `mev` namespace handler implementation.
+
+use crate::{
+    eth::{
+        error::{EthApiError, EthResult},
+        revm_utils::FillableTransaction,
+        utils::recover_raw_transaction,
+        EthTransactions, TransactionSource,
+    },
+    BlockingTaskGuard,
+};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_payload_builder::bundle::{
+    Bundle, BundlePool, BundlePoolError, BundleTransaction, MAX_FUTURE_BLOCKS,
+};
+use reth_primitives::{keccak256, TransactionSignedEcRecovered, B256, U256, U64};
+use reth_provider::{BlockNumReader, StateProvider};
+use reth_revm::database::StateProviderDatabase;
+use reth_rpc_api::MevApiServer;
+use reth_rpc_types::{
+    BlockId, BlockNumberOrTag, BundleItem, Log, Refund, SendBundleRequest, SendBundleResponse,
+    SimBundleLogs, SimBundleOverrides, SimBundleResponse,
+};
+use revm::{
+    db::CacheDB,
+    primitives::{EVMError, Env, ResultAndState},
+    Database,
+};
+use revm_primitives::db::DatabaseCommit;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The maximum depth of nested bundles.
+const MAX_NESTING_DEPTH: usize = 5;
+
+/// The maximum number of items of a bundle, including the items of its nested bundles.
+const MAX_BODY_SIZE: usize = 50;
+
+/// The maximum number of blocks a bundle can be valid for.
+const MAX_BLOCK_RANGE: u64 = 30;
+
+/// The default timeout of `mev_simBundle`.
+const DEFAULT_SIM_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The gas cost of paying out a refund.
+const REFUND_PAYOUT_GAS: u64 = 21_000;
+
+/// `mev` API implementation.
+///
+/// Bundles sent via `mev_sendBundle` are added to a local [BundlePool] the payload builder
+/// includes bundles from, while `mev_simBundle` simulates a bundle on top of a block.
+pub struct MevApi<Provider, Eth> {
+    inner: Arc<MevApiInner<Provider, Eth>>,
+}
+
+// === impl MevApi ===
+
+impl<Provider, Eth> MevApi<Provider, Eth> {
+    /// The provider that can interact with the chain.
+    pub fn provider(&self) -> &Provider {
+        &self.inner.provider
+    }
+
+    /// The pool the bundles sent to the node are added to.
+    pub fn bundle_pool(&self) -> &BundlePool {
+        &self.inner.bundle_pool
+    }
+
+    /// Create a new instance of the [MevApi]
+    pub fn new(
+        provider: Provider,
+        eth_api: Eth,
+        bundle_pool: BundlePool,
+        blocking_task_guard: BlockingTaskGuard,
+    ) -> Self {
+        let inner = Arc::new(MevApiInner { provider, eth_api, bundle_pool, blocking_task_guard });
+        Self { inner }
+    }
+}
+
+impl<Provider, Eth> MevApi<Provider, Eth>
+where
+    Provider: BlockNumReader + 'static,
+    Eth: EthTransactions + 'static,
+{
+    /// Adds the bundle to the bundle pool, so it can be included by the payload builder.
+    ///
+    /// Nested bundles are flattened into the bundle, which is valid for the blocks all of the
+    /// nested bundles are valid for. Refunds aren't paid by the local payload builder.
+    pub async fn send_bundle(&self, request: SendBundleRequest) -> EthResult<SendBundleResponse> {
+        validate_bundle(&request, 0)?;
+
+        let bundle = flatten_bundle(request)?;
+        if bundle.max_block_number - bundle.block_number > MAX_BLOCK_RANGE {
+            return Err(MevBundleError::BlockRangeTooLarge(MAX_BLOCK_RANGE).into())
+        }
+
+        let next_block = self.provider().best_block_number()? + 1;
+        if bundle.is_expired(next_block) {
+            return Err(MevBundleError::BundleExpired(bundle.max_block_number).into())
+        }
+        if bundle.is_too_far_in_future(next_block) {
+            return Err(MevBundleError::BlockNumberTooHigh(MAX_FUTURE_BLOCKS).into())
+        }
+
+        let bundle_hash = bundle.hash;
+        let pool = self.bundle_pool();
+        pool.remove_expired(next_block);
+        pool.add_bundle(bundle).map_err(MevBundleError::from)?;
+
+        Ok(SendBundleResponse { bundle_hash })
+    }
+
+    /// Simulates the bundle, including its nested bundles, at the top of a block on top of the
+    /// given parent block.
+    ///
+    /// If the bundle can't be included, because one of its transactions is invalid or reverts
+    /// without being allowed to, the response isn't successful and contains the error.
+    pub async fn sim_bundle(
+        &self,
+        request: SendBundleRequest,
+        overrides: SimBundleOverrides,
+    ) -> EthResult<SimBundleResponse> {
+        validate_bundle(&request, 0)?;
+
+        let SimBundleOverrides {
+            parent_block,
+            block_number,
+            coinbase,
+            timestamp,
+            gas_limit,
+            base_fee,
+            timeout,
+        } = overrides;
+
+        // resolve the transactions the bundle refers to by hash, those need to be pending
+        let mut hashes = Vec::new();
+        collect_hashes(&request, &mut hashes);
+        let mut pending = HashMap::with_capacity(hashes.len());
+        for hash in hashes {
+            match self.inner.eth_api.transaction_by_hash(hash).await? {
+                Some(TransactionSource::Pool(tx)) => {
+                    pending.insert(hash, tx);
+                }
+                _ => return Err(MevBundleError::UnknownTransaction(hash).into()),
+            }
+        }
+        let bundle = SimBundle::new(request, &pending)?;
+
+        let parent_block = parent_block.unwrap_or(BlockId::Number(BlockNumberOrTag::Latest));
+        let (cfg, mut block_env, at) = self.inner.eth_api.evm_env_at(parent_block).await?;
+        let state_block = block_env.number.to::<u64>();
+
+        block_env.number =
+            U256::from(block_number.map(|number| number.to()).unwrap_or(state_block + 1));
+        block_env.timestamp = timestamp
+            .map(|timestamp| U256::from(timestamp.to::<u64>()))
+            .unwrap_or_else(|| block_env.timestamp + U256::from(12));
+        if let Some(coinbase) = coinbase {
+            block_env.coinbase = coinbase;
+        }
+        if let Some(gas_limit) = gas_limit {
+            block_env.gas_limit = U256::from(gas_limit.to::<u64>());
+        }
+        if let Some(base_fee) = base_fee {
+            block_env.basefee = U256::from(base_fee.to::<u64>());
+        }
+
+        let sim_block = block_env.number.to::<u64>();
+        bundle.ensure_valid_for_block(sim_block)?;
+
+        let timeout =
+            timeout.map(|timeout| Duration::from_secs(timeout.to())).unwrap_or(DEFAULT_SIM_TIMEOUT);
+        let deadline = Instant::now() + timeout;
+
+        let _permit = self.inner.blocking_task_guard.clone().acquire_owned().await;
+        let outcome = self
+            .inner
+            .eth_api
+            .spawn_with_state_at_block(at, move |state| {
+                let env = Env { cfg, block: block_env, ..Default::default() };
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+                BundleSimulator { env, deadline, timeout }.simulate(&bundle, &mut db)
+            })
+            .await?;
+
+        let SimOutcome { gas_used, profit, refundable_value, logs, error } = outcome;
+        let mev_gas_price = profit.checked_div(U256::from(gas_used)).unwrap_or_default();
+        let to_u64 = |value: U256| U64::from(u64::try_from(value).unwrap_or(u64::MAX));
+
+        Ok(SimBundleResponse {
+            success: error.is_none(),
+            error,
+            state_block: U64::from(state_block),
+            mev_gas_price: to_u64(mev_gas_price),
+            profit: to_u64(profit),
+            refundable_value: to_u64(refundable_value),
+            gas_used: U64::from(gas_used),
+            logs: Some(logs),
+        })
+    }
+}
+
+#[async_trait]
+impl<Provider, Eth> MevApiServer for MevApi<Provider, Eth>
+where
+    Provider: BlockNumReader + 'static,
+    Eth: EthTransactions + 'static,
+{
+    /// Handler for `mev_sendBundle`
+    async fn send_bundle(&self, request: SendBundleRequest) -> RpcResult<SendBundleResponse> {
+        Ok(MevApi::send_bundle(self, request).await?)
+    }
+
+    /// Handler for `mev_simBundle`
+    async fn sim_bundle(
+        &self,
+        bundle: SendBundleRequest,
+        sim_overrides: SimBundleOverrides,
+    ) -> RpcResult<SimBundleResponse> {
+        Ok(MevApi::sim_bundle(self, bundle, sim_overrides).await?)
+    }
+}
+
+impl<Provider, Eth> std::fmt::Debug for MevApi<Provider, Eth> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MevApi").finish_non_exhaustive()
+    }
+}
+
+impl<Provider, Eth> Clone for MevApi<Provider, Eth> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+/// Container type for `MevApi` internals
+struct MevApiInner<Provider, Eth> {
+    /// The provider that can interact with the chain.
+    provider: Provider,
+    /// Access to commonly used code of the `eth` namespace
+    eth_api: Eth,
+    /// The pool the bundles sent to the node are added to.
+    bundle_pool: BundlePool,
+    /// Restricts the number of concurrent simulations.
+    blocking_task_guard: BlockingTaskGuard,
+}
+
+/// Validates the structure of the bundle and its nested bundles.
+///
+/// Returns the number of items of the bundle, including the items of its nested bundles.
+fn validate_bundle(bundle: &SendBundleRequest, depth: usize) -> Result<usize, MevBundleError> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(MevBundleError::MaxDepthExceeded(MAX_NESTING_DEPTH))
+    }
+    if bundle.bundle_body.is_empty() {
+        return Err(MevBundleError::EmptyBundleBody)
+    }
+
+    let block = bundle.inclusion.block_number();
+    if block == 0 {
+        return Err(MevBundleError::BundleMissingBlockNumber)
+    }
+    if bundle.inclusion.max_block_number().is_some_and(|max_block| max_block < block) {
+        return Err(MevBundleError::InvalidInclusion)
+    }
+
+    if let Some(validity) = &bundle.validity {
+        let mut total_percent = 0u64;
+        for refund in validity.refund.iter().flatten() {
+            if refund.body_idx as usize >= bundle.bundle_body.len() {
+                return Err(MevBundleError::InvalidRefundIndex(refund.body_idx))
+            }
+            total_percent = total_percent.saturating_add(refund.percent);
+        }
+        if total_percent > 100 {
+            return Err(MevBundleError::InvalidRefundPercent)
+        }
+
+        let total_config_percent = validity
+            .refund_config
+            .iter()
+            .flatten()
+            .fold(0u64, |total, config| total.saturating_add(config.percent));
+        if total_config_percent > 100 {
+            return Err(MevBundleError::InvalidRefundPercent)
+        }
+    }
+
+    let mut size = 0;
+    for item in &bundle.bundle_body {
+        size += match item {
+            BundleItem::Bundle { bundle } => validate_bundle(bundle, depth + 1)?,
+            BundleItem::Hash { .. } | BundleItem::Tx { .. } => 1,
+        };
+    }
+    if size > MAX_BODY_SIZE {
+        return Err(MevBundleError::BundleTooLarge(MAX_BODY_SIZE))
+    }
+
+    Ok(size)
+}
+
+/// Collects the hashes of all transactions the bundle and its nested bundles refer to by hash.
+fn collect_hashes(bundle: &SendBundleRequest, hashes: &mut Vec<B256>) {
+    for item in &bundle.bundle_body {
+        match item {
+            BundleItem::Hash { hash } => hashes.push(*hash),
+            BundleItem::Bundle { bundle } => collect_hashes(bundle, hashes),
+            BundleItem::Tx { .. } => {}
+        }
+    }
+}
+
+/// Decodes and recovers the raw transaction of a bundle.
+fn recover_bundle_transaction(
+    tx: reth_primitives::Bytes,
+) -> EthResult<TransactionSignedEcRecovered> {
+    Ok(recover_raw_transaction(tx)?.into_ecrecovered_transaction())
+}
+
+/// Flattens the bundle and its nested bundles into a single [Bundle] of the [BundlePool].
+///
+/// The hash of the bundle is the hash of the concatenated hashes of its items, where the hash of
+/// a nested bundle is computed the same way.
+fn flatten_bundle(request: SendBundleRequest) -> EthResult<Bundle> {
+    let SendBundleRequest { inclusion, bundle_body, .. } = request;
+    let mut block_number = inclusion.block_number();
+    let mut max_block_number = inclusion.max_block_number().unwrap_or(block_number);
+
+    let mut transactions = Vec::with_capacity(bundle_body.len());
+    let mut hash_bytes = Vec::with_capacity(32 * bundle_body.len());
+    for item in bundle_body {
+        match item {
+            BundleItem::Hash { hash } => {
+                hash_bytes.extend_from_slice(hash.as_slice());
+                transactions.push(BundleTransaction::Hash(hash));
+            }
+            BundleItem::Tx { tx, can_revert } => {
+                let transaction = recover_bundle_transaction(tx)?;
+                if transaction.is_eip4844() {
+                    return Err(MevBundleError::BlobTransaction(transaction.hash()).into())
+                }
+                hash_bytes.extend_from_slice(transaction.hash().as_slice());
+                transactions.push(BundleTransaction::Signed { transaction, can_revert });
+            }
+            BundleItem::Bundle { bundle } => {
+                let nested = flatten_bundle(*bundle)?;
+                hash_bytes.extend_from_slice(nested.hash.as_slice());
+                block_number = block_number.max(nested.block_number);
+                max_block_number = max_block_number.min(nested.max_block_number);
+                transactions.extend(nested.transactions);
+            }
+        }
+    }
+
+    if max_block_number < block_number {
+        return Err(MevBundleError::InvalidInclusion.into())
+    }
+
+    Ok(Bundle {
+        hash: keccak256(&hash_bytes),
+        transactions,
+        block_number,
+        max_block_number,
+        min_timestamp: None,
+        max_timestamp: None,
+        replacement_uuid: None,
+    })
+}
+
+/// A bundle of `mev_simBundle` whose transactions are recovered and ready to be simulated.
+#[derive(Debug)]
+struct SimBundle {
+    /// The items of the bundle, in the order they're executed in.
+    items: Vec<SimBundleItem>,
+    /// The refunds of the bundle's earnings.
+    refunds: Vec<Refund>,
+    /// Whether the logs of the bundle's transactions can be shared.
+    share_logs: bool,
+    /// The first block the bundle is valid for.
+    block_number: u64,
+    /// The last block the bundle is valid for.
+    max_block_number: u64,
+}
+
+/// An item of a [SimBundle].
+#[derive(Debug)]
+enum SimBundleItem {
+    /// A transaction of the bundle.
+    Tx { tx: TransactionSignedEcRecovered, can_revert: bool },
+    /// A nested bundle.
+    Bundle(SimBundle),
+}
+
+impl SimBundle {
+    /// Recovers the transactions of the bundle, taking the transactions that are referred to by
+    /// hash from the given pending transactions.
+    fn new(
+        request: SendBundleRequest,
+        pending: &HashMap<B256, TransactionSignedEcRecovered>,
+    ) -> EthResult<Self> {
+        let SendBundleRequest { inclusion, bundle_body, validity, privacy, .. } = request;
+        let block_number = inclusion.block_number();
+        let max_block_number = inclusion.max_block_number().unwrap_or(block_number);
+
+        let items = bundle_body
+            .into_iter()
+            .map(|item| -> EthResult<SimBundleItem> {
+                Ok(match item {
+                    BundleItem::Hash { hash } => SimBundleItem::Tx {
+                        tx: pending
+                            .get(&hash)
+                            .cloned()
+                            .ok_or(MevBundleError::UnknownTransaction(hash))?,
+                        can_revert: false,
+                    },
+                    BundleItem::Tx { tx, can_revert } => {
+                        SimBundleItem::Tx { tx: recover_bundle_transaction(tx)?, can_revert }
+                    }
+                    BundleItem::Bundle { bundle } => {
+                        SimBundleItem::Bundle(SimBundle::new(*bundle, pending)?)
+                    }
+                })
+            })
+            .collect::<EthResult<Vec<_>>>()?;
+
+        let share_logs =
+            privacy.and_then(|privacy| privacy.hints).map(|hints| hints.has_logs()).unwrap_or(true);
+
+        Ok(Self {
+            items,
+            refunds: validity.and_then(|validity| validity.refund).unwrap_or_default(),
+            share_logs,
+            block_number,
+            max_block_number,
+        })
+    }
+
+    /// Returns an error if the bundle or any of its nested bundles isn't valid for the block with
+    /// the given number.
+    fn ensure_valid_for_block(&self, block_number: u64) -> Result<(), MevBundleError> {
+        if !(self.block_number..=self.max_block_number).contains(&block_number) {
+            return Err(MevBundleError::InvalidBlock(block_number))
+        }
+        for item in &self.items {
+            if let SimBundleItem::Bundle(bundle) = item {
+                bundle.ensure_valid_for_block(block_number)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of simulating a [SimBundle].
+#[derive(Debug, Default)]
+struct SimOutcome {
+    /// The gas used by the transactions of the bundle.
+    gas_used: u64,
+    /// The earnings of the coinbase, after paying the refunds.
+    profit: U256,
+    /// The earnings of the coinbase that can be refunded.
+    refundable_value: U256,
+    /// The logs of the bundle's items.
+    logs: Vec<SimBundleLogs>,
+    /// Set if the bundle can't be included.
+    error: Option<String>,
+}
+
+/// Simulates [SimBundle]s on top of a block.
+#[derive(Debug)]
+struct BundleSimulator {
+    /// The environment of the block the bundle is simulated in.
+    env: Env,
+    /// The point in time the simulation is aborted at.
+    deadline: Instant,
+    /// The timeout of the simulation.
+    timeout: Duration,
+}
+
+impl BundleSimulator {
+    /// Executes the bundle and its nested bundles on top of the given state.
+    ///
+    /// Stops at the first transaction that's invalid or reverts without being allowed to.
+    fn simulate<S: StateProvider>(
+        &self,
+        bundle: &SimBundle,
+        db: &mut CacheDB<StateProviderDatabase<S>>,
+    ) -> EthResult<SimOutcome> {
+        let coinbase = self.env.block.coinbase;
+        let mut outcome = SimOutcome::default();
+
+        for (idx, item) in bundle.items.iter().enumerate() {
+            let is_refunded = bundle.refunds.iter().any(|refund| refund.body_idx as usize == idx);
+
+            let value = match item {
+                SimBundleItem::Tx { tx, can_revert } => {
+                    if Instant::now() > self.deadline {
+                        return Err(EthApiError::ExecutionTimedOut(self.timeout))
+                    }
+
+                    let coinbase_before =
+                        db.basic(coinbase)?.map(|acc| acc.balance).unwrap_or_default();
+
+                    let mut evm = revm::EVM::with_env(self.env.clone());
+                    tx.try_fill_tx_env(&mut evm.env.tx)?;
+                    evm.database(&mut *db);
+
+                    let ResultAndState { result, state } = match evm.transact() {
+                        Ok(res) => res,
+                        Err(EVMError::Transaction(err)) => {
+                            outcome.error =
+                                Some(format!("invalid transaction {:?}: {err:?}", tx.hash()));
+                            return Ok(outcome)
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+                    if !result.is_success() && !can_revert {
+                        outcome.error = Some(format!("transaction {:?} reverted", tx.hash()));
+                        return Ok(outcome)
+                    }
+
+                    let coinbase_after =
+                        state.get(&coinbase).map(|acc| acc.info.balance).unwrap_or(coinbase_before);
+
+                    outcome.gas_used += result.gas_used();
+                    outcome.logs.push(SimBundleLogs {
+                        tx_logs: Some(
+                            result
+                                .logs()
+                                .into_iter()
+                                .map(|log| Log {
+                                    address: log.address,
+                                    topics: log.topics,
+                                    data: log.data,
+                                    block_hash: None,
+                                    block_number: None,
+                                    transaction_hash: Some(tx.hash()),
+                                    transaction_index: None,
+                                    log_index: None,
+                                    removed: false,
+                                })
+                                .collect(),
+                        ),
+                        bundle_logs: None,
+                    });
+                    db.commit(state);
+
+                    coinbase_after.saturating_sub(coinbase_before)
+                }
+                SimBundleItem::Bundle(nested) => {
+                    let nested_outcome = self.simulate(nested, db)?;
+                    outcome.gas_used += nested_outcome.gas_used;
+                    outcome.logs.push(SimBundleLogs {
+                        tx_logs: None,
+                        bundle_logs: nested.share_logs.then_some(nested_outcome.logs),
+                    });
+                    if nested_outcome.error.is_some() {
+                        outcome.error = nested_outcome.error;
+                        return Ok(outcome)
+                    }
+
+                    nested_outcome.profit
+                }
+            };
+
+            outcome.profit += value;
+            if !is_refunded {
+                outcome.refundable_value += value;
+            }
+        }
+
+        // the refunds are paid out of the refundable value, including the cost of the payout
+        let payout_cost = U256::from(REFUND_PAYOUT_GAS) * self.env.block.basefee;
+        for refund in &bundle.refunds {
+            let amount = outcome.refundable_value * U256::from(refund.percent) / U256::from(100);
+            if amount < payout_cost {
+                outcome.error = Some(format!(
+                    "refund of body index {} doesn't cover the cost of its payout",
+                    refund.body_idx
+                ));
+                return Ok(outcome)
+            }
+            outcome.profit = outcome.profit.saturating_sub(amount);
+        }
+
+        Ok(outcome)
+    }
+}
+
+/// [MevApi] specific errors.
+#[derive(Debug, thiserror::Error)]
+pub enum MevBundleError {
+    /// Thrown if the bundle does not contain any items.
+    #[error("bundle missing body")]
+    EmptyBundleBody,
+    /// Thrown if the bundle does not contain a block number, or block number is 0.
+    #[error("bundle missing block")]
+    BundleMissingBlockNumber,
+    /// Thrown if the last block of the bundle is before its first block.
+    #[error("invalid bundle inclusion")]
+    InvalidInclusion,
+    /// Thrown if the bundle is valid for too many blocks.
+    #[error("bundle can't be valid for more than {0} blocks")]
+    BlockRangeTooLarge(u64),
+    /// Thrown if the bundle can't be included in the next block or any later block.
+    #[error("bundle expired at block {0}")]
+    BundleExpired(u64),
+    /// Thrown if the bundle is sent for a block too far past the next block.
+    #[error("bundle can't be sent more than {0} blocks in advance")]
+    BlockNumberTooHigh(u64),
+    /// Thrown if the bundle, or any of its nested bundles, isn't valid for the simulated block.
+    #[error("bundle is not valid for block {0}")]
+    InvalidBlock(u64),
+    /// Thrown if the bundles are nested too deeply.
+    #[error("bundles can't be nested more than {0} levels deep")]
+    MaxDepthExceeded(usize),
+    /// Thrown if the bundle has too many items.
+    #[error("bundle can't have more than {0} items")]
+    BundleTooLarge(usize),
+    /// Thrown if a refund refers to an item that isn't part of the bundle.
+    #[error("invalid refund body index {0}")]
+    InvalidRefundIndex(u64),
+    /// Thrown if the refund percentages of the bundle add up to more than 100.
+    #[error("refund percentages exceed 100")]
+    InvalidRefundPercent,
+    /// Thrown if a transaction the bundle refers to by hash isn't pending.
+    #[error("unknown pending transaction {0:?}")]
+    UnknownTransaction(B256),
+    /// Thrown if the bundle contains a blob transaction.
+    #[error("blob transaction {0:?} can't be part of a bundle")]
+    BlobTransaction(B256),
+    /// Thrown if the bundle can't be added to the bundle pool.
+    #[error(transparent)]
+    BundlePool(#[from] BundlePoolError),
+}
+
+impl From<MevBundleError> for EthApiError {
+    fn from(err: MevBundleError) -> Self {
+        EthApiError::InvalidParams(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{
+        constants::GWEI_TO_WEI, Address, Bytes, Signature, Transaction, TransactionKind,
+        TransactionSigned, TxEip1559,
+    };
+    use reth_provider::test_utils::NoopProvider;
+    use reth_rpc_types::{Inclusion, Privacy, PrivacyHint, RefundConfig, Validity};
+    use revm::primitives::{AccountInfo, Bytecode};
+
+    const COINBASE: Address = Address::with_last_byte(0xc0);
+    const SENDER: Address = Address::with_last_byte(0x01);
+    /// A contract that always reverts: `PUSH1 0 PUSH1 0 REVERT`.
+    const REVERTER: Address = Address::with_last_byte(0x02);
+    const BASE_FEE: u64 = GWEI_TO_WEI;
+
+    fn hash_item(hash: u8) -> BundleItem {
+        BundleItem::Hash { hash: B256::with_last_byte(hash) }
+    }
+
+    fn request(block: u64, max_block: Option<u64>, body: Vec<BundleItem>) -> SendBundleRequest {
+        SendBundleRequest::new(U64::from(block), max_block.map(U64::from), Default::default(), body)
+    }
+
+    #[test]
+    fn validate_bundles() {
+        assert!(matches!(
+            validate_bundle(&request(1, None, vec![]), 0),
+            Err(MevBundleError::EmptyBundleBody)
+        ));
+        assert!(matches!(
+            validate_bundle(&request(2, Some(1), vec![hash_item(1)]), 0),
+            Err(MevBundleError::InvalidInclusion)
+        ));
+
+        let nested = request(1, None, vec![hash_item(1), hash_item(2)]);
+        let bundle =
+            request(1, None, vec![BundleItem::Bundle { bundle: Box::new(nested) }, hash_item(3)]);
+        assert_eq!(validate_bundle(&bundle, 0).unwrap(), 3);
+
+        let mut bundle = request(1, None, vec![hash_item(1)]);
+        for _ in 0..=MAX_NESTING_DEPTH {
+            bundle = request(1, None, vec![BundleItem::Bundle { bundle: Box::new(bundle) }]);
+        }
+        assert!(matches!(validate_bundle(&bundle, 0), Err(MevBundleError::MaxDepthExceeded(_))));
+    }
+
+    #[test]
+    fn validate_refunds() {
+        let mut bundle = request(1, None, vec![hash_item(1), hash_item(2)]);
+        bundle.validity = Some(Validity {
+            refund: Some(vec![Refund { body_idx: 2, percent: 10 }]),
+            refund_config: None,
+        });
+        assert!(matches!(validate_bundle(&bundle, 0), Err(MevBundleError::InvalidRefundIndex(2))));
+
+        bundle.validity = Some(Validity {
+            refund: Some(vec![
+                Refund { body_idx: 0, percent: 60 },
+                Refund { body_idx: 1, percent: 50 },
+            ]),
+            refund_config: None,
+        });
+        assert!(matches!(validate_bundle(&bundle, 0), Err(MevBundleError::InvalidRefundPercent)));
+
+        bundle.validity = Some(Validity {
+            refund: Some(vec![Refund { body_idx: 0, percent: 90 }]),
+            refund_config: Some(vec![RefundConfig { address: Default::default(), percent: 101 }]),
+        });
+        assert!(matches!(validate_bundle(&bundle, 0), Err(MevBundleError::InvalidRefundPercent)));
+
+        bundle.validity.as_mut().unwrap().refund_config = None;
+        assert!(validate_bundle(&bundle, 0).is_ok());
+    }
+
+    #[test]
+    fn flatten_nested_bundle() {
+        let nested = request(2, Some(10), vec![hash_item(1)]);
+        let nested_hash = keccak256(B256::with_last_byte(1));
+        let bundle = request(
+            1,
+            Some(5),
+            vec![BundleItem::Bundle { bundle: Box::new(nested) }, hash_item(2)],
+        );
+
+        let bundle = flatten_bundle(bundle).unwrap();
+        assert_eq!(
+            bundle.transactions,
+            vec![
+                BundleTransaction::Hash(B256::with_last_byte(1)),
+                BundleTransaction::Hash(B256::with_last_byte(2))
+            ]
+        );
+        assert_eq!((bundle.block_number, bundle.max_block_number), (2, 5));
+        assert_eq!(
+            bundle.hash,
+            keccak256([nested_hash.as_slice(), B256::with_last_byte(2).as_slice()].concat())
+        );
+
+        // the nested bundle is only valid for blocks the outer bundle isn't valid for
+        let nested = request(6, None, vec![hash_item(1)]);
+        let bundle = request(1, Some(5), vec![BundleItem::Bundle { bundle: Box::new(nested) }]);
+        assert!(flatten_bundle(bundle).is_err());
+
+        let bundle = request(
+            1,
+            None,
+            vec![BundleItem::Tx { tx: Bytes::from_static(&[1, 2, 3]), can_revert: false }],
+        );
+        assert!(flatten_bundle(bundle).is_err());
+    }
+
+    #[test]
+    fn sim_bundle_privacy_and_inclusion() {
+        let mut nested = request(2, Some(3), vec![hash_item(1)]);
+        nested.privacy =
+            Some(Privacy { hints: Some(PrivacyHint::default().with_hash()), builders: None });
+        let mut bundle =
+            request(1, None, vec![BundleItem::Bundle { bundle: Box::new(nested) }, hash_item(1)]);
+        bundle.inclusion = Inclusion { block: U64::from(1), max_block: Some(U64::from(5)) };
+
+        let pending = HashMap::from([(B256::with_last_byte(1), Default::default())]);
+        let bundle = SimBundle::new(bundle, &pending).unwrap();
+        assert!(bundle.share_logs);
+        let SimBundleItem::Bundle(nested) = &bundle.items[0] else { panic!("expected bundle") };
+        assert!(!nested.share_logs);
+
+        assert!(bundle.ensure_valid_for_block(2).is_ok());
+        assert!(matches!(bundle.ensure_valid_for_block(4), Err(MevBundleError::InvalidBlock(4))));
+    }
+
+    fn simulator() -> BundleSimulator {
+        let mut env = Env::default();
+        env.block.coinbase = COINBASE;
+        env.block.basefee = U256::from(BASE_FEE);
+        BundleSimulator {
+            env,
+            deadline: Instant::now() + DEFAULT_SIM_TIMEOUT,
+            timeout: DEFAULT_SIM_TIMEOUT,
+        }
+    }
+
+    fn seeded_db() -> CacheDB<StateProviderDatabase<NoopProvider>> {
+        let mut db = CacheDB::new(StateProviderDatabase::new(NoopProvider::default()));
+        db.insert_account_info(
+            SENDER,
+            AccountInfo { balance: U256::from(10u128.pow(18)), ..Default::default() },
+        );
+        db.insert_account_info(
+            REVERTER,
+            AccountInfo {
+                code: Some(Bytecode::new_raw(Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xfd]))),
+                ..Default::default()
+            },
+        );
+        db
+    }
+
+    /// Returns a transaction of [SENDER] that doesn't pay a priority fee, so the coinbase only
+    /// earns the transferred value.
+    fn tx(nonce: u64, to: Address, value: u64) -> TransactionSignedEcRecovered {
+        let tx = Transaction::Eip1559(TxEip1559 {
+            chain_id: 1,
+            nonce,
+            gas_limit: 100_000,
+            max_fee_per_gas: BASE_FEE as u128,
+            max_priority_fee_per_gas: 0,
+            to: TransactionKind::Call(to),
+            value: U256::from(value).into(),
+            ..Default::default()
+        });
+        TransactionSignedEcRecovered::from_signed_transaction(
+            TransactionSigned::from_transaction_and_signature(tx, Signature::default()),
+            SENDER,
+        )
+    }
+
+    fn sim_bundle(items: Vec<SimBundleItem>, refunds: Vec<Refund>) -> SimBundle {
+        SimBundle { items, refunds, share_logs: true, block_number: 1, max_block_number: 1 }
+    }
+
+    fn item(tx: TransactionSignedEcRecovered, can_revert: bool) -> SimBundleItem {
+        SimBundleItem::Tx { tx, can_revert }
+    }
+
+    #[test]
+    fn simulate_coinbase_payment() {
+        let bundle = sim_bundle(vec![item(tx(0, COINBASE, 1_000), false)], vec![]);
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.gas_used, 21_000);
+        assert_eq!(outcome.profit, U256::from(1_000));
+        assert_eq!(outcome.refundable_value, U256::from(1_000));
+    }
+
+    #[test]
+    fn simulate_reverting_transaction() {
+        let bundle = sim_bundle(
+            vec![item(tx(0, REVERTER, 0), true), item(tx(1, COINBASE, 1_000), false)],
+            vec![],
+        );
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.logs.len(), 2);
+        assert_eq!(outcome.profit, U256::from(1_000));
+
+        // the same transaction invalidates the bundle if it's not allowed to revert
+        let bundle = sim_bundle(
+            vec![item(tx(0, REVERTER, 0), false), item(tx(1, COINBASE, 1_000), false)],
+            vec![],
+        );
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+        assert!(outcome.error.unwrap().contains("reverted"));
+        assert_eq!(outcome.profit, U256::ZERO);
+    }
+
+    #[test]
+    fn simulate_nested_bundle_profit() {
+        let nested = sim_bundle(vec![item(tx(0, COINBASE, 1_000), false)], vec![]);
+        let bundle = sim_bundle(
+            vec![SimBundleItem::Bundle(nested), item(tx(1, COINBASE, 2_000), false)],
+            vec![],
+        );
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.gas_used, 42_000);
+        assert_eq!(outcome.profit, U256::from(3_000));
+        assert!(outcome.logs[0].bundle_logs.is_some());
+    }
+
+    #[test]
+    fn simulate_refunds() {
+        let payout_cost = REFUND_PAYOUT_GAS * BASE_FEE;
+
+        // the first transaction is refunded half of the value the backrun pays to the coinbase
+        let refunds = vec![Refund { body_idx: 0, percent: 50 }];
+        let bundle = sim_bundle(
+            vec![item(tx(0, COINBASE, 0), false), item(tx(1, COINBASE, 4 * payout_cost), false)],
+            refunds.clone(),
+        );
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+        assert!(outcome.error.is_none());
+        assert_eq!(outcome.refundable_value, U256::from(4 * payout_cost));
+        assert_eq!(outcome.profit, U256::from(2 * payout_cost));
+
+        // the refund doesn't cover the cost of paying it out
+        let bundle = sim_bundle(
+            vec![item(tx(0, COINBASE, 0), false), item(tx(1, COINBASE, payout_cost), false)],
+            refunds,
+        );
+        let outcome = simulator().simulate(&bundle, &mut seeded_db()).unwrap();
+        assert!(outcome.error.unwrap().contains("doesn't cover the cost of its payout"));
+    }
+}
@@ -0,0 +1,389 @@
+use crate::eth::error::{EthApiError, EthResult};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_primitives::{
+    address, keccak256, Address, BlockNumberOrTag, ForkCondition, Hardfork, SealedHeader, B256,
+};
+use reth_provider::{BlockReaderIdExt, ChainSpecProvider, StateProviderFactory};
+use reth_rpc_api::OptimismApiServer;
+use reth_rpc_types::{
+    L2BlockRef, OutputResponse, RollupBlockId, RollupConfig, RollupGenesis, RollupSyncStatus,
+};
+use reth_tasks::TaskSpawner;
+use std::{future::Future, sync::Arc};
+use tokio::sync::oneshot;
+
+/// The address of the `L2ToL1MessagePasser` predeploy, which stores the withdrawals initiated on
+/// L2.
+pub const L2_TO_L1_MESSAGE_PASSER: Address = address!("4200000000000000000000000000000000000016");
+
+/// The version of the L2 output root.
+const OUTPUT_ROOT_VERSION: B256 = B256::ZERO;
+
+/// `optimism` API implementation.
+///
+/// This type provides the functionality for handling `optimism` rollup RPC requests.
+pub struct OptimismApi<Provider> {
+    inner: Arc<OptimismApiInner<Provider>>,
+}
+
+// === impl OptimismApi ===
+
+impl<Provider> OptimismApi<Provider> {
+    /// The provider that can interact with the chain.
+    pub fn provider(&self) -> &Provider {
+        &self.inner.provider
+    }
+
+    /// Create a new instance of the [OptimismApi]
+    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        let inner = Arc::new(OptimismApiInner { provider, task_spawner });
+        Self { inner }
+    }
+}
+
+impl<Provider> OptimismApi<Provider>
+where
+    Provider: BlockReaderIdExt + ChainSpecProvider + StateProviderFactory + 'static,
+{
+    /// Executes the future on a new blocking task.
+    async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
+    where
+        C: FnOnce(Self) -> F,
+        F: Future<Output = EthResult<R>> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let this = self.clone();
+        let f = c(this);
+        self.inner.task_spawner.spawn_blocking(Box::pin(async move {
+            let res = f.await;
+            let _ = tx.send(res);
+        }));
+        rx.await.map_err(|_| EthApiError::InternalEthError)?
+    }
+
+    /// Returns the L2 output of the given block.
+    pub async fn output_at_block(
+        &self,
+        block_number: BlockNumberOrTag,
+    ) -> EthResult<OutputResponse> {
+        self.on_blocking_task(|this| async move { this.try_output_at_block(block_number) }).await
+    }
+
+    fn try_output_at_block(&self, block_number: BlockNumberOrTag) -> EthResult<OutputResponse> {
+        self.ensure_optimism()?;
+
+        let header = self
+            .provider()
+            .sealed_header_by_number_or_tag(block_number)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let state_root = header.state_root;
+        let withdrawal_storage_root = self
+            .provider()
+            .state_by_block_hash(header.hash)?
+            .storage_root(L2_TO_L1_MESSAGE_PASSER)?;
+        let block_ref = self.l2_block_ref(header)?;
+
+        Ok(OutputResponse {
+            version: OUTPUT_ROOT_VERSION,
+            output_root: output_root(state_root, withdrawal_storage_root, block_ref.hash),
+            block_ref,
+            withdrawal_storage_root,
+            state_root,
+        })
+    }
+
+    /// Returns the L2 sync status of the node.
+    pub async fn sync_status(&self) -> EthResult<RollupSyncStatus> {
+        self.on_blocking_task(|this| async move { this.try_sync_status() }).await
+    }
+
+    fn try_sync_status(&self) -> EthResult<RollupSyncStatus> {
+        self.ensure_optimism()?;
+
+        // blocks that are not known yet are reported as zero, like the rollup node does
+        let block_ref = |header: Option<SealedHeader>| -> EthResult<L2BlockRef> {
+            Ok(header.map(|header| self.l2_block_ref(header)).transpose()?.unwrap_or_default())
+        };
+
+        Ok(RollupSyncStatus {
+            unsafe_l2: block_ref(self.provider().latest_header()?)?,
+            safe_l2: block_ref(self.provider().safe_header()?)?,
+            finalized_l2: block_ref(self.provider().finalized_header()?)?,
+        })
+    }
+
+    /// Returns the rollup config of the chain.
+    pub async fn rollup_config(&self) -> EthResult<RollupConfig> {
+        self.on_blocking_task(|this| async move { this.try_rollup_config() }).await
+    }
+
+    fn try_rollup_config(&self) -> EthResult<RollupConfig> {
+        self.ensure_optimism()?;
+
+        let chain_spec = self.provider().chain_spec();
+
+        // the rollup starts at the Bedrock block, which is the genesis block of new OP Stack
+        // chains
+        let genesis_number = match chain_spec.fork(Hardfork::Bedrock) {
+            ForkCondition::Block(number) => number,
+            _ => 0,
+        };
+        let genesis = self
+            .provider()
+            .sealed_header_by_number_or_tag(genesis_number.into())?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+
+        Ok(RollupConfig {
+            genesis: RollupGenesis {
+                l2: RollupBlockId { hash: genesis.hash, number: genesis.number },
+                l2_time: genesis.timestamp,
+            },
+            l2_chain_id: chain_spec.chain().id(),
+            regolith_time: chain_spec.fork(Hardfork::Regolith).as_timestamp(),
+            canyon_time: chain_spec.fork(Hardfork::Canyon).as_timestamp(),
+        })
+    }
+
+    /// Returns an error if the chain is not an OP Stack chain.
+    fn ensure_optimism(&self) -> EthResult<()> {
+        if !self.provider().chain_spec().is_optimism() {
+            return Err(EthApiError::Unsupported(
+                "the optimism namespace is only available on OP Stack chains",
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns the [L2BlockRef] of the block with the given header.
+    ///
+    /// The L1 origin is read from the L1 attributes deposit, which is the first transaction of
+    /// every OP Stack block. Blocks without it, like the genesis block, have a zero L1 origin.
+    fn l2_block_ref(&self, header: SealedHeader) -> EthResult<L2BlockRef> {
+        let l1_attributes = self
+            .provider()
+            .transactions_by_block(header.hash.into())?
+            .and_then(|transactions| transactions.into_iter().next())
+            .and_then(|tx| L1Attributes::try_from_calldata(tx.input()))
+            .unwrap_or_default();
+
+        Ok(L2BlockRef {
+            hash: header.hash,
+            number: header.number,
+            parent_hash: header.parent_hash,
+            timestamp: header.timestamp,
+            l1_origin: l1_attributes.l1_origin,
+            sequence_number: l1_attributes.sequence_number,
+        })
+    }
+}
+
+#[async_trait]
+impl<Provider> OptimismApiServer for OptimismApi<Provider>
+where
+    Provider: BlockReaderIdExt + ChainSpecProvider + StateProviderFactory + 'static,
+{
+    /// Handler for `optimism_outputAtBlock`
+    async fn output_at_block(&self, block_number: BlockNumberOrTag) -> RpcResult<OutputResponse> {
+        Ok(OptimismApi::output_at_block(self, block_number).await?)
+    }
+
+    /// Handler for `optimism_syncStatus`
+    async fn sync_status(&self) -> RpcResult<RollupSyncStatus> {
+        Ok(OptimismApi::sync_status(self).await?)
+    }
+
+    /// Handler for `optimism_rollupConfig`
+    async fn rollup_config(&self) -> RpcResult<RollupConfig> {
+        Ok(OptimismApi::rollup_config(self).await?)
+    }
+}
+
+impl<Provider> std::fmt::Debug for OptimismApi<Provider> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OptimismApi").finish_non_exhaustive()
+    }
+}
+
+impl<Provider> Clone for OptimismApi<Provider> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+struct OptimismApiInner<Provider> {
+    /// The provider that can interact with the chain.
+    provider: Provider,
+    /// The type that can spawn tasks which would otherwise block.
+    task_spawner: Box<dyn TaskSpawner>,
+}
+
+/// Computes the L2 output root of a block.
+///
+/// See also the [L2 output commitment specification](https://github.com/ethereum-optimism/optimism/blob/develop/specs/proposals.md#l2-output-commitment-construction).
+fn output_root(state_root: B256, withdrawal_storage_root: B256, block_hash: B256) -> B256 {
+    let mut preimage = [0u8; 128];
+    preimage[..32].copy_from_slice(OUTPUT_ROOT_VERSION.as_slice());
+    preimage[32..64].copy_from_slice(state_root.as_slice());
+    preimage[64..96].copy_from_slice(withdrawal_storage_root.as_slice());
+    preimage[96..].copy_from_slice(block_hash.as_slice());
+    keccak256(preimage)
+}
+
+/// The L1 origin fields of the L1 attributes deposit of a block.
+#[derive(Debug, Default)]
+struct L1Attributes {
+    l1_origin: RollupBlockId,
+    sequence_number: u64,
+}
+
+impl L1Attributes {
+    /// Parses the L1 origin from the calldata of the `setL1BlockValues` call, which contains the
+    /// 32 byte words `number`, `timestamp`, `basefee`, `hash` and `sequenceNumber`, followed by
+    /// the fee parameters.
+    fn try_from_calldata(calldata: &[u8]) -> Option<Self> {
+        // skip the function selector
+        let data = calldata.get(4..).filter(|data| data.len() >= 5 * 32)?;
+        let word_as_u64 = |word: usize| -> Option<u64> {
+            Some(u64::from_be_bytes(data[word * 32 + 24..(word + 1) * 32].try_into().ok()?))
+        };
+        Some(Self {
+            l1_origin: RollupBlockId {
+                hash: B256::from_slice(&data[3 * 32..4 * 32]),
+                number: word_as_u64(0)?,
+            },
+            sequence_number: word_as_u64(4)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
+
+    #[cfg(feature = "optimism")]
+    use reth_primitives::{
+        constants::EMPTY_ROOT_HASH, Block, ChainSpecBuilder, Header, Signature, Transaction,
+        TransactionKind, TransactionSigned, TxDeposit, U256,
+    };
+    #[cfg(feature = "optimism")]
+    use reth_provider::{test_utils::ExtendedAccount, StateRootProvider};
+
+    /// Returns the calldata of a `setL1BlockValues` call with the given L1 origin.
+    fn l1_attributes_calldata(l1_origin: RollupBlockId, sequence_number: u64) -> Vec<u8> {
+        let mut calldata = vec![0x01, 0x5d, 0x8e, 0xb9];
+        calldata.extend_from_slice(&[0u8; 24]);
+        calldata.extend_from_slice(&l1_origin.number.to_be_bytes());
+        // timestamp and basefee
+        calldata.extend_from_slice(&[0u8; 64]);
+        calldata.extend_from_slice(l1_origin.hash.as_slice());
+        calldata.extend_from_slice(&[0u8; 24]);
+        calldata.extend_from_slice(&sequence_number.to_be_bytes());
+        // batcher hash, fee overhead and fee scalar
+        calldata.extend_from_slice(&[0u8; 96]);
+        calldata
+    }
+
+    #[test]
+    fn parse_l1_attributes() {
+        let l1_origin = RollupBlockId { hash: B256::random(), number: 17_422_590 };
+        let calldata = l1_attributes_calldata(l1_origin, 3);
+
+        let attributes = L1Attributes::try_from_calldata(&calldata).unwrap();
+        assert_eq!(attributes.l1_origin, l1_origin);
+        assert_eq!(attributes.sequence_number, 3);
+
+        assert!(L1Attributes::try_from_calldata(&calldata[..100]).is_none());
+    }
+
+    #[tokio::test]
+    async fn unsupported_on_non_optimism_chains() {
+        let api = OptimismApi::new(MockEthProvider::default(), Box::<TokioTaskExecutor>::default());
+        assert!(matches!(api.rollup_config().await, Err(EthApiError::Unsupported(_))));
+        assert!(matches!(api.sync_status().await, Err(EthApiError::Unsupported(_))));
+    }
+
+    #[cfg(feature = "optimism")]
+    #[tokio::test]
+    async fn output_at_block() {
+        let mut provider = MockEthProvider::default();
+        provider.chain_spec = Arc::new(ChainSpecBuilder::mainnet().regolith_activated().build());
+
+        let l1_origin = RollupBlockId { hash: B256::random(), number: 17_422_590 };
+        let deposit = TransactionSigned::from_transaction_and_signature(
+            Transaction::Deposit(TxDeposit {
+                to: TransactionKind::Call(Address::random()),
+                input: l1_attributes_calldata(l1_origin, 3).into(),
+                ..Default::default()
+            }),
+            Signature::default(),
+        );
+        let genesis = Header::default();
+        let header = Header {
+            number: 1,
+            parent_hash: genesis.hash_slow(),
+            timestamp: 2,
+            state_root: B256::random(),
+            ..Default::default()
+        };
+        let block_hash = header.hash_slow();
+        provider
+            .extend_headers([(genesis.hash_slow(), genesis.clone()), (block_hash, header.clone())]);
+        provider.extend_blocks([
+            (genesis.hash_slow(), Block { header: genesis.clone(), ..Default::default() }),
+            (
+                block_hash,
+                Block { header: header.clone(), body: vec![deposit], ..Default::default() },
+            ),
+        ]);
+        provider.add_account(
+            L2_TO_L1_MESSAGE_PASSER,
+            ExtendedAccount::new(0, U256::ZERO).extend_storage([(B256::random(), U256::from(1))]),
+        );
+
+        let api = OptimismApi::new(provider.clone(), Box::<TokioTaskExecutor>::default());
+        let output = api.output_at_block(BlockNumberOrTag::Number(1)).await.unwrap();
+
+        let withdrawal_storage_root = provider.storage_root(L2_TO_L1_MESSAGE_PASSER).unwrap();
+        assert_ne!(withdrawal_storage_root, EMPTY_ROOT_HASH);
+        assert_eq!(output.withdrawal_storage_root, withdrawal_storage_root);
+        assert_eq!(output.state_root, header.state_root);
+        assert_eq!(
+            output.output_root,
+            keccak256(
+                [
+                    B256::ZERO.as_slice(),
+                    header.state_root.as_slice(),
+                    withdrawal_storage_root.as_slice(),
+                    block_hash.as_slice(),
+                ]
+                .concat()
+            )
+        );
+
+        let block_ref = L2BlockRef {
+            hash: block_hash,
+            number: 1,
+            parent_hash: genesis.hash_slow(),
+            timestamp: 2,
+            l1_origin,
+            sequence_number: 3,
+        };
+        assert_eq!(output.block_ref, block_ref);
+
+        let sync_status = api.sync_status().await.unwrap();
+        assert_eq!(sync_status.unsafe_l2, block_ref);
+        assert_eq!(sync_status.safe_l2, L2BlockRef::default());
+
+        let rollup_config = api.rollup_config().await.unwrap();
+        assert_eq!(
+            rollup_config.genesis.l2,
+            RollupBlockId { hash: genesis.hash_slow(), number: 0 }
+        );
+        assert_eq!(rollup_config.regolith_time, Some(0));
+        assert_eq!(rollup_config.canyon_time, None);
+    }
+}
@@ -1,33 +1,428 @@
 #![allow(dead_code, unused_variables)]
-use crate::result::internal_rpc_err;
+use crate::{
+    eth::{
+        error::{EthApiError, EthResult},
+        EthTransactions,
+    },
+    result::internal_rpc_err,
+};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
-use reth_primitives::{Address, BlockId, BlockNumberOrTag, TxHash, B256};
+use reth_db::models::{StoredCallTrace, StoredTraceKind, StoredTransactionTraces};
+use reth_interfaces::RethResult;
+use reth_primitives::{
+    Account, Address, BlockId, BlockNumber, BlockNumberOrTag, Bytes, TxHash, TxNumber, B256,
+};
+use reth_provider::{
+    AccountReader, AddressAppearancesReader, BlockReader, StateProviderFactory,
+    TransactionTracesReader,
+};
+use reth_revm::tracing::TracingInspectorConfig;
 use reth_rpc_api::{EthApiServer, OtterscanServer};
 use reth_rpc_types::{
-    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, TraceEntry,
-    Transaction, TransactionsWithReceipts,
+    BlockDetails, ContractCreator, InternalOperation, OtsBlockTransactions, OtsTransactionReceipt,
+    TraceEntry, Transaction, TransactionsWithReceipts,
 };
+use revm::primitives::ExecutionResult;
+use std::{collections::HashMap, ops::Range};
 
 const API_LEVEL: u64 = 8;
 
+/// The tracing config for replaying transactions for the `ots_` namespace, which also includes
+/// calls to precompiles.
+fn tracing_config() -> TracingInspectorConfig {
+    TracingInspectorConfig::default_parity().set_exclude_precompile_calls(false)
+}
+
+/// Returns the creator of the contract if it was created in the recorded traces of a transaction.
+///
+/// Creations in a call that failed, directly or by one of its parents, are ignored.
+fn stored_contract_creator(traces: &StoredTransactionTraces, address: Address) -> Option<Address> {
+    let is_persisted = |trace: &StoredCallTrace| {
+        !traces.traces.iter().any(|parent| {
+            !parent.error.is_empty() && trace.trace_address.starts_with(&parent.trace_address)
+        })
+    };
+    traces
+        .traces
+        .iter()
+        .find(|trace| {
+            trace.kind == StoredTraceKind::Create && trace.to == address && is_persisted(trace)
+        })
+        .map(|trace| trace.from)
+}
+
+/// Returns the first block up to `tip` after which the state of the account satisfies the
+/// predicate, by binary searching the historical states of the account.
+///
+/// The predicate must be monotonic, i.e. once it's satisfied it must stay satisfied for all later
+/// blocks. Returns `None` if it isn't satisfied at the tip.
+fn find_account_change<State: AccountReader>(
+    state_at: impl Fn(BlockNumber) -> RethResult<State>,
+    tip: BlockNumber,
+    address: Address,
+    predicate: impl Fn(Option<Account>) -> bool,
+) -> RethResult<Option<BlockNumber>> {
+    let satisfied_at = |block_number| -> RethResult<bool> {
+        Ok(predicate(state_at(block_number)?.basic_account(address)?))
+    };
+
+    if !satisfied_at(tip)? {
+        return Ok(None)
+    }
+
+    // the predicate is always satisfied at `high`
+    let (mut low, mut high) = (0, tip);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if satisfied_at(mid)? {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(Some(high))
+}
+
+/// Returns the first block of the last range of blocks up to `tip` after which the state of the
+/// account satisfies the predicate, e.g. the block of the last creation of a contract.
+///
+/// The search steps back from the tip with growing distances until the predicate isn't
+/// satisfied, and then binary searches the historical states of the account between that block
+/// and the last one that satisfied it. Returns `None` if it isn't satisfied at the tip.
+fn find_last_account_change<State: AccountReader>(
+    state_at: impl Fn(BlockNumber) -> RethResult<State>,
+    tip: BlockNumber,
+    address: Address,
+    predicate: impl Fn(Option<Account>) -> bool,
+) -> RethResult<Option<BlockNumber>> {
+    let satisfied_at = |block_number| -> RethResult<bool> {
+        Ok(predicate(state_at(block_number)?.basic_account(address)?))
+    };
+
+    if !satisfied_at(tip)? {
+        return Ok(None)
+    }
+
+    // the predicate is always satisfied at `high`
+    let (mut high, mut distance) = (tip, 1);
+    let mut low = loop {
+        if high == 0 {
+            return Ok(Some(0))
+        }
+        let block_number = high.saturating_sub(distance);
+        if !satisfied_at(block_number)? {
+            break block_number
+        }
+        high = block_number;
+        distance *= 2;
+    };
+
+    // the predicate is never satisfied at `low`
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if satisfied_at(mid)? {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Ok(Some(high))
+}
+
 /// Otterscan Api
 #[derive(Debug)]
-pub struct OtterscanApi<Eth> {
+pub struct OtterscanApi<Provider, Eth> {
+    provider: Provider,
     eth: Eth,
 }
 
-impl<Eth> OtterscanApi<Eth> {
+impl<Provider, Eth> OtterscanApi<Provider, Eth> {
     /// Creates a new instance of `Otterscan`.
-    pub fn new(eth: Eth) -> Self {
-        Self { eth }
+    pub fn new(provider: Provider, eth: Eth) -> Self {
+        Self { provider, eth }
+    }
+}
+
+impl<Provider, Eth> OtterscanApi<Provider, Eth>
+where
+    Provider: BlockReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
+        + StateProviderFactory
+        + 'static,
+    Eth: EthTransactions + 'static,
+{
+    /// Returns the first block after which the state of the account satisfies the predicate.
+    ///
+    /// See [find_account_change].
+    fn account_change_block(
+        &self,
+        address: Address,
+        predicate: impl Fn(Option<Account>) -> bool,
+    ) -> RethResult<Option<BlockNumber>> {
+        find_account_change(
+            |block_number| self.provider.history_by_block_number(block_number),
+            self.provider.best_block_number()?,
+            address,
+            predicate,
+        )
+    }
+
+    /// Returns the transaction of the sender with the given nonce.
+    ///
+    /// The block of the transaction is the first block after which the nonce of the sender is
+    /// greater than the given nonce.
+    async fn transaction_by_sender_and_nonce(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> EthResult<Option<Transaction>> {
+        let Some(block_number) = self.account_change_block(sender, |account| {
+            account.is_some_and(|account| account.nonce > nonce)
+        })?
+        else {
+            return Ok(None)
+        };
+
+        // senders might have been pruned, so they're recovered for the transactions with a
+        // matching nonce
+        let transactions =
+            self.provider.transactions_by_block(block_number.into())?.unwrap_or_default();
+        let Some(hash) = transactions
+            .into_iter()
+            .find(|tx| tx.nonce() == nonce && tx.recover_signer() == Some(sender))
+            .map(|tx| tx.hash())
+        else {
+            return Ok(None)
+        };
+
+        Ok(EthTransactions::transaction_by_hash(&self.eth, hash).await?.map(Into::into))
+    }
+
+    /// Returns the transaction that created the contract and its creator.
+    ///
+    /// The block of the transaction is the last block after which the account has its current
+    /// code, as a contract might have been destroyed and created again. Only that block is
+    /// replayed to find the transaction that created the contract, unless its traces have been
+    /// recorded. Contracts of the genesis block don't have a creator.
+    async fn contract_creator(&self, address: Address) -> EthResult<Option<ContractCreator>> {
+        let Some(code_hash) = self
+            .provider
+            .latest()?
+            .basic_account(address)?
+            .filter(|account| account.has_bytecode())
+            .and_then(|account| account.bytecode_hash)
+        else {
+            return Ok(None)
+        };
+
+        let Some(block_number) = find_last_account_change(
+            |block_number| self.provider.history_by_block_number(block_number),
+            self.provider.best_block_number()?,
+            address,
+            |account| account.is_some_and(|account| account.bytecode_hash == Some(code_hash)),
+        )?
+        .filter(|block_number| *block_number > 0) else {
+            return Ok(None)
+        };
+
+        let creation = match self.recorded_contract_creation(block_number, address)? {
+            Some(creation) => creation,
+            None => EthTransactions::trace_block_with(
+                &self.eth,
+                block_number.into(),
+                tracing_config(),
+                move |tx_info, inspector, _, _, _| {
+                    Ok(inspector
+                        .into_otterscan_builder()
+                        .contract_creator(address)
+                        .and_then(|creator| tx_info.hash.map(|hash| (hash, creator))))
+                },
+            )
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .last(),
+        };
+        let Some((hash, creator)) = creation else { return Ok(None) };
+
+        let tx = EthTransactions::transaction_by_hash(&self.eth, hash).await?;
+        Ok(tx.map(|tx| ContractCreator { tx: tx.into(), creator }))
+    }
+
+    /// Returns the last creation of the contract in the recorded traces of the block, with the
+    /// hash of the transaction that created it.
+    ///
+    /// Returns `None` if the traces of the block haven't been recorded.
+    fn recorded_contract_creation(
+        &self,
+        block_number: BlockNumber,
+        address: Address,
+    ) -> RethResult<Option<Option<(TxHash, Address)>>> {
+        let Some(traces) = self.provider.transaction_traces_by_block(block_number.into())? else {
+            return Ok(None)
+        };
+        let transactions =
+            self.provider.transactions_by_block(block_number.into())?.unwrap_or_default();
+        Ok(Some(
+            traces
+                .iter()
+                .zip(transactions)
+                .filter_map(|(traces, tx)| {
+                    stored_contract_creator(traces, address).map(|creator| (tx.hash(), creator))
+                })
+                .last(),
+        ))
+    }
+
+    /// Returns the transactions of the address in the blocks before the given block, from the
+    /// newest to the oldest.
+    ///
+    /// Block `0` starts the search at the tip of the chain.
+    async fn search_before(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthResult<TransactionsWithReceipts> {
+        let before = match block_number {
+            0 => u64::MAX,
+            block_number => self
+                .provider
+                .block_body_indices(block_number)?
+                .map(|body| body.first_tx_num())
+                .unwrap_or(u64::MAX),
+        };
+
+        let mut tx_numbers =
+            self.provider.address_appearances_before(address, before, page_size)?;
+        let last_page = tx_numbers.len() < page_size;
+
+        // Pages always contain whole blocks, so include the remaining transactions of the block
+        // of the oldest transaction.
+        if let Some(&oldest) = tx_numbers.last().filter(|_| !last_page) {
+            if let Some(block_range) = self.block_tx_range(oldest)? {
+                let remaining = (oldest - block_range.start) as usize;
+                let appearances =
+                    self.provider.address_appearances_before(address, oldest, remaining)?;
+                tx_numbers.extend(
+                    appearances.into_iter().take_while(|tx_number| *tx_number >= block_range.start),
+                );
+            }
+        }
+
+        let (txs, receipts) = self.transactions_with_receipts(tx_numbers).await?;
+        Ok(TransactionsWithReceipts { txs, receipts, first_page: block_number == 0, last_page })
+    }
+
+    /// Returns the transactions of the address in the blocks after the given block, from the
+    /// newest to the oldest.
+    async fn search_after(
+        &self,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
+    ) -> EthResult<TransactionsWithReceipts> {
+        let last_page = block_number == 0;
+        let Some(from) =
+            self.provider.block_body_indices(block_number)?.map(|body| body.next_tx_num())
+        else {
+            // there are no blocks after a block that doesn't exist yet
+            return Ok(TransactionsWithReceipts {
+                txs: Vec::new(),
+                receipts: Vec::new(),
+                first_page: true,
+                last_page,
+            })
+        };
+
+        let mut tx_numbers = self.provider.address_appearances_from(address, from, page_size)?;
+        let first_page = tx_numbers.len() < page_size;
+
+        // Pages always contain whole blocks, so include the remaining transactions of the block
+        // of the newest transaction.
+        if let Some(&newest) = tx_numbers.last().filter(|_| !first_page) {
+            if let Some(block_range) = self.block_tx_range(newest)? {
+                let remaining = (block_range.end - newest - 1) as usize;
+                let appearances =
+                    self.provider.address_appearances_from(address, newest + 1, remaining)?;
+                tx_numbers.extend(
+                    appearances.into_iter().take_while(|tx_number| *tx_number < block_range.end),
+                );
+            }
+        }
+        tx_numbers.reverse();
+
+        let (txs, receipts) = self.transactions_with_receipts(tx_numbers).await?;
+        Ok(TransactionsWithReceipts { txs, receipts, first_page, last_page })
+    }
+
+    /// Returns the range of transaction numbers of the block that contains the given transaction.
+    fn block_tx_range(&self, tx_number: TxNumber) -> EthResult<Option<Range<TxNumber>>> {
+        let Some(block_number) = self.provider.transaction_block(tx_number)? else {
+            return Ok(None)
+        };
+        Ok(self.provider.block_body_indices(block_number)?.map(|body| body.tx_num_range()))
+    }
+
+    /// Returns the transactions with the given numbers and their receipts.
+    ///
+    /// Transactions that can't be found anymore, e.g. because they have been unwound, are
+    /// skipped.
+    async fn transactions_with_receipts(
+        &self,
+        tx_numbers: Vec<TxNumber>,
+    ) -> EthResult<(Vec<Transaction>, Vec<OtsTransactionReceipt>)> {
+        let mut txs = Vec::with_capacity(tx_numbers.len());
+        let mut receipts = Vec::with_capacity(tx_numbers.len());
+        let mut timestamps = HashMap::new();
+
+        for tx_number in tx_numbers {
+            let Some(hash) = self.provider.transaction_by_id(tx_number)?.map(|tx| tx.hash()) else {
+                continue
+            };
+            let (Some(tx), Some(receipt)) = (
+                EthTransactions::transaction_by_hash(&self.eth, hash).await?,
+                EthTransactions::transaction_receipt(&self.eth, hash).await?,
+            ) else {
+                continue
+            };
+
+            let Some(block_number) = receipt.block_number.map(|number| number.to::<u64>()) else {
+                continue
+            };
+            let timestamp = match timestamps.get(&block_number) {
+                Some(timestamp) => *timestamp,
+                None => {
+                    let timestamp = self
+                        .provider
+                        .header_by_number(block_number)?
+                        .map(|header| header.timestamp)
+                        .unwrap_or_default();
+                    timestamps.insert(block_number, timestamp);
+                    timestamp
+                }
+            };
+
+            txs.push(tx.into());
+            receipts.push(OtsTransactionReceipt { receipt, timestamp });
+        }
+
+        Ok((txs, receipts))
     }
 }
 
 #[async_trait]
-impl<Eth> OtterscanServer for OtterscanApi<Eth>
+impl<Provider, Eth> OtterscanServer for OtterscanApi<Provider, Eth>
 where
-    Eth: EthApiServer,
+    Provider: BlockReader
+        + AddressAppearancesReader
+        + TransactionTracesReader
+        + StateProviderFactory
+        + 'static,
+    Eth: EthApiServer + EthTransactions + 'static,
 {
     /// Handler for `ots_hasCode`
     async fn has_code(&self, address: Address, block_number: Option<BlockId>) -> RpcResult<bool> {
@@ -41,17 +436,44 @@ where
 
     /// Handler for `ots_getInternalOperations`
     async fn get_internal_operations(&self, tx_hash: TxHash) -> RpcResult<Vec<InternalOperation>> {
-        Err(internal_rpc_err("unimplemented"))
+        let operations = self
+            .eth
+            .spawn_trace_transaction_in_block(tx_hash, tracing_config(), |_, inspector, _, _| {
+                Ok(inspector.into_otterscan_builder().into_internal_operations())
+            })
+            .await?
+            .ok_or(EthApiError::TransactionNotFound)?;
+        Ok(operations)
     }
 
     /// Handler for `ots_getTransactionError`
-    async fn get_transaction_error(&self, tx_hash: TxHash) -> RpcResult<String> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn get_transaction_error(&self, tx_hash: TxHash) -> RpcResult<Bytes> {
+        let output = self
+            .eth
+            .spawn_trace_transaction_in_block(
+                tx_hash,
+                TracingInspectorConfig::default_parity(),
+                |_, _, res, _| match res.result {
+                    ExecutionResult::Revert { output, .. } => Ok(output),
+                    // successful and halted transactions don't have a revert output
+                    _ => Ok(Bytes::default()),
+                },
+            )
+            .await?
+            .ok_or(EthApiError::TransactionNotFound)?;
+        Ok(output)
     }
 
     /// Handler for `ots_traceTransaction`
-    async fn trace_transaction(&self, tx_hash: TxHash) -> RpcResult<TraceEntry> {
-        Err(internal_rpc_err("unimplemented"))
+    async fn trace_transaction(&self, tx_hash: TxHash) -> RpcResult<Vec<TraceEntry>> {
+        let entries = self
+            .eth
+            .spawn_trace_transaction_in_block(tx_hash, tracing_config(), |_, inspector, _, _| {
+                Ok(inspector.into_otterscan_builder().into_trace_entries())
+            })
+            .await?
+            .ok_or(EthApiError::TransactionNotFound)?;
+        Ok(entries)
     }
 
     /// Handler for `ots_getBlockDetails`
@@ -86,7 +508,9 @@ where
         block_number: BlockNumberOrTag,
         page_size: usize,
     ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        // Otterscan always sends a block number, where `0` means the tip of the chain
+        let block_number = block_number.as_number().unwrap_or_default();
+        Ok(self.search_before(address, block_number, page_size).await?)
     }
 
     /// Handler for `searchTransactionsAfter`
@@ -96,7 +520,9 @@ where
         block_number: BlockNumberOrTag,
         page_size: usize,
     ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        // Otterscan always sends a block number, where `0` means the genesis block
+        let block_number = block_number.as_number().unwrap_or_default();
+        Ok(self.search_after(address, block_number, page_size).await?)
     }
 
     /// Handler for `getTransactionBySenderAndNonce`
@@ -105,11 +531,274 @@ where
         sender: Address,
         nonce: u64,
     ) -> RpcResult<Option<Transaction>> {
-        Err(internal_rpc_err("unimplemented"))
+        Ok(self.transaction_by_sender_and_nonce(sender, nonce).await?)
     }
 
     /// Handler for `getContractCreator`
     async fn get_contract_creator(&self, address: Address) -> RpcResult<Option<ContractCreator>> {
-        Err(internal_rpc_err("unimplemented"))
+        Ok(self.contract_creator(address).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eth::{cache::EthStateCache, gas_oracle::GasPriceOracle},
+        BlockingTaskPool, EthApi,
+    };
+    use reth_blockchain_tree::noop::NoopBlockchainTree;
+    use reth_db::{
+        models::AccountBeforeTx, tables, test_utils::create_test_rw_db, transaction::DbTxMut,
+    };
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{
+        constants::ETHEREUM_BLOCK_GAS_LIMIT,
+        stage::{StageCheckpoint, StageId},
+        MAINNET, U256,
+    };
+    use reth_provider::{
+        providers::BlockchainProvider,
+        test_utils::{blocks::BlockChainTestData, ExtendedAccount, MockEthProvider},
+        BlockWriter, HistoryWriter, OriginalValuesKnown, ProviderFactory, StageCheckpointWriter,
+        TransactionTracesWriter,
+    };
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    #[test]
+    fn find_account_change_in_history() {
+        let factory = ProviderFactory::new(create_test_rw_db(), MAINNET.clone());
+        let data = BlockChainTestData::default();
+        let provider = factory.provider_rw().unwrap();
+        provider.insert_block(data.genesis, None, None).unwrap();
+        for (block, state) in data.blocks {
+            let (block, senders) = block.into_components();
+            provider.insert_block(block, Some(senders), None).unwrap();
+            state.write_to_db(provider.tx_ref(), OriginalValuesKnown::No).unwrap();
+        }
+        provider.update_history_indices(1..=2).unwrap();
+        provider.commit().unwrap();
+
+        // the account is created with nonce 1 and balance 10 in block 1, and has nonce 3 and
+        // balance 20 after block 2
+        let account = Address::new([0x60; 20]);
+        let find = |predicate: fn(Option<Account>) -> bool| {
+            find_account_change(
+                |block_number| factory.history_by_block_number(block_number),
+                2,
+                account,
+                predicate,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(find(|acc| acc.is_some()), Some(1));
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.nonce > 0)), Some(1));
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.nonce > 1)), Some(2));
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.nonce > 2)), Some(2));
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.nonce > 3)), None);
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.balance > U256::from(10))), Some(2));
+        assert_eq!(find(|acc| acc.is_some_and(|acc| acc.has_bytecode())), None);
+    }
+
+    #[tokio::test]
+    async fn contract_creator_from_recorded_traces() {
+        let contract = Address::new([0x70; 20]);
+        let deployer = Address::new([0x71; 20]);
+        let caller = Address::new([0x72; 20]);
+
+        // the test chain has one transaction in each of the blocks 1 and 2
+        let factory = ProviderFactory::new(create_test_rw_db(), MAINNET.clone());
+        let data = BlockChainTestData::default();
+        let hashes = data.blocks.iter().map(|(block, _)| block.body[0].hash()).collect::<Vec<_>>();
+        let provider = factory.provider_rw().unwrap();
+        provider.insert_block(data.genesis, None, None).unwrap();
+        for (block, _) in data.blocks {
+            let (block, senders) = block.into_components();
+            provider.insert_block(block, Some(senders), None).unwrap();
+        }
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                contract,
+                Account { bytecode_hash: Some(B256::with_last_byte(1)), ..Default::default() },
+            )
+            .unwrap();
+        // the contract is created in block 1
+        provider
+            .tx_ref()
+            .put::<tables::AccountChangeSet>(1, AccountBeforeTx { address: contract, info: None })
+            .unwrap();
+        provider.update_history_indices(1..=1).unwrap();
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(2)).unwrap();
+
+        // the first transaction creates the contract through the deployer, the second one tries
+        // to create it again in a call that's reverted
+        let call = |to, subtraces| StoredCallTrace {
+            from: caller,
+            to,
+            has_result: true,
+            subtraces,
+            ..Default::default()
+        };
+        let create = |trace_address| StoredCallTrace {
+            kind: StoredTraceKind::Create,
+            from: deployer,
+            to: contract,
+            has_result: true,
+            trace_address,
+            ..Default::default()
+        };
+        let reverted = StoredCallTrace { error: "Reverted".to_string(), ..call(deployer, 1) };
+        provider
+            .insert_transaction_traces(vec![
+                (
+                    0,
+                    StoredTransactionTraces {
+                        output: Bytes::default(),
+                        traces: vec![call(deployer, 1), create(vec![0])],
+                    },
+                ),
+                (
+                    1,
+                    StoredTransactionTraces {
+                        output: Bytes::default(),
+                        traces: vec![reverted, create(vec![0])],
+                    },
+                ),
+            ])
+            .unwrap();
+        provider.commit().unwrap();
+
+        let provider =
+            BlockchainProvider::new(factory.clone(), NoopBlockchainTree::default()).unwrap();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default());
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider.clone(), Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+        );
+        let api = OtterscanApi::new(provider, eth_api);
+
+        let creator = api.contract_creator(contract).await.unwrap().unwrap();
+        assert_eq!((creator.tx.hash, creator.creator), (hashes[0], deployer));
+        // accounts without code don't have a creator
+        assert!(api.contract_creator(deployer).await.unwrap().is_none());
+
+        // the contract is destroyed and created again with new code by the second transaction
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::AccountChangeSet>(
+                2,
+                AccountBeforeTx {
+                    address: contract,
+                    info: Some(Account {
+                        bytecode_hash: Some(B256::with_last_byte(2)),
+                        ..Default::default()
+                    }),
+                },
+            )
+            .unwrap();
+        provider.update_history_indices(2..=2).unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::TransactionTraces>(
+                1,
+                StoredTransactionTraces {
+                    output: Bytes::default(),
+                    traces: vec![call(deployer, 1), create(vec![0])],
+                },
+            )
+            .unwrap();
+        provider.commit().unwrap();
+
+        let creator = api.contract_creator(contract).await.unwrap().unwrap();
+        assert_eq!((creator.tx.hash, creator.creator), (hashes[1], deployer));
+    }
+
+    #[test]
+    fn find_last_account_change_after_recreation() {
+        let address = Address::with_last_byte(1);
+        let find = |has_code: &'static [bool]| {
+            find_last_account_change(
+                |block_number| -> RethResult<MockEthProvider> {
+                    let state = MockEthProvider::default();
+                    if has_code[block_number as usize] {
+                        state.add_account(
+                            address,
+                            ExtendedAccount::new(1, U256::ZERO)
+                                .with_bytecode(Bytes::from_static(&[0x00])),
+                        );
+                    }
+                    Ok(state)
+                },
+                has_code.len() as u64 - 1,
+                address,
+                |account| account.is_some_and(|account| account.has_bytecode()),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(find(&[false, false, true, true]), Some(2));
+        // the contract is destroyed in block 3 and created again in block 6
+        assert_eq!(find(&[false, true, true, false, false, false, true, true, true]), Some(6));
+        assert_eq!(find(&[false, true, true, true, true, true, true, true, false]), None);
+        assert_eq!(find(&[true, true, true, true, true]), Some(0));
+        assert_eq!(find(&[true]), Some(0));
+    }
+
+    #[test]
+    fn ignore_failed_creations() {
+        let contract = Address::with_last_byte(1);
+        let create = |from, error: &str, trace_address| StoredCallTrace {
+            kind: StoredTraceKind::Create,
+            from,
+            to: contract,
+            has_result: error.is_empty(),
+            error: error.to_string(),
+            trace_address,
+            ..Default::default()
+        };
+        let traces = |traces| StoredTransactionTraces { output: Bytes::default(), traces };
+
+        assert_eq!(
+            stored_contract_creator(&traces(vec![create(Address::ZERO, "", vec![])]), contract),
+            Some(Address::ZERO)
+        );
+        assert_eq!(
+            stored_contract_creator(
+                &traces(vec![create(Address::ZERO, "Reverted", vec![])]),
+                contract
+            ),
+            None
+        );
+        // the creation of a reverted call is discarded, even if the creation itself succeeded
+        let parent =
+            StoredCallTrace { error: "Reverted".to_string(), subtraces: 2, ..Default::default() };
+        let creator = Address::with_last_byte(2);
+        assert_eq!(
+            stored_contract_creator(
+                &traces(vec![parent.clone(), create(creator, "", vec![0])]),
+                contract
+            ),
+            None
+        );
+        // a later successful creation is found
+        assert_eq!(
+            stored_contract_creator(
+                &traces(vec![
+                    StoredCallTrace { error: String::new(), ..parent },
+                    create(creator, "Out of gas", vec![0]),
+                    create(creator, "", vec![1]),
+                ]),
+                contract
+            ),
+            Some(creator)
+        );
     }
 }
@@ -2,11 +2,16 @@ use crate::eth::error::{EthApiError, EthResult};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_interfaces::RethResult;
-use reth_primitives::{Address, BlockId, U256};
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
+use reth_primitives::{Account, Address, BlockId, Bytes, B256, U256, U64};
+use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProvider, StateProviderFactory};
 use reth_rpc_api::RethApiServer;
+use reth_rpc_types::trace::parity::{AccountDiff, Delta, StateDiff};
 use reth_tasks::TaskSpawner;
-use std::{collections::HashMap, future::Future, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    future::Future,
+    sync::Arc,
+};
 use tokio::sync::oneshot;
 
 /// `reth` API implementation.
@@ -25,8 +30,14 @@ impl<Provider> RethApi<Provider> {
     }
 
     /// Create a new instance of the [RethApi]
-    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = Arc::new(RethApiInner { provider, task_spawner });
+    ///
+    /// `max_changeset_blocks` is the maximum block range of `reth_getStateDiff`.
+    pub fn new(
+        provider: Provider,
+        task_spawner: Box<dyn TaskSpawner>,
+        max_changeset_blocks: u64,
+    ) -> Self {
+        let inner = Arc::new(RethApiInner { provider, task_spawner, max_changeset_blocks });
         Self { inner }
     }
 }
@@ -81,6 +92,132 @@ where
         )?;
         Ok(hash_map)
     }
+
+    /// Returns the changes of the accounts modified in the given inclusive block range.
+    ///
+    /// Fails if the changesets of the range were pruned.
+    pub async fn state_diff(
+        &self,
+        from_block: BlockId,
+        to_block: Option<BlockId>,
+    ) -> EthResult<StateDiff> {
+        self.on_blocking_task(|this| async move { this.try_state_diff(from_block, to_block) }).await
+    }
+
+    fn try_state_diff(
+        &self,
+        from_block: BlockId,
+        to_block: Option<BlockId>,
+    ) -> EthResult<StateDiff> {
+        let Some(from) = self.provider().block_number_for_id(from_block)? else {
+            return Err(EthApiError::UnknownBlockNumber)
+        };
+        let to = match to_block {
+            Some(to_block) => self
+                .provider()
+                .block_number_for_id(to_block)?
+                .ok_or(EthApiError::UnknownBlockNumber)?,
+            None => from,
+        };
+        if from > to {
+            return Err(EthApiError::InvalidBlockRange)
+        }
+        let max_blocks = self.inner.max_changeset_blocks;
+        if to - from >= max_blocks {
+            return Err(EthApiError::BlockRangeTooLarge(max_blocks))
+        }
+
+        // the changesets of a block hold the values from before the block, so the first entry of
+        // an account or slot in the range is its value before the range
+        //
+        // the changesets can't be read if they were pruned, so a pruned range is an error rather
+        // than an incomplete diff
+        let mut accounts_before = BTreeMap::new();
+        let mut storages_before = BTreeMap::<Address, BTreeMap<B256, U256>>::new();
+        for block_number in from..=to {
+            for account_before in self.provider().account_block_changeset(block_number)? {
+                accounts_before.entry(account_before.address).or_insert(account_before.info);
+            }
+            for (address, entry) in self.provider().storage_block_changeset(block_number)? {
+                storages_before.entry(address).or_default().entry(entry.key).or_insert(entry.value);
+            }
+        }
+
+        let state = self.provider().history_by_block_number(to)?;
+        Ok(state_diff(&state, accounts_before, storages_before)?)
+    }
+}
+
+/// Returns the changes of the given accounts and storage slots, from their values before the
+/// changes to their values in the given state.
+///
+/// Accounts that were touched but not changed are skipped.
+fn state_diff(
+    state: &impl StateProvider,
+    accounts_before: BTreeMap<Address, Option<Account>>,
+    mut storages_before: BTreeMap<Address, BTreeMap<B256, U256>>,
+) -> RethResult<StateDiff> {
+    let addresses = accounts_before.keys().chain(storages_before.keys()).copied();
+    let mut state_diff = StateDiff::default();
+    for address in addresses.collect::<BTreeSet<_>>() {
+        let after = state.basic_account(address)?;
+        // accounts with storage changes only are missing from the account changesets
+        let before = accounts_before.get(&address).copied().unwrap_or(after);
+
+        let mut account_diff = AccountDiff {
+            balance: delta(before.map(|acc| acc.balance), after.map(|acc| acc.balance)),
+            nonce: delta(
+                before.map(|acc| U64::from(acc.nonce)),
+                after.map(|acc| U64::from(acc.nonce)),
+            ),
+            code: delta(
+                before.map(|acc| account_code(state, acc)).transpose()?,
+                after.map(|acc| account_code(state, acc)).transpose()?,
+            ),
+            storage: BTreeMap::new(),
+        };
+        for (key, value_before) in storages_before.remove(&address).unwrap_or_default() {
+            let value_after = state.storage(address, key)?.unwrap_or_default();
+            if value_before == value_after {
+                continue
+            }
+            let (value_before, value_after) = (B256::from(value_before), B256::from(value_after));
+            let storage_delta = match (before, after) {
+                (None, _) => Delta::Added(value_after),
+                (_, None) => Delta::Removed(value_before),
+                _ => Delta::changed(value_before, value_after),
+            };
+            account_diff.storage.insert(key, storage_delta);
+        }
+
+        // skip accounts that were touched but not changed
+        if account_diff.balance.is_unchanged() &&
+            account_diff.nonce.is_unchanged() &&
+            account_diff.code.is_unchanged() &&
+            account_diff.storage.is_empty()
+        {
+            continue
+        }
+        state_diff.insert(address, account_diff);
+    }
+    Ok(state_diff)
+}
+
+/// Returns the difference between the value before and after, where `None` is a missing account.
+fn delta<T: PartialEq>(before: Option<T>, after: Option<T>) -> Delta<T> {
+    match (before, after) {
+        (Some(from), Some(to)) if from == to => Delta::Unchanged,
+        (Some(from), Some(to)) => Delta::changed(from, to),
+        (None, Some(to)) => Delta::Added(to),
+        (Some(from), None) => Delta::Removed(from),
+        (None, None) => Delta::Unchanged,
+    }
+}
+
+/// Returns the code of the account, which is empty if the account has no code.
+fn account_code(state: &impl StateProvider, account: Account) -> RethResult<Bytes> {
+    let Some(code_hash) = account.bytecode_hash else { return Ok(Bytes::new()) };
+    Ok(state.bytecode_by_hash(code_hash)?.map(|code| code.original_bytes()).unwrap_or_default())
 }
 
 #[async_trait]
@@ -95,6 +232,15 @@ where
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(RethApi::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_getStateDiff`
+    async fn reth_get_state_diff(
+        &self,
+        from_block: BlockId,
+        to_block: Option<BlockId>,
+    ) -> RpcResult<StateDiff> {
+        Ok(RethApi::state_diff(self, from_block, to_block).await?)
+    }
 }
 
 impl<Provider> std::fmt::Debug for RethApi<Provider> {
@@ -114,4 +260,98 @@ struct RethApiInner<Provider> {
     provider: Provider,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
+    /// The maximum number of blocks whose changesets are read in a single request.
+    max_changeset_blocks: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    #[test]
+    fn state_diff_deltas() {
+        let state = MockEthProvider::default();
+        let (changed, created, destroyed, touched, storage_only) = (
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+            Address::with_last_byte(3),
+            Address::with_last_byte(4),
+            Address::with_last_byte(5),
+        );
+        let (slot, other_slot) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let code = Bytes::from_static(&[0x60, 0x00]);
+
+        state.add_account(changed, ExtendedAccount::new(1, U256::from(10)));
+        state.add_account(
+            created,
+            ExtendedAccount::new(1, U256::from(5))
+                .with_bytecode(code.clone())
+                .extend_storage([(slot, U256::from(7))]),
+        );
+        state.add_account(touched, ExtendedAccount::new(0, U256::from(1)));
+        state.add_account(
+            storage_only,
+            ExtendedAccount::new(0, U256::ZERO).extend_storage([(slot, U256::from(2))]),
+        );
+
+        let accounts_before = BTreeMap::from([
+            (changed, Some(Account { nonce: 0, balance: U256::from(20), bytecode_hash: None })),
+            (created, None),
+            (destroyed, Some(Account { nonce: 3, balance: U256::from(1), bytecode_hash: None })),
+            (touched, Some(Account { nonce: 0, balance: U256::from(1), bytecode_hash: None })),
+        ]);
+        let storages_before = BTreeMap::from([
+            (created, BTreeMap::from([(slot, U256::ZERO)])),
+            (destroyed, BTreeMap::from([(slot, U256::from(4))])),
+            (storage_only, BTreeMap::from([(slot, U256::from(1)), (other_slot, U256::ZERO)])),
+        ]);
+
+        let diff = state_diff(&state, accounts_before, storages_before).unwrap();
+        assert_eq!(diff.len(), 4);
+
+        // the untouched account is skipped
+        assert!(!diff.contains_key(&touched));
+
+        assert_eq!(
+            diff[&changed],
+            AccountDiff {
+                balance: Delta::changed(U256::from(20), U256::from(10)),
+                nonce: Delta::changed(U64::from(0), U64::from(1)),
+                code: Delta::Unchanged,
+                storage: BTreeMap::new(),
+            }
+        );
+        assert_eq!(
+            diff[&created],
+            AccountDiff {
+                balance: Delta::Added(U256::from(5)),
+                nonce: Delta::Added(U64::from(1)),
+                code: Delta::Added(code),
+                storage: BTreeMap::from([(slot, Delta::Added(B256::with_last_byte(7)))]),
+            }
+        );
+        assert_eq!(
+            diff[&destroyed],
+            AccountDiff {
+                balance: Delta::Removed(U256::from(1)),
+                nonce: Delta::Removed(U64::from(3)),
+                code: Delta::Removed(Bytes::new()),
+                storage: BTreeMap::from([(slot, Delta::Removed(B256::with_last_byte(4)))]),
+            }
+        );
+        // the unchanged slot is skipped
+        assert_eq!(
+            diff[&storage_only],
+            AccountDiff {
+                balance: Delta::Unchanged,
+                nonce: Delta::Unchanged,
+                code: Delta::Unchanged,
+                storage: BTreeMap::from([(
+                    slot,
+                    Delta::changed(B256::with_last_byte(1), B256::with_last_byte(2))
+                )]),
+            }
+        );
+    }
 }
@@ -8,12 +8,21 @@ use crate::{
     BlockingTaskGuard,
 };
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult as Result;
+use futures::{Stream, StreamExt, TryStreamExt};
+use jsonrpsee::{
+    core::{RpcResult as Result, SubscriptionResult},
+    server::SubscriptionMessage,
+    PendingSubscriptionSink,
+};
 use reth_consensus_common::calc::{base_block_reward, block_reward};
+use reth_db::models::{StoredCallTrace, StoredTraceKind};
 use reth_primitives::{
     revm::env::tx_env_with_recovered, BlockId, BlockNumberOrTag, Bytes, SealedHeader, B256, U256,
+    U64,
+};
+use reth_provider::{
+    BlockReader, ChainSpecProvider, EvmEnvProvider, StateProviderFactory, TransactionTracesReader,
 };
-use reth_provider::{BlockReader, ChainSpecProvider, EvmEnvProvider, StateProviderFactory};
 use reth_revm::{
     database::StateProviderDatabase,
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
@@ -21,14 +30,21 @@ use reth_revm::{
 use reth_rpc_api::TraceApiServer;
 use reth_rpc_types::{
     state::StateOverride,
-    trace::{filter::TraceFilter, parity::*, tracerequest::TraceCallRequest},
-    BlockError, BlockOverrides, CallRequest, Index,
+    trace::{
+        filter::{TraceFilter, TraceFilterMatcher},
+        parity::*,
+        tracerequest::TraceCallRequest,
+    },
+    BlockOverrides, CallRequest, Index, TransactionInfo,
 };
 use revm::{db::CacheDB, primitives::Env};
 use revm_primitives::db::DatabaseCommit;
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, ops::RangeInclusive, pin::pin, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+/// The number of blocks `trace_filter` traces in parallel.
+const TRACE_FILTER_BATCH_SIZE: u64 = 16;
+
 /// `trace` API implementation.
 ///
 /// This type provides the functionality for handling `trace` related requests.
@@ -45,8 +61,20 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
     }
 
     /// Create a new instance of the [TraceApi]
-    pub fn new(provider: Provider, eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        let inner = Arc::new(TraceApiInner { provider, eth_api, blocking_task_guard });
+    ///
+    /// `max_trace_filter_blocks` is the maximum block range of `trace_filter`.
+    pub fn new(
+        provider: Provider,
+        eth_api: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        max_trace_filter_blocks: u64,
+    ) -> Self {
+        let inner = Arc::new(TraceApiInner {
+            provider,
+            eth_api,
+            blocking_task_guard,
+            max_trace_filter_blocks,
+        });
         Self { inner }
     }
 
@@ -62,7 +90,12 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
 
 impl<Provider, Eth> TraceApi<Provider, Eth>
 where
-    Provider: BlockReader + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + 'static,
+    Provider: BlockReader
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + TransactionTracesReader
+        + 'static,
     Eth: EthTransactions + 'static,
 {
     /// Executes the given call and returns a number of possible traces for it.
@@ -233,83 +266,194 @@ where
 
     /// Returns all transaction traces that match the given filter.
     ///
-    /// This is similar to [Self::trace_block] but only returns traces for transactions that match
-    /// the filter.
+    /// Every call frame of the transactions in the filter's block range is matched against the
+    /// filter, so internal calls are included. The first `after` matching traces are skipped and at
+    /// most `count` traces are returned.
     pub async fn trace_filter(
         &self,
         filter: TraceFilter,
     ) -> EthResult<Vec<LocalizedTransactionTrace>> {
-        let matcher = filter.matcher();
-        let TraceFilter { from_block, to_block, after: _after, count: _count, .. } = filter;
-        let start = from_block.unwrap_or(0);
-        let end = if let Some(to_block) = to_block {
-            to_block
-        } else {
-            self.provider().best_block_number()?
-        };
-
-        // ensure that the range is not too large, since we need to fetch all blocks in the range
-        let distance = end.saturating_sub(start);
-        if distance > 100 {
-            return Err(EthApiError::InvalidParams(
-                "Block range too large; currently limited to 100 blocks".to_string(),
-            ))
+        let range = self.trace_filter_range(&filter)?;
+
+        // ensure that the range is not too large, since we need to trace all blocks in the range
+        let max_blocks = self.inner.max_trace_filter_blocks;
+        let distance = range.end().saturating_sub(*range.start());
+        if distance > max_blocks {
+            return Err(EthApiError::InvalidParams(format!(
+                "Block range too large; currently limited to {max_blocks} blocks"
+            )))
         }
 
-        // fetch all blocks in that range
-        let blocks = self.provider().block_range(start..=end)?;
-
-        // find relevant blocks to trace
-        let mut target_blocks = Vec::new();
-        for block in blocks {
-            let mut transaction_indices = HashSet::new();
-            let mut highest_matching_index = 0;
-            for (tx_idx, tx) in block.body.iter().enumerate() {
-                let from = tx.recover_signer().ok_or(BlockError::InvalidSignature)?;
-                let to = tx.to();
-                if matcher.matches(from, to) {
-                    let idx = tx_idx as u64;
-                    transaction_indices.insert(idx);
-                    highest_matching_index = idx;
+        self.trace_filter_stream(filter, range, false).try_collect().await
+    }
+
+    /// Returns the block range of the given filter, which ends at the latest block by default.
+    fn trace_filter_range(&self, filter: &TraceFilter) -> EthResult<RangeInclusive<u64>> {
+        let start = filter.from_block.unwrap_or(0);
+        let end = match filter.to_block {
+            Some(to_block) => to_block,
+            None => self.provider().best_block_number()?,
+        };
+        Ok(start..=end)
+    }
+
+    /// Returns a stream of the traces in the given block range that match the filter, honoring its
+    /// `after` and `count` fields.
+    ///
+    /// The blocks are traced in batches of [TRACE_FILTER_BATCH_SIZE] blocks, which are traced in
+    /// parallel. The next batch is only traced once the stream is polled again.
+    ///
+    /// If `permit_per_batch` is set, a tracing permit is acquired for each batch and released
+    /// before the next one, so that a long running stream doesn't block other tracing calls.
+    /// Otherwise the caller is expected to hold a permit.
+    fn trace_filter_stream(
+        &self,
+        filter: TraceFilter,
+        range: RangeInclusive<u64>,
+        permit_per_batch: bool,
+    ) -> impl Stream<Item = EthResult<LocalizedTransactionTrace>> + '_ {
+        let matcher = Arc::new(filter.matcher());
+        let end = *range.end();
+        let batches = range.step_by(TRACE_FILTER_BATCH_SIZE as usize).map(move |batch_start| {
+            batch_start..=end.min(batch_start.saturating_add(TRACE_FILTER_BATCH_SIZE - 1))
+        });
+
+        let traces = futures::stream::iter(batches)
+            .then(move |batch| {
+                let matcher = matcher.clone();
+                async move {
+                    let _permit = if permit_per_batch {
+                        Some(self.acquire_trace_permit().await)
+                    } else {
+                        None
+                    };
+                    self.trace_filter_blocks(batch, matcher).await
                 }
-            }
-            if !transaction_indices.is_empty() {
-                target_blocks.push((block.number, transaction_indices, highest_matching_index));
-            }
+            })
+            .map_ok(|traces| futures::stream::iter(traces.into_iter().map(Ok::<_, EthApiError>)))
+            .try_flatten();
+        paginate_traces(traces, filter.after.unwrap_or_default(), filter.count)
+    }
+
+    /// Traces the blocks of the given range in parallel and returns the traces that match, in
+    /// order.
+    async fn trace_filter_blocks(
+        &self,
+        range: RangeInclusive<u64>,
+        matcher: Arc<TraceFilterMatcher>,
+    ) -> EthResult<Vec<LocalizedTransactionTrace>> {
+        let block_traces =
+            range.map(|block_number| self.localized_block_traces(block_number.into()));
+
+        let block_traces = futures::future::try_join_all(block_traces).await?;
+        Ok(block_traces
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|trace| matcher.matches_trace(&trace.trace))
+            .collect())
+    }
+
+    /// Returns the recorded traces of the transaction, if it was traced by the `TransactionTraces`
+    /// stage.
+    fn stored_transaction_traces(
+        &self,
+        hash: B256,
+    ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
+        let Some(id) = self.provider().transaction_id(hash)? else { return Ok(None) };
+        let Some(traces) = self.provider().transaction_traces(id)? else { return Ok(None) };
+        let Some((_, meta)) = self.provider().transaction_by_hash_with_meta(hash)? else {
+            return Ok(None)
+        };
+
+        let tx_info = TransactionInfo {
+            hash: Some(meta.tx_hash),
+            index: Some(meta.index),
+            block_hash: Some(meta.block_hash),
+            block_number: Some(meta.block_number),
+            base_fee: meta.base_fee,
+        };
+        Ok(Some(
+            localize_traces(tx_info, traces.traces.into_iter().map(from_stored_trace)).collect(),
+        ))
+    }
+
+    /// Returns the recorded traces of all transactions of the block, if they were traced by the
+    /// `TransactionTraces` stage.
+    ///
+    /// Returns `None` if the block doesn't exist or if its traces weren't recorded, e.g. because
+    /// the block was only inserted after the last pipeline run.
+    async fn stored_block_traces(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<(TransactionInfo, TraceResults)>>> {
+        let Some(block) = self.inner.eth_api.block_by_id(block_id).await? else { return Ok(None) };
+        // look up the traces by hash, so that blocks that aren't persisted yet are never matched
+        // with the traces of the canonical block at the same height
+        let Some(traces) = self.provider().transaction_traces_by_block(block.header.hash.into())?
+        else {
+            return Ok(None)
+        };
+
+        let traces = block
+            .body
+            .iter()
+            .zip(traces)
+            .enumerate()
+            .map(|(index, (tx, traces))| {
+                let tx_info = TransactionInfo {
+                    hash: Some(tx.hash),
+                    index: Some(index as u64),
+                    block_hash: Some(block.header.hash),
+                    block_number: Some(block.header.number),
+                    base_fee: block.header.base_fee_per_gas,
+                };
+                let trace_results = TraceResults {
+                    output: traces.output,
+                    trace: traces.traces.into_iter().map(from_stored_trace).collect(),
+                    state_diff: None,
+                    vm_trace: None,
+                };
+                (tx_info, trace_results)
+            })
+            .collect();
+        Ok(Some(traces))
+    }
+
+    /// Returns the traces of all transactions of the block.
+    ///
+    /// The recorded traces are used if available, otherwise the block is re-executed.
+    async fn localized_block_traces(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
+        if let Some(traces) = self.stored_block_traces(block_id).await? {
+            return Ok(Some(
+                traces
+                    .into_iter()
+                    .flat_map(|(tx_info, trace_results)| {
+                        localize_traces(tx_info, trace_results.trace)
+                    })
+                    .collect(),
+            ))
         }
 
-        // trace all relevant blocks
-        let mut block_traces = Vec::with_capacity(target_blocks.len());
-        for (num, indices, highest_idx) in target_blocks {
-            let traces = self.inner.eth_api.trace_block_until(
-                num.into(),
-                Some(highest_idx),
+        let traces = self
+            .inner
+            .eth_api
+            .trace_block_with(
+                block_id,
                 TracingInspectorConfig::default_parity(),
-                move |tx_info, inspector, res, _, _| {
-                    if let Some(idx) = tx_info.index {
-                        if !indices.contains(&idx) {
-                            // only record traces for relevant transactions
-                            return Ok(None)
-                        }
-                    }
+                |tx_info, inspector, res, _, _| {
                     let traces = inspector
                         .with_transaction_gas_used(res.gas_used())
                         .into_parity_builder()
                         .into_localized_transaction_traces(tx_info);
-                    Ok(Some(traces))
+                    Ok(traces)
                 },
-            );
-            block_traces.push(traces);
-        }
-
-        let block_traces = futures::future::try_join_all(block_traces).await?;
-        let all_traces = block_traces
-            .into_iter()
-            .flatten()
-            .flat_map(|traces| traces.into_iter().flatten().flat_map(|traces| traces.into_iter()))
-            .collect();
-
-        Ok(all_traces)
+            )
+            .await?;
+        Ok(traces.map(|traces| traces.into_iter().flatten().collect()))
     }
 
     /// Returns all traces for the given transaction hash
@@ -317,6 +461,10 @@ where
         &self,
         hash: B256,
     ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
+        if let Some(traces) = self.stored_transaction_traces(hash)? {
+            return Ok(Some(traces))
+        }
+
         self.inner
             .eth_api
             .spawn_trace_transaction_in_block(
@@ -338,23 +486,9 @@ where
         &self,
         block_id: BlockId,
     ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
-        let traces = self.inner.eth_api.trace_block_with(
-            block_id,
-            TracingInspectorConfig::default_parity(),
-            |tx_info, inspector, res, _, _| {
-                let traces = inspector
-                    .with_transaction_gas_used(res.gas_used())
-                    .into_parity_builder()
-                    .into_localized_transaction_traces(tx_info);
-                Ok(traces)
-            },
-        );
-
+        let traces = self.localized_block_traces(block_id);
         let block = self.inner.eth_api.block_by_id(block_id);
-        let (maybe_traces, maybe_block) = futures::try_join!(traces, block)?;
-
-        let mut maybe_traces =
-            maybe_traces.map(|traces| traces.into_iter().flatten().collect::<Vec<_>>());
+        let (mut maybe_traces, maybe_block) = futures::try_join!(traces, block)?;
 
         if let (Some(block), Some(traces)) = (maybe_block, maybe_traces.as_mut()) {
             if let Some(header_td) = self.provider().header_td(&block.header.hash)? {
@@ -399,6 +533,21 @@ where
         block_id: BlockId,
         trace_types: HashSet<TraceType>,
     ) -> EthResult<Option<Vec<TraceResultsWithTransactionHash>>> {
+        // the recorded traces can only be used if nothing but the call traces are requested
+        if trace_types.len() == 1 && trace_types.contains(&TraceType::Trace) {
+            if let Some(traces) = self.stored_block_traces(block_id).await? {
+                return Ok(Some(
+                    traces
+                        .into_iter()
+                        .map(|(tx_info, full_trace)| TraceResultsWithTransactionHash {
+                            transaction_hash: tx_info.hash.expect("tx hash is set"),
+                            full_trace,
+                        })
+                        .collect(),
+                ))
+            }
+        }
+
         self.inner
             .eth_api
             .trace_block_with(
@@ -428,7 +577,12 @@ where
 #[async_trait]
 impl<Provider, Eth> TraceApiServer for TraceApi<Provider, Eth>
 where
-    Provider: BlockReader + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + 'static,
+    Provider: BlockReader
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + TransactionTracesReader
+        + 'static,
     Eth: EthTransactions + 'static,
 {
     /// Executes the given call and returns a number of possible traces for it.
@@ -503,11 +657,41 @@ where
     /// This is similar to `eth_getLogs` but for traces.
     ///
     /// # Limitations
-    /// This currently requires block filter fields, since reth does not have address indices yet.
+    /// This traces every block of the requested range, since reth does not index the addresses of
+    /// internal calls. The range is therefore limited to the configured maximum number of blocks.
     async fn trace_filter(&self, filter: TraceFilter) -> Result<Vec<LocalizedTransactionTrace>> {
+        let _permit = self.acquire_trace_permit().await;
         Ok(TraceApi::trace_filter(self, filter).await?)
     }
 
+    /// Handler for `trace_subscribeFilter`
+    async fn trace_subscribe_filter(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: TraceFilter,
+    ) -> SubscriptionResult {
+        let range = match self.trace_filter_range(&filter) {
+            Ok(range) => range,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+        let sink = pending.accept().await?;
+
+        // the range isn't limited, so the permit is only held while a batch of blocks is traced
+        let mut traces = pin!(self.trace_filter_stream(filter, range, true));
+        while let Some(trace) = traces.next().await {
+            let msg = SubscriptionMessage::from_json(&trace?)?;
+            if sink.send(msg).await.is_err() {
+                // connection dropped
+                break
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns transaction trace at given index.
     /// Handler for `trace_get`
     async fn trace_get(
@@ -547,6 +731,8 @@ struct TraceApiInner<Provider, Eth> {
     eth_api: Eth,
     // restrict the number of concurrent calls to `trace_*`
     blocking_task_guard: BlockingTaskGuard,
+    /// The maximum block range of `trace_filter`
+    max_trace_filter_blocks: u64,
 }
 
 /// Returns the [TracingInspectorConfig] depending on the enabled [TraceType]s
@@ -579,9 +765,113 @@ fn reward_trace(header: &SealedHeader, reward: RewardAction) -> LocalizedTransac
     }
 }
 
+/// Localizes the recorded traces of a transaction with the given [TransactionInfo].
+fn localize_traces(
+    tx_info: TransactionInfo,
+    traces: impl IntoIterator<Item = TransactionTrace>,
+) -> impl Iterator<Item = LocalizedTransactionTrace> {
+    traces.into_iter().map(move |trace| LocalizedTransactionTrace {
+        trace,
+        block_hash: tx_info.block_hash,
+        block_number: tx_info.block_number,
+        transaction_hash: tx_info.hash,
+        transaction_position: tx_info.index,
+    })
+}
+
+/// Converts a trace recorded by the `TransactionTraces` stage into a parity trace.
+fn from_stored_trace(trace: StoredCallTrace) -> TransactionTrace {
+    let action = match trace.kind {
+        kind @ (StoredTraceKind::Call |
+        StoredTraceKind::CallCode |
+        StoredTraceKind::DelegateCall |
+        StoredTraceKind::StaticCall) => Action::Call(CallAction {
+            from: trace.from,
+            call_type: match kind {
+                StoredTraceKind::CallCode => CallType::CallCode,
+                StoredTraceKind::DelegateCall => CallType::DelegateCall,
+                StoredTraceKind::StaticCall => CallType::StaticCall,
+                _ => CallType::Call,
+            },
+            gas: U64::from(trace.gas),
+            input: trace.input,
+            to: trace.to,
+            value: trace.value,
+        }),
+        StoredTraceKind::Create => Action::Create(CreateAction {
+            from: trace.from,
+            value: trace.value,
+            gas: U64::from(trace.gas),
+            init: trace.input,
+        }),
+        StoredTraceKind::Selfdestruct => Action::Selfdestruct(SelfdestructAction {
+            address: trace.from,
+            refund_address: trace.to,
+            balance: trace.value,
+        }),
+        StoredTraceKind::BlockReward => Action::Reward(RewardAction {
+            author: trace.from,
+            value: trace.value,
+            reward_type: RewardType::Block,
+        }),
+        StoredTraceKind::UncleReward => Action::Reward(RewardAction {
+            author: trace.from,
+            value: trace.value,
+            reward_type: RewardType::Uncle,
+        }),
+    };
+
+    let result = trace.has_result.then(|| {
+        if trace.kind == StoredTraceKind::Create {
+            TraceOutput::Create(CreateOutput {
+                gas_used: U64::from(trace.gas_used),
+                code: trace.output,
+                address: trace.to,
+            })
+        } else {
+            TraceOutput::Call(CallOutput {
+                gas_used: U64::from(trace.gas_used),
+                output: trace.output,
+            })
+        }
+    });
+
+    TransactionTrace {
+        action,
+        error: (!trace.error.is_empty()).then_some(trace.error),
+        result,
+        subtraces: trace.subtraces as usize,
+        trace_address: trace.trace_address.into_iter().map(|index| index as usize).collect(),
+    }
+}
+
+/// Skips the first `after` traces of the stream and yields at most `count` traces, see
+/// [TraceFilter].
+fn paginate_traces<S>(
+    traces: S,
+    after: u64,
+    count: Option<u64>,
+) -> impl Stream<Item = EthResult<LocalizedTransactionTrace>>
+where
+    S: Stream<Item = EthResult<LocalizedTransactionTrace>>,
+{
+    let count = count.map_or(usize::MAX, |count| count as usize);
+    let mut skipped = 0;
+    traces
+        .try_filter(move |_| {
+            let skip = skipped < after;
+            if skip {
+                skipped += 1;
+            }
+            futures::future::ready(!skip)
+        })
+        .take(count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reth_primitives::Address;
 
     #[test]
     fn test_parity_config() {
@@ -606,4 +896,114 @@ mod tests {
         // not required for StateDiff
         assert!(!config.record_state_diff);
     }
+
+    #[test]
+    fn test_from_stored_trace() {
+        let trace = from_stored_trace(StoredCallTrace {
+            kind: StoredTraceKind::StaticCall,
+            from: Address::with_last_byte(1),
+            to: Address::with_last_byte(2),
+            gas: 10_000,
+            has_result: true,
+            gas_used: 100,
+            output: Bytes::from_static(&[1]),
+            subtraces: 1,
+            trace_address: vec![0],
+            ..Default::default()
+        });
+        assert_eq!(
+            trace,
+            TransactionTrace {
+                action: Action::Call(CallAction {
+                    from: Address::with_last_byte(1),
+                    call_type: CallType::StaticCall,
+                    gas: U64::from(10_000),
+                    input: Bytes::new(),
+                    to: Address::with_last_byte(2),
+                    value: U256::ZERO,
+                }),
+                error: None,
+                result: Some(TraceOutput::Call(CallOutput {
+                    gas_used: U64::from(100),
+                    output: Bytes::from_static(&[1]),
+                })),
+                subtraces: 1,
+                trace_address: vec![0],
+            }
+        );
+
+        // a reverted creation keeps its result
+        let trace = from_stored_trace(StoredCallTrace {
+            kind: StoredTraceKind::Create,
+            from: Address::with_last_byte(1),
+            to: Address::with_last_byte(3),
+            has_result: true,
+            error: "Reverted".to_string(),
+            ..Default::default()
+        });
+        assert!(trace.action.is_create());
+        assert_eq!(trace.error.as_deref(), Some("Reverted"));
+        assert!(matches!(
+            trace.result,
+            Some(TraceOutput::Create(CreateOutput { address, .. })) if address == Address::with_last_byte(3)
+        ));
+
+        // a failed call has no result
+        let trace = from_stored_trace(StoredCallTrace {
+            error: "Out of gas".to_string(),
+            ..Default::default()
+        });
+        assert!(trace.result.is_none());
+    }
+
+    fn reward_trace(position: u64) -> LocalizedTransactionTrace {
+        LocalizedTransactionTrace {
+            trace: TransactionTrace {
+                action: Action::Reward(RewardAction {
+                    author: Address::ZERO,
+                    value: U256::ZERO,
+                    reward_type: RewardType::Block,
+                }),
+                error: None,
+                result: None,
+                subtraces: 0,
+                trace_address: vec![],
+            },
+            block_hash: None,
+            block_number: None,
+            transaction_hash: None,
+            transaction_position: Some(position),
+        }
+    }
+
+    async fn paginate(after: u64, count: Option<u64>) -> Vec<u64> {
+        let traces = futures::stream::iter((0..10).map(|position| Ok(reward_trace(position))));
+        paginate_traces(traces, after, count)
+            .map_ok(|trace| trace.transaction_position.unwrap())
+            .try_collect()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_trace_filter_pagination() {
+        assert_eq!(paginate(0, None).await, (0..10).collect::<Vec<_>>());
+        assert_eq!(paginate(3, None).await, (3..10).collect::<Vec<_>>());
+        assert_eq!(paginate(0, Some(2)).await, vec![0, 1]);
+        assert_eq!(paginate(3, Some(2)).await, vec![3, 4]);
+        assert_eq!(paginate(8, Some(5)).await, vec![8, 9]);
+        assert!(paginate(10, None).await.is_empty());
+        assert!(paginate(0, Some(0)).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trace_filter_pagination_stops_at_error() {
+        let traces = futures::stream::iter(vec![
+            Ok(reward_trace(0)),
+            Err(EthApiError::InvalidParams("batch failed".to_string())),
+            Ok(reward_trace(1)),
+        ]);
+        let result: EthResult<Vec<_>> = paginate_traces(traces, 0, None).try_collect().await;
+        assert!(result.is_err());
+    }
 }
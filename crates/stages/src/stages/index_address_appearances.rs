@@ -0,0 +1,349 @@
+use crate::{ExecInput, ExecOutput, Stage, StageError, UnwindInput, UnwindOutput};
+use reth_db::database::Database;
+use reth_primitives::{
+    stage::{StageCheckpoint, StageId},
+    PruneCheckpoint, PruneMode, PruneSegment,
+};
+use reth_provider::{
+    AddressAppearancesWriter, DatabaseProviderRW, PruneCheckpointReader, PruneCheckpointWriter,
+};
+use std::fmt::Debug;
+
+/// Stage is indexing the transactions each address appears in, as the sender, the recipient, the
+/// created contract, the emitter of a log or a party of an internal call. For more information on
+/// index sharding take a look at [`reth_db::tables::AddressAppearances`].
+///
+/// The internal calls are read from the traces recorded by the
+/// [`TransactionTracesStage`](crate::stages::TransactionTracesStage), so this stage needs to run
+/// after it for them to be indexed.
+///
+/// This stage is optional and isn't part of the default stage sets, it only needs to run if the
+/// index is used, e.g. by the `ots_searchTransactionsBefore` and `ots_searchTransactionsAfter`
+/// RPC methods.
+#[derive(Debug)]
+pub struct IndexAddressAppearancesStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+}
+
+impl IndexAddressAppearancesStage {
+    /// Create new instance of [IndexAddressAppearancesStage].
+    pub fn new(commit_threshold: u64, prune_mode: Option<PruneMode>) -> Self {
+        Self { commit_threshold, prune_mode }
+    }
+}
+
+impl Default for IndexAddressAppearancesStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, prune_mode: None }
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB: Database> Stage<DB> for IndexAddressAppearancesStage {
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexAddressAppearances
+    }
+
+    /// Execute the stage.
+    async fn execute(
+        &mut self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| mode.prune_target_block(input.target(), PruneSegment::AddressAppearances))
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::AddressAppearances)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::AddressAppearances,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (range, is_final_range) = input.next_block_range_with_threshold(self.commit_threshold);
+
+        let appearances = provider.address_appearances_with_range(range.clone())?;
+        provider.insert_address_appearances(appearances)?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    async fn unwind(
+        &mut self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_address_appearances(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestTransaction;
+    use reth_db::{
+        models::{
+            ShardedKey, StoredBlockBodyIndices, StoredCallTrace, StoredTraceKind,
+            StoredTransactionTraces,
+        },
+        tables,
+        transaction::DbTxMut,
+        TxNumberList,
+    };
+    use reth_primitives::{
+        address, Address, Log, Receipt, Signature, Transaction, TransactionKind, TransactionSigned,
+        TxLegacy, TxNumber, MAINNET,
+    };
+    use reth_provider::{AddressAppearancesReader, ProviderFactory};
+    use std::collections::BTreeMap;
+
+    const SENDER: Address = address!("0000000000000000000000000000000000000001");
+    const RECIPIENT: Address = address!("0000000000000000000000000000000000000002");
+    const OTHER_SENDER: Address = address!("0000000000000000000000000000000000000003");
+
+    fn transaction(nonce: u64, to: TransactionKind) -> TransactionSigned {
+        TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy { nonce, to, ..Default::default() }),
+            Signature::default(),
+        )
+    }
+
+    fn cast(table: Vec<(ShardedKey<Address>, TxNumberList)>) -> BTreeMap<Address, Vec<TxNumber>> {
+        table
+            .into_iter()
+            .map(|(k, v)| {
+                assert_eq!(k.highest_block_number, u64::MAX);
+                (k.key, v.iter(0).map(|tx| tx as TxNumber).collect())
+            })
+            .collect()
+    }
+
+    /// Sets up three transactions:
+    ///   - block 1, tx 0: a call from `SENDER` to `RECIPIENT`
+    ///   - block 1, tx 1: a contract creation by `SENDER`, with a log emitted by `RECIPIENT`
+    ///   - block 2, tx 2: a call from `OTHER_SENDER` to `SENDER`
+    fn setup(tx: &TestTransaction) {
+        tx.commit(|tx| {
+            tx.put::<tables::BlockBodyIndices>(0, StoredBlockBodyIndices::default())?;
+            tx.put::<tables::BlockBodyIndices>(
+                1,
+                StoredBlockBodyIndices { first_tx_num: 0, tx_count: 2 },
+            )?;
+            tx.put::<tables::BlockBodyIndices>(
+                2,
+                StoredBlockBodyIndices { first_tx_num: 2, tx_count: 1 },
+            )?;
+
+            let transactions = [
+                (SENDER, transaction(0, TransactionKind::Call(RECIPIENT))),
+                (SENDER, transaction(1, TransactionKind::Create)),
+                (OTHER_SENDER, transaction(0, TransactionKind::Call(SENDER))),
+            ];
+            for (tx_number, (sender, transaction)) in transactions.into_iter().enumerate() {
+                tx.put::<tables::Transactions>(tx_number as TxNumber, transaction.into())?;
+                tx.put::<tables::TxSenders>(tx_number as TxNumber, sender)?;
+            }
+
+            tx.put::<tables::Receipts>(
+                1,
+                Receipt {
+                    success: true,
+                    logs: vec![Log { address: RECIPIENT, ..Default::default() }],
+                    ..Default::default()
+                },
+            )?;
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    async fn unwind(tx: &TestTransaction, unwind_from: u64, unwind_to: u64) {
+        let input = UnwindInput {
+            checkpoint: StageCheckpoint::new(unwind_from),
+            unwind_to,
+            ..Default::default()
+        };
+        let mut stage = IndexAddressAppearancesStage::default();
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).await.unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) });
+        provider.commit().unwrap();
+    }
+
+    #[tokio::test]
+    async fn insert_and_unwind_appearances() {
+        let tx = TestTransaction::default();
+        setup(&tx);
+
+        let input = ExecInput { target: Some(2), ..Default::default() };
+        let mut stage = IndexAddressAppearancesStage::default();
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(2), done: true });
+        provider.commit().unwrap();
+
+        let created = SENDER.create(1);
+        let table = cast(tx.table::<tables::AddressAppearances>().unwrap());
+        assert_eq!(
+            table,
+            BTreeMap::from([
+                (SENDER, vec![0, 1, 2]),
+                (RECIPIENT, vec![0, 1]),
+                (OTHER_SENDER, vec![2]),
+                (created, vec![1]),
+            ])
+        );
+
+        // unwind the second block
+        unwind(&tx, 2, 1).await;
+        let table = cast(tx.table::<tables::AddressAppearances>().unwrap());
+        assert_eq!(
+            table,
+            BTreeMap::from([(SENDER, vec![0, 1]), (RECIPIENT, vec![0, 1]), (created, vec![1])])
+        );
+
+        // unwind everything
+        unwind(&tx, 1, 0).await;
+        assert!(tx.table::<tables::AddressAppearances>().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn index_internal_calls() {
+        const INTERNAL: Address = address!("0000000000000000000000000000000000000004");
+
+        let tx = TestTransaction::default();
+        setup(&tx);
+        // the callee of the last transaction calls `INTERNAL` and fails to create a contract
+        tx.commit(|tx| {
+            tx.put::<tables::TransactionTraces>(
+                2,
+                StoredTransactionTraces {
+                    output: Default::default(),
+                    traces: vec![
+                        StoredCallTrace {
+                            from: OTHER_SENDER,
+                            to: SENDER,
+                            has_result: true,
+                            subtraces: 2,
+                            ..Default::default()
+                        },
+                        StoredCallTrace {
+                            from: SENDER,
+                            to: INTERNAL,
+                            has_result: true,
+                            trace_address: vec![0],
+                            ..Default::default()
+                        },
+                        StoredCallTrace {
+                            kind: StoredTraceKind::Create,
+                            from: SENDER,
+                            error: "Out of gas".to_string(),
+                            trace_address: vec![1],
+                            ..Default::default()
+                        },
+                    ],
+                },
+            )
+        })
+        .unwrap();
+
+        let input = ExecInput { target: Some(2), ..Default::default() };
+        let mut stage = IndexAddressAppearancesStage::default();
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(2), done: true });
+        provider.commit().unwrap();
+
+        let table = cast(tx.table::<tables::AddressAppearances>().unwrap());
+        assert_eq!(table.get(&INTERNAL), Some(&vec![2]));
+        assert_eq!(table.get(&SENDER), Some(&vec![0, 1, 2]));
+        assert!(!table.contains_key(&Address::ZERO));
+
+        // the internal calls are unwound as well
+        unwind(&tx, 2, 1).await;
+        let table = cast(tx.table::<tables::AddressAppearances>().unwrap());
+        assert!(!table.contains_key(&INTERNAL));
+    }
+
+    #[tokio::test]
+    async fn read_appearances() {
+        let tx = TestTransaction::default();
+        setup(&tx);
+
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let appearances = provider.address_appearances_with_range(1..=2).unwrap();
+        provider.insert_address_appearances(appearances).unwrap();
+        provider.commit().unwrap();
+
+        assert_eq!(factory.address_appearances_before(SENDER, u64::MAX, 10).unwrap(), [2, 1, 0]);
+        assert_eq!(factory.address_appearances_before(SENDER, 2, 10).unwrap(), [1, 0]);
+        assert_eq!(factory.address_appearances_before(SENDER, 2, 1).unwrap(), [1]);
+        assert!(factory.address_appearances_before(SENDER, 0, 10).unwrap().is_empty());
+        assert_eq!(factory.address_appearances_from(SENDER, 1, 10).unwrap(), [1, 2]);
+        assert_eq!(factory.address_appearances_from(RECIPIENT, 1, 1).unwrap(), [1]);
+        assert!(factory.address_appearances_from(RECIPIENT, 2, 10).unwrap().is_empty());
+        assert!(factory.address_appearances_from(Address::ZERO, 0, 10).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn insert_appearances_with_prune_mode() {
+        let tx = TestTransaction::default();
+        setup(&tx);
+        tx.commit(|tx| {
+            tx.put::<tables::BlockBodyIndices>(
+                20000,
+                StoredBlockBodyIndices { first_tx_num: 3, tx_count: 0 },
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        let input = ExecInput { target: Some(20000), ..Default::default() };
+        let mut stage = IndexAddressAppearancesStage {
+            prune_mode: Some(PruneMode::Before(2)),
+            ..Default::default()
+        };
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(20000), done: true });
+        provider.commit().unwrap();
+
+        // only the second block is indexed
+        let table = cast(tx.table::<tables::AddressAppearances>().unwrap());
+        assert_eq!(table, BTreeMap::from([(SENDER, vec![2]), (OTHER_SENDER, vec![2])]));
+    }
+}
@@ -12,6 +12,8 @@ mod hashing_storage;
 mod headers;
 /// Index history of account changes
 mod index_account_history;
+/// Index transactions of addresses
+mod index_address_appearances;
 /// Index history of storage changes
 mod index_storage_history;
 /// Stage for computing state root.
@@ -20,6 +22,8 @@ mod merkle;
 mod sender_recovery;
 /// The total difficulty stage
 mod total_difficulty;
+/// The transaction traces stage
+mod transaction_traces;
 /// The transaction lookup stage
 mod tx_lookup;
 
@@ -30,10 +34,12 @@ pub use hashing_account::*;
 pub use hashing_storage::*;
 pub use headers::*;
 pub use index_account_history::*;
+pub use index_address_appearances::*;
 pub use index_storage_history::*;
 pub use merkle::*;
 pub use sender_recovery::*;
 pub use total_difficulty::*;
+pub use transaction_traces::*;
 pub use tx_lookup::*;
 
 #[cfg(test)]
@@ -0,0 +1,616 @@
+use crate::{BlockErrorKind, ExecInput, ExecOutput, Stage, StageError, UnwindInput, UnwindOutput};
+use reth_db::{
+    database::Database,
+    models::{StoredCallTrace, StoredTraceKind, StoredTransactionTraces},
+    transaction::{DbTx, DbTxMut},
+};
+use reth_interfaces::provider::ProviderError;
+use reth_primitives::{
+    stage::{StageCheckpoint, StageId},
+    BlockNumber, PruneCheckpoint, PruneMode, PruneSegment, TxNumber,
+};
+use reth_provider::{
+    BlockReader, ChainSpecProvider, DatabaseProvider, DatabaseProviderRW, HeaderProvider,
+    HistoricalStateProviderRef, LowestAvailableBlocks, PruneCheckpointReader,
+    PruneCheckpointWriter, StageCheckpointReader, StageCheckpointWriter, TransactionTracesWriter,
+    TransactionsProvider,
+};
+use reth_revm::{
+    database::StateProviderDatabase,
+    processor::EVMProcessor,
+    stack::{Hook, InspectorStack, InspectorStackConfig},
+    tracing::TracingInspectorConfig,
+};
+use reth_rpc_types::trace::parity::{Action, CallType, RewardType, TraceOutput, TransactionTrace};
+use std::ops::RangeInclusive;
+
+/// Stage that records the parity style call traces of each transaction, so they can be served by
+/// `trace_block` and `trace_transaction` without re-executing the transactions. The traces are
+/// written to [`reth_db::tables::TransactionTraces`].
+///
+/// Each block is re-executed by the [EVMProcessor] with a
+/// [TracingInspector](reth_revm::tracing::TracingInspector) on top of the historical state of the
+/// parent block, so this stage needs to run after the history indices have been built. Blocks whose
+/// parent state has been pruned are skipped.
+///
+/// This stage is optional and isn't part of the default stage sets.
+#[derive(Debug)]
+pub struct TransactionTracesStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// Pruning configuration.
+    pub prune_mode: Option<PruneMode>,
+}
+
+impl TransactionTracesStage {
+    /// Create new instance of [TransactionTracesStage].
+    pub fn new(commit_threshold: u64, prune_mode: Option<PruneMode>) -> Self {
+        Self { commit_threshold, prune_mode }
+    }
+}
+
+impl Default for TransactionTracesStage {
+    fn default() -> Self {
+        Self { commit_threshold: 10_000, prune_mode: None }
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB: Database> Stage<DB> for TransactionTracesStage {
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::TransactionTraces
+    }
+
+    /// Execute the stage.
+    async fn execute(
+        &mut self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| mode.prune_target_block(input.target(), PruneSegment::TransactionTraces))
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::TransactionTraces)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::TransactionTraces,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+
+        // Skip the blocks that can't be traced, because the state of their parent was pruned.
+        let lowest_available_blocks = lowest_available_blocks(provider)?;
+        let lowest_traceable_block = lowest_available_blocks
+            .account_history_block_number
+            .max(lowest_available_blocks.storage_history_block_number)
+            .unwrap_or_default();
+        if lowest_traceable_block > input.checkpoint().block_number + 1 {
+            input.checkpoint =
+                Some(StageCheckpoint::new((lowest_traceable_block - 1).min(input.target())));
+        }
+
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let (range, is_final_range) = input.next_block_range_with_threshold(self.commit_threshold);
+
+        for block_number in range.clone() {
+            let traces = trace_block(provider, block_number, lowest_available_blocks)?;
+            provider.insert_transaction_traces(traces)?;
+        }
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    async fn unwind(
+        &mut self,
+        provider: &DatabaseProviderRW<'_, &DB>,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_progress, _) =
+            input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        provider.unwind_transaction_traces(range)?;
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_progress) })
+    }
+}
+
+/// Records the traces of the given blocks that were appended outside of the pipeline, e.g. the
+/// canonical blocks committed by the blockchain tree, so the recorded traces keep up with the tip
+/// of the chain.
+///
+/// The traces are only recorded if the [TransactionTracesStage] is enabled and up to date with the
+/// parent block. Otherwise the blocks are left to the stage.
+pub fn record_appended_transaction_traces<TX: DbTxMut + DbTx>(
+    provider: &DatabaseProvider<TX>,
+    range: RangeInclusive<BlockNumber>,
+) -> Result<(), StageError> {
+    if !provider
+        .get_stage_checkpoint(StageId::TransactionTraces)?
+        .is_some_and(|checkpoint| checkpoint.block_number + 1 == *range.start())
+    {
+        return Ok(())
+    }
+
+    let lowest_available_blocks = lowest_available_blocks(provider)?;
+    for block_number in range.clone() {
+        let traces = trace_block(provider, block_number, lowest_available_blocks)?;
+        provider.insert_transaction_traces(traces)?;
+    }
+    provider
+        .save_stage_checkpoint(StageId::TransactionTraces, StageCheckpoint::new(*range.end()))?;
+
+    Ok(())
+}
+
+/// Returns the lowest blocks at which the account and storage history are available, according to
+/// their prune checkpoints.
+fn lowest_available_blocks<TX: DbTx>(
+    provider: &DatabaseProvider<TX>,
+) -> Result<LowestAvailableBlocks, StageError> {
+    let lowest_available_block = |segment| -> Result<Option<BlockNumber>, StageError> {
+        Ok(provider
+            .get_prune_checkpoint(segment)?
+            .and_then(|checkpoint| checkpoint.block_number)
+            .map(|block_number| block_number + 1))
+    };
+
+    Ok(LowestAvailableBlocks {
+        account_history_block_number: lowest_available_block(PruneSegment::AccountHistory)?,
+        storage_history_block_number: lowest_available_block(PruneSegment::StorageHistory)?,
+    })
+}
+
+/// Re-executes the block on top of the state of its parent and returns the traces of its
+/// transactions, keyed by their transaction numbers.
+///
+/// The block is executed by the [EVMProcessor], so that the traces follow the same rules as the
+/// execution of the block, e.g. the EIP-4788 beacon root contract call or the OP Stack deposit and
+/// fee rules.
+fn trace_block<TX: DbTx>(
+    provider: &DatabaseProvider<TX>,
+    block_number: BlockNumber,
+    lowest_available_blocks: LowestAvailableBlocks,
+) -> Result<Vec<(TxNumber, StoredTransactionTraces)>, StageError> {
+    let body = provider
+        .block_body_indices(block_number)?
+        .ok_or(ProviderError::BlockBodyIndicesNotFound(block_number))?;
+    if body.tx_count == 0 {
+        return Ok(Vec::new())
+    }
+
+    let td = provider
+        .header_td_by_number(block_number)?
+        .ok_or(ProviderError::HeaderNotFound(block_number.into()))?;
+    let block = provider
+        .block(block_number.into())?
+        .ok_or(ProviderError::BlockNotFound(block_number.into()))?;
+    // Senders might have been pruned, the executor recovers them if so.
+    let senders = provider.senders_by_tx_range(body.tx_num_range())?;
+    let senders = (senders.len() == block.body.len()).then_some(senders);
+
+    let state = HistoricalStateProviderRef::new_with_lowest_available_blocks(
+        provider.tx_ref(),
+        block_number,
+        lowest_available_blocks,
+    );
+    let mut executor =
+        EVMProcessor::new_with_db(provider.chain_spec(), StateProviderDatabase::new(state));
+    executor.set_stack(InspectorStack::new(InspectorStackConfig {
+        tracing: Some(TracingInspectorConfig::default_parity()),
+        hook: Hook::All,
+        ..Default::default()
+    }));
+
+    let mut traces = Vec::with_capacity(block.body.len());
+    executor
+        .inspect_transactions(&block, td, senders, |executor, _, result| {
+            let inspector =
+                executor.stack_mut().take_tracing_inspector().expect("tracing inspector is set");
+            let tx_number = body.first_tx_num + traces.len() as u64;
+            traces.push((
+                tx_number,
+                StoredTransactionTraces {
+                    output: result.output().cloned().unwrap_or_default(),
+                    traces: inspector
+                        .with_transaction_gas_used(result.gas_used())
+                        .into_parity_builder()
+                        .into_transaction_traces()
+                        .into_iter()
+                        .map(into_stored_trace)
+                        .collect(),
+                },
+            ));
+        })
+        .map_err(|error| StageError::Block {
+            block: block.header.clone().seal_slow(),
+            error: BlockErrorKind::Execution(error),
+        })?;
+
+    Ok(traces)
+}
+
+/// Converts a parity trace into its database representation.
+fn into_stored_trace(trace: TransactionTrace) -> StoredCallTrace {
+    let mut stored = StoredCallTrace {
+        has_result: trace.result.is_some(),
+        error: trace.error.unwrap_or_default(),
+        subtraces: trace.subtraces as u64,
+        trace_address: trace.trace_address.into_iter().map(|index| index as u64).collect(),
+        ..Default::default()
+    };
+
+    match trace.action {
+        Action::Call(call) => {
+            stored.kind = match call.call_type {
+                // traces of executed calls always have a call type
+                CallType::None | CallType::Call => StoredTraceKind::Call,
+                CallType::CallCode => StoredTraceKind::CallCode,
+                CallType::DelegateCall => StoredTraceKind::DelegateCall,
+                CallType::StaticCall => StoredTraceKind::StaticCall,
+            };
+            stored.from = call.from;
+            stored.to = call.to;
+            stored.value = call.value;
+            stored.gas = call.gas.to();
+            stored.input = call.input;
+        }
+        Action::Create(create) => {
+            stored.kind = StoredTraceKind::Create;
+            stored.from = create.from;
+            stored.value = create.value;
+            stored.gas = create.gas.to();
+            stored.input = create.init;
+        }
+        Action::Selfdestruct(selfdestruct) => {
+            stored.kind = StoredTraceKind::Selfdestruct;
+            stored.from = selfdestruct.address;
+            stored.to = selfdestruct.refund_address;
+            stored.value = selfdestruct.balance;
+        }
+        Action::Reward(reward) => {
+            stored.kind = match reward.reward_type {
+                RewardType::Block => StoredTraceKind::BlockReward,
+                RewardType::Uncle => StoredTraceKind::UncleReward,
+            };
+            stored.from = reward.author;
+            stored.value = reward.value;
+        }
+    }
+
+    match trace.result {
+        Some(TraceOutput::Call(output)) => {
+            stored.gas_used = output.gas_used.to();
+            stored.output = output.output;
+        }
+        Some(TraceOutput::Create(output)) => {
+            stored.to = output.address;
+            stored.gas_used = output.gas_used.to();
+            stored.output = output.code;
+        }
+        None => {}
+    }
+
+    stored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestTransaction;
+    use reth_db::{
+        models::{AccountBeforeTx, ShardedKey, StoredBlockBodyIndices},
+        tables::{self, BlockNumberList},
+        transaction::DbTxMut,
+    };
+    use reth_primitives::{
+        address, bytes, constants::BEACON_ROOTS_ADDRESS, Account, Address, Bytecode, Bytes,
+        ChainSpecBuilder, Header, Signature, Transaction, TransactionKind, TransactionSigned,
+        TxLegacy, B256, MAINNET, U256,
+    };
+    use reth_provider::{ProviderFactory, TransactionTracesReader};
+    use std::sync::Arc;
+
+    const SENDER: Address = address!("0000000000000000000000000000000000000001");
+    const CONTRACT: Address = address!("0000000000000000000000000000000000000002");
+    const TARGET: Address = address!("0000000000000000000000000000000000000003");
+
+    /// PUSH1 0 (x5), PUSH20 TARGET, PUSH2 0x1000, CALL, STOP
+    static CONTRACT_CODE: Bytes =
+        bytes!("60006000600060006000730000000000000000000000000000000000000003611000f100");
+
+    /// Inserts the accounts with the given code at genesis, so the historical state of the first
+    /// block is read from the plain state.
+    fn insert_accounts(tx: &TestTransaction, accounts: &[(Address, Option<Bytes>)]) {
+        tx.commit(|tx| {
+            for (address, code) in accounts {
+                let bytecode = code.clone().map(Bytecode::new_raw);
+                tx.put::<tables::PlainAccountState>(
+                    *address,
+                    Account {
+                        nonce: 0,
+                        balance: U256::ZERO,
+                        bytecode_hash: bytecode.as_ref().map(Bytecode::hash_slow),
+                    },
+                )?;
+                tx.put::<tables::AccountChangeSet>(
+                    0,
+                    AccountBeforeTx { address: *address, info: None },
+                )?;
+                tx.put::<tables::AccountHistory>(
+                    ShardedKey::last(*address),
+                    BlockNumberList::new([0usize]).unwrap(),
+                )?;
+                if let Some(bytecode) = bytecode {
+                    tx.put::<tables::Bytecodes>(bytecode.hash_slow(), bytecode)?;
+                }
+            }
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    /// Inserts the first block with the given header and transactions.
+    fn insert_block(
+        tx: &TestTransaction,
+        header: Header,
+        transactions: Vec<(TransactionSigned, Address)>,
+    ) {
+        tx.commit(|tx| {
+            tx.put::<tables::Headers>(1, header)?;
+            tx.put::<tables::HeaderTD>(1, U256::ZERO.into())?;
+            tx.put::<tables::BlockBodyIndices>(0, StoredBlockBodyIndices::default())?;
+            tx.put::<tables::BlockBodyIndices>(
+                1,
+                StoredBlockBodyIndices { first_tx_num: 0, tx_count: transactions.len() as u64 },
+            )?;
+            for (tx_number, (transaction, sender)) in (0..).zip(transactions) {
+                tx.put::<tables::Transactions>(tx_number, transaction.into())?;
+                tx.put::<tables::TxSenders>(tx_number, sender)?;
+            }
+            Ok(())
+        })
+        .unwrap()
+    }
+
+    /// Sets up a block with a single transaction from `SENDER` to `CONTRACT`, which calls `TARGET`.
+    fn setup(tx: &TestTransaction) {
+        insert_accounts(
+            tx,
+            &[(SENDER, None), (CONTRACT, Some(CONTRACT_CODE.clone())), (TARGET, None)],
+        );
+
+        let transaction = TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy {
+                nonce: 0,
+                gas_limit: 100_000,
+                to: TransactionKind::Call(CONTRACT),
+                ..Default::default()
+            }),
+            Signature::default(),
+        );
+        insert_block(
+            tx,
+            Header { number: 1, gas_limit: 1_000_000, ..Default::default() },
+            vec![(transaction, SENDER)],
+        );
+    }
+
+    #[tokio::test]
+    async fn record_and_unwind_traces() {
+        let tx = TestTransaction::default();
+        setup(&tx);
+
+        let input = ExecInput { target: Some(1), ..Default::default() };
+        let mut stage = TransactionTracesStage::default();
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+
+        let traces = factory.transaction_traces_by_block(1.into()).unwrap().unwrap();
+        assert_eq!(traces.len(), 1);
+        let traces = &traces[0].traces;
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].subtraces, 1);
+        assert_eq!(traces[1].trace_address, vec![0]);
+        assert_eq!(
+            (traces[0].kind, traces[0].from, traces[0].to),
+            (StoredTraceKind::Call, SENDER, CONTRACT)
+        );
+        assert_eq!(
+            (traces[1].kind, traces[1].from, traces[1].to),
+            (StoredTraceKind::Call, CONTRACT, TARGET)
+        );
+        assert_eq!(
+            factory.transaction_traces(0).unwrap().map(|traces| traces.traces.len()),
+            Some(2)
+        );
+
+        // unwind the block
+        let input =
+            UnwindInput { checkpoint: StageCheckpoint::new(1), unwind_to: 0, ..Default::default() };
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.unwind(&provider, input).await.unwrap();
+        assert_eq!(out, UnwindOutput { checkpoint: StageCheckpoint::new(0) });
+        provider.commit().unwrap();
+
+        assert!(tx.table::<tables::TransactionTraces>().unwrap().is_empty());
+        assert!(factory.transaction_traces_by_block(1.into()).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn skip_blocks_with_pruned_state() {
+        let tx = TestTransaction::default();
+        setup(&tx);
+        tx.commit(|tx| {
+            tx.put::<tables::PruneCheckpoints>(
+                PruneSegment::AccountHistory,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(2),
+                },
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        let input = ExecInput { target: Some(1), ..Default::default() };
+        let mut stage = TransactionTracesStage::default();
+        let factory = ProviderFactory::new(tx.tx.as_ref(), MAINNET.clone());
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+
+        assert!(tx.table::<tables::TransactionTraces>().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn trace_after_beacon_root_contract_call() {
+        // the EIP-4788 beacon roots contract
+        static BEACON_ROOTS_CODE: Bytes = bytes!("3373fffffffffffffffffffffffffffffffffffffffe14604d57602036146024575f5ffd5b5f35801560495762001fff810690815414603c575f5ffd5b62001fff01545f5260205ff35b5f5ffd5b62001fff42064281555f359062001fff015500");
+
+        let tx = TestTransaction::default();
+        insert_accounts(
+            &tx,
+            &[(SENDER, None), (BEACON_ROOTS_ADDRESS, Some(BEACON_ROOTS_CODE.clone()))],
+        );
+
+        // look up the parent beacon block root of the block itself, which is only available if the
+        // pre-block call was applied before the transaction
+        let timestamp = 12;
+        let parent_beacon_block_root = B256::with_last_byte(0x69);
+        let transaction = TransactionSigned::from_transaction_and_signature(
+            Transaction::Legacy(TxLegacy {
+                nonce: 0,
+                gas_limit: 100_000,
+                to: TransactionKind::Call(BEACON_ROOTS_ADDRESS),
+                input: U256::from(timestamp).to_be_bytes_vec().into(),
+                ..Default::default()
+            }),
+            Signature::default(),
+        );
+        insert_block(
+            &tx,
+            Header {
+                number: 1,
+                timestamp,
+                gas_limit: 1_000_000,
+                base_fee_per_gas: Some(0),
+                blob_gas_used: Some(0),
+                excess_blob_gas: Some(0),
+                parent_beacon_block_root: Some(parent_beacon_block_root),
+                ..Default::default()
+            },
+            vec![(transaction, SENDER)],
+        );
+
+        let input = ExecInput { target: Some(1), ..Default::default() };
+        let mut stage = TransactionTracesStage::default();
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().cancun_activated().build());
+        let factory = ProviderFactory::new(tx.tx.as_ref(), chain_spec);
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+
+        let traces = factory.transaction_traces(0).unwrap().unwrap();
+        let root = Bytes::from(parent_beacon_block_root.to_vec());
+        assert_eq!(traces.output, root);
+        assert_eq!(traces.traces.len(), 1);
+        assert!(traces.traces[0].error.is_empty());
+        assert_eq!(traces.traces[0].output, root);
+    }
+
+    #[cfg(feature = "optimism")]
+    #[tokio::test]
+    async fn trace_deposit() {
+        use reth_primitives::TxDeposit;
+
+        const DEPOSITOR: Address = address!("deaddeaddeaddeaddeaddeaddeaddeaddead0001");
+        const L1_BLOCK: Address = address!("4200000000000000000000000000000000000015");
+
+        let tx = TestTransaction::default();
+        insert_accounts(
+            &tx,
+            &[
+                (DEPOSITOR, None),
+                (SENDER, None),
+                (CONTRACT, Some(CONTRACT_CODE.clone())),
+                (TARGET, None),
+            ],
+        );
+
+        let deposit = |from, to, mint: Option<u128>, input| {
+            TransactionSigned::from_transaction_and_signature(
+                Transaction::Deposit(TxDeposit {
+                    source_hash: B256::ZERO,
+                    from,
+                    to: TransactionKind::Call(to),
+                    mint,
+                    value: mint.unwrap_or_default().into(),
+                    gas_limit: 1_000_000,
+                    is_system_transaction: false,
+                    input,
+                }),
+                Signature::optimism_deposit_tx_signature(),
+            )
+        };
+        // The L1 attributes deposit, followed by a deposit that transfers the minted value. The
+        // sender has no balance and the deposits have no gas price, so the transfer only succeeds
+        // if the mint is applied and the deposit is exempt from the base fee.
+        let value = 1_000_000_000_000_000_000;
+        insert_block(
+            &tx,
+            Header {
+                number: 1,
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(1_000_000_000),
+                ..Default::default()
+            },
+            vec![
+                (deposit(DEPOSITOR, L1_BLOCK, None, Bytes::from(vec![0; 4 + 8 * 32])), DEPOSITOR),
+                (deposit(SENDER, CONTRACT, Some(value), Bytes::new()), SENDER),
+            ],
+        );
+
+        let input = ExecInput { target: Some(1), ..Default::default() };
+        let mut stage = TransactionTracesStage::default();
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().regolith_activated().build());
+        let factory = ProviderFactory::new(tx.tx.as_ref(), chain_spec);
+        let provider = factory.provider_rw().unwrap();
+        let out = stage.execute(&provider, input).await.unwrap();
+        assert_eq!(out, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+
+        let traces = factory.transaction_traces(1).unwrap().unwrap().traces;
+        assert_eq!(traces.len(), 2);
+        assert_eq!(
+            (traces[0].from, traces[0].to, traces[0].value),
+            (SENDER, CONTRACT, U256::from(value))
+        );
+        assert!(traces[0].error.is_empty());
+        assert_eq!((traces[1].from, traces[1].to), (CONTRACT, TARGET));
+    }
+}
@@ -35,7 +35,6 @@ impl_compression_for_compact!(
     Account,
     Log,
     Receipt,
-    TxType,
     StorageEntry,
     StoredNibbles,
     BranchNodeCompact,
@@ -46,12 +45,40 @@ impl_compression_for_compact!(
     StoredBlockWithdrawals,
     Bytecode,
     AccountBeforeTx,
-    TransactionSignedNoHash,
     CompactU256,
     StageCheckpoint,
     PruneCheckpoint
 );
 
+/// Implements compression for Compact types that can fail to decode, like types that are tagged by
+/// a transaction type unknown to this version.
+macro_rules! impl_fallible_compression_for_compact {
+    ($($name:tt),+) => {
+        $(
+            impl Compress for $name
+            {
+                type Compressed = Vec<u8>;
+
+                fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(self, buf: &mut B) {
+                    let _  = Compact::to_compact(self, buf);
+                }
+            }
+
+            impl Decompress for $name
+            {
+                fn decompress<B: AsRef<[u8]>>(value: B) -> Result<$name, $crate::DatabaseError> {
+                    let value = value.as_ref();
+                    let (obj, _) = $name::try_from_compact(&value, value.len())
+                        .ok_or($crate::DatabaseError::Decode)?;
+                    Ok(obj)
+                }
+            }
+        )+
+    };
+}
+
+impl_fallible_compression_for_compact!(TxType, TransactionSignedNoHash);
+
 macro_rules! impl_compression_fixed_compact {
     ($($name:tt),+) => {
         $(
@@ -119,3 +146,62 @@ macro_rules! add_wrapper_struct {
 
 add_wrapper_struct!((U256, CompactU256));
 add_wrapper_struct!((u64, CompactU64));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DatabaseError;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn decompress_unknown_transaction_type() {
+        // bitflags with the extended transaction type identifier, followed by the signature and an
+        // unknown transaction type byte
+        let extended_identifier = TxType::EIP4844.to_compact(&mut Vec::<u8>::new());
+        let mut value = vec![(extended_identifier << 1) as u8];
+        value.extend([0u8; 64]);
+        value.push(0x7f);
+
+        assert_matches!(TransactionSignedNoHash::decompress(&value), Err(DatabaseError::Decode));
+    }
+
+    /// Returns the encoding of a transaction as it was stored before the extended transaction
+    /// type identifier, when EIP-4844 transactions were stored with the identifier `3` and no
+    /// type byte.
+    fn encoding_before_extended_identifier(identifier: u8, transaction: impl Compact) -> Vec<u8> {
+        let mut value = vec![0];
+        let sig_bit = Signature::default().to_compact(&mut value) as u8;
+        transaction.to_compact(&mut value);
+        value[0] = sig_bit | (identifier << 1);
+        value
+    }
+
+    #[test]
+    fn decompress_encoding_before_extended_identifier() {
+        let legacy =
+            TxLegacy { chain_id: Some(1), nonce: 1, gas_limit: 21_000, ..Default::default() };
+        let value = encoding_before_extended_identifier(0, legacy.clone());
+        assert_eq!(
+            TransactionSignedNoHash::decompress(&value).unwrap(),
+            TransactionSignedNoHash {
+                signature: Signature::default(),
+                transaction: Transaction::Legacy(legacy)
+            }
+        );
+
+        let eip1559 = TxEip1559 { chain_id: 1, nonce: 1, gas_limit: 21_000, ..Default::default() };
+        let value = encoding_before_extended_identifier(2, eip1559.clone());
+        assert_eq!(
+            TransactionSignedNoHash::decompress(&value).unwrap(),
+            TransactionSignedNoHash {
+                signature: Signature::default(),
+                transaction: Transaction::Eip1559(eip1559)
+            }
+        );
+
+        // the payload is read as the type byte, which is why the database version was bumped
+        let eip4844 = TxEip4844 { chain_id: 1, gas_limit: 21_000, ..Default::default() };
+        let value = encoding_before_extended_identifier(3, eip4844);
+        assert_matches!(TransactionSignedNoHash::decompress(&value), Err(DatabaseError::Decode));
+    }
+}
@@ -30,7 +30,8 @@ use crate::{
             accounts::{AccountBeforeTx, BlockNumberAddress},
             blocks::{HeaderHash, StoredBlockOmmers},
             storage_sharded_key::StorageShardedKey,
-            ShardedKey, StoredBlockBodyIndices, StoredBlockWithdrawals,
+            ShardedKey, StoredBadBlock, StoredBlockBodyIndices, StoredBlockWithdrawals,
+            StoredTransactionTraces,
         },
     },
 };
@@ -51,7 +52,7 @@ pub enum TableType {
 }
 
 /// Number of tables that should be present inside database.
-pub const NUM_TABLES: usize = 26;
+pub const NUM_TABLES: usize = 29;
 
 /// The general purpose of this is to use with a combination of Tables enum,
 /// by implementing a `TableViewer` trait you can operate on db tables in an abstract way.
@@ -184,7 +185,10 @@ tables!([
     (TxSenders, TableType::Table),
     (SyncStage, TableType::Table),
     (SyncStageProgress, TableType::Table),
-    (PruneCheckpoints, TableType::Table)
+    (PruneCheckpoints, TableType::Table),
+    (AddressAppearances, TableType::Table),
+    (BadBlocks, TableType::Table),
+    (TransactionTraces, TableType::Table)
 ]);
 
 /// Macro to declare key value table.
@@ -414,10 +418,42 @@ table!(
     ( PruneCheckpoints ) PruneSegment | PruneCheckpoint
 );
 
+table!(
+    /// Stores pointers to the transactions each address appears in, as the sender, the recipient,
+    /// the created contract, the emitter of a log or a party of an internal call. Internal calls
+    /// are read from [`TransactionTraces`], so they're only indexed for the transactions that were
+    /// traced.
+    ///
+    /// Sharded like [`AccountHistory`], but the shards contain transaction numbers instead of block
+    /// numbers. The last shard of an address is keyed with `u64::MAX`.
+    ///
+    /// This index is optional and only populated if the `IndexAddressAppearances` stage is enabled.
+    ( AddressAppearances ) ShardedKey<Address> | TxNumberList
+);
+
+table!(
+    /// Stores the most recent blocks that were rejected as invalid, alongside their validation
+    /// error, so they can be inspected and traced after the fact.
+    ///
+    /// Only a bounded number of bad blocks is kept, the ones with the lowest numbers are evicted
+    /// first.
+    ( BadBlocks ) BlockHash | StoredBadBlock
+);
+
+table!(
+    /// Stores the parity style call traces of each transaction, so `trace_block` and
+    /// `trace_transaction` don't need to re-execute the transactions.
+    ///
+    /// This table is optional and only populated if the `TransactionTraces` stage is enabled.
+    ( TransactionTraces ) TxNumber | StoredTransactionTraces
+);
+
 /// Alias Types
 
-/// List with transaction numbers.
+/// List with block numbers.
 pub type BlockNumberList = IntegerList;
+/// List with transaction numbers.
+pub type TxNumberList = IntegerList;
 /// Encoded stage id.
 pub type StageId = String;
 
@@ -453,6 +489,9 @@ mod tests {
         (TableType::Table, SyncStage::NAME),
         (TableType::Table, SyncStageProgress::NAME),
         (TableType::Table, PruneCheckpoints::NAME),
+        (TableType::Table, AddressAppearances::NAME),
+        (TableType::Table, BadBlocks::NAME),
+        (TableType::Table, TransactionTraces::NAME),
     ];
 
     #[test]
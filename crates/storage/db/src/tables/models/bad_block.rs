@@ -0,0 +1,55 @@
+//! Bad block related models and types.
+
+use crate::{
+    table::{Compress, Decompress},
+    DatabaseError,
+};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use reth_primitives::SealedBlock;
+use serde::{Deserialize, Serialize};
+
+/// A block that was rejected as invalid, as it is saved inside
+/// [`BadBlocks`][crate::tables::BadBlocks].
+///
+/// The block is stored RLP encoded, since it isn't part of the canonical chain and is rarely read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, RlpEncodable, RlpDecodable)]
+pub struct StoredBadBlock {
+    /// The rejected block.
+    pub block: SealedBlock,
+    /// The reason the block was rejected.
+    pub error: String,
+}
+
+impl Compress for StoredBadBlock {
+    type Compressed = Vec<u8>;
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(self, buf: &mut B) {
+        self.encode(buf)
+    }
+}
+
+impl Decompress for StoredBadBlock {
+    fn decompress<B: AsRef<[u8]>>(value: B) -> Result<Self, DatabaseError> {
+        Self::decode(&mut value.as_ref()).map_err(|_| DatabaseError::Decode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::Header;
+
+    #[test]
+    fn stored_bad_block_roundtrip() {
+        let bad_block = StoredBadBlock {
+            block: SealedBlock {
+                header: Header { number: 1, gas_limit: 21_000, ..Default::default() }.seal_slow(),
+                ..Default::default()
+            },
+            error: "transaction gas limit is more than blocks available gas".to_string(),
+        };
+
+        let decompressed = StoredBadBlock::decompress(bad_block.clone().compress()).unwrap();
+        assert_eq!(decompressed, bad_block);
+    }
+}
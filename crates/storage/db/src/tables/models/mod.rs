@@ -10,14 +10,18 @@ use reth_primitives::{
 };
 
 pub mod accounts;
+pub mod bad_block;
 pub mod blocks;
 pub mod integer_list;
 pub mod sharded_key;
 pub mod storage_sharded_key;
+pub mod traces;
 
 pub use accounts::*;
+pub use bad_block::StoredBadBlock;
 pub use blocks::*;
 pub use sharded_key::ShardedKey;
+pub use traces::{StoredCallTrace, StoredTraceKind, StoredTransactionTraces};
 
 /// Macro that implements [`Encode`] and [`Decode`] for uint types.
 macro_rules! impl_uints {
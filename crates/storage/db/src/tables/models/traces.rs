@@ -0,0 +1,199 @@
+//! Transaction trace related models and types.
+
+use crate::{
+    table::{Compress, Decompress},
+    DatabaseError,
+};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use reth_primitives::{Address, Bytes, U256};
+use serde::{Deserialize, Serialize};
+
+/// The zstd compression level of the stored traces.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// The parity style call traces of a transaction, as they're saved inside
+/// [`TransactionTraces`][crate::tables::TransactionTraces].
+///
+/// The traces are stored as zstd compressed RLP, since they're only read by the RPC.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+pub struct StoredTransactionTraces {
+    /// The output of the transaction.
+    pub output: Bytes,
+    /// The traces of all calls of the transaction, in the order they were made.
+    pub traces: Vec<StoredCallTrace>,
+}
+
+impl Compress for StoredTransactionTraces {
+    type Compressed = Vec<u8>;
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(self, buf: &mut B) {
+        let mut rlp = Vec::with_capacity(self.length());
+        self.encode(&mut rlp);
+        let compressed = zstd::bulk::compress(&rlp, COMPRESSION_LEVEL)
+            .expect("failed to compress transaction traces");
+        buf.put_slice(&compressed)
+    }
+}
+
+impl Decompress for StoredTransactionTraces {
+    fn decompress<B: AsRef<[u8]>>(value: B) -> Result<Self, DatabaseError> {
+        let rlp = zstd::stream::decode_all(value.as_ref()).map_err(|_| DatabaseError::Decode)?;
+        Self::decode(&mut rlp.as_slice()).map_err(|_| DatabaseError::Decode)
+    }
+}
+
+/// A single parity style trace of a transaction.
+///
+/// The meaning of the address and value fields depends on the [kind](StoredTraceKind) of the
+/// trace:
+///
+/// - calls: `from` is the caller, `to` the callee, `input` the calldata and `output` the return
+///   data.
+/// - creations: `from` is the creator, `to` the created contract, `input` the init code and
+///   `output` the deployed code.
+/// - selfdestructs: `from` is the destroyed contract, `to` the refund address and `value` its
+///   balance before it was destroyed.
+/// - rewards: `from` is the author of the block.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, RlpEncodable, RlpDecodable,
+)]
+pub struct StoredCallTrace {
+    /// The kind of the trace.
+    pub kind: StoredTraceKind,
+    /// The address the trace originates from.
+    pub from: Address,
+    /// The address the trace targets.
+    pub to: Address,
+    /// The value transferred.
+    pub value: U256,
+    /// The gas available to the call.
+    pub gas: u64,
+    /// The input of the call.
+    pub input: Bytes,
+    /// Whether the trace has a result, which is the case for all calls and creations that didn't
+    /// fail, or that were reverted.
+    pub has_result: bool,
+    /// The gas used by the call.
+    pub gas_used: u64,
+    /// The output of the call.
+    pub output: Bytes,
+    /// The error message of the call, empty if the call didn't fail.
+    pub error: String,
+    /// The number of child traces.
+    pub subtraces: u64,
+    /// The position of the trace in the call tree.
+    pub trace_address: Vec<u64>,
+}
+
+/// The kind of a [StoredCallTrace].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StoredTraceKind {
+    /// A regular call.
+    #[default]
+    Call,
+    /// A `CALLCODE`.
+    CallCode,
+    /// A `DELEGATECALL`.
+    DelegateCall,
+    /// A `STATICCALL`.
+    StaticCall,
+    /// A contract creation.
+    Create,
+    /// A `SELFDESTRUCT`.
+    Selfdestruct,
+    /// A block reward.
+    BlockReward,
+    /// An uncle reward.
+    UncleReward,
+}
+
+impl StoredTraceKind {
+    /// Returns the identifier of the kind, as it's encoded.
+    fn id(self) -> u8 {
+        match self {
+            StoredTraceKind::Call => 0,
+            StoredTraceKind::CallCode => 1,
+            StoredTraceKind::DelegateCall => 2,
+            StoredTraceKind::StaticCall => 3,
+            StoredTraceKind::Create => 4,
+            StoredTraceKind::Selfdestruct => 5,
+            StoredTraceKind::BlockReward => 6,
+            StoredTraceKind::UncleReward => 7,
+        }
+    }
+}
+
+impl Encodable for StoredTraceKind {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        self.id().encode(out)
+    }
+
+    fn length(&self) -> usize {
+        self.id().length()
+    }
+}
+
+impl Decodable for StoredTraceKind {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(match u8::decode(buf)? {
+            0 => StoredTraceKind::Call,
+            1 => StoredTraceKind::CallCode,
+            2 => StoredTraceKind::DelegateCall,
+            3 => StoredTraceKind::StaticCall,
+            4 => StoredTraceKind::Create,
+            5 => StoredTraceKind::Selfdestruct,
+            6 => StoredTraceKind::BlockReward,
+            7 => StoredTraceKind::UncleReward,
+            _ => return Err(alloy_rlp::Error::Custom("unknown trace kind")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_transaction_traces_roundtrip() {
+        let traces = StoredTransactionTraces {
+            output: Bytes::from_static(&[1, 2, 3]),
+            traces: vec![
+                StoredCallTrace {
+                    kind: StoredTraceKind::Call,
+                    from: Address::with_last_byte(1),
+                    to: Address::with_last_byte(2),
+                    value: U256::from(1),
+                    gas: 21_000,
+                    has_result: true,
+                    gas_used: 21_000,
+                    output: Bytes::from_static(&[1, 2, 3]),
+                    subtraces: 1,
+                    ..Default::default()
+                },
+                StoredCallTrace {
+                    kind: StoredTraceKind::Create,
+                    from: Address::with_last_byte(2),
+                    gas: 10_000,
+                    input: Bytes::from_static(&[0x60, 0x00]),
+                    error: "Reverted".to_string(),
+                    trace_address: vec![0],
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let decompressed = StoredTransactionTraces::decompress(traces.clone().compress()).unwrap();
+        assert_eq!(decompressed, traces);
+    }
+
+    #[test]
+    fn decode_unknown_trace_kind() {
+        for kind in 0..8u8 {
+            let decoded = StoredTraceKind::decode(&mut alloy_rlp::encode(kind).as_slice()).unwrap();
+            assert_eq!(decoded.id(), kind);
+        }
+        assert!(StoredTraceKind::decode(&mut alloy_rlp::encode(8u8).as_slice()).is_err());
+    }
+}
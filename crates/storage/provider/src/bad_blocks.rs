@@ -0,0 +1,100 @@
+//! Recording of the blocks that were rejected as invalid.
+
+use crate::{BadBlocksWriter, ProviderFactory};
+use reth_db::{database::Database, models::StoredBadBlock};
+use reth_interfaces::RethResult;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tracing::{trace, warn};
+
+/// The number of bad blocks that can wait to be written by the [BadBlocksRecorder].
+pub const BAD_BLOCKS_CHANNEL_CAPACITY: usize = 16;
+
+/// Alias type for the producers of bad blocks to use.
+///
+/// The bad blocks are written by the [BadBlocksRecorder], so the producers never need to open a
+/// write transaction themselves. The channel is bounded, so the producers should drop the bad
+/// blocks that don't fit with [Sender::try_send] instead of waiting for the recorder.
+pub type BadBlocksSender = Sender<StoredBadBlock>;
+
+/// Creates a bounded channel of bad blocks, with a capacity of [BAD_BLOCKS_CHANNEL_CAPACITY], for
+/// the producers and the [BadBlocksRecorder].
+pub fn bad_blocks_channel() -> (BadBlocksSender, Receiver<StoredBadBlock>) {
+    mpsc::channel(BAD_BLOCKS_CHANNEL_CAPACITY)
+}
+
+/// Routine that listens to bad blocks on the `blocks_rx` receiver and writes them to the database.
+///
+/// The writes block until other write transactions finish, e.g. the ones of the pipeline, so this
+/// should be spawned as its own blocking task.
+#[derive(Debug)]
+pub struct BadBlocksRecorder<DB> {
+    factory: ProviderFactory<DB>,
+    blocks_rx: Receiver<StoredBadBlock>,
+}
+
+impl<DB> BadBlocksRecorder<DB> {
+    /// Creates a new [BadBlocksRecorder] with the provided receiver of bad blocks.
+    pub fn new(factory: ProviderFactory<DB>, blocks_rx: Receiver<StoredBadBlock>) -> Self {
+        Self { factory, blocks_rx }
+    }
+}
+
+impl<DB: Database> BadBlocksRecorder<DB> {
+    fn insert_bad_block(&self, bad_block: StoredBadBlock) -> RethResult<()> {
+        let provider = self.factory.provider_rw()?;
+        provider.insert_bad_block(bad_block.block, bad_block.error)?;
+        provider.commit()?;
+        Ok(())
+    }
+}
+
+impl<DB: Database + Unpin> Future for BadBlocksRecorder<DB> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // Loop until we drain the `blocks_rx` channel
+        loop {
+            let Some(bad_block) = ready!(this.blocks_rx.poll_recv(cx)) else {
+                // Channel has closed
+                return Poll::Ready(())
+            };
+
+            let hash = bad_block.block.hash;
+            trace!(target: "providers::bad_blocks", ?hash, "Recording bad block");
+            if let Err(error) = this.insert_bad_block(bad_block) {
+                warn!(target: "providers::bad_blocks", ?error, ?hash, "Failed to record bad block");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BadBlocksReader;
+    use reth_db::test_utils::create_test_rw_db;
+    use reth_interfaces::test_utils::generators::{self, random_block};
+    use reth_primitives::MAINNET;
+
+    #[tokio::test]
+    async fn records_bad_blocks() {
+        let factory = ProviderFactory::new(create_test_rw_db(), MAINNET.clone());
+        let (blocks_tx, blocks_rx) = bad_blocks_channel();
+        let recorder = BadBlocksRecorder::new(factory.clone(), blocks_rx);
+
+        let block = random_block(&mut generators::rng(), 1, None, Some(1), None);
+        let bad_block = StoredBadBlock { block: block.clone(), error: "invalid block".to_string() };
+        blocks_tx.try_send(bad_block.clone()).unwrap();
+        drop(blocks_tx);
+        recorder.await;
+
+        assert_eq!(factory.provider().unwrap().bad_block(block.hash).unwrap(), Some(bad_block));
+    }
+}
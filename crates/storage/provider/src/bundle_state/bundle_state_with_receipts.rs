@@ -5,6 +5,8 @@ use reth_db::{
     transaction::{DbTx, DbTxMut},
 };
 use reth_interfaces::db::DatabaseError;
+#[cfg(feature = "optimism")]
+use reth_primitives::ChainSpec;
 use reth_primitives::{
     keccak256, logs_bloom,
     revm::compat::{into_reth_acc, into_revm_acc},
@@ -235,6 +237,23 @@ impl BundleStateWithReceipts {
         self.receipts.root_slow(self.block_number_to_index(block_number)?)
     }
 
+    /// Returns the receipt root of an OP Stack block for all recorded receipts.
+    ///
+    /// See [Receipts::optimism_root_slow] for the differences to [Self::receipts_root_slow].
+    #[cfg(feature = "optimism")]
+    pub fn optimism_receipts_root_slow(
+        &self,
+        block_number: BlockNumber,
+        chain_spec: &ChainSpec,
+        timestamp: u64,
+    ) -> Option<B256> {
+        self.receipts.optimism_root_slow(
+            self.block_number_to_index(block_number)?,
+            chain_spec,
+            timestamp,
+        )
+    }
+
     /// Return reference to receipts.
     pub fn receipts(&self) -> &Receipts {
         &self.receipts
@@ -16,23 +16,26 @@
 /// Various provider traits.
 mod traits;
 pub use traits::{
-    AccountExtReader, AccountReader, BlockExecutionWriter, BlockExecutor, BlockExecutorStats,
+    AccountExtReader, AccountReader, AddressAppearancesReader, AddressAppearancesWriter,
+    BadBlocksReader, BadBlocksWriter, BlockExecutionWriter, BlockExecutor, BlockExecutorStats,
     BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource,
     BlockWriter, BlockchainTreePendingStateProvider, BundleStateDataProvider, CanonChainTracker,
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotifications,
     CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider, ExecutorFactory,
-    HashingWriter, HeaderProvider, HistoryWriter, PrunableBlockExecutor, PruneCheckpointReader,
-    PruneCheckpointWriter, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
-    StageCheckpointWriter, StateProvider, StateProviderBox, StateProviderFactory,
-    StateRootProvider, StorageReader, TransactionVariant, TransactionsProvider,
-    TransactionsProviderExt, WithdrawalsProvider,
+    HashedAccountEntry, HashingWriter, HeaderProvider, HistoryWriter, PrunableBlockExecutor,
+    PruneCheckpointReader, PruneCheckpointWriter, ReceiptProvider, ReceiptProviderIdExt,
+    StageCheckpointReader, StageCheckpointWriter, StateProvider, StateProviderBox,
+    StateProviderFactory, StateRootProvider, StateVisitor, StorageReader, TransactionTracesReader,
+    TransactionTracesWriter, TransactionVariant, TransactionsProvider, TransactionsProviderExt,
+    WithdrawalsProvider, MAX_BAD_BLOCKS,
 };
 
 /// Provider trait implementations.
 pub mod providers;
 pub use providers::{
     DatabaseProvider, DatabaseProviderRO, DatabaseProviderRW, HistoricalStateProvider,
-    HistoricalStateProviderRef, LatestStateProvider, LatestStateProviderRef, ProviderFactory,
+    HistoricalStateProviderRef, LatestStateProvider, LatestStateProviderRef, LowestAvailableBlocks,
+    ProviderFactory,
 };
 
 #[cfg(any(test, feature = "test-utils"))]
@@ -45,5 +48,10 @@ pub use reth_interfaces::provider::ProviderError;
 pub mod chain;
 pub use chain::{Chain, DisplayBlocksChain};
 
+pub mod bad_blocks;
+pub use bad_blocks::{
+    bad_blocks_channel, BadBlocksRecorder, BadBlocksSender, BAD_BLOCKS_CHANNEL_CAPACITY,
+};
+
 pub mod bundle_state;
 pub use bundle_state::{BundleStateWithReceipts, OriginalValuesKnown, StateChanges, StateReverts};
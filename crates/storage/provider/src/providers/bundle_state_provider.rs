@@ -64,6 +64,15 @@ impl<SP: StateProvider, BSDP: BundleStateDataProvider> StateRootProvider
         state.extend(post_state.clone());
         self.state_provider.state_root(&state)
     }
+
+    fn storage_root(&self, address: Address) -> RethResult<B256> {
+        // the storage root can only be computed if the account is not touched by the
+        // bundle state
+        if self.post_state_data_provider.state().state().state().contains_key(&address) {
+            return Err(ProviderError::UnsupportedProvider.into())
+        }
+        self.state_provider.storage_root(address)
+    }
 }
 
 impl<SP: StateProvider, BSDP: BundleStateDataProvider> StateProvider
@@ -1,11 +1,17 @@
 use crate::{
     providers::state::{historical::HistoricalStateProvider, latest::LatestStateProvider},
     traits::{BlockSource, ReceiptProvider},
-    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, EvmEnvProvider,
-    HeaderProvider, ProviderError, PruneCheckpointReader, StageCheckpointReader, StateProviderBox,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AddressAppearancesReader, BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider,
+    EvmEnvProvider, HeaderProvider, ProviderError, PruneCheckpointReader, StageCheckpointReader,
+    StateProviderBox, TransactionTracesReader, TransactionVariant, TransactionsProvider,
+    WithdrawalsProvider,
+};
+use reth_db::{
+    database::Database,
+    init_db,
+    models::{StoredBlockBodyIndices, StoredTransactionTraces},
+    DatabaseEnv,
 };
-use reth_db::{database::Database, init_db, models::StoredBlockBodyIndices, DatabaseEnv};
 use reth_interfaces::{db::LogLevel, RethError, RethResult};
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
@@ -415,20 +421,66 @@ impl<DB: Database> PruneCheckpointReader for ProviderFactory<DB> {
     }
 }
 
+impl<DB: Database> AddressAppearancesReader for ProviderFactory<DB> {
+    fn address_appearances_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        self.provider()?.address_appearances_before(address, before, limit)
+    }
+
+    fn address_appearances_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        self.provider()?.address_appearances_from(address, from, limit)
+    }
+}
+
+impl<DB: Database> TransactionTracesReader for ProviderFactory<DB> {
+    fn transaction_traces(&self, id: TxNumber) -> RethResult<Option<StoredTransactionTraces>> {
+        self.provider()?.transaction_traces(id)
+    }
+
+    fn transaction_traces_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> RethResult<Option<Vec<StoredTransactionTraces>>> {
+        self.provider()?.transaction_traces_by_block(block)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ProviderFactory;
-    use crate::{BlockHashReader, BlockNumReader, BlockWriter, TransactionsProvider};
+    use crate::{
+        test_utils::blocks::BlockChainTestData, BadBlocksReader, BadBlocksWriter, BlockHashReader,
+        BlockNumReader, BlockWriter, ChangeSetReader, PruneCheckpointWriter, StageCheckpointReader,
+        StageCheckpointWriter, TransactionsProvider, MAX_BAD_BLOCKS,
+    };
     use alloy_rlp::Decodable;
     use assert_matches::assert_matches;
     use reth_db::{
+        models::BlockNumberAddress,
         tables,
         test_utils::{create_test_rw_db, ERROR_TEMPDIR},
+        transaction::{DbTx, DbTxMut},
         DatabaseEnv,
     };
-    use reth_interfaces::test_utils::{generators, generators::random_block};
+    use reth_interfaces::{
+        provider::ProviderError,
+        test_utils::{generators, generators::random_block},
+        RethError,
+    };
     use reth_primitives::{
-        hex_literal::hex, ChainSpecBuilder, PruneMode, PruneModes, SealedBlock, TxNumber, B256,
+        hex_literal::hex,
+        stage::{StageCheckpoint, StageId},
+        Address, BlockNumber, ChainSpecBuilder, PruneCheckpoint, PruneMode, PruneModes,
+        PruneSegment, SealedBlock, StorageEntry, TxNumber, B256, MAINNET, U256,
     };
     use std::{ops::RangeInclusive, sync::Arc};
 
@@ -481,6 +533,142 @@ mod tests {
         provider.block_hash(0).unwrap();
     }
 
+    #[test]
+    fn storage_block_changeset() {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db, Arc::new(chain_spec));
+
+        let address = Address::with_last_byte(1);
+        let entry = StorageEntry { key: B256::with_last_byte(2), value: U256::from(3) };
+        {
+            let provider = factory.provider_rw().unwrap();
+            provider
+                .tx_ref()
+                .put::<tables::StorageChangeSet>(BlockNumberAddress((1, address)), entry)
+                .unwrap();
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.storage_block_changeset(0).unwrap(), vec![]);
+        assert_eq!(provider.storage_block_changeset(1).unwrap(), vec![(address, entry)]);
+        assert_eq!(provider.storage_block_changeset(2).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn index_appended_address_appearances() {
+        // appends the first block with the address appearance index enabled, and returns the
+        // checkpoint of the index and its number of entries
+        let append_block = |traces_checkpoint: Option<BlockNumber>| {
+            let factory = ProviderFactory::new(create_test_rw_db(), MAINNET.clone());
+            let data = BlockChainTestData::default();
+            let (block, state) = data.blocks[0].clone();
+
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(data.genesis, None, None).unwrap();
+            provider
+                .save_stage_checkpoint(StageId::IndexAddressAppearances, StageCheckpoint::new(0))
+                .unwrap();
+            if let Some(block_number) = traces_checkpoint {
+                provider
+                    .save_stage_checkpoint(
+                        StageId::TransactionTraces,
+                        StageCheckpoint::new(block_number),
+                    )
+                    .unwrap();
+            }
+            provider.append_blocks_with_bundle_state(vec![block], state, None).unwrap();
+            provider.index_appended_address_appearances(1..=1).unwrap();
+
+            (
+                provider
+                    .get_stage_checkpoint(StageId::IndexAddressAppearances)
+                    .unwrap()
+                    .map(|checkpoint| checkpoint.block_number),
+                provider.tx_ref().entries::<tables::AddressAppearances>().unwrap(),
+            )
+        };
+
+        // the transaction traces are disabled
+        let (checkpoint, entries) = append_block(None);
+        assert_eq!(checkpoint, Some(1));
+        assert!(entries > 0);
+
+        // the transaction traces already cover the block
+        assert_eq!(append_block(Some(1)), (Some(1), entries));
+
+        // the transaction traces of the block haven't been recorded yet, so the internal calls
+        // would be missing from the index
+        assert_eq!(append_block(Some(0)), (Some(0), 0));
+    }
+
+    #[test]
+    fn pruned_block_changesets() {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db, Arc::new(chain_spec));
+
+        {
+            let provider = factory.provider_rw().unwrap();
+            for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+                provider
+                    .save_prune_checkpoint(
+                        segment,
+                        PruneCheckpoint {
+                            block_number: Some(1),
+                            tx_number: None,
+                            prune_mode: PruneMode::Before(2),
+                        },
+                    )
+                    .unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        assert_matches!(
+            provider.account_block_changeset(1),
+            Err(RethError::Provider(ProviderError::StateAtBlockPruned(1)))
+        );
+        assert_matches!(
+            provider.storage_block_changeset(1),
+            Err(RethError::Provider(ProviderError::StateAtBlockPruned(1)))
+        );
+        assert_eq!(provider.account_block_changeset(2).unwrap(), vec![]);
+        assert_eq!(provider.storage_block_changeset(2).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn insert_bad_blocks() {
+        let chain_spec = ChainSpecBuilder::mainnet().build();
+        let db = create_test_rw_db();
+        let factory = ProviderFactory::new(db, Arc::new(chain_spec));
+
+        let mut rng = generators::rng();
+        let blocks = (0..MAX_BAD_BLOCKS as u64 + 2)
+            .map(|number| random_block(&mut rng, number, None, Some(1), None))
+            .collect::<Vec<_>>();
+        {
+            let provider = factory.provider_rw().unwrap();
+            // insert out of order, the lowest blocks should be evicted regardless
+            for block in blocks.iter().rev() {
+                provider.insert_bad_block(block.clone(), "invalid block".to_string()).unwrap();
+            }
+            provider.commit().unwrap();
+        }
+
+        let provider = factory.provider().unwrap();
+        let bad_blocks = provider.bad_blocks().unwrap();
+        assert_eq!(bad_blocks.len(), MAX_BAD_BLOCKS);
+        assert_eq!(
+            bad_blocks.iter().map(|bad_block| bad_block.block.number).collect::<Vec<_>>(),
+            (2..MAX_BAD_BLOCKS as u64 + 2).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(provider.bad_block(blocks[0].hash).unwrap(), None);
+        assert_eq!(provider.bad_block(blocks[2].hash).unwrap().unwrap().block, blocks[2]);
+    }
+
     #[test]
     fn insert_block_with_prune_modes() {
         let chain_spec = ChainSpecBuilder::mainnet().build();
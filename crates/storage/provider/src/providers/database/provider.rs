@@ -4,11 +4,13 @@ use crate::{
     traits::{
         AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
     },
-    AccountReader, BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader, BlockWriter,
-    Chain, EvmEnvProvider, HashingWriter, HeaderProvider, HistoryWriter, OriginalValuesKnown,
-    ProviderError, PruneCheckpointReader, PruneCheckpointWriter, StageCheckpointReader,
-    StorageReader, TransactionVariant, TransactionsProvider, TransactionsProviderExt,
-    WithdrawalsProvider,
+    AccountReader, AddressAppearancesReader, AddressAppearancesWriter, BadBlocksReader,
+    BadBlocksWriter, BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader,
+    BlockWriter, Chain, ChainSpecProvider, EvmEnvProvider, HashingWriter, HeaderProvider,
+    HistoryWriter, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
+    PruneCheckpointWriter, StageCheckpointReader, StorageReader, TransactionTracesReader,
+    TransactionTracesWriter, TransactionVariant, TransactionsProvider, TransactionsProviderExt,
+    WithdrawalsProvider, MAX_BAD_BLOCKS,
 };
 use itertools::{izip, Itertools};
 use reth_db::{
@@ -17,12 +19,13 @@ use reth_db::{
     database::{Database, DatabaseGAT},
     models::{
         sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        ShardedKey, StoredBlockBodyIndices, StoredBlockOmmers, StoredBlockWithdrawals,
+        ShardedKey, StoredBadBlock, StoredBlockBodyIndices, StoredBlockOmmers,
+        StoredBlockWithdrawals, StoredTraceKind, StoredTransactionTraces,
     },
     table::{Table, TableRow},
     tables,
     transaction::{DbTx, DbTxMut},
-    BlockNumberList, DatabaseError,
+    BlockNumberList, DatabaseError, TxNumberList,
 };
 use reth_interfaces::{
     executor::{BlockExecutionError, BlockValidationError},
@@ -38,13 +41,14 @@ use reth_primitives::{
     trie::Nibbles,
     Account, Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders,
     ChainInfo, ChainSpec, Hardfork, Head, Header, PruneCheckpoint, PruneModes, PruneSegment,
-    Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, StorageEntry, TransactionMeta,
-    TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash, TxHash, TxNumber,
-    Withdrawal, B256, U256,
+    Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, StorageEntry, TransactionKind,
+    TransactionMeta, TransactionSigned, TransactionSignedEcRecovered, TransactionSignedNoHash,
+    TxHash, TxNumber, Withdrawal, B256, U256,
 };
 use reth_trie::{prefix_set::PrefixSetMut, StateRoot};
 use revm::primitives::{BlockEnv, CfgEnv, SpecId};
 use std::{
+    cmp::Reverse,
     collections::{hash_map, BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::Debug,
     ops::{Deref, DerefMut, Range, RangeBounds, RangeInclusive},
@@ -187,6 +191,21 @@ impl<TX: DbTx> DatabaseProvider<TX> {
             .walk(Some(T::Key::default()))?
             .collect::<Result<Vec<_>, DatabaseError>>()
     }
+
+    /// Returns [ProviderError::StateAtBlockPruned] if the changesets of the given block were
+    /// pruned by the given history segment.
+    fn ensure_changeset_not_pruned(
+        &self,
+        segment: PruneSegment,
+        block_number: BlockNumber,
+    ) -> RethResult<()> {
+        let pruned_block_number =
+            self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number);
+        if pruned_block_number.map_or(false, |pruned| block_number <= pruned) {
+            return Err(ProviderError::StateAtBlockPruned(block_number).into())
+        }
+        Ok(())
+    }
 }
 
 impl<TX: DbTxMut + DbTx> DatabaseProvider<TX> {
@@ -195,6 +214,34 @@ impl<TX: DbTxMut + DbTx> DatabaseProvider<TX> {
         Ok(self.tx.commit()?)
     }
 
+    /// Updates the address appearance index with the given blocks that were appended outside of
+    /// the pipeline, e.g. by [BlockWriter::append_blocks_with_bundle_state].
+    ///
+    /// The index is optional, so it's only updated if it's enabled and up to date with the parent
+    /// block. It includes the internal calls of the recorded transaction traces, so if those are
+    /// enabled, it's only updated once they cover the blocks. Otherwise the blocks are left to the
+    /// pipeline.
+    pub fn index_appended_address_appearances(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RethResult<()> {
+        let traces_recorded = self
+            .get_stage_checkpoint(StageId::TransactionTraces)?
+            .map_or(true, |checkpoint| checkpoint.block_number >= *range.end());
+        if traces_recorded &&
+            self.get_stage_checkpoint(StageId::IndexAddressAppearances)?
+                .is_some_and(|checkpoint| checkpoint.block_number + 1 == *range.start())
+        {
+            let appearances = self.address_appearances_with_range(range.clone())?;
+            self.insert_address_appearances(appearances)?;
+            self.save_stage_checkpoint(
+                StageId::IndexAddressAppearances,
+                StageCheckpoint::new(*range.end()),
+            )?;
+        }
+        Ok(())
+    }
+
     // TODO(joshie) TEMPORARY should be moved to trait providers
 
     /// Unwind or peek at last N blocks of state recreating the [`BundleStateWithReceipts`].
@@ -850,6 +897,7 @@ impl<TX: DbTx> ChangeSetReader for DatabaseProvider<TX> {
         &self,
         block_number: BlockNumber,
     ) -> RethResult<Vec<AccountBeforeTx>> {
+        self.ensure_changeset_not_pruned(PruneSegment::AccountHistory, block_number)?;
         let range = block_number..=block_number;
         self.tx
             .cursor_read::<tables::AccountChangeSet>()?
@@ -860,6 +908,22 @@ impl<TX: DbTx> ChangeSetReader for DatabaseProvider<TX> {
             })
             .collect()
     }
+
+    fn storage_block_changeset(
+        &self,
+        block_number: BlockNumber,
+    ) -> RethResult<Vec<(Address, StorageEntry)>> {
+        self.ensure_changeset_not_pruned(PruneSegment::StorageHistory, block_number)?;
+        let range = block_number..=block_number;
+        self.tx
+            .cursor_read::<tables::StorageChangeSet>()?
+            .walk_range(BlockNumberAddress::range(range))?
+            .map(|result| -> RethResult<_> {
+                let (index, storage_entry) = result?;
+                Ok((index.address(), storage_entry))
+            })
+            .collect()
+    }
 }
 
 impl<TX: DbTx> HeaderProvider for DatabaseProvider<TX> {
@@ -1484,6 +1548,12 @@ impl<TX: DbTx> EvmEnvProvider for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: Send + Sync> ChainSpecProvider for DatabaseProvider<TX> {
+    fn chain_spec(&self) -> Arc<ChainSpec> {
+        self.chain_spec.clone()
+    }
+}
+
 impl<TX: DbTx> StageCheckpointReader for DatabaseProvider<TX> {
     fn get_stage_checkpoint(&self, id: StageId) -> RethResult<Option<StageCheckpoint>> {
         Ok(self.tx.get::<tables::SyncStage>(id.to_string())?)
@@ -1966,6 +2036,269 @@ impl<TX: DbTxMut + DbTx> HistoryWriter for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: DbTx> AddressAppearancesReader for DatabaseProvider<TX> {
+    fn address_appearances_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        let mut appearances = Vec::new();
+        if before == 0 || limit == 0 {
+            return Ok(appearances)
+        }
+
+        // The last shard of an address is keyed with `u64::MAX`, so if the address has any shards,
+        // this finds the shard that contains the highest transaction lower than `before`.
+        let mut cursor = self.tx.cursor_read::<tables::AddressAppearances>()?;
+        let mut item = cursor.seek(ShardedKey::new(address, before - 1))?;
+        while let Some((sharded_key, list)) = item {
+            if sharded_key.key != address {
+                break
+            }
+
+            let shard = list.iter(0).map(|tx| tx as TxNumber).collect::<Vec<_>>();
+            for tx_number in shard.into_iter().rev().filter(|tx_number| *tx_number < before) {
+                appearances.push(tx_number);
+                if appearances.len() == limit {
+                    return Ok(appearances)
+                }
+            }
+
+            item = cursor.prev()?;
+        }
+
+        Ok(appearances)
+    }
+
+    fn address_appearances_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        let mut appearances = Vec::new();
+        if limit == 0 {
+            return Ok(appearances)
+        }
+
+        let mut cursor = self.tx.cursor_read::<tables::AddressAppearances>()?;
+        let mut item = cursor.seek(ShardedKey::new(address, from))?;
+        while let Some((sharded_key, list)) = item {
+            if sharded_key.key != address {
+                break
+            }
+
+            for tx_number in list.iter(0).map(|tx| tx as TxNumber).skip_while(|tx| *tx < from) {
+                appearances.push(tx_number);
+                if appearances.len() == limit {
+                    return Ok(appearances)
+                }
+            }
+
+            item = cursor.next()?;
+        }
+
+        Ok(appearances)
+    }
+}
+
+impl<TX: DbTxMut + DbTx> AddressAppearancesWriter for DatabaseProvider<TX> {
+    fn address_appearances_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RethResult<BTreeMap<Address, Vec<TxNumber>>> {
+        let from_tx_number = self
+            .block_body_indices(*range.start())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(*range.start()))?
+            .first_tx_num();
+        let to_tx_number = self
+            .block_body_indices(*range.end())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(*range.end()))?
+            .next_tx_num();
+
+        let mut appearances: BTreeMap<Address, Vec<TxNumber>> = BTreeMap::new();
+        let mut append = |address: Address, tx_number: TxNumber| {
+            let tx_numbers = appearances.entry(address).or_default();
+            // an address can appear multiple times in the same transaction
+            if tx_numbers.last() != Some(&tx_number) {
+                tx_numbers.push(tx_number);
+            }
+        };
+
+        let mut senders = self.tx.cursor_read::<tables::TxSenders>()?;
+        let mut receipts = self.tx.cursor_read::<tables::Receipts>()?;
+        let mut traces = self.tx.cursor_read::<tables::TransactionTraces>()?;
+        let mut transactions = self.tx.cursor_read::<tables::Transactions>()?;
+        for entry in transactions.walk_range(from_tx_number..to_tx_number)? {
+            let (tx_number, transaction) = entry?;
+
+            // Senders might have been pruned, recover them if so.
+            let sender = match senders.seek_exact(tx_number)? {
+                Some((_, sender)) => sender,
+                None => transaction.recover_signer().ok_or(BlockExecutionError::Validation(
+                    BlockValidationError::SenderRecoveryError,
+                ))?,
+            };
+            append(sender, tx_number);
+
+            match transaction.transaction.kind() {
+                TransactionKind::Call(to) => append(*to, tx_number),
+                TransactionKind::Create => {
+                    append(sender.create(transaction.transaction.nonce()), tx_number)
+                }
+            }
+
+            if let Some((_, receipt)) = receipts.seek_exact(tx_number)? {
+                for log in receipt.logs {
+                    append(log.address, tx_number);
+                }
+            }
+
+            if let Some((_, traces)) = traces.seek_exact(tx_number)? {
+                for trace in traces.traces {
+                    append(trace.from, tx_number);
+                    // failed creations have no created contract and rewards have no recipient
+                    let has_target = match trace.kind {
+                        StoredTraceKind::Create => trace.has_result,
+                        StoredTraceKind::BlockReward | StoredTraceKind::UncleReward => false,
+                        _ => true,
+                    };
+                    if has_target {
+                        append(trace.to, tx_number);
+                    }
+                }
+            }
+        }
+
+        Ok(appearances)
+    }
+
+    fn insert_address_appearances(
+        &self,
+        appearances: BTreeMap<Address, Vec<TxNumber>>,
+    ) -> RethResult<()> {
+        self.append_history_index::<_, tables::AddressAppearances>(appearances, ShardedKey::new)
+    }
+
+    fn unwind_address_appearances(&self, range: RangeInclusive<BlockNumber>) -> RethResult<usize> {
+        let first_tx_number = self
+            .block_body_indices(*range.start())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(*range.start()))?
+            .first_tx_num();
+        let appearances = self.address_appearances_with_range(range)?;
+
+        let mut cursor = self.tx.cursor_write::<tables::AddressAppearances>()?;
+        for address in appearances.keys().copied() {
+            let partial_shard = unwind_history_shards::<_, tables::AddressAppearances, _>(
+                &mut cursor,
+                ShardedKey::last(address),
+                first_tx_number,
+                |sharded_key| sharded_key.key == address,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(address),
+                    TxNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        Ok(appearances.len())
+    }
+}
+
+impl<TX: DbTx> BadBlocksReader for DatabaseProvider<TX> {
+    fn bad_blocks(&self) -> RethResult<Vec<StoredBadBlock>> {
+        let mut bad_blocks = self
+            .tx
+            .cursor_read::<tables::BadBlocks>()?
+            .walk(None)?
+            .map(|entry| entry.map(|(_, bad_block)| bad_block))
+            .collect::<Result<Vec<_>, _>>()?;
+        bad_blocks.sort_by_key(|bad_block| Reverse(bad_block.block.number));
+        Ok(bad_blocks)
+    }
+
+    fn bad_block(&self, hash: BlockHash) -> RethResult<Option<StoredBadBlock>> {
+        Ok(self.tx.get::<tables::BadBlocks>(hash)?)
+    }
+}
+
+impl<TX: DbTxMut + DbTx> BadBlocksWriter for DatabaseProvider<TX> {
+    fn insert_bad_block(&self, block: SealedBlock, error: String) -> RethResult<()> {
+        self.tx.put::<tables::BadBlocks>(block.hash, StoredBadBlock { block, error })?;
+
+        // evict the bad blocks with the lowest numbers
+        for bad_block in self.bad_blocks()?.into_iter().skip(MAX_BAD_BLOCKS) {
+            self.tx.delete::<tables::BadBlocks>(bad_block.block.hash, None)?;
+        }
+        Ok(())
+    }
+}
+
+impl<TX: DbTx> TransactionTracesReader for DatabaseProvider<TX> {
+    fn transaction_traces(&self, id: TxNumber) -> RethResult<Option<StoredTransactionTraces>> {
+        Ok(self.tx.get::<tables::TransactionTraces>(id)?)
+    }
+
+    fn transaction_traces_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> RethResult<Option<Vec<StoredTransactionTraces>>> {
+        let Some(block_number) = self.convert_hash_or_number(block)? else { return Ok(None) };
+        let Some(body) = self.block_body_indices(block_number)? else { return Ok(None) };
+
+        let traces = self
+            .tx
+            .cursor_read::<tables::TransactionTraces>()?
+            .walk_range(body.tx_num_range())?
+            .map(|entry| entry.map(|(_, traces)| traces))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The traces are only usable if all transactions of the block have been traced.
+        Ok((traces.len() as u64 == body.tx_count).then_some(traces))
+    }
+}
+
+impl<TX: DbTxMut + DbTx> TransactionTracesWriter for DatabaseProvider<TX> {
+    fn insert_transaction_traces(
+        &self,
+        traces: Vec<(TxNumber, StoredTransactionTraces)>,
+    ) -> RethResult<()> {
+        let mut cursor = self.tx.cursor_write::<tables::TransactionTraces>()?;
+        for (tx_number, traces) in traces {
+            cursor.upsert(tx_number, traces)?;
+        }
+        Ok(())
+    }
+
+    fn unwind_transaction_traces(&self, range: RangeInclusive<BlockNumber>) -> RethResult<usize> {
+        let from_tx_number = self
+            .block_body_indices(*range.start())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(*range.start()))?
+            .first_tx_num();
+        let to_tx_number = self
+            .block_body_indices(*range.end())?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(*range.end()))?
+            .next_tx_num();
+
+        let mut cursor = self.tx.cursor_write::<tables::TransactionTraces>()?;
+        let mut walker = cursor.walk_range(from_tx_number..to_tx_number)?;
+        let mut deleted = 0;
+        while let Some(entry) = walker.next() {
+            entry?;
+            walker.delete_current()?;
+            deleted += 1;
+        }
+
+        Ok(deleted)
+    }
+}
+
 impl<TX: DbTxMut + DbTx> BlockExecutionWriter for DatabaseProvider<TX> {
     /// Return range of blocks and its execution result
     fn get_or_take_block_and_execution_range<const TAKE: bool>(
@@ -2009,6 +2342,28 @@ impl<TX: DbTxMut + DbTx> BlockExecutionWriter for DatabaseProvider<TX> {
             // Unwind storage history indices.
             self.unwind_storage_history_indices(storage_range)?;
 
+            // Unwind the address appearance index, if it's enabled and covers the range.
+            if let Some(checkpoint) = self.get_stage_checkpoint(StageId::IndexAddressAppearances)? {
+                if checkpoint.block_number >= *range.start() {
+                    self.unwind_address_appearances(*range.start()..=checkpoint.block_number)?;
+                    self.save_stage_checkpoint(
+                        StageId::IndexAddressAppearances,
+                        StageCheckpoint::new(range.start().saturating_sub(1)),
+                    )?;
+                }
+            }
+
+            // Unwind the transaction traces, if they're enabled and cover the range.
+            if let Some(checkpoint) = self.get_stage_checkpoint(StageId::TransactionTraces)? {
+                if checkpoint.block_number >= *range.start() {
+                    self.unwind_transaction_traces(*range.start()..=checkpoint.block_number)?;
+                    self.save_stage_checkpoint(
+                        StageId::TransactionTraces,
+                        StageCheckpoint::new(range.start().saturating_sub(1)),
+                    )?;
+                }
+            }
+
             // Calculate the reverted merkle root.
             // This is the same as `StateRoot::incremental_root_with_updates`, only the prefix sets
             // are pre-loaded.
@@ -1,12 +1,16 @@
 use crate::{
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockchainTreePendingStateProvider, BundleStateDataProvider, CanonChainTracker,
-    CanonStateNotifications, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    EvmEnvProvider, HeaderProvider, ProviderError, PruneCheckpointReader, ReceiptProvider,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProviderBox, StateProviderFactory,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountReader, AddressAppearancesReader, BadBlocksReader, BlockHashReader, BlockIdReader,
+    BlockNumReader, BlockReader, BlockReaderIdExt, BlockchainTreePendingStateProvider,
+    BundleStateDataProvider, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, ProviderError,
+    PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
+    StateProviderBox, StateProviderFactory, TransactionTracesReader, TransactionVariant,
+    TransactionsProvider, WithdrawalsProvider,
+};
+use reth_db::{
+    database::Database,
+    models::{StoredBadBlock, StoredBlockBodyIndices, StoredTransactionTraces},
 };
-use reth_db::{database::Database, models::StoredBlockBodyIndices};
 use reth_interfaces::{
     blockchain_tree::{BlockchainTreeEngine, BlockchainTreeViewer},
     consensus::ForkchoiceState,
@@ -16,8 +20,9 @@ use reth_primitives::{
     stage::{StageCheckpoint, StageId},
     Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumHash, BlockNumber,
     BlockNumberOrTag, BlockWithSenders, ChainInfo, ChainSpec, Header, PruneCheckpoint,
-    PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, TransactionMeta,
-    TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, B256, U256,
+    PruneSegment, Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, StorageEntry,
+    TransactionMeta, TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal,
+    B256, U256,
 };
 use revm::primitives::{BlockEnv, CfgEnv};
 use std::{
@@ -29,7 +34,7 @@ use std::{
 use tracing::trace;
 
 pub use state::{
-    historical::{HistoricalStateProvider, HistoricalStateProviderRef},
+    historical::{HistoricalStateProvider, HistoricalStateProviderRef, LowestAvailableBlocks},
     latest::{LatestStateProvider, LatestStateProviderRef},
 };
 
@@ -475,6 +480,61 @@ where
     }
 }
 
+impl<DB, Tree> AddressAppearancesReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn address_appearances_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        self.database.provider()?.address_appearances_before(address, before, limit)
+    }
+
+    fn address_appearances_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        self.database.provider()?.address_appearances_from(address, from, limit)
+    }
+}
+
+impl<DB, Tree> BadBlocksReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn bad_blocks(&self) -> RethResult<Vec<StoredBadBlock>> {
+        self.database.provider()?.bad_blocks()
+    }
+
+    fn bad_block(&self, hash: BlockHash) -> RethResult<Option<StoredBadBlock>> {
+        self.database.provider()?.bad_block(hash)
+    }
+}
+
+impl<DB, Tree> TransactionTracesReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    fn transaction_traces(&self, id: TxNumber) -> RethResult<Option<StoredTransactionTraces>> {
+        self.database.provider()?.transaction_traces(id)
+    }
+
+    fn transaction_traces_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> RethResult<Option<Vec<StoredTransactionTraces>>> {
+        self.database.provider()?.transaction_traces_by_block(block)
+    }
+}
+
 impl<DB, Tree> ChainSpecProvider for BlockchainProvider<DB, Tree>
 where
     DB: Send + Sync,
@@ -817,6 +877,13 @@ where
     ) -> RethResult<Vec<AccountBeforeTx>> {
         self.database.provider()?.account_block_changeset(block_number)
     }
+
+    fn storage_block_changeset(
+        &self,
+        block_number: BlockNumber,
+    ) -> RethResult<Vec<(Address, StorageEntry)>> {
+        self.database.provider()?.storage_block_changeset(block_number)
+    }
 }
 
 impl<DB, Tree> AccountReader for BlockchainProvider<DB, Tree>
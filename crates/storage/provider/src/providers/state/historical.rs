@@ -1,19 +1,32 @@
 use crate::{
-    providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
-    BundleStateWithReceipts, ProviderError, StateProvider, StateRootProvider,
+    providers::state::{
+        hashed_account_range, hashed_storage_range_with_state, incremental_state_roots,
+        macros::delegate_provider_impls, visit_hashed_accounts,
+    },
+    AccountReader, BlockHashReader, BundleStateWithReceipts, HashedAccountEntry, ProviderError,
+    StateProvider, StateRootProvider, StateVisitor,
 };
 use reth_db::{
     cursor::{DbCursorRO, DbDupCursorRO},
-    models::{storage_sharded_key::StorageShardedKey, ShardedKey},
+    models::{
+        storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress, ShardedKey,
+    },
     table::Table,
     tables,
     transaction::DbTx,
     BlockNumberList,
 };
-use reth_interfaces::RethResult;
+use reth_interfaces::{RethError, RethResult};
 use reth_primitives::{
-    trie::AccountProof, Account, Address, BlockNumber, Bytecode, StorageKey, StorageValue, B256,
+    keccak256, trie::AccountProof, Account, Address, BlockNumber, Bytecode, StorageEntry,
+    StorageKey, StorageValue, B256, U256,
 };
+use reth_trie::{
+    hashed_cursor::{HashedPostState, HashedPostStateCursorFactory, HashedStorage},
+    proof::Proof,
+    StateRoot, StateRootError, StorageRoot,
+};
+use std::collections::HashMap;
 
 /// State provider for a given block number which takes a tx reference.
 ///
@@ -94,6 +107,87 @@ impl<'b, TX: DbTx> HistoricalStateProviderRef<'b, TX> {
         )
     }
 
+    /// Returns the hashed state that reverts the storage slots of the account that changed at and
+    /// after our block.
+    fn revert_storage(&self, address: Address) -> RethResult<HashedPostState> {
+        if !self.lowest_available_blocks.is_storage_history_available(self.block_number) {
+            return Err(ProviderError::StateAtBlockPruned(self.block_number).into())
+        }
+
+        // The first changeset entry at or after our block holds the value at our block.
+        let mut slots = HashMap::new();
+        let mut storage_changesets = self.tx.cursor_dup_read::<tables::StorageChangeSet>()?;
+        let start = BlockNumberAddress((self.block_number, address));
+        for entry in storage_changesets.walk_range(start..)? {
+            let (key, StorageEntry { key: slot, value }) = entry?;
+            if key.address() == address {
+                slots.entry(slot).or_insert(value);
+            }
+        }
+
+        let mut hashed_storage = HashedStorage::new(false);
+        for (slot, value) in slots {
+            let hashed_slot = keccak256(slot);
+            if value == U256::ZERO {
+                hashed_storage.insert_zero_valued_slot(hashed_slot);
+            } else {
+                hashed_storage.insert_non_zero_valued_storage(hashed_slot, value);
+            }
+        }
+
+        let mut hashed_state = HashedPostState::default();
+        hashed_state.insert_hashed_storage(keccak256(address), hashed_storage);
+        Ok(hashed_state)
+    }
+
+    /// Returns the hashed state that reverts all changes made at and after our block, i.e. the
+    /// accounts and storage slots at our block that differ from the latest state.
+    fn revert_state(&self) -> RethResult<HashedPostState> {
+        if !self.lowest_available_blocks.is_account_history_available(self.block_number) ||
+            !self.lowest_available_blocks.is_storage_history_available(self.block_number)
+        {
+            return Err(ProviderError::StateAtBlockPruned(self.block_number).into())
+        }
+
+        // The first changeset entry at or after our block holds the value at our block.
+        let mut accounts = HashMap::new();
+        let mut account_changesets = self.tx.cursor_dup_read::<tables::AccountChangeSet>()?;
+        for entry in account_changesets.walk_range(self.block_number..)? {
+            let (_, AccountBeforeTx { address, info }) = entry?;
+            accounts.entry(address).or_insert(info);
+        }
+
+        let mut storages = HashMap::<Address, HashMap<StorageKey, StorageValue>>::new();
+        let mut storage_changesets = self.tx.cursor_dup_read::<tables::StorageChangeSet>()?;
+        let start = BlockNumberAddress((self.block_number, Address::ZERO));
+        for entry in storage_changesets.walk_range(start..)? {
+            let (key, StorageEntry { key: slot, value }) = entry?;
+            storages.entry(key.address()).or_default().entry(slot).or_insert(value);
+        }
+
+        let mut hashed_state = HashedPostState::default();
+        for (address, info) in accounts {
+            match info {
+                Some(account) => hashed_state.insert_account(keccak256(address), account),
+                None => hashed_state.insert_cleared_account(keccak256(address)),
+            }
+        }
+        for (address, slots) in storages {
+            // Slots that were wiped after our block have changeset entries as well, so the
+            // storage is never wiped.
+            let mut hashed_storage = HashedStorage::new(false);
+            for (slot, value) in slots {
+                if value == U256::ZERO {
+                    hashed_storage.insert_zero_valued_slot(keccak256(slot));
+                } else {
+                    hashed_storage.insert_non_zero_valued_storage(keccak256(slot), value);
+                }
+            }
+            hashed_state.insert_hashed_storage(keccak256(address), hashed_storage);
+        }
+        Ok(hashed_state)
+    }
+
     fn history_info<T, K>(
         &self,
         key: K,
@@ -198,8 +292,39 @@ impl<'b, TX: DbTx> BlockHashReader for HistoricalStateProviderRef<'b, TX> {
 }
 
 impl<'b, TX: DbTx> StateRootProvider for HistoricalStateProviderRef<'b, TX> {
-    fn state_root(&self, _post_state: &BundleStateWithReceipts) -> RethResult<B256> {
-        Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
+    /// Computes the state root by reverting the changes made after the block and applying the
+    /// post state on top of the latest hashed state.
+    fn state_root(&self, post_state: &BundleStateWithReceipts) -> RethResult<B256> {
+        let mut hashed_state = self.revert_state()?;
+        hashed_state.extend(post_state.hash_state_slow());
+        let hashed_state = hashed_state.sorted();
+        let (account_prefix_set, storage_prefix_sets) = hashed_state.construct_prefix_sets();
+
+        StateRoot::new(self.tx)
+            .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(self.tx, &hashed_state))
+            .with_changed_account_prefixes(account_prefix_set)
+            .with_changed_storage_prefixes(storage_prefix_sets)
+            .root()
+            .map_err(|err| RethError::Database(err.into()))
+    }
+
+    /// Computes the state roots on top of the reverted state, which is only computed once.
+    fn state_roots(&self, changes: Vec<BundleStateWithReceipts>) -> RethResult<Vec<B256>> {
+        incremental_state_roots(self.tx, self.revert_state()?, changes)
+    }
+
+    /// Computes the storage root by reverting the storage slots of the account that changed after
+    /// the block on top of the latest hashed storage.
+    fn storage_root(&self, address: Address) -> RethResult<B256> {
+        let hashed_address = keccak256(address);
+        let hashed_state = self.revert_storage(address)?.sorted();
+        let (_, mut storage_prefix_sets) = hashed_state.construct_prefix_sets();
+
+        StorageRoot::new_hashed(self.tx, hashed_address)
+            .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(self.tx, &hashed_state))
+            .with_changed_prefixes(storage_prefix_sets.remove(&hashed_address).unwrap_or_default())
+            .root()
+            .map_err(|err| RethError::Database(StateRootError::from(err).into()))
     }
 }
 
@@ -239,9 +364,55 @@ impl<'b, TX: DbTx> StateProvider for HistoricalStateProviderRef<'b, TX> {
         self.tx.get::<tables::Bytecodes>(code_hash).map_err(Into::into)
     }
 
-    /// Get account and storage proofs.
-    fn proof(&self, _address: Address, _keys: &[B256]) -> RethResult<AccountProof> {
-        Err(ProviderError::StateRootNotAvailableForHistoricalBlock.into())
+    /// Get account and storage proofs by reverting the changes made after the block on top of the
+    /// latest hashed state.
+    ///
+    /// All changesets from the block up to the tip are read, so the cost grows with the distance
+    /// to the tip.
+    fn proof(&self, address: Address, keys: &[B256]) -> RethResult<AccountProof> {
+        let hashed_state = self.revert_state()?.sorted();
+        let (account_prefix_set, storage_prefix_sets) = hashed_state.construct_prefix_sets();
+
+        Proof::new(self.tx)
+            .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(self.tx, &hashed_state))
+            .with_changed_account_prefixes(account_prefix_set)
+            .with_changed_storage_prefixes(storage_prefix_sets)
+            .account_proof(address, keys)
+            .map_err(|err| RethError::Database(err.into()))
+    }
+
+    /// Iterates over the accounts by reverting the changes made after the block on top of the
+    /// latest hashed state, so the cost grows with the distance to the tip.
+    fn account_range(
+        &self,
+        start: B256,
+        limit: usize,
+        storage_limit: usize,
+    ) -> RethResult<Vec<HashedAccountEntry>> {
+        hashed_account_range(self.tx, self.revert_state()?, start, limit, storage_limit)
+    }
+
+    /// Visits the accounts on top of the latest hashed state, after reverting the changes made
+    /// after the block once.
+    fn visit_accounts(&self, visitor: &mut dyn StateVisitor, with_storage: bool) -> RethResult<()> {
+        visit_hashed_accounts(self.tx, self.revert_state()?, visitor, with_storage)
+    }
+
+    /// Iterates over the storage slots by reverting the slots of the account that changed after
+    /// the block on top of the latest hashed storage.
+    fn storage_range(
+        &self,
+        address: Address,
+        start: B256,
+        limit: usize,
+    ) -> RethResult<Vec<StorageEntry>> {
+        hashed_storage_range_with_state(
+            self.tx,
+            self.revert_storage(address)?,
+            keccak256(address),
+            start,
+            limit,
+        )
     }
 }
 
@@ -327,7 +498,9 @@ impl LowestAvailableBlocks {
 mod tests {
     use crate::{
         providers::state::historical::{HistoryInfo, LowestAvailableBlocks},
-        AccountReader, HistoricalStateProvider, HistoricalStateProviderRef, StateProvider,
+        AccountReader, BundleStateWithReceipts, HashedAccountEntry, HistoricalStateProvider,
+        HistoricalStateProviderRef, LatestStateProviderRef, StateProvider, StateRootProvider,
+        StateVisitor,
     };
     use reth_db::{
         database::Database,
@@ -337,12 +510,18 @@ mod tests {
         transaction::{DbTx, DbTxMut},
         BlockNumberList,
     };
-    use reth_interfaces::provider::ProviderError;
-    use reth_primitives::{address, b256, Account, Address, StorageEntry, B256, U256};
+    use reth_interfaces::{provider::ProviderError, RethResult};
+    use reth_primitives::{
+        address, b256, keccak256, Account, Address, Receipts, StorageEntry, B256, U256,
+    };
+    use reth_trie::test_utils::{state_root, storage_root, storage_root_prehashed};
+    use std::collections::HashMap;
 
     const ADDRESS: Address = address!("0000000000000000000000000000000000000001");
     const HIGHER_ADDRESS: Address = address!("0000000000000000000000000000000000000005");
     const STORAGE: B256 = b256!("0000000000000000000000000000000000000000000000000000000000000001");
+    const OTHER_STORAGE: B256 =
+        b256!("0000000000000000000000000000000000000000000000000000000000000002");
 
     fn assert_state_provider<T: StateProvider>() {}
     #[allow(unused)]
@@ -549,6 +728,461 @@ mod tests {
         );
     }
 
+    #[test]
+    fn history_provider_storage_root() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+
+        // `OTHER_STORAGE` was never changed, `STORAGE` was created at block 3 and changed at 7
+        let other_entry = StorageEntry { key: OTHER_STORAGE, value: U256::from(5) };
+        let entry_plain = StorageEntry { key: STORAGE, value: U256::from(100) };
+        let entry_at7 = StorageEntry { key: STORAGE, value: U256::from(7) };
+        let entry_at3 = StorageEntry { key: STORAGE, value: U256::ZERO };
+
+        tx.put::<tables::StorageHistory>(
+            StorageShardedKey::new(ADDRESS, STORAGE, u64::MAX),
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSet>((3, ADDRESS).into(), entry_at3).unwrap();
+        tx.put::<tables::StorageChangeSet>((7, ADDRESS).into(), entry_at7).unwrap();
+        for entry in [entry_plain, other_entry] {
+            tx.put::<tables::PlainStorageState>(ADDRESS, entry).unwrap();
+            tx.put::<tables::HashedStorage>(
+                keccak256(ADDRESS),
+                StorageEntry { key: keccak256(entry.key), value: entry.value },
+            )
+            .unwrap();
+        }
+        tx.commit().unwrap();
+
+        let tx = db.tx().unwrap();
+        let latest_root = storage_root(
+            [(STORAGE, entry_plain.value), (OTHER_STORAGE, other_entry.value)].into_iter(),
+        );
+        assert_eq!(LatestStateProviderRef::new(&tx).storage_root(ADDRESS), Ok(latest_root));
+        assert_eq!(HistoricalStateProviderRef::new(&tx, 8).storage_root(ADDRESS), Ok(latest_root));
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).storage_root(ADDRESS),
+            Ok(storage_root(
+                [(STORAGE, entry_at7.value), (OTHER_STORAGE, other_entry.value)].into_iter()
+            ))
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 0).storage_root(ADDRESS),
+            Ok(storage_root([(OTHER_STORAGE, other_entry.value)].into_iter()))
+        );
+    }
+
+    #[test]
+    fn history_provider_state_root() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+
+        // `ADDRESS` is created at block 3 and changed at 7, `HIGHER_ADDRESS` was never changed
+        let acc_plain = Account { nonce: 100, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at7 = Account { nonce: 7, balance: U256::ZERO, bytecode_hash: None };
+        let higher_acc = Account { nonce: 4, balance: U256::ZERO, bytecode_hash: None };
+        tx.put::<tables::AccountChangeSet>(3, AccountBeforeTx { address: ADDRESS, info: None })
+            .unwrap();
+        tx.put::<tables::AccountChangeSet>(
+            7,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at7) },
+        )
+        .unwrap();
+        for (address, account) in [(ADDRESS, acc_plain), (HIGHER_ADDRESS, higher_acc)] {
+            tx.put::<tables::HashedAccount>(keccak256(address), account).unwrap();
+        }
+
+        // `STORAGE` of `ADDRESS` is created at block 3 and changed at 7
+        let entry_plain = StorageEntry { key: STORAGE, value: U256::from(100) };
+        let other_entry = StorageEntry { key: OTHER_STORAGE, value: U256::from(5) };
+        tx.put::<tables::StorageChangeSet>(
+            (3, ADDRESS).into(),
+            StorageEntry { key: STORAGE, value: U256::ZERO },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSet>(
+            (7, ADDRESS).into(),
+            StorageEntry { key: STORAGE, value: U256::from(7) },
+        )
+        .unwrap();
+        for (address, entry) in [(ADDRESS, entry_plain), (HIGHER_ADDRESS, other_entry)] {
+            tx.put::<tables::HashedStorage>(
+                keccak256(address),
+                StorageEntry { key: keccak256(entry.key), value: entry.value },
+            )
+            .unwrap();
+        }
+        tx.commit().unwrap();
+
+        let higher = (HIGHER_ADDRESS, (higher_acc, vec![(OTHER_STORAGE, other_entry.value)]));
+        let tx = db.tx().unwrap();
+        let empty = BundleStateWithReceipts::default();
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 8).state_root(&empty),
+            Ok(state_root(
+                [(ADDRESS, (acc_plain, vec![(STORAGE, entry_plain.value)])), higher.clone()]
+                    .into_iter()
+            ))
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).state_root(&empty),
+            Ok(state_root(
+                [(ADDRESS, (acc_at7, vec![(STORAGE, U256::from(7))])), higher.clone()].into_iter()
+            ))
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 0).state_root(&empty),
+            Ok(state_root([higher.clone()].into_iter()))
+        );
+
+        // the post state is applied on top of the historical state
+        let post_state = BundleStateWithReceipts::new_init(
+            HashMap::from([(
+                ADDRESS,
+                (None, Some(acc_at7), HashMap::from([(STORAGE, (U256::ZERO, U256::from(7)))])),
+            )]),
+            HashMap::new(),
+            vec![],
+            Receipts::new(),
+            0,
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 0).state_root(&post_state),
+            HistoricalStateProviderRef::new(&tx, 4).state_root(&empty)
+        );
+
+        // the roots of consecutive changes are the roots of the accumulated changes
+        let next_change = BundleStateWithReceipts::new_init(
+            HashMap::from([(
+                HIGHER_ADDRESS,
+                (
+                    Some(higher_acc),
+                    Some(acc_plain),
+                    HashMap::from([(OTHER_STORAGE, (other_entry.value, U256::from(1)))]),
+                ),
+            )]),
+            HashMap::new(),
+            vec![],
+            Receipts::new(),
+            0,
+        );
+        let mut accumulated = post_state.clone();
+        accumulated.extend(next_change.clone());
+        let provider = HistoricalStateProviderRef::new(&tx, 0);
+        assert_eq!(
+            provider.state_roots(vec![post_state.clone(), next_change]),
+            Ok(vec![
+                provider.state_root(&post_state).unwrap(),
+                provider.state_root(&accumulated).unwrap()
+            ])
+        );
+    }
+
+    #[test]
+    fn history_provider_proof() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+
+        // `ADDRESS` and its `STORAGE` are created at block 3 and changed at 7, `HIGHER_ADDRESS`
+        // was never changed
+        let acc_plain = Account { nonce: 100, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at7 = Account { nonce: 7, balance: U256::ZERO, bytecode_hash: None };
+        let higher_acc = Account { nonce: 4, balance: U256::ZERO, bytecode_hash: None };
+        tx.put::<tables::AccountChangeSet>(3, AccountBeforeTx { address: ADDRESS, info: None })
+            .unwrap();
+        tx.put::<tables::AccountChangeSet>(
+            7,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at7) },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSet>(
+            (3, ADDRESS).into(),
+            StorageEntry { key: STORAGE, value: U256::ZERO },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSet>(
+            (7, ADDRESS).into(),
+            StorageEntry { key: STORAGE, value: U256::from(7) },
+        )
+        .unwrap();
+        let higher =
+            (HIGHER_ADDRESS, higher_acc, StorageEntry { key: OTHER_STORAGE, value: U256::from(5) });
+        for (address, account, entry) in
+            [(ADDRESS, acc_plain, StorageEntry { key: STORAGE, value: U256::from(100) }), higher]
+        {
+            tx.put::<tables::HashedAccount>(keccak256(address), account).unwrap();
+            tx.put::<tables::HashedStorage>(
+                keccak256(address),
+                StorageEntry { key: keccak256(entry.key), value: entry.value },
+            )
+            .unwrap();
+        }
+        tx.commit().unwrap();
+        let tx = db.tx().unwrap();
+
+        // the proofs at a block match the proofs of a database that only contains its state
+        let at4 =
+            vec![(ADDRESS, acc_at7, StorageEntry { key: STORAGE, value: U256::from(7) }), higher];
+        for (block_number, state) in [(4, at4), (0, vec![higher])] {
+            let expected_db = create_test_rw_db();
+            let expected_tx = expected_db.tx_mut().unwrap();
+            for (address, account, entry) in state {
+                expected_tx.put::<tables::HashedAccount>(keccak256(address), account).unwrap();
+                expected_tx
+                    .put::<tables::HashedStorage>(
+                        keccak256(address),
+                        StorageEntry { key: keccak256(entry.key), value: entry.value },
+                    )
+                    .unwrap();
+            }
+            expected_tx.commit().unwrap();
+            let expected_tx = expected_db.tx().unwrap();
+
+            for address in [ADDRESS, HIGHER_ADDRESS] {
+                assert_eq!(
+                    HistoricalStateProviderRef::new(&tx, block_number)
+                        .proof(address, &[STORAGE, OTHER_STORAGE]),
+                    LatestStateProviderRef::new(&expected_tx)
+                        .proof(address, &[STORAGE, OTHER_STORAGE])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn history_provider_account_and_storage_range() {
+        const DELETED_ADDRESS: Address = address!("0000000000000000000000000000000000000003");
+
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+
+        // `ADDRESS` is created at block 3 and changed at 7, `DELETED_ADDRESS` is created at block 2
+        // and deleted at 5, `HIGHER_ADDRESS` is created at block 4
+        let acc_plain = Account { nonce: 100, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at7 = Account { nonce: 7, balance: U256::ZERO, bytecode_hash: None };
+        let deleted_acc = Account { nonce: 1, balance: U256::ZERO, bytecode_hash: None };
+        let higher_acc_plain = Account { nonce: 4, balance: U256::ZERO, bytecode_hash: None };
+        for (address, blocks) in
+            [(ADDRESS, vec![3, 7]), (DELETED_ADDRESS, vec![2, 5]), (HIGHER_ADDRESS, vec![4])]
+        {
+            tx.put::<tables::AccountHistory>(
+                ShardedKey::last(address),
+                BlockNumberList::new(blocks).unwrap(),
+            )
+            .unwrap();
+        }
+        for (block_number, address, info) in [
+            (2, DELETED_ADDRESS, None),
+            (3, ADDRESS, None),
+            (4, HIGHER_ADDRESS, None),
+            (5, DELETED_ADDRESS, Some(deleted_acc)),
+            (7, ADDRESS, Some(acc_at7)),
+        ] {
+            tx.put::<tables::AccountChangeSet>(block_number, AccountBeforeTx { address, info })
+                .unwrap();
+        }
+        tx.put::<tables::PlainAccountState>(ADDRESS, acc_plain).unwrap();
+        tx.put::<tables::PlainAccountState>(HIGHER_ADDRESS, higher_acc_plain).unwrap();
+
+        // `STORAGE` is set at block 3 and changed at 7, `OTHER_STORAGE` is set at block 3 and
+        // cleared at 5
+        tx.put::<tables::StorageHistory>(
+            StorageShardedKey::new(ADDRESS, STORAGE, u64::MAX),
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StorageHistory>(
+            StorageShardedKey::new(ADDRESS, OTHER_STORAGE, u64::MAX),
+            BlockNumberList::new([3, 5]).unwrap(),
+        )
+        .unwrap();
+        let entry_plain = StorageEntry { key: STORAGE, value: U256::from(100) };
+        let entry_at7 = StorageEntry { key: STORAGE, value: U256::from(7) };
+        let other_entry_at5 = StorageEntry { key: OTHER_STORAGE, value: U256::from(5) };
+        for key in [STORAGE, OTHER_STORAGE] {
+            tx.put::<tables::StorageChangeSet>(
+                (3, ADDRESS).into(),
+                StorageEntry { key, value: U256::ZERO },
+            )
+            .unwrap();
+        }
+        tx.put::<tables::StorageChangeSet>((5, ADDRESS).into(), other_entry_at5).unwrap();
+        tx.put::<tables::StorageChangeSet>((7, ADDRESS).into(), entry_at7).unwrap();
+        tx.put::<tables::PlainStorageState>(ADDRESS, entry_plain).unwrap();
+
+        // the latest hashed state
+        let hashed = |entry: StorageEntry| StorageEntry { key: keccak256(entry.key), ..entry };
+        tx.put::<tables::HashedAccount>(keccak256(ADDRESS), acc_plain).unwrap();
+        tx.put::<tables::HashedAccount>(keccak256(HIGHER_ADDRESS), higher_acc_plain).unwrap();
+        tx.put::<tables::HashedStorage>(keccak256(ADDRESS), hashed(entry_plain)).unwrap();
+        tx.commit().unwrap();
+
+        let tx = db.tx().unwrap();
+
+        // the accounts are ordered by their hashed addresses, as are the storage slots by their
+        // hashed keys
+        let account_entry = |address: Address, account: Account, storage: &[StorageEntry]| {
+            let mut storage = storage.iter().copied().map(hashed).collect::<Vec<_>>();
+            storage.sort_by_key(|entry| entry.key);
+            HashedAccountEntry {
+                hashed_address: keccak256(address),
+                account,
+                storage_root: storage_root_prehashed(
+                    storage.iter().map(|entry| (entry.key, entry.value)),
+                ),
+                storage,
+            }
+        };
+        let sorted = |mut entries: Vec<HashedAccountEntry>| {
+            entries.sort_by_key(|entry| entry.hashed_address);
+            entries
+        };
+        let at4 = sorted(vec![
+            account_entry(ADDRESS, acc_at7, &[entry_at7, other_entry_at5]),
+            account_entry(DELETED_ADDRESS, deleted_acc, &[]),
+        ]);
+        let latest = sorted(vec![
+            account_entry(ADDRESS, acc_plain, &[entry_plain]),
+            account_entry(HIGHER_ADDRESS, higher_acc_plain, &[]),
+        ]);
+
+        // accounts
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 1).account_range(B256::ZERO, 10, 10),
+            Ok(vec![])
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).account_range(B256::ZERO, 10, 10),
+            Ok(at4.clone())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).account_range(B256::ZERO, 1, 10),
+            Ok(at4[..1].to_vec())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).account_range(at4[1].hashed_address, 10, 10),
+            Ok(at4[1..].to_vec())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 10).account_range(B256::ZERO, 10, 10),
+            Ok(latest.clone())
+        );
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).account_range(B256::ZERO, 10, 10),
+            Ok(latest.clone())
+        );
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).account_range(latest[1].hashed_address, 10, 10),
+            Ok(latest[1..].to_vec())
+        );
+
+        // visiting the accounts yields the same accounts and storage as the ranges
+        #[derive(Default)]
+        struct Collector(Vec<HashedAccountEntry>);
+        impl StateVisitor for Collector {
+            fn visit_account(
+                &mut self,
+                hashed_address: B256,
+                account: Account,
+                storage_root: B256,
+            ) -> RethResult<()> {
+                self.0.push(HashedAccountEntry {
+                    hashed_address,
+                    account,
+                    storage_root,
+                    storage: vec![],
+                });
+                Ok(())
+            }
+
+            fn visit_storage(&mut self, entry: StorageEntry) -> RethResult<()> {
+                self.0.last_mut().unwrap().storage.push(entry);
+                Ok(())
+            }
+
+            fn visit_account_end(&mut self) -> RethResult<()> {
+                Ok(())
+            }
+        }
+        let visit = |provider: &dyn StateProvider, with_storage: bool| {
+            let mut collector = Collector::default();
+            provider.visit_accounts(&mut collector, with_storage).map(|_| collector.0)
+        };
+        assert_eq!(visit(&HistoricalStateProviderRef::new(&tx, 4), true), Ok(at4.clone()));
+        assert_eq!(visit(&HistoricalStateProviderRef::new(&tx, 10), true), Ok(latest.clone()));
+        assert_eq!(visit(&LatestStateProviderRef::new(&tx), true), Ok(latest.clone()));
+        assert_eq!(
+            visit(&HistoricalStateProviderRef::new(&tx, 4), false),
+            Ok(at4
+                .iter()
+                .map(|entry| HashedAccountEntry { storage: vec![], ..entry.clone() })
+                .collect())
+        );
+
+        // the storage of the accounts is limited
+        let address_at4 = at4.iter().find(|entry| entry.hashed_address == keccak256(ADDRESS));
+        let address_at4 = address_at4.unwrap().clone();
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).account_range(keccak256(ADDRESS), 1, 1),
+            Ok(vec![HashedAccountEntry {
+                storage: address_at4.storage[..1].to_vec(),
+                ..address_at4.clone()
+            }])
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).account_range(keccak256(ADDRESS), 1, 0),
+            Ok(vec![HashedAccountEntry { storage: vec![], ..address_at4.clone() }])
+        );
+
+        // the storage roots agree with the ones of the providers
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).storage_root(ADDRESS),
+            Ok(address_at4.storage_root)
+        );
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).storage_root(ADDRESS),
+            Ok(storage_root_prehashed([(keccak256(STORAGE), entry_plain.value)].into_iter()))
+        );
+
+        // storage
+        let storage_at4 = address_at4.storage;
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 3).storage_range(ADDRESS, B256::ZERO, 10),
+            Ok(vec![])
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).storage_range(ADDRESS, B256::ZERO, 10),
+            Ok(storage_at4.clone())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).storage_range(ADDRESS, storage_at4[1].key, 10),
+            Ok(storage_at4[1..].to_vec())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 4).storage_range(ADDRESS, B256::ZERO, 1),
+            Ok(storage_at4[..1].to_vec())
+        );
+        assert_eq!(
+            HistoricalStateProviderRef::new(&tx, 8).storage_range(ADDRESS, B256::ZERO, 10),
+            Ok(vec![hashed(entry_plain)])
+        );
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).storage_range(ADDRESS, B256::ZERO, 10),
+            Ok(vec![hashed(entry_plain)])
+        );
+        let after_storage = B256::from(U256::from_be_bytes(keccak256(STORAGE).0) + U256::from(1));
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).storage_range(ADDRESS, after_storage, 10),
+            Ok(vec![])
+        );
+        assert_eq!(
+            LatestStateProviderRef::new(&tx).storage_range(HIGHER_ADDRESS, B256::ZERO, 10),
+            Ok(vec![])
+        );
+    }
+
     #[test]
     fn history_provider_unavailable() {
         let db = create_test_rw_db();
@@ -1,17 +1,22 @@
 use crate::{
-    providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
-    BundleStateWithReceipts, StateProvider, StateRootProvider,
+    providers::state::{
+        hashed_account_range, hashed_storage_range_with_state, incremental_state_roots,
+        macros::delegate_provider_impls, visit_hashed_accounts,
+    },
+    AccountReader, BlockHashReader, BundleStateWithReceipts, HashedAccountEntry, StateProvider,
+    StateRootProvider, StateVisitor,
 };
 use reth_db::{
     cursor::{DbCursorRO, DbDupCursorRO},
     tables,
     transaction::DbTx,
 };
-use reth_interfaces::{provider::ProviderError, RethError, RethResult};
+use reth_interfaces::{RethError, RethResult};
 use reth_primitives::{
-    keccak256, trie::AccountProof, Account, Address, BlockNumber, Bytecode, StorageKey,
-    StorageValue, B256,
+    keccak256, trie::AccountProof, Account, Address, BlockNumber, Bytecode, StorageEntry,
+    StorageKey, StorageValue, B256,
 };
+use reth_trie::{hashed_cursor::HashedPostState, proof::Proof, StateRootError, StorageRoot};
 
 /// State provider over latest state that takes tx reference.
 #[derive(Debug)]
@@ -62,6 +67,16 @@ impl<'b, TX: DbTx> StateRootProvider for LatestStateProviderRef<'b, TX> {
     fn state_root(&self, bundle_state: &BundleStateWithReceipts) -> RethResult<B256> {
         bundle_state.state_root_slow(self.db).map_err(|err| RethError::Database(err.into()))
     }
+
+    fn state_roots(&self, changes: Vec<BundleStateWithReceipts>) -> RethResult<Vec<B256>> {
+        incremental_state_roots(self.db, HashedPostState::default(), changes)
+    }
+
+    fn storage_root(&self, address: Address) -> RethResult<B256> {
+        StorageRoot::new(self.db, address)
+            .root()
+            .map_err(|err| RethError::Database(StateRootError::from(err).into()))
+    }
 }
 
 impl<'b, TX: DbTx> StateProvider for LatestStateProviderRef<'b, TX> {
@@ -85,17 +100,38 @@ impl<'b, TX: DbTx> StateProvider for LatestStateProviderRef<'b, TX> {
         self.db.get::<tables::Bytecodes>(code_hash).map_err(Into::into)
     }
 
-    fn proof(&self, address: Address, _keys: &[B256]) -> RethResult<AccountProof> {
-        let _hashed_address = keccak256(address);
-        let _root = self
-            .db
-            .cursor_read::<tables::Headers>()?
-            .last()?
-            .ok_or_else(|| ProviderError::HeaderNotFound(0.into()))?
-            .1
-            .state_root;
+    fn proof(&self, address: Address, keys: &[B256]) -> RethResult<AccountProof> {
+        Proof::new(self.db)
+            .account_proof(address, keys)
+            .map_err(|err| RethError::Database(err.into()))
+    }
 
-        unimplemented!()
+    fn account_range(
+        &self,
+        start: B256,
+        limit: usize,
+        storage_limit: usize,
+    ) -> RethResult<Vec<HashedAccountEntry>> {
+        hashed_account_range(self.db, HashedPostState::default(), start, limit, storage_limit)
+    }
+
+    fn visit_accounts(&self, visitor: &mut dyn StateVisitor, with_storage: bool) -> RethResult<()> {
+        visit_hashed_accounts(self.db, HashedPostState::default(), visitor, with_storage)
+    }
+
+    fn storage_range(
+        &self,
+        address: Address,
+        start: B256,
+        limit: usize,
+    ) -> RethResult<Vec<StorageEntry>> {
+        hashed_storage_range_with_state(
+            self.db,
+            HashedPostState::default(),
+            keccak256(address),
+            start,
+            limit,
+        )
     }
 }
 
@@ -32,6 +32,8 @@ macro_rules! delegate_provider_impls {
             for $target =>
             StateRootProvider $(where [$($generics)*])? {
                 fn state_root(&self, state: &crate::BundleStateWithReceipts) -> reth_interfaces::RethResult<reth_primitives::B256>;
+                fn state_roots(&self, changes: Vec<crate::BundleStateWithReceipts>) -> reth_interfaces::RethResult<Vec<reth_primitives::B256>>;
+                fn storage_root(&self, address: reth_primitives::Address) -> reth_interfaces::RethResult<reth_primitives::B256>;
             }
             AccountReader $(where [$($generics)*])? {
                 fn basic_account(&self, address: reth_primitives::Address) -> reth_interfaces::RethResult<Option<reth_primitives::Account>>;
@@ -44,6 +46,9 @@ macro_rules! delegate_provider_impls {
                 fn storage(&self, account: reth_primitives::Address, storage_key: reth_primitives::StorageKey) -> reth_interfaces::RethResult<Option<reth_primitives::StorageValue>>;
                 fn proof(&self, address: reth_primitives::Address, keys: &[reth_primitives::B256]) -> reth_interfaces::RethResult<reth_primitives::trie::AccountProof>;
                 fn bytecode_by_hash(&self, code_hash: reth_primitives::B256) -> reth_interfaces::RethResult<Option<reth_primitives::Bytecode>>;
+                fn account_range(&self, start: reth_primitives::B256, limit: usize, storage_limit: usize) -> reth_interfaces::RethResult<Vec<crate::HashedAccountEntry>>;
+                fn visit_accounts(&self, visitor: &mut dyn crate::StateVisitor, with_storage: bool) -> reth_interfaces::RethResult<()>;
+                fn storage_range(&self, address: reth_primitives::Address, start: reth_primitives::B256, limit: usize) -> reth_interfaces::RethResult<Vec<reth_primitives::StorageEntry>>;
             }
         );
     }
@@ -1,4 +1,172 @@
 //! [StateProvider](crate::StateProvider) implementations
+use crate::{BundleStateWithReceipts, HashedAccountEntry, StateVisitor};
+use reth_db::{transaction::DbTx, DatabaseError};
+use reth_interfaces::{RethError, RethResult};
+use reth_primitives::{StorageEntry, B256};
+use reth_trie::{
+    hashed_cursor::{
+        HashedAccountCursor, HashedCursorFactory, HashedPostState, HashedPostStateCursorFactory,
+        HashedStorageCursor,
+    },
+    prefix_set::PrefixSetMut,
+    StateRoot, StateRootError, StorageRoot,
+};
+use std::collections::HashMap;
+
 pub(crate) mod historical;
 pub(crate) mod latest;
 pub(crate) mod macros;
+
+/// Computes the state roots after applying each of the changes, one after the other, on top of the
+/// hashed state and the latest hashed state of the database.
+///
+/// The hashed state and its prefix sets are only extended by each of the changes, instead of being
+/// rebuilt for every root.
+pub(crate) fn incremental_state_roots<TX: DbTx>(
+    tx: &TX,
+    mut hashed_state: HashedPostState,
+    changes: Vec<BundleStateWithReceipts>,
+) -> RethResult<Vec<B256>> {
+    let mut account_prefix_set = PrefixSetMut::default();
+    let mut storage_prefix_sets = HashMap::new();
+    hashed_state.extend_prefix_sets(&mut account_prefix_set, &mut storage_prefix_sets);
+
+    let mut roots = Vec::with_capacity(changes.len());
+    for change in changes {
+        let change = change.hash_state_slow();
+        change.extend_prefix_sets(&mut account_prefix_set, &mut storage_prefix_sets);
+        hashed_state.extend(change);
+        hashed_state.sort();
+
+        let root = StateRoot::new(tx)
+            .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(tx, &hashed_state))
+            .with_changed_account_prefixes(account_prefix_set.clone().freeze())
+            .with_changed_storage_prefixes(
+                storage_prefix_sets
+                    .iter()
+                    .map(|(hashed_address, prefix_set)| {
+                        (*hashed_address, prefix_set.clone().freeze())
+                    })
+                    .collect(),
+            )
+            .root()
+            .map_err(|err| RethError::Database(err.into()))?;
+        roots.push(root);
+    }
+    Ok(roots)
+}
+
+/// Returns up to `limit` accounts with a hashed address greater than or equal to `start`, with up
+/// to `storage_limit` of their storage slots, from the hashed state on top of the latest hashed
+/// state of the database.
+pub(crate) fn hashed_account_range<TX: DbTx>(
+    tx: &TX,
+    hashed_state: HashedPostState,
+    start: B256,
+    limit: usize,
+    storage_limit: usize,
+) -> RethResult<Vec<HashedAccountEntry>> {
+    let hashed_state = hashed_state.sorted();
+    let (_, storage_prefix_sets) = hashed_state.construct_prefix_sets();
+    let factory = HashedPostStateCursorFactory::new(tx, &hashed_state);
+    let mut account_cursor = factory.hashed_account_cursor()?;
+    let mut storage_cursor = factory.hashed_storage_cursor()?;
+
+    let mut accounts = Vec::new();
+    let mut entry = account_cursor.seek(start)?;
+    while let Some((hashed_address, account)) = entry {
+        if accounts.len() == limit {
+            break
+        }
+        let storage_root = StorageRoot::new_hashed(tx, hashed_address)
+            .with_hashed_cursor_factory(factory.clone())
+            .with_changed_prefixes(
+                storage_prefix_sets.get(&hashed_address).cloned().unwrap_or_default(),
+            )
+            .root()
+            .map_err(|err| RethError::Database(StateRootError::from(err).into()))?;
+        let storage =
+            hashed_storage_range(&mut storage_cursor, hashed_address, B256::ZERO, storage_limit)?;
+        accounts.push(HashedAccountEntry { hashed_address, account, storage_root, storage });
+        entry = account_cursor.next()?;
+    }
+    Ok(accounts)
+}
+
+/// Visits all accounts, and their storage slots if `with_storage` is set, of the hashed state on
+/// top of the latest hashed state of the database, through a single pair of cursors.
+pub(crate) fn visit_hashed_accounts<TX: DbTx>(
+    tx: &TX,
+    hashed_state: HashedPostState,
+    visitor: &mut dyn StateVisitor,
+    with_storage: bool,
+) -> RethResult<()> {
+    let hashed_state = hashed_state.sorted();
+    let (_, storage_prefix_sets) = hashed_state.construct_prefix_sets();
+    let factory = HashedPostStateCursorFactory::new(tx, &hashed_state);
+    let mut account_cursor = factory.hashed_account_cursor()?;
+    let mut storage_cursor = factory.hashed_storage_cursor()?;
+
+    let mut entry = account_cursor.seek(B256::ZERO)?;
+    while let Some((hashed_address, account)) = entry {
+        let storage_root = StorageRoot::new_hashed(tx, hashed_address)
+            .with_hashed_cursor_factory(factory.clone())
+            .with_changed_prefixes(
+                storage_prefix_sets.get(&hashed_address).cloned().unwrap_or_default(),
+            )
+            .root()
+            .map_err(|err| RethError::Database(StateRootError::from(err).into()))?;
+        visitor.visit_account(hashed_address, account, storage_root)?;
+
+        if with_storage && !storage_cursor.is_storage_empty(hashed_address)? {
+            let mut storage_entry = storage_cursor.seek(hashed_address, B256::ZERO)?;
+            while let Some(entry) = storage_entry {
+                visitor.visit_storage(entry)?;
+                storage_entry = storage_cursor.next()?;
+            }
+        }
+        visitor.visit_account_end()?;
+
+        entry = account_cursor.next()?;
+    }
+    Ok(())
+}
+
+/// Returns up to `limit` storage slots of the account with a hashed key greater than or equal to
+/// `start`, from the hashed state on top of the latest hashed state of the database.
+pub(crate) fn hashed_storage_range_with_state<TX: DbTx>(
+    tx: &TX,
+    hashed_state: HashedPostState,
+    hashed_address: B256,
+    start: B256,
+    limit: usize,
+) -> RethResult<Vec<StorageEntry>> {
+    let hashed_state = hashed_state.sorted();
+    let mut cursor =
+        HashedPostStateCursorFactory::new(tx, &hashed_state).hashed_storage_cursor()?;
+    Ok(hashed_storage_range(&mut cursor, hashed_address, start, limit)?)
+}
+
+/// Returns up to `limit` storage slots of the account with a hashed key greater than or equal to
+/// `start`.
+fn hashed_storage_range(
+    cursor: &mut impl HashedStorageCursor,
+    hashed_address: B256,
+    start: B256,
+    limit: usize,
+) -> Result<Vec<StorageEntry>, DatabaseError> {
+    let mut entries = Vec::new();
+    if limit == 0 || cursor.is_storage_empty(hashed_address)? {
+        return Ok(entries)
+    }
+
+    let mut entry = cursor.seek(hashed_address, start)?;
+    while let Some(storage_entry) = entry {
+        entries.push(storage_entry);
+        if entries.len() == limit {
+            break
+        }
+        entry = cursor.next()?;
+    }
+    Ok(entries)
+}
@@ -1,19 +1,19 @@
 use crate::{
     bundle_state::BundleStateWithReceipts,
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BundleStateDataProvider, ChainSpecProvider, EvmEnvProvider, HeaderProvider,
-    ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountReader, BadBlocksReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader,
+    BlockReaderIdExt, BundleStateDataProvider, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    HeaderProvider, ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use parking_lot::Mutex;
-use reth_db::models::StoredBlockBodyIndices;
+use reth_db::models::{AccountBeforeTx, StoredBadBlock, StoredBlockBodyIndices};
 use reth_interfaces::{provider::ProviderError, RethResult};
 use reth_primitives::{
-    keccak256, trie::AccountProof, Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId,
-    BlockNumber, BlockWithSenders, Bytecode, Bytes, ChainInfo, ChainSpec, Header, Receipt,
-    SealedBlock, SealedHeader, StorageKey, StorageValue, TransactionMeta, TransactionSigned,
-    TransactionSignedNoHash, TxHash, TxNumber, B256, U256,
+    keccak256, revm::compat::into_reth_acc, trie::AccountProof, Account, Address, Block, BlockHash,
+    BlockHashOrNumber, BlockId, BlockNumber, BlockWithSenders, Bytecode, Bytes, ChainInfo,
+    ChainSpec, Header, Receipt, SealedBlock, SealedHeader, StorageEntry, StorageKey, StorageValue,
+    TransactionMeta, TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, B256, U256,
 };
 use revm::primitives::{BlockEnv, CfgEnv};
 use std::{
@@ -484,8 +484,53 @@ impl AccountReader for MockEthProvider {
 }
 
 impl StateRootProvider for MockEthProvider {
-    fn state_root(&self, _state: &BundleStateWithReceipts) -> RethResult<B256> {
-        todo!()
+    fn state_root(&self, state: &BundleStateWithReceipts) -> RethResult<B256> {
+        let mut accounts = self
+            .accounts
+            .lock()
+            .iter()
+            .map(|(address, account)| {
+                (*address, (account.account, account.storage.clone().into_iter().collect()))
+            })
+            .collect::<BTreeMap<Address, (Account, BTreeMap<B256, U256>)>>();
+
+        // apply the changes of the bundle on top of the local account store
+        for (address, bundle_account) in state.state().state() {
+            let Some(info) = &bundle_account.info else {
+                accounts.remove(address);
+                continue
+            };
+            let (account, storage) = accounts.entry(*address).or_default();
+            *account = into_reth_acc(info.clone());
+            if bundle_account.status.was_destroyed() {
+                storage.clear();
+            }
+            for (slot, value) in &bundle_account.storage {
+                storage.insert(B256::new(slot.to_be_bytes()), value.present_value);
+            }
+        }
+
+        Ok(reth_trie::test_utils::state_root(accounts.into_iter().map(
+            |(address, (account, storage))| {
+                (address, (account, storage.into_iter().filter(|(_, value)| *value != U256::ZERO)))
+            },
+        )))
+    }
+
+    fn storage_root(&self, address: Address) -> RethResult<B256> {
+        let lock = self.accounts.lock();
+        let storage = lock
+            .get(&address)
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_, value)| **value != U256::ZERO)
+                    .map(|(slot, value)| (*slot, *value))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        Ok(reth_trie::test_utils::storage_root(storage.into_iter()))
     }
 }
 
@@ -499,6 +544,30 @@ impl StateProvider for MockEthProvider {
         Ok(lock.get(&account).and_then(|account| account.storage.get(&storage_key)).cloned())
     }
 
+    fn storage_range(
+        &self,
+        address: Address,
+        start: B256,
+        limit: usize,
+    ) -> RethResult<Vec<StorageEntry>> {
+        let lock = self.accounts.lock();
+        let mut entries = lock
+            .get(&address)
+            .map(|account| {
+                account
+                    .storage
+                    .iter()
+                    .filter(|(_, value)| **value != U256::ZERO)
+                    .map(|(key, value)| StorageEntry { key: keccak256(key), value: *value })
+                    .filter(|entry| entry.key >= start)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        entries.sort_by_key(|entry| entry.key);
+        entries.truncate(limit);
+        Ok(entries)
+    }
+
     fn bytecode_by_hash(&self, code_hash: B256) -> RethResult<Option<Bytecode>> {
         let lock = self.accounts.lock();
         Ok(lock.values().find_map(|account| {
@@ -638,3 +707,29 @@ impl WithdrawalsProvider for MockEthProvider {
         unimplemented!()
     }
 }
+
+impl ChangeSetReader for MockEthProvider {
+    fn account_block_changeset(
+        &self,
+        _block_number: BlockNumber,
+    ) -> RethResult<Vec<AccountBeforeTx>> {
+        Ok(Vec::default())
+    }
+
+    fn storage_block_changeset(
+        &self,
+        _block_number: BlockNumber,
+    ) -> RethResult<Vec<(Address, StorageEntry)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl BadBlocksReader for MockEthProvider {
+    fn bad_blocks(&self) -> RethResult<Vec<StoredBadBlock>> {
+        Ok(Vec::new())
+    }
+
+    fn bad_block(&self, _hash: BlockHash) -> RethResult<Option<StoredBadBlock>> {
+        Ok(None)
+    }
+}
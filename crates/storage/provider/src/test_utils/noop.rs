@@ -1,20 +1,23 @@
 use crate::{
     bundle_state::BundleStateWithReceipts,
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, PruneCheckpointReader,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProvider, StateProviderBox,
-    StateProviderFactory, StateRootProvider, TransactionVariant, TransactionsProvider,
+    AccountReader, AddressAppearancesReader, BadBlocksReader, BlockHashReader, BlockIdReader,
+    BlockNumReader, BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
+    EvmEnvProvider, HeaderProvider, PruneCheckpointReader, ReceiptProviderIdExt,
+    StageCheckpointReader, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, TransactionTracesReader, TransactionVariant, TransactionsProvider,
     WithdrawalsProvider,
 };
-use reth_db::models::{AccountBeforeTx, StoredBlockBodyIndices};
+use reth_db::models::{
+    AccountBeforeTx, StoredBadBlock, StoredBlockBodyIndices, StoredTransactionTraces,
+};
 use reth_interfaces::RethResult;
 use reth_primitives::{
     stage::{StageCheckpoint, StageId},
     trie::AccountProof,
     Account, Address, Block, BlockHash, BlockHashOrNumber, BlockId, BlockNumber, Bytecode,
     ChainInfo, ChainSpec, Header, PruneCheckpoint, PruneSegment, Receipt, SealedBlock,
-    SealedHeader, StorageKey, StorageValue, TransactionMeta, TransactionSigned,
+    SealedHeader, StorageEntry, StorageKey, StorageValue, TransactionMeta, TransactionSigned,
     TransactionSignedNoHash, TxHash, TxNumber, B256, MAINNET, U256,
 };
 use revm::primitives::{BlockEnv, CfgEnv};
@@ -260,12 +263,23 @@ impl ChangeSetReader for NoopProvider {
     ) -> RethResult<Vec<AccountBeforeTx>> {
         Ok(Vec::default())
     }
+
+    fn storage_block_changeset(
+        &self,
+        _block_number: BlockNumber,
+    ) -> RethResult<Vec<(Address, StorageEntry)>> {
+        Ok(Vec::default())
+    }
 }
 
 impl StateRootProvider for NoopProvider {
     fn state_root(&self, _state: &BundleStateWithReceipts) -> RethResult<B256> {
         Ok(B256::default())
     }
+
+    fn storage_root(&self, _address: Address) -> RethResult<B256> {
+        Ok(B256::default())
+    }
 }
 
 impl StateProvider for NoopProvider {
@@ -391,3 +405,46 @@ impl PruneCheckpointReader for NoopProvider {
         Ok(None)
     }
 }
+
+impl BadBlocksReader for NoopProvider {
+    fn bad_blocks(&self) -> RethResult<Vec<StoredBadBlock>> {
+        Ok(Vec::new())
+    }
+
+    fn bad_block(&self, _hash: BlockHash) -> RethResult<Option<StoredBadBlock>> {
+        Ok(None)
+    }
+}
+
+impl AddressAppearancesReader for NoopProvider {
+    fn address_appearances_before(
+        &self,
+        _address: Address,
+        _before: TxNumber,
+        _limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        Ok(Vec::new())
+    }
+
+    fn address_appearances_from(
+        &self,
+        _address: Address,
+        _from: TxNumber,
+        _limit: usize,
+    ) -> RethResult<Vec<TxNumber>> {
+        Ok(Vec::new())
+    }
+}
+
+impl TransactionTracesReader for NoopProvider {
+    fn transaction_traces(&self, _id: TxNumber) -> RethResult<Option<StoredTransactionTraces>> {
+        Ok(None)
+    }
+
+    fn transaction_traces_by_block(
+        &self,
+        _block: BlockHashOrNumber,
+    ) -> RethResult<Option<Vec<StoredTransactionTraces>>> {
+        Ok(None)
+    }
+}
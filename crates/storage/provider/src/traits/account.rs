@@ -1,7 +1,7 @@
 use auto_impl::auto_impl;
 use reth_db::models::AccountBeforeTx;
-use reth_interfaces::RethResult;
-use reth_primitives::{Account, Address, BlockNumber};
+use reth_interfaces::{provider::ProviderError, RethResult};
+use reth_primitives::{Account, Address, BlockNumber, StorageEntry};
 use std::{
     collections::{BTreeMap, BTreeSet},
     ops::{RangeBounds, RangeInclusive},
@@ -48,8 +48,21 @@ pub trait AccountExtReader: Send + Sync {
 #[auto_impl(&, Arc, Box)]
 pub trait ChangeSetReader: Send + Sync {
     /// Iterate over account changesets and return the account state from before this block.
+    ///
+    /// Returns [ProviderError::StateAtBlockPruned] if the account changesets of the block were
+    /// pruned.
     fn account_block_changeset(
         &self,
         block_number: BlockNumber,
     ) -> RethResult<Vec<AccountBeforeTx>>;
+
+    /// Iterate over storage changesets and return the storage slots from before this block,
+    /// alongside the address of their account.
+    ///
+    /// Returns [ProviderError::StateAtBlockPruned] if the storage changesets of the block were
+    /// pruned.
+    fn storage_block_changeset(
+        &self,
+        block_number: BlockNumber,
+    ) -> RethResult<Vec<(Address, StorageEntry)>>;
 }
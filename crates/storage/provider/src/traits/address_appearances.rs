@@ -0,0 +1,55 @@
+use auto_impl::auto_impl;
+use reth_interfaces::RethResult;
+use reth_primitives::{Address, BlockNumber, TxNumber};
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// The trait for fetching the transactions an address appears in.
+///
+/// See also [AddressAppearances](reth_db::tables::AddressAppearances).
+#[auto_impl(&, Arc)]
+pub trait AddressAppearancesReader: Send + Sync {
+    /// Returns up to `limit` numbers of the transactions the address appears in that are lower
+    /// than `before`, in descending order.
+    fn address_appearances_before(
+        &self,
+        address: Address,
+        before: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>>;
+
+    /// Returns up to `limit` numbers of the transactions the address appears in that are greater
+    /// than or equal to `from`, in ascending order.
+    fn address_appearances_from(
+        &self,
+        address: Address,
+        from: TxNumber,
+        limit: usize,
+    ) -> RethResult<Vec<TxNumber>>;
+}
+
+/// The trait for updating the address appearance index.
+#[auto_impl(&, Arc, Box)]
+pub trait AddressAppearancesWriter: Send + Sync {
+    /// Collects the addresses that appear in the transactions of the given block range, mapped to
+    /// the numbers of the transactions they appear in.
+    ///
+    /// An address appears in a transaction if it's the sender, the recipient, the contract created
+    /// by the transaction, the emitter of one of its logs or the caller or callee of one of its
+    /// internal calls. Logs are skipped if the receipts have been pruned and internal calls are
+    /// only known if the transaction was traced by the `TransactionTraces` stage.
+    fn address_appearances_with_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RethResult<BTreeMap<Address, Vec<TxNumber>>>;
+
+    /// Insert address appearances to the database. Used inside IndexAddressAppearances stage.
+    fn insert_address_appearances(
+        &self,
+        appearances: BTreeMap<Address, Vec<TxNumber>>,
+    ) -> RethResult<()>;
+
+    /// Unwind and clear the address appearances of the transactions in the given block range.
+    ///
+    /// Returns number of addresses unwound.
+    fn unwind_address_appearances(&self, range: RangeInclusive<BlockNumber>) -> RethResult<usize>;
+}
@@ -0,0 +1,29 @@
+use auto_impl::auto_impl;
+use reth_db::models::StoredBadBlock;
+use reth_interfaces::RethResult;
+use reth_primitives::{BlockHash, SealedBlock};
+
+/// The maximum number of bad blocks kept in the database, same as geth.
+pub const MAX_BAD_BLOCKS: usize = 10;
+
+/// The trait for fetching the blocks that were rejected as invalid.
+///
+/// See also [BadBlocks](reth_db::tables::BadBlocks).
+#[auto_impl(&, Arc)]
+pub trait BadBlocksReader: Send + Sync {
+    /// Returns all bad blocks, in descending order of their block number.
+    fn bad_blocks(&self) -> RethResult<Vec<StoredBadBlock>>;
+
+    /// Returns the bad block with the given hash, if it's known.
+    fn bad_block(&self, hash: BlockHash) -> RethResult<Option<StoredBadBlock>>;
+}
+
+/// The trait for recording the blocks that were rejected as invalid.
+#[auto_impl(&, Arc, Box)]
+pub trait BadBlocksWriter: Send + Sync {
+    /// Inserts a block that was rejected with the given error.
+    ///
+    /// If there are more than [MAX_BAD_BLOCKS] bad blocks afterwards, the ones with the lowest
+    /// block numbers are removed, which may be the inserted block itself.
+    fn insert_bad_block(&self, block: SealedBlock, error: String) -> RethResult<()>;
+}
@@ -67,8 +67,11 @@ pub enum CanonStateNotification {
     /// Chain got extended without reorg and only new chain is returned.
     Commit { new: Arc<Chain> },
     /// Chain reorgs and both old and new chain are returned.
-    /// Revert is just a subset of reorg where the new chain is empty.
     Reorg { old: Arc<Chain>, new: Arc<Chain> },
+    /// Chain got unwound without committing new blocks and only the old chain is returned.
+    ///
+    /// The new tip is the parent of the first block of the old chain.
+    Revert { old: Arc<Chain> },
 }
 
 // For one reason or another, the compiler can't derive PartialEq for CanonStateNotification.
@@ -80,6 +83,7 @@ impl PartialEq for CanonStateNotification {
                 old1 == old2 && new1 == new2
             }
             (Self::Commit { new: new1 }, Self::Commit { new: new2 }) => new1 == new2,
+            (Self::Revert { old: old1 }, Self::Revert { old: old2 }) => old1 == old2,
             _ => false,
         }
     }
@@ -89,7 +93,7 @@ impl CanonStateNotification {
     /// Get old chain if any.
     pub fn reverted(&self) -> Option<Arc<Chain>> {
         match self {
-            Self::Reorg { old, .. } => Some(old.clone()),
+            Self::Reorg { old, .. } | Self::Revert { old } => Some(old.clone()),
             Self::Commit { .. } => None,
         }
     }
@@ -101,17 +105,19 @@ impl CanonStateNotification {
         match self {
             Self::Reorg { new, .. } => Some(new.clone()),
             Self::Commit { new } => Some(new.clone()),
+            Self::Revert { .. } => None,
         }
     }
 
     /// Returns the new tip of the chain.
     ///
     /// Returns the new tip for [Self::Reorg] and [Self::Commit] variants which commit at least 1
-    /// new block.
-    pub fn tip(&self) -> &SealedBlockWithSenders {
+    /// new block, and `None` for [Self::Revert].
+    pub fn tip(&self) -> Option<&SealedBlockWithSenders> {
         match self {
-            Self::Reorg { new, .. } => new.tip(),
-            Self::Commit { new } => new.tip(),
+            Self::Reorg { new, .. } => Some(new.tip()),
+            Self::Commit { new } => Some(new.tip()),
+            Self::Revert { .. } => None,
         }
     }
 
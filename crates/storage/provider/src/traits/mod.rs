@@ -32,8 +32,8 @@ pub use receipts::{ReceiptProvider, ReceiptProviderIdExt};
 
 mod state;
 pub use state::{
-    BlockchainTreePendingStateProvider, BundleStateDataProvider, StateProvider, StateProviderBox,
-    StateProviderFactory, StateRootProvider,
+    BlockchainTreePendingStateProvider, BundleStateDataProvider, HashedAccountEntry, StateProvider,
+    StateProviderBox, StateProviderFactory, StateRootProvider, StateVisitor,
 };
 
 mod transactions;
@@ -63,5 +63,14 @@ pub use hashing::HashingWriter;
 mod history;
 pub use history::HistoryWriter;
 
+mod address_appearances;
+pub use address_appearances::{AddressAppearancesReader, AddressAppearancesWriter};
+
+mod transaction_traces;
+pub use transaction_traces::{TransactionTracesReader, TransactionTracesWriter};
+
+mod bad_blocks;
+pub use bad_blocks::{BadBlocksReader, BadBlocksWriter, MAX_BAD_BLOCKS};
+
 mod prune_checkpoint;
 pub use prune_checkpoint::{PruneCheckpointReader, PruneCheckpointWriter};
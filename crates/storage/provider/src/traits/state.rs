@@ -3,13 +3,43 @@ use crate::{BlockHashReader, BlockIdReader, BundleStateWithReceipts};
 use auto_impl::auto_impl;
 use reth_interfaces::{provider::ProviderError, RethResult};
 use reth_primitives::{
-    trie::AccountProof, Address, BlockHash, BlockId, BlockNumHash, BlockNumber, BlockNumberOrTag,
-    Bytecode, StorageKey, StorageValue, B256, KECCAK_EMPTY, U256,
+    trie::AccountProof, Account, Address, BlockHash, BlockId, BlockNumHash, BlockNumber,
+    BlockNumberOrTag, Bytecode, StorageEntry, StorageKey, StorageValue, B256, KECCAK_EMPTY, U256,
 };
 
 /// Type alias of boxed [StateProvider].
 pub type StateProviderBox<'a> = Box<dyn StateProvider + 'a>;
 
+/// An account of the state, as returned by [StateProvider::account_range].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedAccountEntry {
+    /// The hash of the address of the account, which is its key in the state trie.
+    pub hashed_address: B256,
+    /// The account.
+    pub account: Account,
+    /// The storage root of the account.
+    pub storage_root: B256,
+    /// The first non-zero storage slots of the account, keyed by their hashed keys.
+    pub storage: Vec<StorageEntry>,
+}
+
+/// A visitor of the accounts of the state, as they're read by [StateProvider::visit_accounts].
+pub trait StateVisitor {
+    /// Visits an account, keyed by the hash of its address, before its storage slots.
+    fn visit_account(
+        &mut self,
+        hashed_address: B256,
+        account: Account,
+        storage_root: B256,
+    ) -> RethResult<()>;
+
+    /// Visits a non-zero storage slot of the last visited account, keyed by its hashed key.
+    fn visit_storage(&mut self, entry: StorageEntry) -> RethResult<()>;
+
+    /// Called after the last storage slot of the last visited account.
+    fn visit_account_end(&mut self) -> RethResult<()>;
+}
+
 /// An abstraction for a type that provides state data.
 #[auto_impl(&, Arc, Box)]
 pub trait StateProvider: BlockHashReader + AccountReader + StateRootProvider + Send + Sync {
@@ -26,6 +56,53 @@ pub trait StateProvider: BlockHashReader + AccountReader + StateRootProvider + S
     /// Get account and storage proofs.
     fn proof(&self, address: Address, keys: &[B256]) -> RethResult<AccountProof>;
 
+    /// Get up to `limit` accounts with a hashed address greater than or equal to `start`, in
+    /// ascending order of their hashed addresses, as they're ordered in the state trie.
+    ///
+    /// Each account comes with up to `storage_limit` of its storage slots, as they're returned by
+    /// [StateProvider::storage_range].
+    ///
+    /// Returns [ProviderError::UnsupportedProvider] if the provider can't iterate over the state.
+    fn account_range(
+        &self,
+        _start: B256,
+        _limit: usize,
+        _storage_limit: usize,
+    ) -> RethResult<Vec<HashedAccountEntry>> {
+        Err(ProviderError::UnsupportedProvider.into())
+    }
+
+    /// Visits all accounts of the state in ascending order of their hashed addresses, with their
+    /// storage slots if `with_storage` is set, in a single pass over the state.
+    ///
+    /// Unlike [StateProvider::account_range], neither the accounts nor their storage are collected,
+    /// so the whole state can be visited without holding it in memory.
+    ///
+    /// Returns [ProviderError::UnsupportedProvider] if the provider can't iterate over the state.
+    fn visit_accounts(
+        &self,
+        _visitor: &mut dyn StateVisitor,
+        _with_storage: bool,
+    ) -> RethResult<()> {
+        Err(ProviderError::UnsupportedProvider.into())
+    }
+
+    /// Get up to `limit` non-zero storage slots of the account with a hashed key greater than or
+    /// equal to `start`, in ascending order of their hashed keys, as they're ordered in the
+    /// storage trie.
+    ///
+    /// The keys of the returned entries are the hashed keys of the slots.
+    ///
+    /// Returns [ProviderError::UnsupportedProvider] if the provider can't iterate over the state.
+    fn storage_range(
+        &self,
+        _address: Address,
+        _start: B256,
+        _limit: usize,
+    ) -> RethResult<Vec<StorageEntry>> {
+        Err(ProviderError::UnsupportedProvider.into())
+    }
+
     /// Get account code by its address.
     ///
     /// Returns `None` if the account doesn't exist or account is not a contract
@@ -232,4 +309,22 @@ pub trait BundleStateDataProvider: Send + Sync {
 pub trait StateRootProvider: Send + Sync {
     /// Returns the state root of the BundleState on top of the current state.
     fn state_root(&self, post_state: &BundleStateWithReceipts) -> RethResult<B256>;
+
+    /// Returns the state roots after applying each of the given changes on top of the current
+    /// state, one after the other, e.g. the state roots after each transaction of a block.
+    ///
+    /// Each of the changes only contains the changes made on top of the previous ones.
+    fn state_roots(&self, changes: Vec<BundleStateWithReceipts>) -> RethResult<Vec<B256>> {
+        let mut post_state = BundleStateWithReceipts::default();
+        changes
+            .into_iter()
+            .map(|change| {
+                post_state.extend(change);
+                self.state_root(&post_state)
+            })
+            .collect()
+    }
+
+    /// Returns the storage root of the given account in the current state.
+    fn storage_root(&self, address: Address) -> RethResult<B256>;
 }
@@ -0,0 +1,40 @@
+use auto_impl::auto_impl;
+use reth_db::models::StoredTransactionTraces;
+use reth_interfaces::RethResult;
+use reth_primitives::{BlockHashOrNumber, BlockNumber, TxNumber};
+use std::ops::RangeInclusive;
+
+/// The trait for fetching the recorded call traces of transactions.
+///
+/// See also [TransactionTraces](reth_db::tables::TransactionTraces).
+#[auto_impl(&, Arc)]
+pub trait TransactionTracesReader: Send + Sync {
+    /// Returns the recorded traces of the transaction with the given number, if any.
+    fn transaction_traces(&self, id: TxNumber) -> RethResult<Option<StoredTransactionTraces>>;
+
+    /// Returns the recorded traces of all transactions of the block, in the order of the
+    /// transactions.
+    ///
+    /// Returns `None` if the block doesn't exist or if the traces of any of its transactions
+    /// haven't been recorded.
+    fn transaction_traces_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> RethResult<Option<Vec<StoredTransactionTraces>>>;
+}
+
+/// The trait for recording the call traces of transactions.
+#[auto_impl(&, Arc, Box)]
+pub trait TransactionTracesWriter: Send + Sync {
+    /// Insert the traces of the transactions with the given numbers. Used inside the
+    /// TransactionTraces stage.
+    fn insert_transaction_traces(
+        &self,
+        traces: Vec<(TxNumber, StoredTransactionTraces)>,
+    ) -> RethResult<()>;
+
+    /// Unwind and clear the traces of the transactions in the given block range.
+    ///
+    /// Returns number of transaction traces unwound.
+    fn unwind_transaction_traces(&self, range: RangeInclusive<BlockNumber>) -> RethResult<usize>;
+}
@@ -172,6 +172,9 @@ pub use crate::{
     },
 };
 
+#[cfg(feature = "optimism")]
+pub use crate::validate::OpTransactionValidator;
+
 pub mod error;
 pub mod maintain;
 pub mod metrics;
@@ -12,7 +12,7 @@ use futures_util::{
 };
 use reth_interfaces::RethError;
 use reth_primitives::{
-    Address, BlockHash, BlockNumber, BlockNumberOrTag, FromRecoveredTransaction,
+    Address, BlockHash, BlockNumber, BlockNumberOrTag, ChainSpec, FromRecoveredTransaction, Header,
 };
 use reth_provider::{
     BlockReaderIdExt, BundleStateWithReceipts, CanonStateNotification, ChainSpecProvider,
@@ -91,9 +91,7 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
         let info = BlockInfo {
             last_seen_block_hash: latest.hash,
             last_seen_block_number: latest.number,
-            pending_basefee: latest
-                .next_block_base_fee(chain_spec.base_fee_params)
-                .unwrap_or_default(),
+            pending_basefee: next_block_base_fee(&chain_spec, &latest),
             pending_blob_fee: latest.next_block_blob_fee(),
         };
         pool.set_block_info(info);
@@ -238,8 +236,7 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `new_tip+1`
-                let pending_block_base_fee =
-                    new_tip.next_block_base_fee(chain_spec.base_fee_params).unwrap_or_default();
+                let pending_block_base_fee = next_block_base_fee(&chain_spec, &new_tip);
                 let pending_block_blob_fee = new_tip.next_block_blob_fee();
 
                 // we know all changed account in the new chain
@@ -313,14 +310,86 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
                 // TODO(mattsse): handle reorged transactions
                 blob_store_tracker.add_new_chain_blocks(&new_blocks);
             }
+            CanonStateNotification::Revert { old } => {
+                let (old_blocks, old_state) = old.inner();
+                let old_first = old_blocks.first();
+
+                if old_blocks.tip().hash != pool_info.last_seen_block_hash {
+                    // the reverted chain doesn't end at the pool's block
+                    maintained_state = MaintainedPoolState::Drifted;
+                }
+
+                // the new tip is the parent of the first reverted block
+                let new_tip = match client.block_by_hash(old_first.parent_hash) {
+                    Ok(Some(block)) => block.seal(old_first.parent_hash),
+                    res => {
+                        debug!(target: "txpool", ?res, "failed to load new tip after revert");
+                        maintained_state = MaintainedPoolState::Drifted;
+                        continue
+                    }
+                };
+
+                let chain_spec = client.chain_spec();
+
+                // fees for the next block: `new_tip+1`
+                let pending_block_base_fee = next_block_base_fee(&chain_spec, &new_tip);
+                let pending_block_blob_fee = new_tip.next_block_blob_fee();
+
+                // all accounts changed in the old chain need to be reloaded at the new tip
+                let changed_accounts = match load_accounts(
+                    client.clone(),
+                    new_tip.hash,
+                    old_state.accounts_iter().map(|(a, _)| a),
+                ) {
+                    Ok(LoadedAccounts { accounts, failed_to_load }) => {
+                        // extend accounts we failed to load from database
+                        dirty_addresses.extend(failed_to_load);
+
+                        accounts
+                    }
+                    Err(err) => {
+                        let (addresses, err) = *err;
+                        debug!(
+                            target: "txpool",
+                            ?err,
+                            "failed to load changed accounts at new tip: {:?}",
+                            new_tip.hash
+                        );
+                        dirty_addresses.extend(addresses);
+                        vec![]
+                    }
+                };
+
+                // all transactions that were mined in the old chain
+                let pruned_old_transactions = old_blocks
+                    .transactions()
+                    .filter_map(|tx| tx.clone().into_ecrecovered())
+                    .map(<P as TransactionPool>::Transaction::from_recovered_transaction)
+                    .collect::<Vec<_>>();
+
+                // update the pool first
+                let update = CanonicalStateUpdate {
+                    new_tip: &new_tip,
+                    pending_block_base_fee,
+                    pending_block_blob_fee,
+                    changed_accounts,
+                    mined_transactions: vec![],
+                };
+                pool.on_canonical_state_change(update);
+
+                // all transactions that were mined in the old chain need to be re-injected
+                //
+                // Note: we no longer know if the tx was local or external
+                metrics.inc_reinserted_transactions(pruned_old_transactions.len());
+                let _ = pool.add_external_transactions(pruned_old_transactions).await;
+            }
             CanonStateNotification::Commit { new } => {
                 let (blocks, state) = new.inner();
                 let tip = blocks.tip();
                 let chain_spec = client.chain_spec();
 
                 // fees for the next block: `tip+1`
-                let pending_block_base_fee =
-                    tip.next_block_base_fee(chain_spec.base_fee_params).unwrap_or_default();
+                let pending_block_base_fee = next_block_base_fee(&chain_spec, tip);
                 let pending_block_blob_fee = tip.next_block_blob_fee();
 
                 let first_block = blocks.first();
@@ -462,6 +531,15 @@ struct LoadedAccounts {
     failed_to_load: Vec<Address>,
 }
 
+/// Returns the base fee of the block following the given block.
+///
+/// The next block is assumed to follow after the block time of the chain, so that the base fee
+/// params of a hardfork that activates with the next block are applied.
+fn next_block_base_fee(chain_spec: &ChainSpec, block: &Header) -> u64 {
+    let next_timestamp = block.timestamp + chain_spec.block_time.as_secs();
+    block.next_block_base_fee(chain_spec.base_fee_params(next_timestamp)).unwrap_or_default()
+}
+
 /// Loads all accounts at the given state
 ///
 /// Returns an error with all given addresses if the state is not available.
@@ -666,6 +666,8 @@ impl FromRecoveredTransaction for MockTransaction {
                 input,
                 accesslist: access_list,
             },
+            #[cfg(feature = "optimism")]
+            Transaction::Deposit(_) => unreachable!("deposit transactions are never pooled"),
         }
     }
 }
@@ -789,7 +791,14 @@ impl proptest::arbitrary::Arbitrary for MockTransaction {
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
         use proptest::prelude::{any, Strategy};
 
-        any::<(Transaction, Address, B256, BlobTransactionSidecar)>()
+        let strategy = any::<(Transaction, Address, B256, BlobTransactionSidecar)>();
+
+        // deposit transactions are never pooled
+        #[cfg(feature = "optimism")]
+        let strategy = strategy
+            .prop_filter("deposit transactions are not pooled", |(tx, ..)| !tx.is_deposit());
+
+        strategy
             .prop_map(|(tx, sender, tx_hash, sidecar)| match &tx {
                 Transaction::Legacy(TxLegacy {
                     nonce,
@@ -865,6 +874,8 @@ impl proptest::arbitrary::Arbitrary for MockTransaction {
                     accesslist: (*access_list).clone(),
                     sidecar,
                 },
+                #[cfg(feature = "optimism")]
+                Transaction::Deposit(_) => unreachable!("filtered out above"),
             })
             .boxed()
     }
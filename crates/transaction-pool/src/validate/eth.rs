@@ -38,6 +38,18 @@ where
     Client: StateProviderFactory,
     Tx: EthPoolTransaction,
 {
+    /// Returns the configured chain spec
+    #[cfg(feature = "optimism")]
+    pub(crate) fn chain_spec(&self) -> &Arc<ChainSpec> {
+        &self.inner.chain_spec
+    }
+
+    /// Returns the configured client
+    #[cfg(feature = "optimism")]
+    pub(crate) fn client(&self) -> &Client {
+        &self.inner.client
+    }
+
     /// Validates a single transaction.
     ///
     /// See also [TransactionValidator::validate_transaction]
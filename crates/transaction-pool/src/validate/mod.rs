@@ -13,11 +13,17 @@ use std::{fmt, time::Instant};
 
 mod constants;
 mod eth;
+#[cfg(feature = "optimism")]
+mod optimism;
 mod task;
 
 /// A `TransactionValidator` implementation that validates ethereum transaction.
 pub use eth::*;
 
+/// A `TransactionValidator` implementation that validates OP Stack transactions.
+#[cfg(feature = "optimism")]
+pub use optimism::OpTransactionValidator;
+
 /// A spawnable task that performs transaction validation.
 pub use task::{TransactionValidationTaskExecutor, ValidationTask};
 
@@ -0,0 +1,306 @@
+//! Transaction validator for OP Stack rollups.
+
+use crate::{
+    traits::TransactionOrigin, validate::ValidTransaction, EthPoolTransaction,
+    EthTransactionValidator, TransactionValidationOutcome, TransactionValidator,
+};
+use parking_lot::RwLock;
+use reth_primitives::{Hardfork, InvalidTransactionError, SealedBlock, DEPOSIT_TX_TYPE_ID, U256};
+use reth_provider::StateProviderFactory;
+use reth_revm::optimism::L1BlockInfo;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Validator for transactions of OP Stack chains.
+///
+/// This wraps the [EthTransactionValidator] and additionally:
+///   - rejects deposit transactions, which can only be included by the sequencer
+///   - requires the sender to be able to pay for the L1 data fee of the transaction
+///
+/// On non OP Stack chains this behaves exactly like the wrapped [EthTransactionValidator].
+#[derive(Debug, Clone)]
+pub struct OpTransactionValidator<Client, Tx> {
+    /// The type that performs the Ethereum validation.
+    inner: EthTransactionValidator<Client, Tx>,
+    /// The L1 block info of the current head block.
+    block_info: Arc<OpL1BlockInfo>,
+}
+
+impl<Client, Tx> OpTransactionValidator<Client, Tx> {
+    /// Creates a new validator that wraps the given [EthTransactionValidator].
+    pub fn new(inner: EthTransactionValidator<Client, Tx>) -> Self {
+        Self { inner, block_info: Default::default() }
+    }
+
+    /// Configures validation rules based on the head block's timestamp.
+    ///
+    /// For example, whether the Regolith hardfork is activated at launch.
+    pub fn with_head_timestamp(self, timestamp: u64) -> Self {
+        self.block_info.timestamp.store(timestamp, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the wrapped [EthTransactionValidator].
+    pub fn inner(&self) -> &EthTransactionValidator<Client, Tx> {
+        &self.inner
+    }
+}
+
+impl<Client, Tx> OpTransactionValidator<Client, Tx>
+where
+    Client: StateProviderFactory,
+    Tx: EthPoolTransaction,
+{
+    /// Validates a single transaction.
+    ///
+    /// See also [TransactionValidator::validate_transaction]
+    pub fn validate_one(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Tx,
+    ) -> TransactionValidationOutcome<Tx> {
+        if !self.inner.chain_spec().is_optimism() {
+            return self.inner.validate_one(origin, transaction)
+        }
+
+        // deposits are derived from L1 and can't be submitted via RPC or p2p
+        if transaction.tx_type() == DEPOSIT_TX_TYPE_ID {
+            return TransactionValidationOutcome::Invalid(
+                transaction,
+                InvalidTransactionError::TxTypeNotSupported.into(),
+            )
+        }
+
+        let outcome = self.inner.validate_one(origin, transaction);
+        let TransactionValidationOutcome::Valid { balance, state_nonce, transaction, propagate } =
+            outcome
+        else {
+            return outcome
+        };
+
+        let l1_block_info = match self.l1_block_info() {
+            Ok(l1_block_info) => l1_block_info,
+            Err(err) => {
+                return TransactionValidationOutcome::Error(*transaction.hash(), Box::new(err))
+            }
+        };
+
+        let tx = transaction.transaction();
+        let encoded = tx.to_recovered_transaction().into_signed().envelope_encoded();
+        let l1_cost = l1_block_info.calculate_tx_l1_cost(&encoded, false, self.is_regolith());
+
+        // the sender must also be able to pay for the L1 data fee
+        let cost = tx.cost().saturating_add(l1_cost);
+        if cost > balance {
+            return TransactionValidationOutcome::Invalid(
+                match transaction {
+                    ValidTransaction::Valid(tx) => tx,
+                    ValidTransaction::ValidWithSidecar { transaction, .. } => transaction,
+                },
+                InvalidTransactionError::InsufficientFunds { cost, available_funds: balance }
+                    .into(),
+            )
+        }
+
+        TransactionValidationOutcome::Valid { balance, state_nonce, transaction, propagate }
+    }
+
+    /// Validates all given transactions.
+    ///
+    /// Returns all outcomes for the given transactions in the same order.
+    ///
+    /// See also [Self::validate_one]
+    pub fn validate_all(
+        &self,
+        transactions: Vec<(TransactionOrigin, Tx)>,
+    ) -> Vec<TransactionValidationOutcome<Tx>> {
+        transactions.into_iter().map(|(origin, tx)| self.validate_one(origin, tx)).collect()
+    }
+
+    /// Returns the L1 block info of the current head block.
+    ///
+    /// If no head block has been observed yet, this is read from the `L1Block` predeploy in the
+    /// latest state.
+    fn l1_block_info(&self) -> reth_interfaces::RethResult<L1BlockInfo> {
+        if let Some(l1_block_info) = self.block_info.l1_block_info.read().clone() {
+            return Ok(l1_block_info)
+        }
+
+        let l1_block_info = L1BlockInfo::try_from_state(&*self.inner.client().latest()?)?;
+        *self.block_info.l1_block_info.write() = Some(l1_block_info.clone());
+        Ok(l1_block_info)
+    }
+
+    /// Returns true if the Regolith hardfork is active at the head block.
+    fn is_regolith(&self) -> bool {
+        self.inner.chain_spec().is_fork_active_at_timestamp(
+            Hardfork::Regolith,
+            self.block_info.timestamp.load(Ordering::Relaxed),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<Client, Tx> TransactionValidator for OpTransactionValidator<Client, Tx>
+where
+    Client: StateProviderFactory,
+    Tx: EthPoolTransaction,
+{
+    type Transaction = Tx;
+
+    async fn validate_transaction(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> TransactionValidationOutcome<Self::Transaction> {
+        self.validate_one(origin, transaction)
+    }
+
+    async fn validate_transactions(
+        &self,
+        transactions: Vec<(TransactionOrigin, Self::Transaction)>,
+    ) -> Vec<TransactionValidationOutcome<Self::Transaction>> {
+        self.validate_all(transactions)
+    }
+
+    fn on_new_head_block(&self, new_tip_block: &SealedBlock) {
+        self.inner.on_new_head_block(new_tip_block);
+
+        if !self.inner.chain_spec().is_optimism() {
+            return
+        }
+
+        self.block_info.timestamp.store(new_tip_block.timestamp, Ordering::Relaxed);
+        // the L1 attributes deposit is always the first transaction of the block, if it can't be
+        // parsed the info is read from the state on the next validation
+        *self.block_info.l1_block_info.write() = new_tip_block
+            .body
+            .first()
+            .and_then(|tx| L1BlockInfo::try_from_calldata(tx.input()).ok());
+    }
+}
+
+/// Tracks the L1 block info of the current head block.
+#[derive(Debug, Default)]
+struct OpL1BlockInfo {
+    /// The L1 block info of the head block, if already known.
+    l1_block_info: RwLock<Option<L1BlockInfo>>,
+    /// The timestamp of the head block.
+    timestamp: AtomicU64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore, error::InvalidPoolTransactionError,
+        validate::EthTransactionValidatorBuilder, EthPooledTransaction, PoolTransaction,
+    };
+    use assert_matches::assert_matches;
+    use reth_primitives::{
+        Address, ChainSpecBuilder, FromRecoveredTransaction, IntoRecoveredTransaction, Signature,
+        Transaction, TransactionKind, TransactionSigned, TransactionSignedEcRecovered, TxDeposit,
+        TxEip1559, B256, MAINNET,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_revm::optimism::L1_BLOCK_CONTRACT;
+
+    fn op_validator(
+        provider: MockEthProvider,
+    ) -> OpTransactionValidator<MockEthProvider, EthPooledTransaction> {
+        let chain_spec = Arc::new(ChainSpecBuilder::mainnet().regolith_activated().build());
+        let inner = EthTransactionValidatorBuilder::new(chain_spec)
+            .build(provider, InMemoryBlobStore::default());
+        OpTransactionValidator::new(inner)
+    }
+
+    fn pooled_transaction(transaction: Transaction) -> EthPooledTransaction {
+        let signed =
+            TransactionSigned::from_transaction_and_signature(transaction, Signature::default());
+        EthPooledTransaction::from_recovered_transaction(
+            TransactionSignedEcRecovered::from_signed_transaction(signed, Address::random()),
+        )
+    }
+
+    fn eip1559_transaction() -> EthPooledTransaction {
+        pooled_transaction(Transaction::Eip1559(TxEip1559 {
+            chain_id: MAINNET.chain.id(),
+            nonce: 0,
+            gas_limit: 21_000,
+            max_fee_per_gas: 1_000_000_000,
+            max_priority_fee_per_gas: 1_000_000,
+            to: TransactionKind::Call(Address::random()),
+            value: U256::from(1_000).into(),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn reject_deposit() {
+        let validator = op_validator(MockEthProvider::default());
+        let deposit = pooled_transaction(Transaction::Deposit(TxDeposit {
+            source_hash: B256::random(),
+            from: Address::random(),
+            to: TransactionKind::Call(Address::random()),
+            gas_limit: 21_000,
+            ..Default::default()
+        }));
+
+        let outcome = validator.validate_one(TransactionOrigin::External, deposit);
+        assert_matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::Consensus(InvalidTransactionError::TxTypeNotSupported)
+            )
+        );
+    }
+
+    #[test]
+    fn charge_l1_cost() {
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            L1_BLOCK_CONTRACT,
+            ExtendedAccount::new(0, U256::ZERO).extend_storage([
+                (B256::with_last_byte(1), U256::from(1_000_000_000)),
+                (B256::with_last_byte(5), U256::from(188)),
+                (B256::with_last_byte(6), U256::from(684_000)),
+            ]),
+        );
+        let validator = op_validator(provider.clone());
+        let transaction = eip1559_transaction();
+
+        let encoded = transaction.to_recovered_transaction().into_signed().envelope_encoded();
+        let l1_cost =
+            validator.l1_block_info().unwrap().calculate_tx_l1_cost(&encoded, false, true);
+        assert!(l1_cost > U256::ZERO);
+
+        // enough to pay for the L2 execution, but not for the L1 data fee
+        provider.add_account(transaction.sender(), ExtendedAccount::new(0, transaction.cost()));
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(outcome.is_invalid());
+
+        provider.add_account(
+            transaction.sender(),
+            ExtendedAccount::new(0, transaction.cost() + l1_cost),
+        );
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+
+    #[test]
+    fn passthrough_non_optimism() {
+        let provider = MockEthProvider::default();
+        let inner = EthTransactionValidatorBuilder::new(MAINNET.clone())
+            .build(provider.clone(), InMemoryBlobStore::default());
+        let validator = OpTransactionValidator::new(inner);
+        let transaction = eip1559_transaction();
+
+        // no L1 data fee is charged on non OP Stack chains
+        provider.add_account(transaction.sender(), ExtendedAccount::new(0, transaction.cost()));
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction);
+        assert!(outcome.is_valid());
+    }
+}
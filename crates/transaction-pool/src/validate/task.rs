@@ -142,6 +142,17 @@ impl<V> TransactionValidationTaskExecutor<V> {
         let (tx, _) = ValidationTask::new();
         Self { validator, to_validation_task: Arc::new(sync::Mutex::new(tx)) }
     }
+
+    /// Maps the validator of this executor while keeping the spawned validation tasks.
+    pub fn map<F, T>(self, f: F) -> TransactionValidationTaskExecutor<T>
+    where
+        F: FnOnce(V) -> T,
+    {
+        TransactionValidationTaskExecutor {
+            validator: f(self.validator),
+            to_validation_task: self.to_validation_task,
+        }
+    }
 }
 
 #[async_trait::async_trait]
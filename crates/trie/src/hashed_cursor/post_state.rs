@@ -6,7 +6,7 @@ use reth_db::{
     transaction::{DbTx, DbTxGAT},
 };
 use reth_primitives::{trie::Nibbles, Account, StorageEntry, B256, U256};
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
 /// The post state account storage with hashed slots.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -51,6 +51,29 @@ impl HashedStorage {
     pub fn insert_zero_valued_slot(&mut self, slot: B256) {
         self.zero_valued_slots.insert(slot);
     }
+
+    /// Applies the given storage on top of this one.
+    ///
+    /// The slots of the given storage take precedence, all slots are discarded if it was wiped.
+    pub fn extend(&mut self, other: Self) {
+        if other.wiped {
+            *self = other;
+            return
+        }
+
+        let updated = other
+            .non_zero_valued_storage
+            .iter()
+            .map(|(slot, _)| *slot)
+            .chain(other.zero_valued_slots.iter().copied())
+            .collect::<HashSet<_>>();
+        self.non_zero_valued_storage.retain(|(slot, _)| !updated.contains(slot));
+        self.zero_valued_slots.retain(|slot| !updated.contains(slot));
+
+        self.sorted &= other.non_zero_valued_storage.is_empty();
+        self.non_zero_valued_storage.extend(other.non_zero_valued_storage);
+        self.zero_valued_slots.extend(other.zero_valued_slots);
+    }
 }
 
 /// The post state with hashed addresses as keys.
@@ -113,6 +136,34 @@ impl HashedPostState {
         self.storages.insert(hashed_address, hashed_storage);
     }
 
+    /// Applies the given post state on top of this one.
+    ///
+    /// The accounts and storage slots of the given post state take precedence.
+    pub fn extend(&mut self, other: Self) {
+        let updated = other
+            .accounts
+            .iter()
+            .map(|(hashed_address, _)| *hashed_address)
+            .chain(other.cleared_accounts.iter().copied())
+            .collect::<HashSet<_>>();
+        self.accounts.retain(|(hashed_address, _)| !updated.contains(hashed_address));
+        self.cleared_accounts.retain(|hashed_address| !updated.contains(hashed_address));
+
+        self.sorted &= other.accounts.is_empty();
+        self.accounts.extend(other.accounts);
+        self.cleared_accounts.extend(other.cleared_accounts);
+
+        for (hashed_address, hashed_storage) in other.storages {
+            self.sorted = false;
+            match self.storages.entry(hashed_address) {
+                Entry::Occupied(mut entry) => entry.get_mut().extend(hashed_storage),
+                Entry::Vacant(entry) => {
+                    entry.insert(hashed_storage);
+                }
+            }
+        }
+    }
+
     /// Construct (PrefixSet)[PrefixSet] from hashed post state.
     /// The prefix sets contain the hashed account and storage keys that have been changed in the
     /// post state.
@@ -120,7 +171,23 @@ impl HashedPostState {
         // Initialize prefix sets.
         let mut account_prefix_set = PrefixSetMut::default();
         let mut storage_prefix_set: HashMap<B256, PrefixSetMut> = HashMap::default();
+        self.extend_prefix_sets(&mut account_prefix_set, &mut storage_prefix_set);
+
+        (
+            account_prefix_set.freeze(),
+            storage_prefix_set.into_iter().map(|(k, v)| (k, v.freeze())).collect(),
+        )
+    }
 
+    /// Inserts the hashed account and storage keys that have been changed in the post state into
+    /// the given prefix sets.
+    ///
+    /// Used to update the prefix sets of a post state that's extended by this one.
+    pub fn extend_prefix_sets(
+        &self,
+        account_prefix_set: &mut PrefixSetMut,
+        storage_prefix_set: &mut HashMap<B256, PrefixSetMut>,
+    ) {
         // Populate account prefix set.
         for (hashed_address, _) in &self.accounts {
             account_prefix_set.insert(Nibbles::unpack(hashed_address));
@@ -141,11 +208,6 @@ impl HashedPostState {
                 storage_prefix_set_entry.insert(Nibbles::unpack(hashed_slot));
             }
         }
-
-        (
-            account_prefix_set.freeze(),
-            storage_prefix_set.into_iter().map(|(k, v)| (k, v.freeze())).collect(),
-        )
     }
 }
 
@@ -976,6 +1038,49 @@ mod tests {
         assert_storage_cursor_order(&factory, expected);
     }
 
+    #[test]
+    fn extended_post_state_takes_precedence() {
+        let (updated, cleared, untouched) = (B256::random(), B256::random(), B256::random());
+        let account = |nonce| Account { nonce, ..Default::default() };
+        let (slot, other_slot) = (B256::with_last_byte(1), B256::with_last_byte(2));
+
+        let mut hashed_post_state = HashedPostState::default();
+        hashed_post_state.insert_account(updated, account(1));
+        hashed_post_state.insert_account(cleared, account(1));
+        hashed_post_state.insert_account(untouched, account(1));
+        let mut hashed_storage = HashedStorage::new(false);
+        hashed_storage.insert_non_zero_valued_storage(slot, U256::from(1));
+        hashed_storage.insert_non_zero_valued_storage(other_slot, U256::from(1));
+        hashed_post_state.insert_hashed_storage(updated, hashed_storage);
+
+        let mut other = HashedPostState::default();
+        other.insert_account(updated, account(2));
+        other.insert_cleared_account(cleared);
+        let mut hashed_storage = HashedStorage::new(false);
+        hashed_storage.insert_zero_valued_slot(slot);
+        other.insert_hashed_storage(updated, hashed_storage);
+        hashed_post_state.extend(other);
+        hashed_post_state.sort();
+
+        let db = create_test_rw_db();
+        let tx = db.tx().unwrap();
+        let factory = HashedPostStateCursorFactory::new(&tx, &hashed_post_state);
+        let mut expected = vec![(updated, account(2)), (untouched, account(1))];
+        expected.sort_unstable_by_key(|(hashed_address, _)| *hashed_address);
+        assert_account_cursor_order(&factory, expected.into_iter());
+        let expected = [(updated, BTreeMap::from([(other_slot, U256::from(1))]))].into_iter();
+        assert_storage_cursor_order(&factory, expected);
+
+        // a wiped storage replaces all previous slots
+        let mut other = HashedPostState::default();
+        other.insert_hashed_storage(updated, HashedStorage::new(true));
+        hashed_post_state.extend(other);
+        hashed_post_state.sort();
+        let factory = HashedPostStateCursorFactory::new(&tx, &hashed_post_state);
+        let mut cursor = factory.hashed_storage_cursor().unwrap();
+        assert!(cursor.is_storage_empty(updated).unwrap());
+    }
+
     #[test]
     fn fuzz_hashed_storage_cursor() {
         proptest!(ProptestConfig::with_cases(10),
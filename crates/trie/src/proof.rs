@@ -2,7 +2,7 @@ use crate::{
     account::EthAccount,
     hashed_cursor::{HashedCursorFactory, HashedStorageCursor},
     node_iter::{AccountNode, AccountNodeIter, StorageNode, StorageNodeIter},
-    prefix_set::PrefixSetMut,
+    prefix_set::{PrefixSet, PrefixSetMut},
     trie_cursor::{AccountTrieCursor, StorageTrieCursor},
     walker::TrieWalker,
     StateRootError, StorageRootError,
@@ -15,6 +15,7 @@ use reth_primitives::{
     trie::{AccountProof, HashBuilder, Nibbles, StorageProof},
     Address, B256,
 };
+use std::collections::HashMap;
 
 /// A struct for generating merkle proofs.
 ///
@@ -27,12 +28,49 @@ pub struct Proof<'a, TX, H> {
     tx: &'a TX,
     /// The factory for hashed cursors.
     hashed_cursor_factory: H,
+    /// A set of account prefixes whose hashed state differs from the trie nodes in the database.
+    changed_account_prefixes: PrefixSet,
+    /// A map containing storage changes with the hashed address as key and a set of storage key
+    /// prefixes as the value.
+    changed_storage_prefixes: HashMap<B256, PrefixSet>,
 }
 
 impl<'a, TX> Proof<'a, TX, &'a TX> {
     /// Create a new [Proof] instance.
     pub fn new(tx: &'a TX) -> Self {
-        Self { tx, hashed_cursor_factory: tx }
+        Self {
+            tx,
+            hashed_cursor_factory: tx,
+            changed_account_prefixes: PrefixSetMut::default().freeze(),
+            changed_storage_prefixes: HashMap::default(),
+        }
+    }
+}
+
+impl<'a, TX, H> Proof<'a, TX, H> {
+    /// Set the changed account prefixes.
+    ///
+    /// Must be set if the hashed cursor factory overlays changes on top of the database state, so
+    /// that the trie nodes of the changed accounts are recomputed.
+    pub fn with_changed_account_prefixes(mut self, prefixes: PrefixSet) -> Self {
+        self.changed_account_prefixes = prefixes;
+        self
+    }
+
+    /// Set the changed storage prefixes.
+    pub fn with_changed_storage_prefixes(mut self, prefixes: HashMap<B256, PrefixSet>) -> Self {
+        self.changed_storage_prefixes = prefixes;
+        self
+    }
+
+    /// Set the hashed cursor factory.
+    pub fn with_hashed_cursor_factory<HF>(self, hashed_cursor_factory: HF) -> Proof<'a, TX, HF> {
+        Proof {
+            tx: self.tx,
+            changed_account_prefixes: self.changed_account_prefixes,
+            changed_storage_prefixes: self.changed_storage_prefixes,
+            hashed_cursor_factory,
+        }
     }
 }
 
@@ -55,7 +93,7 @@ where
         let trie_cursor = AccountTrieCursor::new(self.tx.cursor_read::<tables::AccountsTrie>()?);
 
         // Create the walker.
-        let mut prefix_set = PrefixSetMut::default();
+        let mut prefix_set = PrefixSetMut::from(self.changed_account_prefixes.iter().cloned());
         prefix_set.insert(target_nibbles.clone());
         let walker = TrieWalker::new(trie_cursor, prefix_set.freeze());
 
@@ -119,7 +157,13 @@ where
         }
 
         let target_nibbles = proofs.iter().map(|p| p.nibbles.clone()).collect::<Vec<_>>();
-        let prefix_set = PrefixSetMut::from(target_nibbles.clone()).freeze();
+        let changed_prefixes = self
+            .changed_storage_prefixes
+            .get(&hashed_address)
+            .into_iter()
+            .flat_map(|prefix_set| prefix_set.iter().cloned());
+        let prefix_set =
+            PrefixSetMut::from(target_nibbles.iter().cloned().chain(changed_prefixes)).freeze();
         let trie_cursor = StorageTrieCursor::new(
             self.tx.cursor_dup_read::<tables::StoragesTrie>()?,
             hashed_address,
@@ -164,7 +208,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::StateRoot;
+    use crate::{
+        hashed_cursor::{HashedPostState, HashedPostStateCursorFactory},
+        StateRoot,
+    };
     use once_cell::sync::Lazy;
     use reth_db::{database::Database, test_utils::create_test_rw_db};
     use reth_interfaces::RethResult;
@@ -438,4 +485,39 @@ mod tests {
         let account_proof = Proof::new(&tx).account_proof(target, &slots).unwrap();
         pretty_assertions::assert_eq!(account_proof, expected);
     }
+
+    #[test]
+    fn testspec_proofs_with_post_state() {
+        let target = Address::from_str("0x2031f89b3ea8014eb51a78c316e42af3e0d7695f").unwrap();
+        let balance = U256::from(1);
+
+        // The expected proofs are generated from a database that already contains the change.
+        let mut changed_spec = TEST_SPEC.as_ref().clone();
+        changed_spec.genesis.alloc.get_mut(&target).unwrap().balance = balance;
+        let expected_db = create_test_rw_db();
+        insert_genesis(expected_db.clone(), Arc::new(changed_spec)).unwrap();
+        let expected_tx = expected_db.tx().unwrap();
+
+        let db = create_test_rw_db();
+        insert_genesis(db.clone(), TEST_SPEC.clone()).unwrap();
+        let tx = db.tx().unwrap();
+
+        let hashed_address = keccak256(target);
+        let account = tx.get::<tables::HashedAccount>(hashed_address).unwrap().unwrap();
+        let mut hashed_state = HashedPostState::default();
+        hashed_state.insert_account(hashed_address, Account { balance, ..account });
+        let hashed_state = hashed_state.sorted();
+        let (account_prefix_set, storage_prefix_sets) = hashed_state.construct_prefix_sets();
+
+        for address in TEST_SPEC.genesis.alloc.keys() {
+            let expected = Proof::new(&expected_tx).account_proof(*address, &[]).unwrap();
+            let account_proof = Proof::new(&tx)
+                .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(&tx, &hashed_state))
+                .with_changed_account_prefixes(account_prefix_set.clone())
+                .with_changed_storage_prefixes(storage_prefix_sets.clone())
+                .account_proof(*address, &[])
+                .unwrap();
+            pretty_assertions::assert_eq!(account_proof, expected);
+        }
+    }
 }